@@ -0,0 +1,2635 @@
+//! Kotlin data classes and Spring Boot controller interfaces, laid out as a Gradle
+//! project under `src/main/kotlin`.
+
+use anyhow::Result;
+use rayon::prelude::*;
+use std::borrow::Cow;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::path::PathBuf;
+
+use super::{GeneratedFile, LanguageBackend};
+use crate::errors::{self, OpenAPIError};
+use crate::escaping::{identifier, string_literal, unescaped};
+use crate::ir::{
+    ApiModel, DefaultValue, EnumConstant, Field, Model, ModelKind, Operation, Parameter,
+    ParameterLocation, ParameterSerialization, Service, TypeRef, Variant,
+};
+use crate::naming::{camel_case, pascal_case};
+use crate::templates::TemplateEngine;
+use crate::types::{
+    Extensions, GeneratorConfig, KotlinClass, KotlinController, KotlinEnumConstant, KotlinMethod,
+    KotlinParameter, KotlinProperty, ParameterConversion, ParameterType, SpringBootProfile,
+    DELIMITED_ITEM_TYPES,
+};
+
+pub struct KotlinSpringBackend {
+    base_package: String,
+    model_package: String,
+    api_package: String,
+    include_validation: bool,
+    include_swagger: bool,
+    spring_boot: SpringBootProfile,
+    template_engine: TemplateEngine,
+}
+
+impl LanguageBackend for KotlinSpringBackend {
+    fn generate_models(&self, api: &ApiModel) -> Result<Vec<GeneratedFile>> {
+        let api = apply_class_names(api)?;
+        api.models
+            .par_iter()
+            .map(|model| {
+                let kotlin_class = self.convert_model_to_kotlin_class(model)?;
+                Ok(GeneratedFile {
+                    path: self.source_path(&self.model_package, &kotlin_class.name),
+                    content: self.template_engine.generate_kotlin_class(&kotlin_class)?,
+                    description: format!("model: {}", kotlin_class.name),
+                })
+            })
+            .collect()
+    }
+
+    fn generate_services(&self, api: &ApiModel) -> Result<Vec<GeneratedFile>> {
+        let api = apply_class_names(api)?;
+        let kotlin_controllers = api
+            .services
+            .iter()
+            .map(|service| self.convert_service_to_kotlin_controller(service))
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut files = kotlin_controllers
+            .iter()
+            .map(|kotlin_controller| {
+                Ok(GeneratedFile {
+                    path: self.source_path(&self.api_package, &kotlin_controller.name),
+                    content: self
+                        .template_engine
+                        .generate_kotlin_controller(kotlin_controller)?,
+                    description: format!("controller: {}", kotlin_controller.name),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let conversions: BTreeSet<ParameterConversion> = kotlin_controllers
+            .iter()
+            .flat_map(|controller| &controller.methods)
+            .flat_map(|method| &method.parameters)
+            .filter_map(|param| param.conversion.clone())
+            .collect();
+        if !conversions.is_empty() {
+            let conversions: Vec<_> = conversions.into_iter().collect();
+            files.push(GeneratedFile {
+                path: self.source_path(&self.api_package, "ParameterConverters"),
+                content: self.template_engine.generate_parameter_converters(
+                    &self.api_package,
+                    &self.model_package,
+                    &conversions,
+                )?,
+                description: "parameter converters".to_string(),
+            });
+        }
+
+        Ok(files)
+    }
+
+    fn generate_project_files(&self, _api: &ApiModel) -> Result<Vec<GeneratedFile>> {
+        Ok(vec![GeneratedFile {
+            path: PathBuf::from("build.gradle.kts"),
+            content: self
+                .template_engine
+                .generate_build_file(&self.base_package, self.spring_boot)?,
+            description: "build.gradle.kts".to_string(),
+        }])
+    }
+}
+
+/// Names of the vendor extensions customizing the generated Kotlin.
+const NAME: &str = "x-kotlin-name";
+const TYPE: &str = "x-kotlin-type";
+const ANNOTATIONS: &str = "x-kotlin-annotations";
+/// Shorter spelling of `x-kotlin-annotations`
+const ANNOTATIONS_ALIAS: &str = "x-annotations";
+const IMPLEMENTS: &str = "x-kotlin-implements";
+
+/// Extensions that apply to classes, properties, methods and parameters.
+const CLASS_EXTENSIONS: &[&str] = &[NAME, ANNOTATIONS, IMPLEMENTS];
+const PROPERTY_EXTENSIONS: &[&str] = &[NAME, TYPE, ANNOTATIONS];
+const METHOD_EXTENSIONS: &[&str] = &[NAME, ANNOTATIONS];
+const PARAMETER_EXTENSIONS: &[&str] = &[];
+
+/// The `x-kotlin-*` extensions of a schema, property or operation:
+///
+/// - `x-kotlin-name`: the class, property or method name to generate instead of the
+///   derived one. Properties keep their wire name through `@JsonProperty`.
+/// - `x-kotlin-type`: the type of a property, as a type name, or as an object with a
+///   `type` and the `import` it needs.
+/// - `x-kotlin-annotations`, or `x-annotations`: an annotation or list of them to add
+///   to the class, property or method, as written in source, each optionally as an
+///   object with the `annotation` and the `import` it needs.
+/// - `x-kotlin-implements`: an interface or list of them for a class to implement.
+#[derive(Debug, Default)]
+struct KotlinExtensions {
+    name: Option<String>,
+    type_override: Option<TypeOverride>,
+    annotations: Vec<String>,
+    /// Imports the annotations need
+    annotation_imports: Vec<String>,
+    implements: Vec<String>,
+}
+
+#[derive(Debug)]
+struct TypeOverride {
+    kotlin_type: String,
+    import: Option<String>,
+}
+
+impl KotlinExtensions {
+    /// Reads the Kotlin extensions among a declaration's extensions, rejecting those
+    /// that are malformed, unknown or don't apply to it. `path` names the declaration.
+    fn read(
+        extensions: &Extensions,
+        path: &str,
+        applicable: &[&str],
+    ) -> Result<Self, OpenAPIError> {
+        let mut kotlin = Self::default();
+
+        for (key, value) in extensions {
+            let extension = match key.as_str() {
+                ANNOTATIONS_ALIAS => ANNOTATIONS,
+                key if key.starts_with("x-kotlin-") => key,
+                _ => continue,
+            };
+            let invalid = |reason: &str| errors::invalid_extension(key, path, reason);
+
+            if !applicable.contains(&extension) {
+                let known = [NAME, TYPE, ANNOTATIONS, IMPLEMENTS].contains(&extension);
+                let reason = match (known, applicable) {
+                    (true, []) => "no Kotlin extensions apply here".to_string(),
+                    (true, _) => format!("it doesn't apply here, only {}", applicable.join(", ")),
+                    (false, _) => format!(
+                        "unknown Kotlin extension, expected one of {}",
+                        applicable.join(", ")
+                    ),
+                };
+                return Err(invalid(&reason));
+            }
+
+            match extension {
+                NAME => {
+                    let name = value
+                        .as_str()
+                        .filter(|name| is_identifier(name))
+                        .ok_or_else(|| invalid("expected a Kotlin identifier"))?;
+                    kotlin.name = Some(name.to_string());
+                }
+                TYPE => {
+                    kotlin.type_override = Some(TypeOverride::read(value).ok_or_else(|| {
+                        invalid("expected a type name, or an object with a `type` and an `import`")
+                    })?);
+                }
+                ANNOTATIONS => {
+                    let annotations = annotation_list(value).ok_or_else(|| {
+                        invalid(
+                            "expected an annotation, an object with an `annotation` and an \
+                             `import`, or a list of them",
+                        )
+                    })?;
+                    for (annotation, import) in annotations {
+                        kotlin.annotations.push(match annotation.starts_with('@') {
+                            true => annotation,
+                            false => format!("@{annotation}"),
+                        });
+                        kotlin.annotation_imports.extend(import);
+                    }
+                }
+                _ => {
+                    kotlin.implements = string_list(value)
+                        .ok_or_else(|| invalid("expected an interface name or a list of them"))?;
+                }
+            }
+        }
+
+        Ok(kotlin)
+    }
+
+    /// Adds the imports the annotations need to `imports`.
+    fn import_annotations(&self, imports: &mut Vec<String>) {
+        for import in &self.annotation_imports {
+            if !imports.contains(import) {
+                imports.push(import.clone());
+            }
+        }
+    }
+}
+
+impl TypeOverride {
+    fn read(value: &serde_json::Value) -> Option<Self> {
+        let non_empty = |value: &serde_json::Value| {
+            value
+                .as_str()
+                .filter(|text| !text.trim().is_empty())
+                .map(str::to_string)
+        };
+
+        match value {
+            serde_json::Value::Object(object) => {
+                let known = object.keys().all(|key| key == "type" || key == "import");
+                let import = match object.get("import") {
+                    Some(import) => Some(non_empty(import)?),
+                    None => None,
+                };
+                known.then_some(())?;
+                Some(Self {
+                    kotlin_type: non_empty(object.get("type")?)?,
+                    import,
+                })
+            }
+            value => Some(Self {
+                kotlin_type: non_empty(value)?,
+                import: None,
+            }),
+        }
+    }
+}
+
+/// Returns a non-empty string, or a list of them, as a list.
+fn string_list(value: &serde_json::Value) -> Option<Vec<String>> {
+    let values = match value {
+        serde_json::Value::Array(values) => values.iter().collect(),
+        value => vec![value],
+    };
+    values
+        .into_iter()
+        .map(|value| {
+            value
+                .as_str()
+                .filter(|text| !text.trim().is_empty())
+                .map(str::to_string)
+        })
+        .collect()
+}
+
+/// Reads annotations given as text or as `{ annotation, import }` objects, alone or
+/// in a list, paired with their imports.
+fn annotation_list(value: &serde_json::Value) -> Option<Vec<(String, Option<String>)>> {
+    let values = match value {
+        serde_json::Value::Array(values) => values.iter().collect(),
+        value => vec![value],
+    };
+    let non_empty = |value: &serde_json::Value| {
+        value
+            .as_str()
+            .filter(|text| !text.trim().is_empty())
+            .map(str::to_string)
+    };
+
+    values
+        .into_iter()
+        .map(|value| match value {
+            serde_json::Value::Object(object) => {
+                let known = object
+                    .keys()
+                    .all(|key| key == "annotation" || key == "import");
+                known.then_some(())?;
+                let import = match object.get("import") {
+                    Some(import) => Some(non_empty(import)?),
+                    None => None,
+                };
+                Some((non_empty(object.get("annotation")?)?, import))
+            }
+            value => Some((non_empty(value)?, None)),
+        })
+        .collect()
+}
+
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Returns the name code refers to an imported class by, e.g. `Instant` for
+/// `java.time.Instant`.
+fn simple_class_name(class_name: &str) -> &str {
+    class_name.rsplit('.').next().unwrap_or(class_name)
+}
+
+/// Gives models their `x-kotlin-name`, and models and sealed sub types whose names
+/// aren't Kotlin identifiers a name that is, and updates every reference to them.
+/// The API is borrowed as it is when no class is renamed.
+fn apply_class_names(api: &ApiModel) -> Result<Cow<'_, ApiModel>> {
+    let mut names = HashMap::new();
+    for model in &api.models {
+        let path = format!("schema '{}'", model.name);
+        let extensions = KotlinExtensions::read(&model.extensions, &path, CLASS_EXTENSIONS)?;
+        let name = extensions.name.unwrap_or_else(|| identifier(&model.name));
+        if name != model.name {
+            names.insert(model.name.clone(), name);
+        }
+        if let ModelKind::OneOf { variants, .. } = &model.kind {
+            for variant in variants {
+                let name = identifier(&variant.name);
+                if name != variant.name {
+                    names.insert(variant.name.clone(), name);
+                }
+            }
+        }
+    }
+    if names.is_empty() {
+        return Ok(Cow::Borrowed(api));
+    }
+    let mut taken: HashSet<&str> = api
+        .models
+        .iter()
+        .filter(|model| !names.contains_key(&model.name))
+        .map(|model| model.name.as_str())
+        .collect();
+
+    // Renamed classes can't take the name of another class
+    for model in &api.models {
+        let Some(name) = names.get(&model.name) else {
+            continue;
+        };
+        if !taken.insert(name) {
+            let path = format!("schema '{}'", model.name);
+            let reason = format!("'{name}' is already the name of another class");
+            return Err(errors::invalid_extension(NAME, path, reason).into());
+        }
+    }
+
+    let rename = |name: &mut String| {
+        if let Some(new_name) = names.get(name.as_str()) {
+            *name = new_name.clone();
+        }
+    };
+    let rename_fields = |fields: &mut Vec<Field>| {
+        for field in fields {
+            rename_type(&mut field.type_ref, &names);
+            if let Some(DefaultValue::EnumConstant { enum_name, .. }) = &mut field.default {
+                rename(enum_name);
+            }
+        }
+    };
+
+    let mut api = api.clone();
+    for model in &mut api.models {
+        rename(&mut model.name);
+        match &mut model.kind {
+            ModelKind::Object { fields } => rename_fields(fields),
+            ModelKind::Enum { .. } => {}
+            ModelKind::OneOf {
+                fields, variants, ..
+            } => {
+                rename_fields(fields);
+                for variant in variants {
+                    rename(&mut variant.name);
+                    rename_fields(&mut variant.fields);
+                }
+            }
+            ModelKind::AnyOf { variants } => variants.iter_mut().for_each(rename),
+        }
+    }
+    for operation in api
+        .services
+        .iter_mut()
+        .flat_map(|service| &mut service.operations)
+    {
+        for param in &mut operation.parameters {
+            rename_type(&mut param.type_ref, &names);
+        }
+        if let Some(body) = &mut operation.request_body {
+            rename_type(&mut body.type_ref, &names);
+        }
+        if let Some(response) = &mut operation.response {
+            rename_type(response, &names);
+        }
+    }
+
+    Ok(Cow::Owned(api))
+}
+
+fn rename_type(type_ref: &mut TypeRef, names: &HashMap<String, String>) {
+    match type_ref {
+        TypeRef::Model(name) | TypeRef::Enum(name) => {
+            if let Some(new_name) = names.get(name.as_str()) {
+                *name = new_name.clone();
+            }
+        }
+        TypeRef::List(inner) | TypeRef::Map(inner) => rename_type(inner, names),
+        _ => {}
+    }
+}
+
+impl KotlinSpringBackend {
+    pub fn new(config: &GeneratorConfig) -> Self {
+        Self {
+            base_package: config.base_package.clone(),
+            model_package: config.model_package(),
+            api_package: config.api_package(),
+            include_validation: config.include_validation,
+            include_swagger: config.include_swagger,
+            spring_boot: config.spring_boot,
+            template_engine: TemplateEngine::with_overrides(
+                config.include_validation,
+                config.include_swagger,
+                &config.templates,
+            ),
+        }
+    }
+
+    /// Returns the path of a Kotlin source file in the directory of its package.
+    fn source_path(&self, package: &str, class_name: &str) -> PathBuf {
+        let package_path: PathBuf = package.split('.').collect();
+        PathBuf::from("src/main/kotlin")
+            .join(package_path)
+            .join(format!("{class_name}.kt"))
+    }
+
+    fn convert_model_to_kotlin_class(&self, model: &Model) -> Result<KotlinClass> {
+        let mut kotlin_class = match &model.kind {
+            ModelKind::Object { fields } => {
+                let mut kotlin_class = self.new_kotlin_class(model, self.get_base_model_imports());
+                kotlin_class.properties = self.convert_fields_to_kotlin_properties(
+                    &model.name,
+                    fields,
+                    &mut kotlin_class.imports,
+                )?;
+                kotlin_class
+            }
+            ModelKind::Enum {
+                value_type,
+                constants,
+            } => self.convert_enum_to_kotlin_enum(model, value_type, constants),
+            ModelKind::OneOf {
+                discriminator,
+                fields,
+                variants,
+            } => self.convert_one_of_to_sealed_class(model, discriminator, fields, variants)?,
+            ModelKind::AnyOf { .. } => self.convert_any_of_to_union_type(model),
+        };
+
+        let path = format!("schema '{}'", model.name);
+        let extensions = KotlinExtensions::read(&model.extensions, &path, CLASS_EXTENSIONS)?;
+        extensions.import_annotations(&mut kotlin_class.imports);
+        kotlin_class.annotations = extensions.annotations;
+        kotlin_class.interfaces = extensions.implements;
+        Ok(kotlin_class)
+    }
+
+    fn new_kotlin_class(&self, model: &Model, imports: Vec<String>) -> KotlinClass {
+        KotlinClass {
+            name: model.name.clone(),
+            package_name: self.model_package.clone(),
+            description: model.description.clone(),
+            properties: Vec::new(),
+            imports,
+            is_sealed: None,
+            sealed_sub_types: None,
+            parent_class: None,
+            discriminator_property: None,
+            discriminator_value: None,
+            enum_constants: None,
+            annotations: Vec::new(),
+            interfaces: Vec::new(),
+        }
+    }
+
+    fn convert_fields_to_kotlin_properties(
+        &self,
+        owner: &str,
+        fields: &[Field],
+        imports: &mut Vec<String>,
+    ) -> Result<Vec<KotlinProperty>> {
+        fields
+            .iter()
+            .map(|field| self.convert_field_to_kotlin_property(owner, field, imports))
+            .collect()
+    }
+
+    fn convert_one_of_to_sealed_class(
+        &self,
+        model: &Model,
+        discriminator: &Option<String>,
+        fields: &[Field],
+        variants: &[Variant],
+    ) -> Result<KotlinClass> {
+        let mut imports = self.get_base_model_imports();
+        imports.extend_from_slice(&[
+            "com.fasterxml.jackson.annotation.JsonSubTypes".to_string(),
+            "com.fasterxml.jackson.annotation.JsonTypeInfo".to_string(),
+        ]);
+
+        // The discriminator is written and consumed by Jackson through @JsonTypeInfo,
+        // so it is not a field.
+        let mut kotlin_class = self.new_kotlin_class(model, imports);
+        kotlin_class.is_sealed = Some(true);
+        kotlin_class.discriminator_property = discriminator.clone();
+        kotlin_class.properties = self.convert_fields_to_kotlin_properties(
+            &model.name,
+            fields,
+            &mut kotlin_class.imports,
+        )?;
+
+        let mut sub_types = Vec::new();
+        for variant in variants {
+            let path = format!("schema '{}'", variant.name);
+            let extensions = KotlinExtensions::read(&variant.extensions, &path, CLASS_EXTENSIONS)?;
+            let mut imports = kotlin_class.imports.clone();
+            extensions.import_annotations(&mut imports);
+            let mut sub_class = KotlinClass {
+                name: variant.name.clone(),
+                package_name: kotlin_class.package_name.clone(),
+                description: variant.description.clone(),
+                properties: Vec::new(),
+                imports,
+                is_sealed: None,
+                sealed_sub_types: None,
+                parent_class: Some(kotlin_class.name.clone()),
+                discriminator_property: None,
+                discriminator_value: variant.discriminator_value.clone(),
+                enum_constants: None,
+                annotations: extensions.annotations,
+                interfaces: extensions.implements,
+            };
+
+            // Subtypes override the abstract properties declared on the sealed parent
+            sub_class
+                .properties
+                .extend(kotlin_class.properties.iter().cloned());
+
+            // Add variant-specific properties
+            for field in &variant.fields {
+                let property = self.convert_field_to_kotlin_property(
+                    &variant.name,
+                    field,
+                    &mut sub_class.imports,
+                )?;
+                if sub_class.properties.iter().any(|p| p.name == property.name) {
+                    continue;
+                }
+
+                sub_class.properties.push(property);
+            }
+
+            // Subtypes are nested in the parent's file, so it needs their imports too
+            for import in &sub_class.imports {
+                if !kotlin_class.imports.contains(import) {
+                    kotlin_class.imports.push(import.clone());
+                }
+            }
+
+            sub_types.push(sub_class);
+        }
+
+        kotlin_class.sealed_sub_types = Some(sub_types);
+        Ok(kotlin_class)
+    }
+
+    fn convert_enum_to_kotlin_enum(
+        &self,
+        model: &Model,
+        value_type: &TypeRef,
+        constants: &[EnumConstant],
+    ) -> KotlinClass {
+        let value_type = self.kotlin_type(value_type);
+
+        let enum_constants = constants
+            .iter()
+            .map(|constant| match &constant.value {
+                serde_json::Value::String(s) => KotlinEnumConstant {
+                    json_property: (constant.name != *s).then(|| s.clone()),
+                    name: identifier(&constant.name),
+                    value: string_literal(s),
+                },
+                value if value_type == "Long" => KotlinEnumConstant {
+                    name: identifier(&constant.name),
+                    value: format!("{value}L"),
+                    json_property: None,
+                },
+                value => KotlinEnumConstant {
+                    name: identifier(&constant.name),
+                    value: value.to_string(),
+                    json_property: None,
+                },
+            })
+            .collect();
+
+        // String values map through @JsonProperty; other values need @JsonValue
+        let jackson_import = if value_type == "String" {
+            "com.fasterxml.jackson.annotation.JsonProperty"
+        } else {
+            "com.fasterxml.jackson.annotation.JsonValue"
+        };
+
+        let value_property = KotlinProperty {
+            name: "value".to_string(),
+            kotlin_type: value_type,
+            nullable: false,
+            default_value: None,
+            example: None,
+            description: None,
+            validation: Vec::new(),
+            json_property: None,
+            annotations: Vec::new(),
+        };
+
+        // The class header carries @Schema when Swagger annotations are on
+        let mut imports = vec![jackson_import.to_string()];
+        if self.include_swagger {
+            imports.push("io.swagger.v3.oas.annotations.media.Schema".to_string());
+        }
+
+        let mut kotlin_class = self.new_kotlin_class(model, imports);
+        kotlin_class.properties = vec![value_property];
+        kotlin_class.enum_constants = Some(enum_constants);
+        kotlin_class
+    }
+
+    fn convert_any_of_to_union_type(&self, model: &Model) -> KotlinClass {
+        let mut imports = self.get_base_model_imports();
+        imports.extend_from_slice(&[
+            "com.fasterxml.jackson.annotation.JsonValue".to_string(),
+            "com.fasterxml.jackson.annotation.JsonCreator".to_string(),
+        ]);
+
+        let mut kotlin_class = self.new_kotlin_class(model, imports);
+
+        // For anyOf, we create a wrapper class that can hold any of the variant types
+        // Add a value property that can hold the actual data
+        let value_property = KotlinProperty {
+            name: "value".to_string(),
+            kotlin_type: "Any".to_string(),
+            nullable: false,
+            default_value: None,
+            example: None,
+            description: Some(
+                "The actual value that matches one or more of the anyOf variants".to_string(),
+            ),
+            validation: vec!["@JsonValue".to_string()],
+            json_property: None,
+            annotations: Vec::new(),
+        };
+        kotlin_class.properties.push(value_property);
+
+        // Add a type property to indicate which variant types are satisfied
+        let type_property = KotlinProperty {
+            name: "supportedTypes".to_string(),
+            kotlin_type: "Set<String>".to_string(),
+            nullable: false,
+            default_value: Some("emptySet()".to_string()),
+            example: None,
+            description: Some("Set of type names that this value satisfies".to_string()),
+            validation: Vec::new(),
+            json_property: None,
+            annotations: Vec::new(),
+        };
+        kotlin_class.properties.push(type_property);
+
+        kotlin_class
+    }
+
+    /// Converts a field of the named class to a property, adding the imports its type
+    /// needs.
+    fn convert_field_to_kotlin_property(
+        &self,
+        owner: &str,
+        field: &Field,
+        imports: &mut Vec<String>,
+    ) -> Result<KotlinProperty> {
+        let path = format!("property '{owner}.{}'", field.name);
+        let extensions = KotlinExtensions::read(&field.extensions, &path, PROPERTY_EXTENSIONS)?;
+        extensions.import_annotations(imports);
+
+        let kotlin_name = identifier(&extensions.name.unwrap_or_else(|| camel_case(&field.name)));
+        let kotlin_type = match extensions.type_override {
+            Some(TypeOverride {
+                kotlin_type,
+                import,
+            }) => {
+                if let Some(import) = import.filter(|import| !imports.contains(import)) {
+                    imports.push(import);
+                }
+                kotlin_type
+            }
+            None => self.imported_kotlin_type(&field.type_ref, imports),
+        };
+        let nullable = field.nullable || !field.required;
+
+        let default_value = match &field.default {
+            Some(DefaultValue::EnumConstant {
+                enum_name,
+                constant,
+                ..
+            }) => Some(format!("{enum_name}.{constant}")),
+            Some(DefaultValue::Literal(value)) => self
+                .format_default_value(value, &kotlin_type)
+                .or_else(|| nullable.then(|| "null".to_string())),
+            None => nullable.then(|| "null".to_string()),
+        };
+        let example = match field.default.as_ref().map(DefaultValue::value) {
+            None | Some(serde_json::Value::Null) => None,
+            Some(serde_json::Value::String(s)) => Some(s.clone()),
+            Some(value) => Some(value.to_string()),
+        };
+
+        Ok(KotlinProperty {
+            json_property: (unescaped(&kotlin_name) != field.name).then(|| field.name.clone()),
+            name: kotlin_name,
+            kotlin_type,
+            nullable,
+            default_value,
+            example,
+            description: field.description.clone(),
+            validation: if self.include_validation {
+                self.generate_validation_annotations(field)
+            } else {
+                Vec::new()
+            },
+            annotations: extensions.annotations,
+        })
+    }
+
+    fn kotlin_type(&self, type_ref: &TypeRef) -> String {
+        if let Some(class_name) = self.qualified_class_name(type_ref) {
+            return simple_class_name(class_name).to_string();
+        }
+
+        match type_ref {
+            TypeRef::String => "String".to_string(),
+            TypeRef::Int32 => "Int".to_string(),
+            TypeRef::Int64 => "Long".to_string(),
+            TypeRef::Float => "Float".to_string(),
+            TypeRef::Double => "Double".to_string(),
+            TypeRef::Boolean => "Boolean".to_string(),
+            TypeRef::Binary => "ByteArray".to_string(),
+            TypeRef::List(items) => format!("List<{}>", self.kotlin_type(items)),
+            TypeRef::Map(values) => format!("Map<String, {}>", self.kotlin_type(values)),
+            TypeRef::Model(name) | TypeRef::Enum(name) => name.clone(),
+            _ => "Any".to_string(),
+        }
+    }
+
+    /// Returns the qualified name of the class a type maps to when code refers to it
+    /// by an imported simple name.
+    fn qualified_class_name<'a>(&self, type_ref: &'a TypeRef) -> Option<&'a str> {
+        match type_ref {
+            TypeRef::Decimal => Some("java.math.BigDecimal"),
+            TypeRef::Date => Some("java.time.LocalDate"),
+            TypeRef::DateTime => Some("java.time.OffsetDateTime"),
+            TypeRef::Uuid => Some("java.util.UUID"),
+            TypeRef::Uri => Some("java.net.URI"),
+            TypeRef::External(class_name) => Some(class_name),
+            _ => None,
+        }
+    }
+
+    /// Returns the Kotlin type of a type, adding the imports it needs.
+    fn imported_kotlin_type(&self, type_ref: &TypeRef, imports: &mut Vec<String>) -> String {
+        self.add_imports_for_type(type_ref, imports);
+        self.kotlin_type(type_ref)
+    }
+
+    /// Returns the Kotlin type of a type in a controller signature, adding the
+    /// imports it needs, including those of models when they live in another package.
+    fn controller_kotlin_type(&self, type_ref: &TypeRef, imports: &mut Vec<String>) -> String {
+        self.add_model_imports(type_ref, imports);
+        self.imported_kotlin_type(type_ref, imports)
+    }
+
+    fn add_model_imports(&self, type_ref: &TypeRef, imports: &mut Vec<String>) {
+        match type_ref {
+            TypeRef::List(inner) | TypeRef::Map(inner) => self.add_model_imports(inner, imports),
+            TypeRef::Model(name) | TypeRef::Enum(name)
+                if self.model_package != self.api_package =>
+            {
+                let import = format!("{}.{name}", self.model_package);
+                if !imports.contains(&import) {
+                    imports.push(import);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn generate_validation_annotations(&self, field: &Field) -> Vec<String> {
+        let mut annotations = Vec::new();
+        let constraints = &field.constraints;
+
+        if field.required && !field.nullable {
+            annotations.push("@NotNull".to_string());
+        }
+        if constraints.email {
+            annotations.push("@Email".to_string());
+        }
+        if constraints.min_length.is_some() || constraints.max_length.is_some() {
+            annotations.push(self.size_annotation(constraints.min_length, constraints.max_length));
+        }
+        if let Some(pattern) = &constraints.pattern {
+            annotations.push(format!("@Pattern(regexp = {})", string_literal(pattern)));
+        }
+        match constraints.minimum {
+            Some(minimum) if constraints.exclusive_minimum => annotations.push(format!(
+                "@DecimalMin(value = \"{minimum}\", inclusive = false)"
+            )),
+            Some(minimum) => annotations.push(format!("@Min({})", minimum as i64)),
+            None => {}
+        }
+        match constraints.maximum {
+            Some(maximum) if constraints.exclusive_maximum => annotations.push(format!(
+                "@DecimalMax(value = \"{maximum}\", inclusive = false)"
+            )),
+            Some(maximum) => annotations.push(format!("@Max({})", maximum as i64)),
+            None => {}
+        }
+        if constraints.min_items.is_some() || constraints.max_items.is_some() {
+            annotations.push(self.size_annotation(constraints.min_items, constraints.max_items));
+        }
+        if constraints.validate_nested {
+            annotations.push("@Valid".to_string());
+        }
+
+        annotations
+    }
+
+    fn size_annotation(&self, min: Option<u32>, max: Option<u32>) -> String {
+        let min = min.unwrap_or(0);
+        let max = max.map_or("Integer.MAX_VALUE".to_string(), |v| v.to_string());
+        format!("@Size(min = {min}, max = {max})")
+    }
+
+    fn convert_service_to_kotlin_controller(&self, service: &Service) -> Result<KotlinController> {
+        let name = identifier(&pascal_case(&service.name));
+        let mut imports = self.get_base_controller_imports();
+        let methods = service
+            .operations
+            .iter()
+            .map(|operation| self.convert_operation_to_kotlin_method(operation, &mut imports))
+            .collect::<Result<_>>()?;
+
+        Ok(KotlinController {
+            name: format!("{name}Controller"),
+            package_name: self.api_package.clone(),
+            description: Some(format!("{name} API controller interface")),
+            methods,
+            imports,
+        })
+    }
+
+    /// Converts an operation to a controller method, adding the imports its types
+    /// need.
+    fn convert_operation_to_kotlin_method(
+        &self,
+        operation: &Operation,
+        imports: &mut Vec<String>,
+    ) -> Result<KotlinMethod> {
+        let path = format!("operation '{}'", operation.name);
+        let extensions = KotlinExtensions::read(&operation.extensions, &path, METHOD_EXTENSIONS)?;
+        extensions.import_annotations(imports);
+
+        let request_body = operation.request_body.as_ref().map(|body| KotlinParameter {
+            name: "body".to_string(),
+            wire_name: None,
+            kotlin_type: self.controller_kotlin_type(&body.type_ref, imports),
+            param_type: ParameterType::Body,
+            conversion: None,
+            required: body.required,
+            description: body.description.clone(),
+            validation: if self.include_validation {
+                vec!["@Valid".to_string()]
+            } else {
+                Vec::new()
+            },
+        });
+
+        let parameters = operation
+            .parameters
+            .iter()
+            .map(|param| self.convert_parameter_to_kotlin(&operation.name, param, imports))
+            .collect::<Result<_>>()?;
+
+        Ok(KotlinMethod {
+            name: identifier(&extensions.name.unwrap_or_else(|| operation.name.clone())),
+            http_method: operation.http_method.clone(),
+            path: operation.path.clone(),
+            summary: operation.summary.clone(),
+            description: operation.description.clone(),
+            parameters,
+            request_body,
+            return_type: self.return_type(operation.response.as_ref(), imports),
+            response_description: operation.response_description.clone(),
+            annotations: extensions.annotations,
+        })
+    }
+
+    fn return_type(&self, response: Option<&TypeRef>, imports: &mut Vec<String>) -> String {
+        let inner_type = response.map_or("Any".to_string(), |type_ref| {
+            self.controller_kotlin_type(type_ref, imports)
+        });
+        format!("ResponseEntity<{inner_type}>")
+    }
+
+    fn convert_parameter_to_kotlin(
+        &self,
+        operation: &str,
+        param: &Parameter,
+        imports: &mut Vec<String>,
+    ) -> Result<KotlinParameter> {
+        let path = format!("parameter '{}' of operation '{operation}'", param.name);
+        KotlinExtensions::read(&param.extensions, &path, PARAMETER_EXTENSIONS)?;
+
+        let param_type = match param.location {
+            ParameterLocation::Path => ParameterType::Path,
+            ParameterLocation::Query => ParameterType::Query,
+            ParameterLocation::Header => ParameterType::Header,
+            ParameterLocation::Cookie => ParameterType::Cookie,
+        };
+
+        let (kotlin_type, conversion) = match &param.serialization {
+            ParameterSerialization::Content(media_type) => {
+                self.map_parameter_content_to_kotlin_type(media_type, &param.type_ref, imports)
+            }
+            ParameterSerialization::Style { style, explode }
+                if param.location == ParameterLocation::Query =>
+            {
+                self.map_query_parameter_to_kotlin_type(&param.type_ref, style, *explode, imports)
+            }
+            ParameterSerialization::Style { .. } => {
+                (self.controller_kotlin_type(&param.type_ref, imports), None)
+            }
+        };
+
+        let validation = if self.include_validation && param.required {
+            vec!["@NotNull".to_string()]
+        } else {
+            Vec::new()
+        };
+
+        let name = identifier(&camel_case(&param.name));
+        Ok(KotlinParameter {
+            wire_name: (unescaped(&name) != param.name).then(|| param.name.clone()),
+            name,
+            kotlin_type,
+            param_type,
+            conversion,
+            required: param.required,
+            description: param.description.clone(),
+            validation,
+        })
+    }
+
+    /// Maps a query parameter to a Kotlin type, taking its serialization style into
+    /// account.
+    ///
+    /// Spring binds `form` arrays natively. Delimited arrays that aren't exploded are
+    /// bound through generated converters, and `deepObject` or exploded `form` objects
+    /// through a `Map` of all query parameters.
+    fn map_query_parameter_to_kotlin_type(
+        &self,
+        type_ref: &TypeRef,
+        style: &str,
+        explode: bool,
+        imports: &mut Vec<String>,
+    ) -> (String, Option<ParameterConversion>) {
+        let is_object = matches!(type_ref, TypeRef::Map(_) | TypeRef::Model(_));
+
+        let delimited = match (type_ref, style, explode) {
+            (TypeRef::List(items), "spaceDelimited", false) => Some(
+                ParameterConversion::SpaceDelimited(self.delimited_item_type(items)),
+            ),
+            (TypeRef::List(items), "pipeDelimited", false) => Some(
+                ParameterConversion::PipeDelimited(self.delimited_item_type(items)),
+            ),
+            _ => None,
+        };
+        if let Some(list) = delimited
+            .as_ref()
+            .and_then(ParameterConversion::delimited_list)
+        {
+            return (list.class_name, delimited);
+        }
+
+        match (type_ref, style, explode) {
+            (_, "deepObject", _) | (_, "form", true) if is_object => {
+                ("Map<String, String>".to_string(), None)
+            }
+            // A non-exploded form object arrives as a single comma separated value
+            (_, "form", false) if is_object => ("String".to_string(), None),
+            _ => (self.controller_kotlin_type(type_ref, imports), None),
+        }
+    }
+
+    /// Returns the Kotlin type the items of a delimited array are parsed into, falling
+    /// back to `String` for items that can't be parsed from text.
+    fn delimited_item_type(&self, items: &TypeRef) -> String {
+        let item_type = self.kotlin_type(items);
+        if DELIMITED_ITEM_TYPES
+            .iter()
+            .any(|(name, _, _)| *name == item_type)
+        {
+            item_type
+        } else {
+            "String".to_string()
+        }
+    }
+
+    /// Maps a parameter defined with `content` to a Kotlin type. A JSON value that
+    /// maps to a generated class is deserialized by a generated converter, other
+    /// values are passed on as they were sent.
+    fn map_parameter_content_to_kotlin_type(
+        &self,
+        media_type: &str,
+        type_ref: &TypeRef,
+        imports: &mut Vec<String>,
+    ) -> (String, Option<ParameterConversion>) {
+        match type_ref {
+            TypeRef::Model(class_name) | TypeRef::Enum(class_name)
+                if media_type.contains("json") =>
+            {
+                self.add_model_imports(type_ref, imports);
+                (
+                    class_name.clone(),
+                    Some(ParameterConversion::Json(class_name.clone())),
+                )
+            }
+            _ => ("String".to_string(), None),
+        }
+    }
+
+    fn get_base_model_imports(&self) -> Vec<String> {
+        let validation = self.spring_boot.validation_package();
+        let mut imports = vec![
+            format!("{validation}.constraints.*"),
+            format!("{validation}.Valid"),
+            "com.fasterxml.jackson.annotation.JsonProperty".to_string(),
+        ];
+
+        if self.include_swagger {
+            imports.push("io.swagger.v3.oas.annotations.media.Schema".to_string());
+        }
+
+        imports
+    }
+
+    fn get_base_controller_imports(&self) -> Vec<String> {
+        let validation = self.spring_boot.validation_package();
+        let mut imports = vec![
+            "org.springframework.http.ResponseEntity".to_string(),
+            "org.springframework.web.bind.annotation.*".to_string(),
+            format!("{validation}.Valid"),
+            format!("{validation}.constraints.*"),
+        ];
+
+        if self.include_swagger {
+            imports.extend([
+                "io.swagger.v3.oas.annotations.Operation".to_string(),
+                "io.swagger.v3.oas.annotations.responses.ApiResponse".to_string(),
+                "io.swagger.v3.oas.annotations.responses.ApiResponses".to_string(),
+            ]);
+        }
+
+        imports
+    }
+
+    fn add_imports_for_type(&self, type_ref: &TypeRef, imports: &mut Vec<String>) {
+        match type_ref {
+            TypeRef::List(inner) | TypeRef::Map(inner) => self.add_imports_for_type(inner, imports),
+            _ => {
+                let import = self
+                    .qualified_class_name(type_ref)
+                    .filter(|class_name| class_name.contains('.'));
+                if let Some(import) = import.filter(|import| !imports.iter().any(|i| i == import)) {
+                    imports.push(import.to_string());
+                }
+            }
+        }
+    }
+
+    /// Returns the Kotlin expression a property of the given type is initialized with,
+    /// or `None` when a string default can't be expressed in that type.
+    fn format_default_value(&self, value: &serde_json::Value, kotlin_type: &str) -> Option<String> {
+        match value {
+            serde_json::Value::Null => Some("null".to_string()),
+            serde_json::Value::String(s) => match kotlin_type {
+                "String" => Some(string_literal(s)),
+                "UUID" => Some(format!("UUID.fromString({})", string_literal(s))),
+                "OffsetDateTime" | "LocalDate" => {
+                    Some(format!("{kotlin_type}.parse({})", string_literal(s)))
+                }
+                _ => None,
+            },
+            serde_json::Value::Bool(b) => Some(b.to_string()),
+            serde_json::Value::Number(n) => Some(n.to_string()),
+            _ => Some(value.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::field_reassign_with_default)]
+mod tests {
+    use super::*;
+    use crate::config::OperationFilters;
+    use crate::generator::OpenAPICodeGenerator;
+    use crate::ir::{Constraints, RequestBody};
+    use crate::mappings::TypeMappings;
+    use crate::templates::TemplateOverrides;
+    use crate::types::*;
+    use indexmap::IndexMap;
+    use serde_json::json;
+
+    fn create_test_config() -> GeneratorConfig {
+        GeneratorConfig {
+            base_package: "com.example.api".to_string(),
+            model_package: None,
+            api_package: None,
+            output_dir: PathBuf::from("/tmp/test_output"),
+            generate_models: true,
+            generate_controllers: true,
+            include_validation: true,
+            include_swagger: true,
+            verbose: false,
+            inline_naming: InlineNamingStrategy::ParentAndProperty,
+            target: TargetLanguage::Kotlin,
+            spring_boot: SpringBootProfile::default(),
+            type_mappings: TypeMappings::default(),
+            templates: TemplateOverrides::default(),
+            filters: OperationFilters::default(),
+        }
+    }
+
+    fn create_test_backend() -> KotlinSpringBackend {
+        KotlinSpringBackend::new(&create_test_config())
+    }
+
+    /// Converts a schema to the Kotlin classes generated for it, through the model
+    /// the generator builds.
+    fn kotlin_classes(name: &str, schema: OpenAPISchema) -> Vec<KotlinClass> {
+        let generator = OpenAPICodeGenerator::new(create_test_config());
+        let backend = create_test_backend();
+
+        generator
+            .convert_schema_to_models(name, Box::new(schema))
+            .unwrap()
+            .iter()
+            .map(|model| backend.convert_model_to_kotlin_class(model).unwrap())
+            .collect()
+    }
+
+    fn kotlin_class(name: &str, schema: OpenAPISchema) -> KotlinClass {
+        kotlin_classes(name, schema).remove(0)
+    }
+
+    fn kotlin_property(name: &str, schema: &OpenAPISchema, required: &[String]) -> KotlinProperty {
+        let generator = OpenAPICodeGenerator::new(create_test_config());
+        let field = generator
+            .convert_schema_to_field(name, schema, required)
+            .unwrap();
+
+        create_test_backend()
+            .convert_field_to_kotlin_property("Owner", &field, &mut Vec::new())
+            .unwrap()
+    }
+
+    fn kotlin_parameter(param: &OpenAPIParameter) -> KotlinParameter {
+        let generator = OpenAPICodeGenerator::new(create_test_config());
+        let param = generator.convert_parameter(param).unwrap();
+
+        create_test_backend()
+            .convert_parameter_to_kotlin("operation", &param, &mut Vec::new())
+            .unwrap()
+    }
+
+    fn kotlin_method(path: &str, http_method: &str, operation: &OpenAPIOperation) -> KotlinMethod {
+        let generator = OpenAPICodeGenerator::new(create_test_config());
+        let operation = generator
+            .convert_operation(path, http_method, operation)
+            .unwrap();
+
+        create_test_backend()
+            .convert_operation_to_kotlin_method(&operation, &mut Vec::new())
+            .unwrap()
+    }
+
+    fn validation_annotations(schema: &OpenAPISchema, required: bool) -> Vec<String> {
+        let required = if required {
+            vec!["value".to_string()]
+        } else {
+            Vec::new()
+        };
+        kotlin_property("value", schema, &required).validation
+    }
+
+    fn string_field(name: &str) -> Field {
+        Field {
+            name: name.to_string(),
+            type_ref: TypeRef::String,
+            required: true,
+            nullable: false,
+            default: None,
+            description: None,
+            constraints: Constraints::default(),
+            extensions: Extensions::new(),
+        }
+    }
+
+    fn extensions(value: serde_json::Value) -> Extensions {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_kotlin_extensions_customize_classes_and_properties() {
+        let mut schema = OpenAPISchema {
+            schema_type: Some("object".into()),
+            required: vec!["born".to_string()],
+            extensions: extensions(json!({
+                "x-kotlin-annotations": "JsonIgnoreProperties(ignoreUnknown = true)",
+                "x-kotlin-implements": ["java.io.Serializable", "Auditable"],
+            })),
+            ..Default::default()
+        };
+        schema.properties.insert(
+            "born".to_string(),
+            OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
+                schema_type: Some("string".into()),
+                format: Some("date-time".to_string()),
+                extensions: extensions(json!({
+                    "x-kotlin-type": { "type": "Instant", "import": "java.time.Instant" },
+                })),
+                ..Default::default()
+            })),
+        );
+        schema.properties.insert(
+            "class".to_string(),
+            OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
+                schema_type: Some("string".into()),
+                extensions: extensions(json!({
+                    "x-kotlin-name": "kind",
+                    "x-annotations": [
+                        {
+                            "annotation": "@JsonIgnore",
+                            "import": "com.fasterxml.jackson.annotation.JsonIgnore",
+                        },
+                        "@Transient",
+                    ],
+                })),
+                ..Default::default()
+            })),
+        );
+
+        let kotlin_class = kotlin_class("Pet", schema);
+
+        assert_eq!(
+            kotlin_class.annotations,
+            vec!["@JsonIgnoreProperties(ignoreUnknown = true)".to_string()]
+        );
+        assert_eq!(
+            kotlin_class.interfaces,
+            ["java.io.Serializable", "Auditable"]
+        );
+
+        let born = &kotlin_class.properties[0];
+        assert_eq!(born.kotlin_type, "Instant");
+        assert!(kotlin_class
+            .imports
+            .contains(&"java.time.Instant".to_string()));
+        assert!(!kotlin_class
+            .imports
+            .contains(&"java.time.OffsetDateTime".to_string()));
+
+        let kind = &kotlin_class.properties[1];
+        assert_eq!(kind.name, "kind");
+        assert_eq!(kind.json_property, Some("class".to_string()));
+        assert_eq!(kind.annotations, ["@JsonIgnore", "@Transient"]);
+        assert!(kotlin_class
+            .imports
+            .contains(&"com.fasterxml.jackson.annotation.JsonIgnore".to_string()));
+    }
+
+    #[test]
+    fn test_kotlin_name_renames_classes_and_their_references() {
+        let backend = create_test_backend();
+        let mut pet = string_field("pet");
+        pet.type_ref = TypeRef::List(Box::new(TypeRef::Model("Pet".to_string())));
+        let api = ApiModel {
+            title: "Test API".to_string(),
+            version: "1.0.0".to_string(),
+            models: vec![
+                Model {
+                    name: "Pet".to_string(),
+                    description: None,
+                    kind: ModelKind::Object {
+                        fields: vec![string_field("name")],
+                    },
+                    extensions: extensions(json!({ "x-kotlin-name": "PetDto" })),
+                },
+                Model {
+                    name: "Owner".to_string(),
+                    description: None,
+                    kind: ModelKind::Object { fields: vec![pet] },
+                    extensions: Extensions::new(),
+                },
+            ],
+            services: vec![Service {
+                name: "pets".to_string(),
+                operations: vec![Operation {
+                    name: "getPet".to_string(),
+                    http_method: "get".to_string(),
+                    path: "/pets".to_string(),
+                    summary: None,
+                    description: None,
+                    parameters: Vec::new(),
+                    request_body: None,
+                    response: Some(TypeRef::Model("Pet".to_string())),
+                    response_description: None,
+                    extensions: extensions(json!({
+                        "x-kotlin-name": "findPet",
+                        "x-kotlin-annotations": ["@Deprecated(\"Use search\")"],
+                    })),
+                }],
+            }],
+        };
+
+        let models = backend.generate_models(&api).unwrap();
+        assert_eq!(models[0].description, "model: PetDto");
+        assert!(models[1].content.contains("val pet: List<PetDto>"));
+
+        let controller = &backend.generate_services(&api).unwrap()[0].content;
+        assert!(controller.contains(
+            "    @Deprecated(\"Use search\")\n    @GetMapping(\"/pets\")\n    fun findPet("
+        ));
+        assert!(controller.contains("): ResponseEntity<PetDto>"));
+
+        // A class can't be renamed to the name of another
+        let mut api = api;
+        api.models[0].extensions = extensions(json!({ "x-kotlin-name": "Owner" }));
+        let error = backend.generate_models(&api).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid extension 'x-kotlin-name' on schema 'Pet': 'Owner' is already the name of another class"
+        );
+    }
+
+    #[test]
+    fn test_malformed_kotlin_extensions() {
+        let error = |value: serde_json::Value, applicable: &[&str]| {
+            KotlinExtensions::read(&extensions(value), "property 'Pet.name'", applicable)
+                .unwrap_err()
+                .to_string()
+        };
+
+        assert_eq!(
+            error(json!({ "x-kotlin-name": "first name" }), PROPERTY_EXTENSIONS),
+            "Invalid extension 'x-kotlin-name' on property 'Pet.name': expected a Kotlin identifier"
+        );
+        assert_eq!(
+            error(json!({ "x-kotlin-type": { "name": "Instant" } }), PROPERTY_EXTENSIONS),
+            "Invalid extension 'x-kotlin-type' on property 'Pet.name': expected a type name, or an object with a `type` and an `import`"
+        );
+        assert_eq!(
+            error(json!({ "x-annotations": [1] }), PROPERTY_EXTENSIONS),
+            "Invalid extension 'x-annotations' on property 'Pet.name': expected an annotation, an object with an `annotation` and an `import`, or a list of them"
+        );
+        assert_eq!(
+            error(
+                json!({ "x-annotations": { "import": "javax.persistence.Transient" } }),
+                PROPERTY_EXTENSIONS
+            ),
+            "Invalid extension 'x-annotations' on property 'Pet.name': expected an annotation, an object with an `annotation` and an `import`, or a list of them"
+        );
+        assert_eq!(
+            error(json!({ "x-kotlin-implements": "Auditable" }), PROPERTY_EXTENSIONS),
+            "Invalid extension 'x-kotlin-implements' on property 'Pet.name': it doesn't apply here, only x-kotlin-name, x-kotlin-type, x-kotlin-annotations"
+        );
+        assert_eq!(
+            error(json!({ "x-kotlin-nmae": "kind" }), PROPERTY_EXTENSIONS),
+            "Invalid extension 'x-kotlin-nmae' on property 'Pet.name': unknown Kotlin extension, expected one of x-kotlin-name, x-kotlin-type, x-kotlin-annotations"
+        );
+        assert_eq!(
+            error(json!({ "x-kotlin-type": "Instant" }), PARAMETER_EXTENSIONS),
+            "Invalid extension 'x-kotlin-type' on property 'Pet.name': no Kotlin extensions apply here"
+        );
+
+        // Other extensions are left alone
+        let kotlin =
+            KotlinExtensions::read(&extensions(json!({ "x-internal": true })), "", &[]).unwrap();
+        assert!(kotlin.name.is_none() && kotlin.annotations.is_empty());
+    }
+
+    #[test]
+    fn test_kotlin_type_names() {
+        let backend = create_test_backend();
+
+        let cases = [
+            (TypeRef::String, "String"),
+            (TypeRef::Int32, "Int"),
+            (TypeRef::Int64, "Long"),
+            (TypeRef::Float, "Float"),
+            (TypeRef::Double, "Double"),
+            (TypeRef::Decimal, "BigDecimal"),
+            (TypeRef::Boolean, "Boolean"),
+            (TypeRef::Date, "LocalDate"),
+            (TypeRef::DateTime, "OffsetDateTime"),
+            (TypeRef::Uuid, "UUID"),
+            (TypeRef::Uri, "URI"),
+            (TypeRef::Binary, "ByteArray"),
+            (TypeRef::List(Box::new(TypeRef::String)), "List<String>"),
+            (TypeRef::List(Box::new(TypeRef::Any)), "List<Any>"),
+            (TypeRef::Map(Box::new(TypeRef::Any)), "Map<String, Any>"),
+            (TypeRef::Model("UserProfile".to_string()), "UserProfile"),
+            (TypeRef::Enum("Status".to_string()), "Status"),
+            (
+                TypeRef::External("org.javamoney.Money".to_string()),
+                "Money",
+            ),
+            (TypeRef::External("Money".to_string()), "Money"),
+            (TypeRef::Any, "Any"),
+        ];
+        for (type_ref, expected) in cases {
+            assert_eq!(backend.kotlin_type(&type_ref), expected);
+        }
+    }
+
+    #[test]
+    fn test_generate_validation_annotations_required() {
+        let schema = OpenAPISchema {
+            schema_type: Some("string".into()),
+            nullable: Some(false),
+            ..Default::default()
+        };
+
+        let annotations = validation_annotations(&schema, true);
+        assert!(annotations.contains(&"@NotNull".to_string()));
+    }
+
+    #[test]
+    fn test_generate_validation_annotations_string_constraints() {
+        let schema = OpenAPISchema {
+            schema_type: Some("string".into()),
+            format: Some("email".to_string()),
+            min_length: Some(5),
+            max_length: Some(100),
+            pattern: Some("[a-z]+".to_string()),
+            ..Default::default()
+        };
+
+        let annotations = validation_annotations(&schema, false);
+        assert!(annotations.contains(&"@Email".to_string()));
+        assert!(annotations.contains(&"@Size(min = 5, max = 100)".to_string()));
+        assert!(annotations.contains(&"@Pattern(regexp = \"[a-z]+\")".to_string()));
+    }
+
+    #[test]
+    fn test_generate_validation_annotations_numeric_constraints() {
+        let schema = OpenAPISchema {
+            schema_type: Some("integer".into()),
+            minimum: Some(0.0),
+            maximum: Some(100.0),
+            ..Default::default()
+        };
+
+        let annotations = validation_annotations(&schema, false);
+        assert!(annotations.contains(&"@Min(0)".to_string()));
+        assert!(annotations.contains(&"@Max(100)".to_string()));
+    }
+
+    #[test]
+    fn test_generate_validation_annotations_edge_cases() {
+        // Schema with exclusive minimum/maximum
+        let exclusive_number_schema = OpenAPISchema {
+            schema_type: Some("number".into()),
+            minimum: Some(0.0),
+            maximum: Some(100.0),
+            exclusive_minimum: Some(json!(true)),
+            exclusive_maximum: Some(json!(true)),
+            ..Default::default()
+        };
+        let annotations = validation_annotations(&exclusive_number_schema, false);
+        assert!(annotations.contains(&"@DecimalMin(value = \"0\", inclusive = false)".to_string()));
+        assert!(
+            annotations.contains(&"@DecimalMax(value = \"100\", inclusive = false)".to_string())
+        );
+
+        // OpenAPI 3.1 gives the exclusive bounds themselves
+        let exclusive_bounds_schema = OpenAPISchema {
+            schema_type: Some("number".into()),
+            exclusive_minimum: Some(json!(0.5)),
+            maximum: Some(10.0),
+            ..Default::default()
+        };
+        assert_eq!(
+            validation_annotations(&exclusive_bounds_schema, false),
+            [
+                "@DecimalMin(value = \"0.5\", inclusive = false)",
+                "@Max(10)"
+            ]
+        );
+
+        let bounded_array = OpenAPISchema {
+            schema_type: Some("array".into()),
+            max_items: Some(3),
+            ..Default::default()
+        };
+        assert_eq!(
+            validation_annotations(&bounded_array, false),
+            ["@Size(min = 0, max = 3)"]
+        );
+    }
+
+    #[test]
+    fn test_validation_annotations_disabled() {
+        let mut config = create_test_config();
+        config.include_validation = false;
+        let backend = KotlinSpringBackend::new(&config);
+
+        let property = backend
+            .convert_field_to_kotlin_property("User", &string_field("name"), &mut Vec::new())
+            .unwrap();
+        assert!(property.validation.is_empty());
+    }
+
+    #[test]
+    fn test_format_default_value() {
+        let backend = create_test_backend();
+        let format = |value, kotlin_type| backend.format_default_value(&value, kotlin_type);
+
+        assert_eq!(
+            format(json!("hello"), "String"),
+            Some("\"hello\"".to_string())
+        );
+        assert_eq!(format(json!(42), "Int"), Some("42".to_string()));
+        assert_eq!(format(json!(true), "Boolean"), Some("true".to_string()));
+        assert_eq!(format(json!(null), "String"), Some("null".to_string()));
+    }
+
+    #[test]
+    fn test_format_default_value_complex_types() {
+        let backend = create_test_backend();
+        let format = |value, kotlin_type| backend.format_default_value(&value, kotlin_type);
+
+        assert_eq!(format(json!(42.5), "Double"), Some("42.5".to_string()));
+        assert_eq!(
+            format(json!([1, 2, 3]), "List<Int>"),
+            Some("[1,2,3]".to_string())
+        );
+        assert_eq!(
+            format(json!({}), "Map<String, Any>"),
+            Some("{}".to_string())
+        );
+        assert_eq!(
+            format(json!({"key": "value"}), "Map<String, Any>"),
+            Some("{\"key\":\"value\"}".to_string())
+        );
+    }
+
+    #[test]
+    fn test_format_default_value_of_string_formats() {
+        let backend = create_test_backend();
+        let format = |value, kotlin_type| backend.format_default_value(&value, kotlin_type);
+
+        assert_eq!(
+            format(json!("123e4567-e89b-12d3-a456-426614174000"), "UUID"),
+            Some("UUID.fromString(\"123e4567-e89b-12d3-a456-426614174000\")".to_string())
+        );
+        assert_eq!(
+            format(json!("2024-01-31"), "LocalDate"),
+            Some("LocalDate.parse(\"2024-01-31\")".to_string())
+        );
+        assert_eq!(
+            format(json!("2024-01-31T10:00:00Z"), "OffsetDateTime"),
+            Some("OffsetDateTime.parse(\"2024-01-31T10:00:00Z\")".to_string())
+        );
+        assert_eq!(format(json!("abc"), "ByteArray"), None);
+    }
+
+    #[test]
+    fn test_inexpressible_string_default_is_dropped() {
+        let mut field = string_field("checksum");
+        field.type_ref = TypeRef::Binary;
+        field.required = false;
+        field.default = Some(DefaultValue::Literal(json!("abc")));
+
+        let property = create_test_backend()
+            .convert_field_to_kotlin_property("File", &field, &mut Vec::new())
+            .unwrap();
+
+        assert_eq!(property.default_value, Some("null".to_string()));
+    }
+
+    #[test]
+    fn test_get_base_model_imports() {
+        let backend = create_test_backend();
+        let imports = backend.get_base_model_imports();
+
+        assert!(imports.contains(&"jakarta.validation.constraints.*".to_string()));
+        assert!(imports.contains(&"jakarta.validation.Valid".to_string()));
+        assert!(imports.contains(&"com.fasterxml.jackson.annotation.JsonProperty".to_string()));
+        assert!(imports.contains(&"io.swagger.v3.oas.annotations.media.Schema".to_string()));
+    }
+
+    #[test]
+    fn test_get_base_controller_imports() {
+        let backend = create_test_backend();
+        let imports = backend.get_base_controller_imports();
+
+        assert!(imports.contains(&"org.springframework.http.ResponseEntity".to_string()));
+        assert!(imports.contains(&"org.springframework.web.bind.annotation.*".to_string()));
+        assert!(imports.contains(&"jakarta.validation.Valid".to_string()));
+        assert!(imports.contains(&"jakarta.validation.constraints.*".to_string()));
+        assert!(imports.contains(&"io.swagger.v3.oas.annotations.Operation".to_string()));
+    }
+
+    #[test]
+    fn test_spring_boot_2_uses_javax_validation() {
+        let mut config = create_test_config();
+        config.spring_boot = SpringBootProfile::Boot2;
+        let backend = KotlinSpringBackend::new(&config);
+
+        let model_imports = backend.get_base_model_imports();
+        assert!(model_imports.contains(&"javax.validation.constraints.*".to_string()));
+        assert!(model_imports.contains(&"javax.validation.Valid".to_string()));
+        let controller_imports = backend.get_base_controller_imports();
+        assert!(controller_imports.contains(&"javax.validation.Valid".to_string()));
+        assert!(!controller_imports
+            .iter()
+            .any(|import| import.starts_with("jakarta.")));
+    }
+
+    #[test]
+    fn test_add_imports_for_type() {
+        let backend = create_test_backend();
+        let mut imports = Vec::new();
+
+        backend.add_imports_for_type(&TypeRef::Date, &mut imports);
+        assert!(imports.contains(&"java.time.LocalDate".to_string()));
+
+        backend.add_imports_for_type(&TypeRef::DateTime, &mut imports);
+        assert!(imports.contains(&"java.time.OffsetDateTime".to_string()));
+
+        backend.add_imports_for_type(&TypeRef::List(Box::new(TypeRef::Uuid)), &mut imports);
+        assert!(imports.contains(&"java.util.UUID".to_string()));
+
+        let money = TypeRef::External("org.javamoney.Money".to_string());
+        backend.add_imports_for_type(&TypeRef::Map(Box::new(money)), &mut imports);
+        assert!(imports.contains(&"org.javamoney.Money".to_string()));
+
+        // Should not add any import for basic types, unqualified classes or twice
+        backend.add_imports_for_type(&TypeRef::String, &mut imports);
+        backend.add_imports_for_type(&TypeRef::External("Money".to_string()), &mut imports);
+        backend.add_imports_for_type(&TypeRef::Date, &mut imports);
+        assert_eq!(imports.len(), 4);
+    }
+
+    #[test]
+    fn test_convert_schema_to_kotlin_property() {
+        let schema = OpenAPISchema {
+            schema_type: Some("string".into()),
+            description: Some("Test property description".to_string()),
+            default: Some(json!("default_value")),
+            min_length: Some(5),
+            max_length: Some(50),
+            ..Default::default()
+        };
+        let required_fields = vec!["testProperty".to_string()];
+
+        let property = kotlin_property("testProperty", &schema, &required_fields);
+
+        assert_eq!(property.name, "testProperty");
+        assert_eq!(property.kotlin_type, "String");
+        assert!(!property.nullable);
+        assert_eq!(
+            property.description,
+            Some("Test property description".to_string())
+        );
+        assert_eq!(
+            property.default_value,
+            Some("\"default_value\"".to_string())
+        );
+        assert_eq!(property.example, Some("default_value".to_string()));
+        assert!(property
+            .validation
+            .contains(&"@Size(min = 5, max = 50)".to_string()));
+    }
+
+    #[test]
+    fn test_convert_schema_to_kotlin_property_optional() {
+        let schema = OpenAPISchema {
+            schema_type: Some("integer".into()),
+            nullable: Some(true),
+            ..Default::default()
+        };
+
+        let property = kotlin_property("optionalField", &schema, &[]);
+
+        assert_eq!(property.name, "optionalField");
+        assert_eq!(property.kotlin_type, "Int");
+        assert!(property.nullable);
+        assert_eq!(property.default_value, Some("null".to_string()));
+    }
+
+    #[test]
+    fn test_property_names_are_camel_cased() {
+        let property = create_test_backend()
+            .convert_field_to_kotlin_property("User", &string_field("first_name"), &mut Vec::new())
+            .unwrap();
+
+        assert_eq!(property.name, "firstName");
+        assert_eq!(property.json_property, Some("first_name".to_string()));
+    }
+
+    #[test]
+    fn test_property_names_are_kotlin_identifiers() {
+        let backend = create_test_backend();
+        let property = |name: &str| {
+            backend
+                .convert_field_to_kotlin_property("Line", &string_field(name), &mut Vec::new())
+                .unwrap()
+        };
+
+        let keyword = property("class");
+        assert_eq!(keyword.name, "`class`");
+        assert_eq!(keyword.json_property, None);
+
+        let digit = property("1stLine");
+        assert_eq!(digit.name, "_1stLine");
+        assert_eq!(digit.json_property, Some("1stLine".to_string()));
+    }
+
+    #[test]
+    fn test_pattern_is_escaped() {
+        let schema = OpenAPISchema {
+            schema_type: Some("string".into()),
+            pattern: Some(r#"^\d+"$"#.to_string()),
+            ..Default::default()
+        };
+
+        let annotations = validation_annotations(&schema, false);
+        assert!(annotations.contains(&r#"@Pattern(regexp = "^\\d+\"\$")"#.to_string()));
+    }
+
+    #[test]
+    fn test_convert_one_of_to_sealed_class() {
+        let mut schema = OpenAPISchema::default();
+
+        // Set up oneOf variants
+        schema.one_of_variants = Some(vec![
+            (
+                "Dog".to_string(),
+                OpenAPISchema {
+                    schema_type: Some("object".into()),
+                    properties: {
+                        let mut props = IndexMap::new();
+                        props.insert(
+                            "breed".to_string(),
+                            OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
+                                schema_type: Some("string".into()),
+                                ..Default::default()
+                            })),
+                        );
+                        props
+                    },
+                    required: vec!["breed".to_string()],
+                    ..Default::default()
+                },
+            ),
+            (
+                "Cat".to_string(),
+                OpenAPISchema {
+                    schema_type: Some("object".into()),
+                    properties: {
+                        let mut props = IndexMap::new();
+                        props.insert(
+                            "meow_sound".to_string(),
+                            OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
+                                schema_type: Some("string".into()),
+                                ..Default::default()
+                            })),
+                        );
+                        props
+                    },
+                    required: vec!["meow_sound".to_string()],
+                    ..Default::default()
+                },
+            ),
+        ]);
+
+        // Add discriminator
+        schema.discriminator = Some(OpenAPIDiscriminator {
+            property_name: "type".to_string(),
+            mapping: IndexMap::new(),
+            extensions: Extensions::new(),
+        });
+
+        let result = kotlin_class("Pet", schema);
+
+        assert_eq!(result.name, "Pet");
+        assert_eq!(result.is_sealed, Some(true));
+        assert!(result.sealed_sub_types.is_some());
+        let sub_types = result.sealed_sub_types.unwrap();
+        assert_eq!(sub_types.len(), 2);
+        assert_eq!(sub_types[0].name, "Dog");
+        assert_eq!(sub_types[1].name, "Cat");
+    }
+
+    #[test]
+    fn test_convert_one_of_to_sealed_class_discriminator_mapping() {
+        let mut schema = OpenAPISchema::default();
+        let variant = |prop: &str| OpenAPISchema {
+            schema_type: Some("object".into()),
+            properties: {
+                let mut props = IndexMap::new();
+                props.insert(
+                    "petType".to_string(),
+                    OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
+                        schema_type: Some("string".into()),
+                        ..Default::default()
+                    })),
+                );
+                props.insert(
+                    prop.to_string(),
+                    OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
+                        schema_type: Some("string".into()),
+                        format: Some("date".to_string()),
+                        ..Default::default()
+                    })),
+                );
+                props
+            },
+            ..Default::default()
+        };
+        schema.one_of_variants = Some(vec![
+            ("Dog".to_string(), variant("adopted")),
+            ("Cat".to_string(), variant("born")),
+        ]);
+        schema.properties.insert(
+            "petType".to_string(),
+            OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
+                schema_type: Some("string".into()),
+                ..Default::default()
+            })),
+        );
+        schema.required = vec!["petType".to_string()];
+        schema.discriminator = Some(OpenAPIDiscriminator {
+            property_name: "petType".to_string(),
+            mapping: {
+                let mut mapping = IndexMap::new();
+                mapping.insert("dog".to_string(), "#/components/schemas/Dog".to_string());
+                mapping
+            },
+            extensions: Extensions::new(),
+        });
+
+        let result = kotlin_class("Pet", schema);
+
+        assert_eq!(result.discriminator_property, Some("petType".to_string()));
+        // The discriminator is handled by @JsonTypeInfo, not as a field
+        assert!(result.properties.is_empty());
+        assert!(result.imports.contains(&"java.time.LocalDate".to_string()));
+
+        let sub_types = result.sealed_sub_types.unwrap();
+        assert_eq!(sub_types[0].discriminator_value, Some("dog".to_string()));
+        assert_eq!(sub_types[1].discriminator_value, Some("Cat".to_string()));
+        assert_eq!(sub_types[0].parent_class, Some("Pet".to_string()));
+        assert!(sub_types[0].properties.iter().all(|p| p.name != "petType"));
+        assert_eq!(sub_types[0].properties[0].name, "adopted");
+    }
+
+    #[test]
+    fn test_convert_enum_to_kotlin_enum() {
+        let schema = OpenAPISchema {
+            schema_type: Some("string".into()),
+            enum_values: vec![
+                serde_json::json!("in-progress"),
+                serde_json::json!("onHold"),
+                serde_json::json!("2fa"),
+                serde_json::json!("DONE"),
+                serde_json::Value::Null,
+            ],
+            ..Default::default()
+        };
+
+        let result = kotlin_class("task_status", schema);
+
+        assert_eq!(result.name, "TaskStatus");
+        assert_eq!(result.properties[0].kotlin_type, "String");
+        let constants = result.enum_constants.unwrap();
+        let names: Vec<_> = constants.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, ["IN_PROGRESS", "ON_HOLD", "VALUE_2FA", "DONE"]);
+        assert_eq!(constants[0].value, "\"in-progress\"");
+        assert_eq!(constants[0].json_property, Some("in-progress".to_string()));
+        assert_eq!(constants[3].json_property, None);
+    }
+
+    #[test]
+    fn test_enum_with_swagger_imports_schema() {
+        let schema = OpenAPISchema {
+            schema_type: Some("string".into()),
+            enum_values: vec![serde_json::json!("created")],
+            ..Default::default()
+        };
+
+        let kotlin_class = kotlin_class("EventType", schema);
+        let content = create_test_backend()
+            .template_engine
+            .generate_kotlin_class(&kotlin_class)
+            .unwrap();
+
+        assert!(content.contains("@Schema(description = \"EventType\")"));
+        assert!(content.contains("import io.swagger.v3.oas.annotations.media.Schema"));
+    }
+
+    #[test]
+    fn test_convert_integer_enum_with_var_names() {
+        let schema = OpenAPISchema {
+            schema_type: Some("integer".into()),
+            format: Some("int64".to_string()),
+            enum_values: vec![
+                serde_json::json!(-1),
+                serde_json::json!(1),
+                serde_json::json!(2),
+            ],
+            enum_var_names: vec!["UNKNOWN".to_string(), "LOW".to_string()],
+            ..Default::default()
+        };
+
+        let result = kotlin_class("Priority", schema);
+
+        assert_eq!(result.properties[0].kotlin_type, "Long");
+        assert!(result
+            .imports
+            .contains(&"com.fasterxml.jackson.annotation.JsonValue".to_string()));
+        let constants = result.enum_constants.unwrap();
+        let names: Vec<_> = constants.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, ["UNKNOWN", "LOW", "VALUE_2"]);
+        assert_eq!(constants[0].value, "-1L");
+    }
+
+    #[test]
+    fn test_inline_enum_property_typed_as_enum_class() {
+        let mut schema = OpenAPISchema {
+            schema_type: Some("object".into()),
+            required: vec!["status".to_string()],
+            ..Default::default()
+        };
+        schema.properties.insert(
+            "status".to_string(),
+            OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
+                schema_type: Some("string".into()),
+                enum_values: vec![serde_json::json!("placed"), serde_json::json!("in-transit")],
+                default: Some(serde_json::json!("in-transit")),
+                pattern: Some("[a-z-]+".to_string()),
+                ..Default::default()
+            })),
+        );
+        schema.properties.insert(
+            "tags".to_string(),
+            OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
+                schema_type: Some("array".into()),
+                items: Some(Box::new(OpenAPISchemaOrRef::Schema(Box::new(
+                    OpenAPISchema {
+                        schema_type: Some("string".into()),
+                        enum_values: vec![serde_json::json!("new")],
+                        ..Default::default()
+                    },
+                )))),
+                ..Default::default()
+            })),
+        );
+
+        let classes = kotlin_classes("Order", schema);
+
+        let names: Vec<_> = classes.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, ["Order", "OrderStatus", "OrderTags"]);
+        assert!(classes[1].enum_constants.is_some());
+
+        let status = &classes[0].properties[0];
+        assert_eq!(status.kotlin_type, "OrderStatus");
+        assert_eq!(
+            status.default_value,
+            Some("OrderStatus.IN_TRANSIT".to_string())
+        );
+        assert_eq!(status.example, Some("in-transit".to_string()));
+        assert_eq!(status.validation, vec!["@NotNull".to_string()]);
+        assert_eq!(classes[0].properties[1].kotlin_type, "List<OrderTags>");
+    }
+
+    fn inline_object(properties: &[(&str, OpenAPISchema)]) -> OpenAPISchema {
+        let mut schema = OpenAPISchema {
+            schema_type: Some("object".into()),
+            ..Default::default()
+        };
+        for (name, property) in properties {
+            schema.properties.insert(
+                name.to_string(),
+                OpenAPISchemaOrRef::Schema(Box::new(property.clone())),
+            );
+        }
+        schema
+    }
+
+    #[test]
+    fn test_inline_objects_generate_nested_classes() {
+        let string = OpenAPISchema {
+            schema_type: Some("string".into()),
+            ..Default::default()
+        };
+        let geo = inline_object(&[("lat", string.clone())]);
+        let address = inline_object(&[("city", string.clone()), ("geo", geo)]);
+        let lines = OpenAPISchema {
+            schema_type: Some("array".into()),
+            items: Some(Box::new(OpenAPISchemaOrRef::Schema(Box::new(
+                inline_object(&[("sku", string.clone())]),
+            )))),
+            ..Default::default()
+        };
+        let free_form = OpenAPISchema {
+            schema_type: Some("object".into()),
+            ..Default::default()
+        };
+        let order = inline_object(&[
+            ("shipping_address", address),
+            ("lines", lines),
+            ("metadata", free_form),
+        ]);
+
+        let classes = kotlin_classes("Order", order);
+
+        let names: Vec<_> = classes.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "Order",
+                "OrderShippingAddress",
+                "OrderShippingAddressGeo",
+                "OrderLines"
+            ]
+        );
+        assert_eq!(classes[0].properties[0].kotlin_type, "OrderShippingAddress");
+        assert_eq!(classes[0].properties[1].kotlin_type, "List<OrderLines>");
+        assert_eq!(classes[0].properties[2].kotlin_type, "Map<String, Any>");
+        assert_eq!(
+            classes[1].properties[1].kotlin_type,
+            "OrderShippingAddressGeo"
+        );
+    }
+
+    #[test]
+    fn test_convert_any_of_to_union_type() {
+        let mut schema = OpenAPISchema::default();
+
+        // Set up anyOf variants
+        schema.any_of_variants = Some(vec![
+            (
+                "StringValue".to_string(),
+                OpenAPISchema {
+                    schema_type: Some("string".into()),
+                    ..Default::default()
+                },
+            ),
+            (
+                "NumberValue".to_string(),
+                OpenAPISchema {
+                    schema_type: Some("number".into()),
+                    ..Default::default()
+                },
+            ),
+        ]);
+
+        let result = kotlin_class("UnionType", schema);
+
+        assert_eq!(result.name, "UnionType");
+        assert!(result
+            .imports
+            .contains(&"com.fasterxml.jackson.annotation.JsonValue".to_string()));
+        assert!(result
+            .imports
+            .contains(&"com.fasterxml.jackson.annotation.JsonCreator".to_string()));
+    }
+
+    #[test]
+    fn test_schema_one_of_variants() {
+        let mut schema = OpenAPISchema::default();
+
+        schema.one_of_variants = Some(vec![(
+            "StringType".to_string(),
+            OpenAPISchema {
+                schema_type: Some("string".into()),
+                ..Default::default()
+            },
+        )]);
+
+        let result = kotlin_class("TestUnion", schema);
+        assert_eq!(result.name, "TestUnion");
+        assert_eq!(result.is_sealed, Some(true));
+    }
+
+    #[test]
+    fn test_schema_any_of_variants() {
+        let mut schema = OpenAPISchema::default();
+
+        schema.any_of_variants = Some(vec![(
+            "StringType".to_string(),
+            OpenAPISchema {
+                schema_type: Some("string".into()),
+                ..Default::default()
+            },
+        )]);
+
+        let result = kotlin_class("TestAnyOf", schema);
+        assert_eq!(result.name, "TestAnyOf");
+        assert!(result
+            .imports
+            .contains(&"com.fasterxml.jackson.annotation.JsonValue".to_string()));
+    }
+
+    #[test]
+    fn test_convert_service_to_kotlin_controller() {
+        let backend = create_test_backend();
+        let service = Service {
+            name: "user-accounts".to_string(),
+            operations: vec![Operation {
+                name: "getUserById".to_string(),
+                http_method: "get".to_string(),
+                path: "/users/{id}".to_string(),
+                summary: Some("Get user".to_string()),
+                description: None,
+                parameters: Vec::new(),
+                request_body: Some(RequestBody {
+                    type_ref: TypeRef::Model("User".to_string()),
+                    required: true,
+                    description: None,
+                }),
+                response: Some(TypeRef::Model("User".to_string())),
+                response_description: Some("Success".to_string()),
+                extensions: Extensions::new(),
+            }],
+        };
+
+        let result = backend
+            .convert_service_to_kotlin_controller(&service)
+            .unwrap();
+
+        assert_eq!(result.name, "UserAccountsController");
+        assert_eq!(result.package_name, "com.example.api.api");
+        assert_eq!(
+            result.description,
+            Some("UserAccounts API controller interface".to_string())
+        );
+        let model_imports: Vec<_> = result
+            .imports
+            .iter()
+            .filter(|import| import.starts_with("com.example.api.model."))
+            .collect();
+        assert_eq!(model_imports, ["com.example.api.model.User"]);
+        assert_eq!(result.methods.len(), 1);
+        assert_eq!(result.methods[0].name, "getUserById");
+        assert_eq!(result.methods[0].return_type, "ResponseEntity<User>");
+
+        let body = result.methods[0].request_body.as_ref().unwrap();
+        assert_eq!(body.name, "body");
+        assert_eq!(body.kotlin_type, "User");
+        assert!(matches!(body.param_type, ParameterType::Body));
+        assert_eq!(body.validation, vec!["@Valid".to_string()]);
+    }
+
+    #[test]
+    fn test_controllers_in_model_package_import_no_models() {
+        let mut config = create_test_config();
+        config.model_package = Some("com.example.shared".to_string());
+        config.api_package = Some("com.example.shared".to_string());
+        let backend = KotlinSpringBackend::new(&config);
+        let service = Service {
+            name: "users".to_string(),
+            operations: vec![Operation {
+                name: "listUsers".to_string(),
+                http_method: "get".to_string(),
+                path: "/users".to_string(),
+                summary: None,
+                description: None,
+                parameters: Vec::new(),
+                request_body: None,
+                response: Some(TypeRef::List(Box::new(TypeRef::Model("User".to_string())))),
+                response_description: None,
+                extensions: Extensions::new(),
+            }],
+        };
+
+        let result = backend
+            .convert_service_to_kotlin_controller(&service)
+            .unwrap();
+
+        assert_eq!(result.package_name, "com.example.shared");
+        assert_eq!(result.methods[0].return_type, "ResponseEntity<List<User>>");
+        assert!(!result
+            .imports
+            .iter()
+            .any(|import| import.ends_with(".User")));
+    }
+
+    #[test]
+    fn test_convert_parameter_to_kotlin() {
+        let param = OpenAPIParameter {
+            name: "user_name".to_string(),
+            location: "query".to_string(),
+            description: Some("Username to filter by".to_string()),
+            required: false,
+            deprecated: false,
+            allow_empty_value: false,
+            style: None,
+            explode: None,
+            allow_reserved: false,
+            schema: Some(OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
+                schema_type: Some("string".into()),
+                ..Default::default()
+            }))),
+            example: None,
+            examples: IndexMap::new(),
+            content: IndexMap::new(),
+            extensions: Extensions::new(),
+        };
+
+        let result = kotlin_parameter(&param);
+
+        assert_eq!(result.name, "userName");
+        assert_eq!(result.wire_name, Some("user_name".to_string()));
+        assert_eq!(result.kotlin_type, "String");
+        assert!(!result.required);
+        assert_eq!(
+            result.description,
+            Some("Username to filter by".to_string())
+        );
+    }
+
+    #[test]
+    fn test_convert_parameter_serialization_styles() {
+        let param =
+            |location: &str, style: Option<&str>, explode: Option<bool>, schema_type: &str| {
+                OpenAPIParameter {
+                    name: "value".to_string(),
+                    location: location.to_string(),
+                    description: None,
+                    required: true,
+                    deprecated: false,
+                    allow_empty_value: false,
+                    style: style.map(str::to_string),
+                    explode,
+                    allow_reserved: false,
+                    schema: Some(OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
+                        schema_type: Some(schema_type.into()),
+                        items: Some(Box::new(OpenAPISchemaOrRef::Schema(Box::new(
+                            OpenAPISchema {
+                                schema_type: Some("string".into()),
+                                ..Default::default()
+                            },
+                        )))),
+                        ..Default::default()
+                    }))),
+                    example: None,
+                    examples: IndexMap::new(),
+                    content: IndexMap::new(),
+                    extensions: Extensions::new(),
+                }
+            };
+        let convert = |param: OpenAPIParameter| kotlin_parameter(&param);
+
+        let cookie = convert(param("cookie", None, None, "string"));
+        assert!(matches!(cookie.param_type, ParameterType::Cookie));
+        assert_eq!(cookie.kotlin_type, "String");
+
+        // Spring binds form arrays natively, exploded or comma separated
+        for explode in [None, Some(false)] {
+            let form = convert(param("query", Some("form"), explode, "array"));
+            assert_eq!(form.kotlin_type, "List<String>");
+            assert_eq!(form.conversion, None);
+        }
+
+        let space = convert(param("query", Some("spaceDelimited"), Some(false), "array"));
+        assert_eq!(space.kotlin_type, "SpaceDelimitedList");
+        assert_eq!(
+            space.conversion,
+            Some(ParameterConversion::SpaceDelimited("String".to_string()))
+        );
+
+        let pipe = convert(param("query", Some("pipeDelimited"), None, "array"));
+        assert_eq!(pipe.kotlin_type, "PipeDelimitedList");
+        assert_eq!(
+            pipe.conversion,
+            Some(ParameterConversion::PipeDelimited("String".to_string()))
+        );
+
+        let exploded_pipe = convert(param("query", Some("pipeDelimited"), Some(true), "array"));
+        assert_eq!(exploded_pipe.kotlin_type, "List<String>");
+
+        let deep_object = convert(param("query", Some("deepObject"), Some(true), "object"));
+        assert_eq!(deep_object.kotlin_type, "Map<String, String>");
+
+        let form_object = convert(param("query", None, None, "object"));
+        assert_eq!(form_object.kotlin_type, "Map<String, String>");
+
+        let unexploded_object = convert(param("query", Some("form"), Some(false), "object"));
+        assert_eq!(unexploded_object.kotlin_type, "String");
+
+        let mut allow_empty = param("query", None, None, "boolean");
+        allow_empty.allow_empty_value = true;
+        assert!(!convert(allow_empty).required);
+    }
+
+    #[test]
+    fn test_convert_parameter_with_json_content() {
+        let mut content = IndexMap::new();
+        content.insert(
+            "application/json".to_string(),
+            OpenAPIMediaType {
+                schema: Some(OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
+                    schema_type: Some("object".into()),
+                    reference_name: Some("Filter".to_string()),
+                    ..Default::default()
+                }))),
+                example: None,
+                examples: IndexMap::new(),
+                encoding: IndexMap::new(),
+                extensions: Extensions::new(),
+            },
+        );
+        let param = OpenAPIParameter {
+            name: "filter".to_string(),
+            location: "query".to_string(),
+            description: None,
+            required: false,
+            deprecated: false,
+            allow_empty_value: false,
+            style: None,
+            explode: None,
+            allow_reserved: false,
+            schema: None,
+            example: None,
+            examples: IndexMap::new(),
+            content,
+            extensions: Extensions::new(),
+        };
+
+        let result = kotlin_parameter(&param);
+        assert_eq!(result.kotlin_type, "Filter");
+        assert_eq!(
+            result.conversion,
+            Some(ParameterConversion::Json("Filter".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_parameter_type_conversions() {
+        // Test header parameter
+        let header_param = OpenAPIParameter {
+            name: "authorization".to_string(),
+            location: "header".to_string(),
+            description: Some("Auth header".to_string()),
+            required: true,
+            deprecated: false,
+            allow_empty_value: false,
+            style: None,
+            explode: None,
+            allow_reserved: false,
+            schema: Some(OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
+                schema_type: Some("string".into()),
+                ..Default::default()
+            }))),
+            example: None,
+            examples: IndexMap::new(),
+            content: IndexMap::new(),
+            extensions: Extensions::new(),
+        };
+
+        let result = kotlin_parameter(&header_param);
+        assert_eq!(result.kotlin_type, "String");
+        assert!(result.required);
+
+        // Test parameter without schema
+        let no_schema_param = OpenAPIParameter {
+            name: "test".to_string(),
+            location: "query".to_string(),
+            description: None,
+            required: false,
+            deprecated: false,
+            allow_empty_value: false,
+            style: None,
+            explode: None,
+            allow_reserved: false,
+            schema: None,
+            example: None,
+            examples: IndexMap::new(),
+            content: IndexMap::new(),
+            extensions: Extensions::new(),
+        };
+
+        let no_schema_result = kotlin_parameter(&no_schema_param);
+        assert_eq!(no_schema_result.kotlin_type, "String");
+    }
+
+    #[test]
+    fn test_determine_return_type() {
+        // Test with 200 response containing direct schema instead of reference
+        let operation = OpenAPIOperation {
+            responses: {
+                let mut responses = IndexMap::new();
+                responses.insert(
+                    "200".to_string(),
+                    OpenAPIResponseOrRef::Response(Box::new(OpenAPIResponse {
+                        description: "Success".to_string(),
+                        content: {
+                            let mut content = IndexMap::new();
+                            content.insert(
+                                "application/json".to_string(),
+                                OpenAPIMediaType {
+                                    schema: Some(OpenAPISchemaOrRef::Schema(Box::new(
+                                        OpenAPISchema {
+                                            schema_type: Some("object".into()),
+                                            ..Default::default()
+                                        },
+                                    ))),
+                                    example: None,
+                                    examples: IndexMap::new(),
+                                    encoding: IndexMap::new(),
+                                    extensions: Extensions::new(),
+                                },
+                            );
+                            content
+                        },
+                        headers: IndexMap::new(),
+                        links: IndexMap::new(),
+                        extensions: Extensions::new(),
+                    })),
+                );
+                responses
+            },
+            ..Default::default()
+        };
+
+        let result = kotlin_method("/things", "get", &operation).return_type;
+        assert_eq!(result, "ResponseEntity<Map<String, Any>>");
+
+        // Test with 204 No Content
+        let operation_no_content = OpenAPIOperation {
+            responses: {
+                let mut responses = IndexMap::new();
+                responses.insert(
+                    "204".to_string(),
+                    OpenAPIResponseOrRef::Response(Box::new(OpenAPIResponse {
+                        description: "No Content".to_string(),
+                        content: IndexMap::new(),
+                        headers: IndexMap::new(),
+                        links: IndexMap::new(),
+                        extensions: Extensions::new(),
+                    })),
+                );
+                responses
+            },
+            ..Default::default()
+        };
+
+        let result_no_content =
+            kotlin_method("/things", "delete", &operation_no_content).return_type;
+        assert_eq!(result_no_content, "ResponseEntity<Any>");
+    }
+
+    #[test]
+    fn test_write_kotlin_class_method() {
+        let backend = create_test_backend();
+        let kotlin_class = KotlinClass {
+            name: "TestUser".to_string(),
+            package_name: "com.example.test".to_string(),
+            description: Some("Test user class".to_string()),
+            properties: vec![KotlinProperty {
+                name: "id".to_string(),
+                kotlin_type: "Long".to_string(),
+                nullable: false,
+                default_value: None,
+                example: None,
+                description: Some("User ID".to_string()),
+                validation: vec!["@NotNull".to_string()],
+                json_property: None,
+                annotations: Vec::new(),
+            }],
+            imports: vec!["javax.validation.constraints.NotNull".to_string()],
+            is_sealed: None,
+            sealed_sub_types: None,
+            parent_class: None,
+            discriminator_property: None,
+            discriminator_value: None,
+            enum_constants: None,
+            annotations: Vec::new(),
+            interfaces: Vec::new(),
+        };
+
+        let content = backend
+            .template_engine
+            .generate_kotlin_class(&kotlin_class)
+            .unwrap();
+        assert!(content.contains("TestUser"));
+        assert!(content.contains("com.example.test"));
+    }
+
+    #[test]
+    fn test_write_kotlin_controller_method() {
+        let backend = create_test_backend();
+        let kotlin_controller = KotlinController {
+            name: "TestController".to_string(),
+            package_name: "com.example.test".to_string(),
+            description: Some("Test controller".to_string()),
+            methods: vec![KotlinMethod {
+                name: "getTest".to_string(),
+                http_method: "get".to_string(),
+                path: "/test".to_string(),
+                summary: Some("Get test".to_string()),
+                description: None,
+                parameters: vec![],
+                request_body: None,
+                return_type: "ResponseEntity<String>".to_string(),
+                response_description: None,
+                annotations: Vec::new(),
+            }],
+            imports: vec!["org.springframework.web.bind.annotation.*".to_string()],
+        };
+
+        let content = backend
+            .template_engine
+            .generate_kotlin_controller(&kotlin_controller)
+            .unwrap();
+        assert!(content.contains("TestController"));
+        assert!(content.contains("com.example.test"));
+    }
+
+    #[test]
+    fn test_generated_file_layout() {
+        let backend = create_test_backend();
+        let api = ApiModel {
+            title: "Test API".to_string(),
+            version: "1.0.0".to_string(),
+            models: vec![Model {
+                name: "User".to_string(),
+                description: None,
+                kind: ModelKind::Object {
+                    fields: vec![string_field("name")],
+                },
+                extensions: Extensions::new(),
+            }],
+            services: vec![Service {
+                name: "users".to_string(),
+                operations: vec![Operation {
+                    name: "searchUsers".to_string(),
+                    http_method: "get".to_string(),
+                    path: "/users".to_string(),
+                    summary: None,
+                    description: None,
+                    parameters: vec![Parameter {
+                        name: "ids".to_string(),
+                        location: ParameterLocation::Query,
+                        type_ref: TypeRef::List(Box::new(TypeRef::String)),
+                        required: false,
+                        description: None,
+                        serialization: ParameterSerialization::Style {
+                            style: "pipeDelimited".to_string(),
+                            explode: false,
+                        },
+                        extensions: Extensions::new(),
+                    }],
+                    request_body: None,
+                    response: None,
+                    response_description: None,
+                    extensions: Extensions::new(),
+                }],
+            }],
+        };
+
+        let paths = |files: Vec<GeneratedFile>| -> Vec<PathBuf> {
+            files.into_iter().map(|file| file.path).collect()
+        };
+        let source = "src/main/kotlin/com/example/api";
+
+        let models = backend.generate_models(&api).unwrap();
+        assert_eq!(models[0].description, "model: User");
+        assert!(models[0]
+            .content
+            .starts_with("package com.example.api.model\n"));
+        assert!(models[0].content.contains("data class User"));
+        assert_eq!(
+            paths(models),
+            [PathBuf::from(format!("{source}/model/User.kt"))]
+        );
+
+        let services = backend.generate_services(&api).unwrap();
+        assert!(services[0]
+            .content
+            .starts_with("package com.example.api.api\n"));
+        assert_eq!(
+            paths(services),
+            [
+                PathBuf::from(format!("{source}/api/UsersController.kt")),
+                PathBuf::from(format!("{source}/api/ParameterConverters.kt")),
+            ]
+        );
+        assert_eq!(
+            paths(backend.generate_project_files(&api).unwrap()),
+            [PathBuf::from("build.gradle.kts")]
+        );
+    }
+}
@@ -0,0 +1,45 @@
+//! Backends render the language-neutral [`ApiModel`] as source files for a target
+//! language and framework. Adding a language means implementing [`LanguageBackend`]
+//! and registering it with a [`TargetLanguage`].
+
+use anyhow::Result;
+use std::path::PathBuf;
+
+use crate::ir::ApiModel;
+use crate::types::{GeneratorConfig, TargetLanguage};
+
+mod kotlin;
+mod rust;
+
+pub use kotlin::KotlinSpringBackend;
+pub use rust::RustAxumBackend;
+
+/// A file produced by a backend, written by the generator.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeneratedFile {
+    /// Path relative to the output directory
+    pub path: PathBuf,
+    pub content: String,
+    /// What the file holds, for verbose output, e.g. `model: Order`
+    pub description: String,
+}
+
+pub trait LanguageBackend: Send + Sync {
+    /// Renders the files for the API's models.
+    fn generate_models(&self, api: &ApiModel) -> Result<Vec<GeneratedFile>>;
+
+    /// Renders the files exposing the API's operations, such as controllers or
+    /// handlers, along with the support code they need.
+    fn generate_services(&self, api: &ApiModel) -> Result<Vec<GeneratedFile>>;
+
+    /// Renders build and project files.
+    fn generate_project_files(&self, api: &ApiModel) -> Result<Vec<GeneratedFile>>;
+}
+
+/// Returns the backend for the configured target language.
+pub fn backend_for(config: &GeneratorConfig) -> Box<dyn LanguageBackend> {
+    match config.target {
+        TargetLanguage::Kotlin => Box::new(KotlinSpringBackend::new(config)),
+        TargetLanguage::Rust => Box::new(RustAxumBackend::new(config)),
+    }
+}
@@ -0,0 +1,1602 @@
+//! Rust models deriving serde, and an async trait per tag with an axum router that
+//! extracts each request and dispatches it to the trait, laid out as a library crate.
+
+use anyhow::Result;
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+
+use super::{GeneratedFile, LanguageBackend};
+use crate::ir::{
+    ApiModel, EnumConstant, Field, Model, ModelKind, Operation, Parameter, ParameterLocation,
+    ParameterSerialization, Service, TypeRef, Variant,
+};
+use crate::naming::{pascal_case, snake_case};
+use crate::types::GeneratorConfig;
+
+const RUST_KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+    "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub",
+    "ref", "return", "self", "static", "struct", "super", "trait", "true", "type", "unsafe", "use",
+    "where", "while", "abstract", "become", "box", "do", "final", "gen", "macro", "override",
+    "priv", "try", "typeof", "unsized", "virtual", "yield",
+];
+
+pub struct RustAxumBackend {
+    generate_models: bool,
+    generate_services: bool,
+}
+
+/// How a handler obtains the value of a parameter from the request.
+#[derive(Debug, Clone, PartialEq)]
+enum Binding {
+    /// Deserialized by axum as a field of the operation's path or query struct, or
+    /// parsed from a header or cookie
+    Value,
+    /// Sent as a single string, split on the delimiter and each item parsed
+    Delimited(char),
+    /// Sent as a JSON document
+    Json,
+    /// All query parameters, for an object spread over them
+    QueryMap,
+}
+
+/// A parameter of a trait method, along with how its handler binds it.
+#[derive(Debug, Clone, PartialEq)]
+struct RustParameter {
+    name: String,
+    wire_name: String,
+    location: ParameterLocation,
+    required: bool,
+    /// Type of the value, without the `Option` of optional parameters
+    value_type: String,
+    binding: Binding,
+}
+
+impl RustParameter {
+    /// The type the trait method takes the parameter as.
+    fn rust_type(&self) -> String {
+        if self.binding == Binding::QueryMap || self.is_repeated_query() || self.required {
+            self.value_type.clone()
+        } else {
+            format!("Option<{}>", self.value_type)
+        }
+    }
+
+    /// Exploded form arrays repeat the parameter, and are empty when it isn't sent.
+    fn is_repeated_query(&self) -> bool {
+        self.location == ParameterLocation::Query
+            && self.binding == Binding::Value
+            && self.value_type.starts_with("Vec<")
+    }
+}
+
+impl LanguageBackend for RustAxumBackend {
+    fn generate_models(&self, api: &ApiModel) -> Result<Vec<GeneratedFile>> {
+        let mut files: Vec<_> = api
+            .models
+            .iter()
+            .map(|model| GeneratedFile {
+                path: PathBuf::from(format!("src/models/{}.rs", snake_case(&model.name))),
+                content: self.render_model(model),
+                description: format!("model: {}", model.name),
+            })
+            .collect();
+
+        let mut content = String::new();
+        for model in &api.models {
+            content.push_str(&format!("mod {};\n", snake_case(&model.name)));
+        }
+        content.push('\n');
+        for model in &api.models {
+            content.push_str(&format!(
+                "pub use {}::{};\n",
+                snake_case(&model.name),
+                model.name
+            ));
+        }
+        files.push(GeneratedFile {
+            path: PathBuf::from("src/models/mod.rs"),
+            content,
+            description: "models module".to_string(),
+        });
+
+        Ok(files)
+    }
+
+    fn generate_services(&self, api: &ApiModel) -> Result<Vec<GeneratedFile>> {
+        let mut services: Vec<_> = api.services.iter().collect();
+        services.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let mut files: Vec<_> = services
+            .iter()
+            .map(|service| GeneratedFile {
+                path: PathBuf::from(format!("src/apis/{}.rs", snake_case(&service.name))),
+                content: self.render_service(service),
+                description: format!("api: {}", self.trait_name(service)),
+            })
+            .collect();
+
+        files.push(GeneratedFile {
+            path: PathBuf::from("src/apis/mod.rs"),
+            content: self.render_apis_module(&services),
+            description: "apis module".to_string(),
+        });
+
+        Ok(files)
+    }
+
+    fn generate_project_files(&self, api: &ApiModel) -> Result<Vec<GeneratedFile>> {
+        let mut lib = String::new();
+        if self.generate_services {
+            lib.push_str("pub mod apis;\n");
+        }
+        if self.generate_models {
+            lib.push_str("pub mod models;\n");
+        }
+
+        Ok(vec![
+            GeneratedFile {
+                path: PathBuf::from("Cargo.toml"),
+                content: self.render_cargo_manifest(api),
+                description: "Cargo.toml".to_string(),
+            },
+            GeneratedFile {
+                path: PathBuf::from("src/lib.rs"),
+                content: lib,
+                description: "src/lib.rs".to_string(),
+            },
+        ])
+    }
+}
+
+impl RustAxumBackend {
+    pub fn new(config: &GeneratorConfig) -> Self {
+        Self {
+            generate_models: config.generate_models,
+            generate_services: config.generate_controllers,
+        }
+    }
+
+    /// Returns a snake_case identifier for a wire name, escaping keywords.
+    fn identifier(&self, name: &str) -> String {
+        let ident = snake_case(name);
+        if ident.is_empty() {
+            return "value".to_string();
+        }
+        if ident.starts_with(|c: char| c.is_ascii_digit()) {
+            return format!("_{ident}");
+        }
+
+        match ident.as_str() {
+            // Keywords that can't be raw identifiers
+            "crate" | "self" | "super" => format!("{ident}_"),
+            _ if RUST_KEYWORDS.contains(&ident.as_str()) => format!("r#{ident}"),
+            _ => ident,
+        }
+    }
+
+    /// Returns the `#[serde(rename)]` argument needed for an identifier to keep its
+    /// wire name.
+    fn rename(&self, ident: &str, wire_name: &str) -> Option<String> {
+        let unescaped = ident.strip_prefix("r#").unwrap_or(ident);
+        (unescaped != wire_name).then(|| format!("rename = \"{}\"", escape(wire_name)))
+    }
+
+    fn rust_type(&self, type_ref: &TypeRef) -> String {
+        match type_ref {
+            TypeRef::String | TypeRef::Uri | TypeRef::Binary => "String".to_string(),
+            TypeRef::Int32 => "i32".to_string(),
+            TypeRef::Int64 => "i64".to_string(),
+            TypeRef::Float => "f32".to_string(),
+            TypeRef::Double | TypeRef::Decimal => "f64".to_string(),
+            TypeRef::Boolean => "bool".to_string(),
+            TypeRef::Date => "chrono::NaiveDate".to_string(),
+            TypeRef::DateTime => "chrono::DateTime<chrono::FixedOffset>".to_string(),
+            TypeRef::Uuid => "uuid::Uuid".to_string(),
+            TypeRef::List(items) => format!("Vec<{}>", self.rust_type(items)),
+            TypeRef::Map(values) => {
+                format!(
+                    "std::collections::HashMap<String, {}>",
+                    self.rust_type(values)
+                )
+            }
+            TypeRef::Model(name) | TypeRef::Enum(name) | TypeRef::External(name) => name.clone(),
+            TypeRef::Any => "serde_json::Value".to_string(),
+        }
+    }
+
+    /// Whether values of the type can be parsed from a single string with `FromStr`.
+    fn is_parsable(&self, type_ref: &TypeRef) -> bool {
+        !matches!(
+            type_ref,
+            TypeRef::List(_)
+                | TypeRef::Map(_)
+                | TypeRef::Model(_)
+                | TypeRef::Enum(_)
+                | TypeRef::External(_)
+                | TypeRef::Any
+        )
+    }
+
+    /// Collects the models a type refers to.
+    fn collect_model_names(&self, type_ref: &TypeRef, names: &mut BTreeSet<String>) {
+        match type_ref {
+            TypeRef::Model(name) | TypeRef::Enum(name) => {
+                names.insert(name.clone());
+            }
+            TypeRef::List(inner) | TypeRef::Map(inner) => self.collect_model_names(inner, names),
+            _ => {}
+        }
+    }
+
+    fn render_doc(&self, description: Option<&str>, indent: &str) -> String {
+        description
+            .map(|description| {
+                description
+                    .trim()
+                    .lines()
+                    .map(|line| {
+                        format!(
+                            "{indent}///{}{line}\n",
+                            if line.is_empty() { "" } else { " " }
+                        )
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    fn render_model(&self, model: &Model) -> String {
+        let mut referenced = BTreeSet::new();
+        let fields: Vec<&Field> = match &model.kind {
+            ModelKind::Object { fields } => fields.iter().collect(),
+            ModelKind::OneOf {
+                fields, variants, ..
+            } => fields
+                .iter()
+                .chain(variants.iter().flat_map(|variant| &variant.fields))
+                .collect(),
+            ModelKind::Enum { .. } | ModelKind::AnyOf { .. } => Vec::new(),
+        };
+        for field in fields {
+            self.collect_model_names(&field.type_ref, &mut referenced);
+        }
+        referenced.remove(&model.name);
+
+        let mut content = match &model.kind {
+            ModelKind::Enum {
+                value_type: TypeRef::Int32 | TypeRef::Int64,
+                ..
+            } => "use serde_repr::{Deserialize_repr, Serialize_repr};\n".to_string(),
+            _ => "use serde::{Deserialize, Serialize};\n".to_string(),
+        };
+        if !referenced.is_empty() {
+            let names: Vec<_> = referenced.into_iter().collect();
+            content.push('\n');
+            content.push_str(&use_declaration("super", &names));
+        }
+        content.push('\n');
+        content.push_str(&self.render_doc(model.description.as_deref(), ""));
+
+        match &model.kind {
+            ModelKind::Object { fields } => {
+                content.push_str("#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]\n");
+                content.push_str(&format!("pub struct {} {{\n", model.name));
+                for field in fields {
+                    content.push_str(&self.render_field(field, &model.name, "    ", "pub "));
+                }
+                content.push_str("}\n");
+            }
+            ModelKind::Enum {
+                value_type,
+                constants,
+            } => content.push_str(&self.render_enum(&model.name, value_type, constants)),
+            ModelKind::OneOf {
+                discriminator,
+                fields,
+                variants,
+            } => content.push_str(&self.render_one_of(
+                &model.name,
+                discriminator.as_deref(),
+                fields,
+                variants,
+            )),
+            ModelKind::AnyOf { variants } => {
+                if !variants.is_empty() {
+                    if model.description.is_some() {
+                        content.push_str("///\n");
+                    }
+                    let variants: Vec<_> = variants
+                        .iter()
+                        .map(|variant| format!("`{variant}`"))
+                        .collect();
+                    content.push_str(&format!(
+                        "/// Matches one or more of {}.\n",
+                        variants.join(", ")
+                    ));
+                }
+                content.push_str("#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]\n");
+                content.push_str("#[serde(transparent)]\n");
+                content.push_str(&format!("pub struct {} {{\n", model.name));
+                content.push_str("    pub value: serde_json::Value,\n");
+                content.push_str("}\n");
+            }
+        }
+
+        content
+    }
+
+    /// Renders a field of a struct or struct variant. A field typed as the model that
+    /// owns it is boxed, so the type has a size.
+    fn render_field(&self, field: &Field, owner: &str, indent: &str, visibility: &str) -> String {
+        let ident = self.identifier(&field.name);
+        let mut rust_type = self.rust_type(&field.type_ref);
+        if matches!(&field.type_ref, TypeRef::Model(name) if name == owner) {
+            rust_type = format!("Box<{rust_type}>");
+        }
+
+        let mut serde_args: Vec<String> = self.rename(&ident, &field.name).into_iter().collect();
+        if field.nullable || !field.required {
+            rust_type = format!("Option<{rust_type}>");
+        }
+        if !field.required {
+            serde_args.push("default".to_string());
+            serde_args.push("skip_serializing_if = \"Option::is_none\"".to_string());
+        }
+
+        let mut content = self.render_doc(field.description.as_deref(), indent);
+        if !serde_args.is_empty() {
+            content.push_str(&format!("{indent}#[serde({})]\n", serde_args.join(", ")));
+        }
+        content.push_str(&format!("{indent}{visibility}{ident}: {rust_type},\n"));
+        content
+    }
+
+    /// Renders an enum of unit variants. String values are mapped with serde renames,
+    /// integer values with `serde_repr` discriminants.
+    fn render_enum(&self, name: &str, value_type: &TypeRef, constants: &[EnumConstant]) -> String {
+        let is_integer = matches!(value_type, TypeRef::Int32 | TypeRef::Int64);
+        let mut content = String::new();
+
+        if is_integer {
+            content.push_str(
+                "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize_repr, Deserialize_repr)]\n",
+            );
+            content.push_str(&format!("#[repr({})]\n", self.rust_type(value_type)));
+        } else {
+            content.push_str(
+                "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]\n",
+            );
+        }
+        content.push_str(&format!("pub enum {name} {{\n"));
+
+        // Discriminants must be unique, so repeated integer values are dropped
+        let mut seen_values = Vec::new();
+        for constant in constants {
+            if seen_values.contains(&&constant.value) {
+                continue;
+            }
+            seen_values.push(&constant.value);
+
+            let variant = pascal_case(&constant.name.to_lowercase());
+            match &constant.value {
+                serde_json::Value::String(value) => {
+                    if let Some(rename) = self.rename(&variant, value) {
+                        content.push_str(&format!("    #[serde({rename})]\n"));
+                    }
+                    content.push_str(&format!("    {variant},\n"));
+                }
+                value => content.push_str(&format!("    {variant} = {value},\n")),
+            }
+        }
+
+        content.push_str("}\n");
+        content
+    }
+
+    /// Renders a oneOf model as an enum of struct variants, each with the shared fields
+    /// followed by its own. serde selects the variant from the discriminator property
+    /// when there is one, and tries each variant in turn otherwise.
+    fn render_one_of(
+        &self,
+        name: &str,
+        discriminator: Option<&str>,
+        fields: &[Field],
+        variants: &[Variant],
+    ) -> String {
+        let mut content =
+            "#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]\n".to_string();
+        match discriminator {
+            Some(property) => {
+                content.push_str(&format!("#[serde(tag = \"{}\")]\n", escape(property)))
+            }
+            None => content.push_str("#[serde(untagged)]\n"),
+        }
+        content.push_str(&format!("pub enum {name} {{\n"));
+
+        for variant in variants {
+            content.push_str(&self.render_doc(variant.description.as_deref(), "    "));
+            if let Some(rename) = variant
+                .discriminator_value
+                .as_ref()
+                .and_then(|value| self.rename(&variant.name, value))
+            {
+                content.push_str(&format!("    #[serde({rename})]\n"));
+            }
+            content.push_str(&format!("    {} {{\n", variant.name));
+
+            let own_fields = variant
+                .fields
+                .iter()
+                .filter(|field| !fields.iter().any(|shared| shared.name == field.name));
+            for field in fields.iter().chain(own_fields) {
+                content.push_str(&self.render_field(field, name, "        ", ""));
+            }
+            content.push_str("    },\n");
+        }
+
+        content.push_str("}\n");
+        content
+    }
+
+    fn trait_name(&self, service: &Service) -> String {
+        format!("{}Api", pascal_case(&service.name))
+    }
+
+    /// Picks the Rust type of a parameter and how its handler binds it from the request.
+    fn convert_parameter(&self, param: &Parameter) -> RustParameter {
+        let type_ref = &param.type_ref;
+        let list_item = match type_ref {
+            TypeRef::List(items) if self.is_parsable(items) => Some(items.as_ref()),
+            _ => None,
+        };
+        let list_of = |items: &TypeRef| format!("Vec<{}>", self.rust_type(items));
+
+        let (value_type, binding) = match (&param.serialization, param.location) {
+            (ParameterSerialization::Content(media_type), _) => match type_ref {
+                TypeRef::Model(_) | TypeRef::Enum(_) if media_type.contains("json") => {
+                    (self.rust_type(type_ref), Binding::Json)
+                }
+                _ => ("String".to_string(), Binding::Value),
+            },
+            (ParameterSerialization::Style { style, explode }, ParameterLocation::Query) => {
+                let is_object = matches!(type_ref, TypeRef::Map(_) | TypeRef::Model(_));
+                match (list_item, style.as_str(), explode) {
+                    (Some(items), "form", true) => (list_of(items), Binding::Value),
+                    (Some(items), "form", false) => (list_of(items), Binding::Delimited(',')),
+                    (Some(items), "spaceDelimited", false) => {
+                        (list_of(items), Binding::Delimited(' '))
+                    }
+                    (Some(items), "pipeDelimited", false) => {
+                        (list_of(items), Binding::Delimited('|'))
+                    }
+                    (_, "deepObject", _) | (_, "form", true) if is_object => (
+                        "std::collections::HashMap<String, String>".to_string(),
+                        Binding::QueryMap,
+                    ),
+                    _ if self.is_parsable(type_ref) || matches!(type_ref, TypeRef::Enum(_)) => {
+                        (self.rust_type(type_ref), Binding::Value)
+                    }
+                    _ => ("String".to_string(), Binding::Value),
+                }
+            }
+            (ParameterSerialization::Style { .. }, location) => match list_item {
+                Some(items) => (list_of(items), Binding::Delimited(',')),
+                // Path segments are deserialized, so they can hold enums
+                None if self.is_parsable(type_ref)
+                    || (location == ParameterLocation::Path
+                        && matches!(type_ref, TypeRef::Enum(_))) =>
+                {
+                    (self.rust_type(type_ref), Binding::Value)
+                }
+                None => ("String".to_string(), Binding::Value),
+            },
+        };
+
+        RustParameter {
+            name: self.identifier(&param.name),
+            wire_name: param.name.clone(),
+            location: param.location,
+            // Path parameters are always sent
+            required: param.required || param.location == ParameterLocation::Path,
+            value_type,
+            binding,
+        }
+    }
+
+    fn render_service(&self, service: &Service) -> String {
+        let trait_name = self.trait_name(service);
+        let operations: Vec<(&Operation, Vec<RustParameter>)> = service
+            .operations
+            .iter()
+            .map(|operation| {
+                let params = operation
+                    .parameters
+                    .iter()
+                    .map(|param| self.convert_parameter(param))
+                    .collect();
+                (operation, params)
+            })
+            .collect();
+        let all_params = || operations.iter().flat_map(|(_, params)| params);
+
+        let mut referenced = BTreeSet::new();
+        for operation in &service.operations {
+            for param in &operation.parameters {
+                self.collect_model_names(&param.type_ref, &mut referenced);
+            }
+            if let Some(body) = &operation.request_body {
+                self.collect_model_names(&body.type_ref, &mut referenced);
+            }
+            if let Some(response) = &operation.response {
+                self.collect_model_names(response, &mut referenced);
+            }
+        }
+
+        let uses_location =
+            |location: ParameterLocation| all_params().any(|param| param.location == location);
+        let uses_query = all_params().any(|param| {
+            param.location == ParameterLocation::Query && param.binding != Binding::QueryMap
+        });
+        let uses_headers =
+            uses_location(ParameterLocation::Header) || uses_location(ParameterLocation::Cookie);
+        let uses_json = service
+            .operations
+            .iter()
+            .any(|operation| operation.request_body.is_some() || operation.response.is_some());
+
+        // Imports
+        let mut content = "use async_trait::async_trait;\n".to_string();
+        let mut extractors = Vec::new();
+        if uses_location(ParameterLocation::Path) {
+            extractors.push("Path");
+        }
+        extractors.push("State");
+        content.push_str(&use_declaration("axum::extract", &extractors));
+        if uses_headers {
+            content.push_str("use axum::http::HeaderMap;\n");
+        }
+        content.push_str("use axum::response::{IntoResponse, Response};\n");
+        // Operations sharing a path are routed together, chained onto the first's
+        let mut routes: Vec<(&str, Vec<&Operation>)> = Vec::new();
+        for operation in &service.operations {
+            match routes.iter_mut().find(|(path, _)| *path == operation.path) {
+                Some((_, route_operations)) => route_operations.push(operation),
+                None => routes.push((&operation.path, vec![operation])),
+            }
+        }
+        let mut methods: Vec<&str> = routes
+            .iter()
+            .map(|(_, route_operations)| route_operations[0].http_method.as_str())
+            .collect();
+        methods.sort_unstable();
+        methods.dedup();
+        content.push_str(&use_declaration("axum::routing", &methods));
+        content.push_str(if uses_json {
+            "use axum::{Json, Router};\n"
+        } else {
+            "use axum::Router;\n"
+        });
+        if uses_query {
+            content.push_str("use axum_extra::extract::Query;\n");
+        }
+        if uses_location(ParameterLocation::Path) || uses_query {
+            content.push_str("use serde::Deserialize;\n");
+        }
+        content.push_str("use std::sync::Arc;\n\n");
+
+        let mut helpers = vec!["ApiError", "ApiResult"];
+        if uses_location(ParameterLocation::Cookie) {
+            helpers.push("cookie_param");
+        }
+        if all_params().any(|param| matches!(param.binding, Binding::Delimited(_))) {
+            helpers.push("delimited_param");
+        }
+        if uses_location(ParameterLocation::Header) {
+            helpers.push("header_param");
+        }
+        if all_params().any(|param| param.binding == Binding::Json) {
+            helpers.push("json_param");
+        }
+        if service.operations.iter().any(|operation| {
+            operation
+                .request_body
+                .as_ref()
+                .is_some_and(|body| !body.required)
+        }) {
+            helpers.push("optional_json_body");
+        }
+        if all_params().any(|param| self.needs_required_check(param)) {
+            helpers.push("required");
+        }
+        content.push_str(&use_declaration("super", &helpers));
+        if !referenced.is_empty() {
+            let names: Vec<_> = referenced.into_iter().collect();
+            content.push_str(&use_declaration("crate::models", &names));
+        }
+        content.push('\n');
+
+        // Trait
+        content.push_str(&format!(
+            "/// Operations tagged `{}`.\n",
+            escape(&service.name)
+        ));
+        content.push_str("#[async_trait]\n");
+        content.push_str(&format!(
+            "pub trait {trait_name}: Send + Sync + 'static {{\n"
+        ));
+        for (i, (operation, params)) in operations.iter().enumerate() {
+            if i > 0 {
+                content.push('\n');
+            }
+            content.push_str(&self.render_trait_method(operation, params));
+        }
+        content.push_str("}\n\n");
+
+        // Router
+        content.push_str(&format!(
+            "/// Routes the operations tagged `{}` to an implementation of [`{trait_name}`].\n",
+            escape(&service.name)
+        ));
+        content.push_str(&format!(
+            "pub fn router<A: {trait_name}>(api: Arc<A>) -> Router {{\n"
+        ));
+        content.push_str("    Router::new()\n");
+        for (path, route_operations) in &routes {
+            let method_router: Vec<_> = route_operations
+                .iter()
+                .map(|operation| {
+                    format!(
+                        "{}({}::<A>)",
+                        operation.http_method,
+                        self.identifier(&operation.name)
+                    )
+                })
+                .collect();
+            let route = format!(
+                "        .route(\"{}\", {})",
+                escape(path),
+                method_router.join(".")
+            );
+            if route.len() <= 100 {
+                content.push_str(&route);
+                content.push('\n');
+            } else {
+                content.push_str("        .route(\n");
+                content.push_str(&format!("            \"{}\",\n", escape(path)));
+                content.push_str(&format!(
+                    "            {},\n",
+                    method_router.join("\n                .")
+                ));
+                content.push_str("        )\n");
+            }
+        }
+        content.push_str("        .with_state(api)\n");
+        content.push_str("}\n");
+
+        // Handlers
+        for (operation, params) in &operations {
+            content.push('\n');
+            content.push_str(&self.render_handler(&trait_name, operation, params));
+        }
+
+        content
+    }
+
+    fn render_trait_method(&self, operation: &Operation, params: &[RustParameter]) -> String {
+        let mut content = String::new();
+        let doc = match (&operation.summary, &operation.description) {
+            (Some(summary), Some(description)) => Some(format!("{summary}\n\n{description}")),
+            (summary, description) => summary.clone().or_else(|| description.clone()),
+        };
+        content.push_str(&self.render_doc(doc.as_deref(), "    "));
+
+        let mut args = vec!["&self".to_string()];
+        args.extend(
+            params
+                .iter()
+                .map(|param| format!("{}: {}", param.name, param.rust_type())),
+        );
+        if let Some(body) = &operation.request_body {
+            let body_type = self.rust_type(&body.type_ref);
+            args.push(if body.required {
+                format!("body: {body_type}")
+            } else {
+                format!("body: Option<{body_type}>")
+            });
+        }
+
+        let response_type = operation
+            .response
+            .as_ref()
+            .map_or("()".to_string(), |response| self.rust_type(response));
+        let signature = format!(
+            "    async fn {}({}) -> ApiResult<{response_type}>;\n",
+            self.identifier(&operation.name),
+            args.join(", ")
+        );
+        if signature.len() <= 100 {
+            content.push_str(&signature);
+        } else {
+            content.push_str(&format!(
+                "    async fn {}(\n",
+                self.identifier(&operation.name)
+            ));
+            for arg in args {
+                content.push_str(&format!("        {arg},\n"));
+            }
+            content.push_str(&format!("    ) -> ApiResult<{response_type}>;\n"));
+        }
+
+        content
+    }
+
+    /// Whether a parameter comes out of its binding as an `Option` that must be
+    /// checked for a required parameter.
+    fn needs_required_check(&self, param: &RustParameter) -> bool {
+        if !param.required || param.binding == Binding::QueryMap {
+            return false;
+        }
+
+        match param.location {
+            ParameterLocation::Header | ParameterLocation::Cookie => true,
+            ParameterLocation::Path | ParameterLocation::Query => param.binding != Binding::Value,
+        }
+    }
+
+    /// Type of a parameter's field in the operation's path or query struct.
+    fn struct_field_type(&self, param: &RustParameter) -> String {
+        match param.binding {
+            Binding::Value if param.location == ParameterLocation::Path => param.value_type.clone(),
+            Binding::Value => param.rust_type(),
+            _ if param.location == ParameterLocation::Path => "String".to_string(),
+            _ => "Option<String>".to_string(),
+        }
+    }
+
+    fn render_params_struct(
+        &self,
+        name: &str,
+        params: &[&RustParameter],
+        location: ParameterLocation,
+    ) -> String {
+        let mut content = "#[derive(Debug, Deserialize)]\n".to_string();
+        content.push_str(&format!("struct {name} {{\n"));
+        for param in params {
+            let mut serde_args: Vec<String> = self
+                .rename(&param.name, &param.wire_name)
+                .into_iter()
+                .collect();
+            if location == ParameterLocation::Query && param.is_repeated_query() {
+                serde_args.push("default".to_string());
+            }
+            if !serde_args.is_empty() {
+                content.push_str(&format!("    #[serde({})]\n", serde_args.join(", ")));
+            }
+            content.push_str(&format!(
+                "    {}: {},\n",
+                param.name,
+                self.struct_field_type(param)
+            ));
+        }
+        content.push_str("}\n");
+        content
+    }
+
+    /// Renders the expression a handler binds a parameter with.
+    fn parameter_expression(&self, param: &RustParameter) -> String {
+        let wire_name = escape(&param.wire_name);
+        let raw = match param.location {
+            ParameterLocation::Path if param.binding == Binding::Value => {
+                return format!("path.{}", param.name);
+            }
+            ParameterLocation::Path => format!("Some(path.{})", param.name),
+            ParameterLocation::Query => match param.binding {
+                Binding::Value => return format!("query.{}", param.name),
+                Binding::QueryMap => return "query_map.clone()".to_string(),
+                _ => format!("query.{}", param.name),
+            },
+            ParameterLocation::Header | ParameterLocation::Cookie => {
+                let helper = if param.location == ParameterLocation::Header {
+                    "header_param"
+                } else {
+                    "cookie_param"
+                };
+                let raw_type = if param.binding == Binding::Value {
+                    &param.value_type
+                } else {
+                    "String"
+                };
+                format!("{helper}::<{raw_type}>(&headers, \"{wire_name}\")?")
+            }
+        };
+
+        let value = match param.binding {
+            Binding::Delimited(delimiter) => {
+                format!("delimited_param(\"{wire_name}\", {raw}, {delimiter:?})?")
+            }
+            Binding::Json => format!("json_param(\"{wire_name}\", {raw})?"),
+            _ => raw,
+        };
+
+        if self.needs_required_check(param) {
+            format!("required(\"{wire_name}\", {value})?")
+        } else {
+            value
+        }
+    }
+
+    fn render_handler(
+        &self,
+        trait_name: &str,
+        operation: &Operation,
+        params: &[RustParameter],
+    ) -> String {
+        let fn_name = self.identifier(&operation.name);
+        let type_prefix = pascal_case(&snake_case(&operation.name));
+        let of_location = |location: ParameterLocation| -> Vec<&RustParameter> {
+            params
+                .iter()
+                .filter(|param| param.location == location)
+                .collect()
+        };
+        let path_params = of_location(ParameterLocation::Path);
+        let query_params: Vec<_> = of_location(ParameterLocation::Query)
+            .into_iter()
+            .filter(|param| param.binding != Binding::QueryMap)
+            .collect();
+
+        let mut content = String::new();
+        let mut extractors = vec![format!("State(api): State<Arc<A>>")];
+        if !path_params.is_empty() {
+            let name = format!("{type_prefix}Path");
+            content.push_str(&self.render_params_struct(
+                &name,
+                &path_params,
+                ParameterLocation::Path,
+            ));
+            content.push('\n');
+            extractors.push(format!("Path(path): Path<{name}>"));
+        }
+        if !query_params.is_empty() {
+            let name = format!("{type_prefix}Query");
+            content.push_str(&self.render_params_struct(
+                &name,
+                &query_params,
+                ParameterLocation::Query,
+            ));
+            content.push('\n');
+            extractors.push(format!("Query(query): Query<{name}>"));
+        }
+        if params
+            .iter()
+            .any(|param| param.binding == Binding::QueryMap)
+        {
+            // Unlike the query struct's, axum's own extractor keeps the last of repeated
+            // parameters rather than rejecting them
+            extractors.push(
+                "axum::extract::Query(query_map): axum::extract::Query<std::collections::HashMap<String, String>>"
+                    .to_string(),
+            );
+        }
+        if params.iter().any(|param| {
+            matches!(
+                param.location,
+                ParameterLocation::Header | ParameterLocation::Cookie
+            )
+        }) {
+            extractors.push("headers: HeaderMap".to_string());
+        }
+        let mut args: Vec<String> = params.iter().map(|param| param.name.clone()).collect();
+        if let Some(body) = &operation.request_body {
+            let body_type = self.rust_type(&body.type_ref);
+            // The body is extracted last, as it consumes the request
+            if body.required {
+                extractors.push(format!("Json(body): Json<{body_type}>"));
+            } else {
+                extractors.push("body: axum::body::Bytes".to_string());
+            }
+            args.push("body".to_string());
+        }
+
+        content.push_str(&format!("async fn {fn_name}<A: {trait_name}>(\n"));
+        for extractor in &extractors {
+            content.push_str(&format!("    {extractor},\n"));
+        }
+        content.push_str(") -> Result<Response, ApiError> {\n");
+
+        for param in params {
+            content.push_str(&format!(
+                "    let {} = {};\n",
+                param.name,
+                self.parameter_expression(param)
+            ));
+        }
+        if operation
+            .request_body
+            .as_ref()
+            .is_some_and(|body| !body.required)
+        {
+            content.push_str("    let body = optional_json_body(&body)?;\n");
+        }
+
+        let binding = if operation.response.is_some() {
+            "(status, response)"
+        } else {
+            "(status, ())"
+        };
+        let call = format!(
+            "    let {binding} = api.{fn_name}({}).await?;\n",
+            args.join(", ")
+        );
+        if call.len() <= 101 {
+            content.push_str(&call);
+        } else {
+            content.push_str(&format!("    let {binding} = api\n"));
+            content.push_str(&format!("        .{fn_name}(\n"));
+            for arg in &args {
+                content.push_str(&format!("            {arg},\n"));
+            }
+            content.push_str("        )\n");
+            content.push_str("        .await?;\n");
+        }
+        if operation.response.is_some() {
+            content.push_str("    Ok((status, Json(response)).into_response())\n");
+        } else {
+            content.push_str("    Ok(status.into_response())\n");
+        }
+        content.push_str("}\n");
+
+        content
+    }
+
+    /// Renders the module declaring each API, and the error type and extraction
+    /// helpers the handlers share.
+    fn render_apis_module(&self, services: &[&Service]) -> String {
+        let mut content = String::new();
+        for service in services {
+            content.push_str(&format!("pub mod {};\n", self.identifier(&service.name)));
+        }
+        if !services.is_empty() {
+            content.push('\n');
+        }
+        content.push_str(APIS_SUPPORT);
+        content
+    }
+
+    fn render_cargo_manifest(&self, api: &ApiModel) -> String {
+        let crate_name = snake_case(&api.title).replace('_', "-");
+        let crate_name =
+            if crate_name.is_empty() || crate_name.starts_with(|c: char| c.is_ascii_digit()) {
+                format!("api-{crate_name}")
+                    .trim_end_matches('-')
+                    .to_string()
+            } else {
+                crate_name
+            };
+
+        // Cargo needs a semantic version, which API versions often aren't
+        let is_semver = api.version.split('.').count() == 3
+            && api
+                .version
+                .split('.')
+                .all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit()));
+        let version = if is_semver {
+            api.version.as_str()
+        } else {
+            "0.1.0"
+        };
+
+        format!(
+            r#"[package]
+name = "{crate_name}"
+version = "{version}"
+edition = "2021"
+description = "{}"
+
+[dependencies]
+async-trait = "0.1"
+axum = "0.8"
+axum-extra = {{ version = "0.10", features = ["query"] }}
+chrono = {{ version = "0.4", features = ["serde"] }}
+serde = {{ version = "1", features = ["derive"] }}
+serde_json = "1"
+serde_repr = "0.1"
+uuid = {{ version = "1", features = ["serde"] }}
+"#,
+            escape(&api.title)
+        )
+    }
+}
+
+/// Renders a `use` declaration importing the names from a module.
+fn use_declaration<S: AsRef<str>>(module: &str, names: &[S]) -> String {
+    match names {
+        [name] => format!("use {module}::{};\n", name.as_ref()),
+        _ => {
+            let names: Vec<_> = names.iter().map(AsRef::as_ref).collect();
+            let declaration = format!("use {module}::{{{}}};\n", names.join(", "));
+            if declaration.len() <= 101 {
+                return declaration;
+            }
+
+            let mut declaration = format!("use {module}::{{\n");
+            for name in names {
+                declaration.push_str(&format!("    {name},\n"));
+            }
+            declaration.push_str("};\n");
+            declaration
+        }
+    }
+}
+
+/// Escapes a value for a Rust or TOML string literal.
+fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+const APIS_SUPPORT: &str = r#"use axum::http::{header, HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
+use serde::de::DeserializeOwned;
+use std::str::FromStr;
+
+/// The status code and body an operation responds with.
+pub type ApiResult<T> = Result<(StatusCode, T), ApiError>;
+
+/// An error response, sent as its status code and a plain text message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiError {
+    pub status: StatusCode,
+    pub message: String,
+}
+
+impl ApiError {
+    pub fn new(status: StatusCode, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            message: message.into(),
+        }
+    }
+
+    pub fn bad_request(message: impl Into<String>) -> Self {
+        Self::new(StatusCode::BAD_REQUEST, message)
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        (self.status, self.message).into_response()
+    }
+}
+
+#[allow(dead_code)]
+fn parse_param<T: FromStr>(name: &str, value: &str) -> Result<T, ApiError> {
+    value
+        .parse()
+        .map_err(|_| ApiError::bad_request(format!("Invalid value for parameter `{name}`")))
+}
+
+/// Parses the value of a header.
+#[allow(dead_code)]
+pub(crate) fn header_param<T: FromStr>(
+    headers: &HeaderMap,
+    name: &str,
+) -> Result<Option<T>, ApiError> {
+    headers
+        .get(name)
+        .map(|value| {
+            let value = value.to_str().map_err(|_| {
+                ApiError::bad_request(format!("Invalid value for parameter `{name}`"))
+            })?;
+            parse_param(name, value)
+        })
+        .transpose()
+}
+
+/// Parses the value of a cookie sent in the `Cookie` header.
+#[allow(dead_code)]
+pub(crate) fn cookie_param<T: FromStr>(
+    headers: &HeaderMap,
+    name: &str,
+) -> Result<Option<T>, ApiError> {
+    headers
+        .get_all(header::COOKIE)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(';'))
+        .filter_map(|pair| pair.trim().split_once('='))
+        .find(|(key, _)| *key == name)
+        .map(|(_, value)| parse_param(name, value))
+        .transpose()
+}
+
+/// Splits a delimited array value and parses its items.
+#[allow(dead_code)]
+pub(crate) fn delimited_param<T: FromStr>(
+    name: &str,
+    value: Option<String>,
+    delimiter: char,
+) -> Result<Option<Vec<T>>, ApiError> {
+    value
+        .map(|value| {
+            value
+                .split(delimiter)
+                .filter(|item| !item.is_empty())
+                .map(|item| parse_param(name, item))
+                .collect()
+        })
+        .transpose()
+}
+
+/// Deserializes a parameter sent as a JSON document.
+#[allow(dead_code)]
+pub(crate) fn json_param<T: DeserializeOwned>(
+    name: &str,
+    value: Option<String>,
+) -> Result<Option<T>, ApiError> {
+    value
+        .map(|value| {
+            serde_json::from_str(&value).map_err(|_| {
+                ApiError::bad_request(format!("Invalid value for parameter `{name}`"))
+            })
+        })
+        .transpose()
+}
+
+/// Deserializes an optional JSON request body, which is absent when empty.
+#[allow(dead_code)]
+pub(crate) fn optional_json_body<T: DeserializeOwned>(body: &[u8]) -> Result<Option<T>, ApiError> {
+    if body.is_empty() {
+        return Ok(None);
+    }
+
+    serde_json::from_slice(body)
+        .map(Some)
+        .map_err(|error| ApiError::bad_request(format!("Invalid request body: {error}")))
+}
+
+#[allow(dead_code)]
+pub(crate) fn required<T>(name: &str, value: Option<T>) -> Result<T, ApiError> {
+    value.ok_or_else(|| ApiError::bad_request(format!("Missing required parameter `{name}`")))
+}
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::OperationFilters;
+    use crate::ir::{Constraints, RequestBody};
+    use crate::mappings::TypeMappings;
+    use crate::templates::TemplateOverrides;
+    use crate::types::{Extensions, InlineNamingStrategy, SpringBootProfile, TargetLanguage};
+    use serde_json::json;
+
+    fn create_test_config() -> GeneratorConfig {
+        GeneratorConfig {
+            base_package: "com.example.api".to_string(),
+            model_package: None,
+            api_package: None,
+            output_dir: PathBuf::from("/tmp/test_output"),
+            generate_models: true,
+            generate_controllers: true,
+            include_validation: true,
+            include_swagger: true,
+            verbose: false,
+            inline_naming: InlineNamingStrategy::ParentAndProperty,
+            target: TargetLanguage::Rust,
+            spring_boot: SpringBootProfile::default(),
+            type_mappings: TypeMappings::default(),
+            templates: TemplateOverrides::default(),
+            filters: OperationFilters::default(),
+        }
+    }
+
+    fn create_test_backend() -> RustAxumBackend {
+        RustAxumBackend::new(&create_test_config())
+    }
+
+    fn field(name: &str, type_ref: TypeRef, required: bool) -> Field {
+        Field {
+            name: name.to_string(),
+            type_ref,
+            required,
+            nullable: false,
+            default: None,
+            description: None,
+            constraints: Constraints::default(),
+            extensions: Extensions::new(),
+        }
+    }
+
+    fn parameter(
+        name: &str,
+        location: ParameterLocation,
+        type_ref: TypeRef,
+        required: bool,
+        style: &str,
+        explode: bool,
+    ) -> Parameter {
+        Parameter {
+            name: name.to_string(),
+            location,
+            type_ref,
+            required,
+            description: None,
+            serialization: ParameterSerialization::Style {
+                style: style.to_string(),
+                explode,
+            },
+            extensions: Extensions::new(),
+        }
+    }
+
+    fn operation(name: &str, http_method: &str, path: &str) -> Operation {
+        Operation {
+            name: name.to_string(),
+            http_method: http_method.to_string(),
+            path: path.to_string(),
+            summary: None,
+            description: None,
+            parameters: Vec::new(),
+            request_body: None,
+            response: None,
+            response_description: None,
+            extensions: Extensions::new(),
+        }
+    }
+
+    #[test]
+    fn test_rust_type_names() {
+        let backend = create_test_backend();
+
+        let cases = [
+            (TypeRef::String, "String"),
+            (TypeRef::Int32, "i32"),
+            (TypeRef::Int64, "i64"),
+            (TypeRef::Float, "f32"),
+            (TypeRef::Double, "f64"),
+            (TypeRef::Boolean, "bool"),
+            (TypeRef::Date, "chrono::NaiveDate"),
+            (TypeRef::DateTime, "chrono::DateTime<chrono::FixedOffset>"),
+            (TypeRef::Uuid, "uuid::Uuid"),
+            (TypeRef::List(Box::new(TypeRef::Int64)), "Vec<i64>"),
+            (
+                TypeRef::Map(Box::new(TypeRef::Any)),
+                "std::collections::HashMap<String, serde_json::Value>",
+            ),
+            (TypeRef::Model("UserProfile".to_string()), "UserProfile"),
+        ];
+        for (type_ref, expected) in cases {
+            assert_eq!(backend.rust_type(&type_ref), expected);
+        }
+    }
+
+    #[test]
+    fn test_identifiers_are_snake_cased_and_escaped() {
+        let backend = create_test_backend();
+
+        assert_eq!(backend.identifier("firstName"), "first_name");
+        assert_eq!(backend.identifier("type"), "r#type");
+        assert_eq!(backend.identifier("self"), "self_");
+        assert_eq!(backend.identifier("2fa"), "_2fa");
+        assert_eq!(backend.rename("r#type", "type"), None);
+        assert_eq!(
+            backend.rename("first_name", "firstName"),
+            Some("rename = \"firstName\"".to_string())
+        );
+    }
+
+    #[test]
+    fn test_render_struct() {
+        let backend = create_test_backend();
+        let mut nullable = field("nickName", TypeRef::String, true);
+        nullable.nullable = true;
+        let model = Model {
+            name: "User".to_string(),
+            description: Some("A user".to_string()),
+            kind: ModelKind::Object {
+                fields: vec![
+                    field("id", TypeRef::Int64, true),
+                    field("status", TypeRef::Enum("Status".to_string()), false),
+                    field("manager", TypeRef::Model("User".to_string()), false),
+                    nullable,
+                ],
+            },
+            extensions: Extensions::new(),
+        };
+
+        let content = backend.render_model(&model);
+        assert!(content.contains("use super::Status;\n"));
+        assert!(content.contains("/// A user\n#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]\npub struct User {"));
+        assert!(content.contains("    pub id: i64,\n"));
+        assert!(content.contains(
+            "    #[serde(default, skip_serializing_if = \"Option::is_none\")]\n    pub status: Option<Status>,"
+        ));
+        // A model containing itself is boxed
+        assert!(content.contains("pub manager: Option<Box<User>>,"));
+        // Required but nullable fields are always serialized
+        assert!(content
+            .contains("    #[serde(rename = \"nickName\")]\n    pub nick_name: Option<String>,"));
+    }
+
+    #[test]
+    fn test_render_enums() {
+        let backend = create_test_backend();
+        let constant = |name: &str, value: serde_json::Value| EnumConstant {
+            name: name.to_string(),
+            value,
+        };
+
+        let strings = backend.render_enum(
+            "Status",
+            &TypeRef::String,
+            &[
+                constant("IN_TRANSIT", json!("in-transit")),
+                constant("Done", json!("Done")),
+            ],
+        );
+        assert!(strings.contains("Serialize, Deserialize)]\npub enum Status {"));
+        assert!(strings.contains("    #[serde(rename = \"in-transit\")]\n    InTransit,\n"));
+        assert!(strings.contains("    Done,\n"));
+        assert!(!strings.contains("rename = \"Done\""));
+
+        let integers = backend.render_enum(
+            "Priority",
+            &TypeRef::Int64,
+            &[
+                constant("VALUE_MINUS_1", json!(-1)),
+                constant("VALUE_1", json!(1)),
+                constant("VALUE_1_2", json!(1)),
+            ],
+        );
+        assert!(integers.contains("Serialize_repr, Deserialize_repr)]\n#[repr(i64)]"));
+        assert!(integers.contains("    ValueMinus1 = -1,\n    Value1 = 1,\n}"));
+    }
+
+    #[test]
+    fn test_render_one_of() {
+        let backend = create_test_backend();
+        let variant = |name: &str, value: &str, fields: Vec<Field>| Variant {
+            name: name.to_string(),
+            description: None,
+            discriminator_value: Some(value.to_string()),
+            fields,
+            extensions: Extensions::new(),
+        };
+        let shared = vec![field("name", TypeRef::String, true)];
+        let variants = vec![
+            variant("Dog", "dog", vec![field("bark", TypeRef::Boolean, true)]),
+            variant("Cat", "Cat", Vec::new()),
+        ];
+
+        let tagged = backend.render_one_of("Pet", Some("petType"), &shared, &variants);
+        assert!(tagged.contains("#[serde(tag = \"petType\")]\npub enum Pet {"));
+        assert!(tagged.contains(
+            "    #[serde(rename = \"dog\")]\n    Dog {\n        name: String,\n        bark: bool,\n    },"
+        ));
+        assert!(tagged.contains("    Cat {\n        name: String,\n    },"));
+
+        let untagged = backend.render_one_of("Pet", None, &shared, &variants);
+        assert!(untagged.contains("#[serde(untagged)]"));
+    }
+
+    #[test]
+    fn test_convert_parameter_bindings() {
+        let backend = create_test_backend();
+        let strings = TypeRef::List(Box::new(TypeRef::String));
+        let object = TypeRef::Model("Filter".to_string());
+        let bind = |param: Parameter| {
+            let param = backend.convert_parameter(&param);
+            (param.rust_type(), param.binding)
+        };
+        let query = ParameterLocation::Query;
+
+        assert_eq!(
+            bind(parameter(
+                "ids",
+                query,
+                strings.clone(),
+                false,
+                "form",
+                true
+            )),
+            ("Vec<String>".to_string(), Binding::Value)
+        );
+        assert_eq!(
+            bind(parameter(
+                "ids",
+                query,
+                strings.clone(),
+                true,
+                "pipeDelimited",
+                false
+            )),
+            ("Vec<String>".to_string(), Binding::Delimited('|'))
+        );
+        assert_eq!(
+            bind(parameter(
+                "ids",
+                query,
+                strings.clone(),
+                false,
+                "form",
+                false
+            )),
+            ("Option<Vec<String>>".to_string(), Binding::Delimited(','))
+        );
+        assert_eq!(
+            bind(parameter(
+                "filter",
+                query,
+                object.clone(),
+                false,
+                "deepObject",
+                true
+            )),
+            (
+                "std::collections::HashMap<String, String>".to_string(),
+                Binding::QueryMap
+            )
+        );
+        assert_eq!(
+            bind(parameter(
+                "X-Ids",
+                ParameterLocation::Header,
+                strings,
+                false,
+                "simple",
+                false
+            )),
+            ("Option<Vec<String>>".to_string(), Binding::Delimited(','))
+        );
+        assert_eq!(
+            bind(parameter(
+                "session",
+                ParameterLocation::Cookie,
+                object.clone(),
+                true,
+                "form",
+                true
+            )),
+            ("String".to_string(), Binding::Value)
+        );
+
+        let mut json = parameter("where", query, object, false, "form", true);
+        json.serialization = ParameterSerialization::Content("application/json".to_string());
+        assert_eq!(bind(json), ("Option<Filter>".to_string(), Binding::Json));
+    }
+
+    #[test]
+    fn test_render_service() {
+        let backend = create_test_backend();
+        let path_param = || {
+            parameter(
+                "orderId",
+                ParameterLocation::Path,
+                TypeRef::Int64,
+                true,
+                "simple",
+                false,
+            )
+        };
+
+        let mut get_order = operation("getOrder", "get", "/orders/{orderId}");
+        get_order.summary = Some("Get an order".to_string());
+        get_order.response = Some(TypeRef::Model("Order".to_string()));
+        get_order.parameters = vec![
+            path_param(),
+            parameter(
+                "X-Trace",
+                ParameterLocation::Header,
+                TypeRef::String,
+                true,
+                "simple",
+                false,
+            ),
+            parameter(
+                "session",
+                ParameterLocation::Cookie,
+                TypeRef::String,
+                false,
+                "form",
+                true,
+            ),
+        ];
+        let mut update_order = operation("updateOrder", "put", "/orders/{orderId}");
+        update_order.parameters = vec![path_param()];
+        update_order.request_body = Some(RequestBody {
+            type_ref: TypeRef::Model("Order".to_string()),
+            required: false,
+            description: None,
+        });
+        let service = Service {
+            name: "orders".to_string(),
+            operations: vec![get_order, update_order],
+        };
+
+        let content = backend.render_service(&service);
+        assert!(content.contains("use axum::routing::get;\n"));
+        assert!(content.contains("use crate::models::Order;\n"));
+        assert!(content.contains("#[async_trait]\npub trait OrdersApi: Send + Sync + 'static {"));
+        assert!(content.contains("    /// Get an order\n    async fn get_order("));
+        assert!(content.contains(
+            "        x_trace: String,\n        session: Option<String>,\n    ) -> ApiResult<Order>;"
+        ));
+        assert!(content.contains(
+            "    async fn update_order(&self, order_id: i64, body: Option<Order>) -> ApiResult<()>;"
+        ));
+        assert!(content
+            .contains(".route(\"/orders/{orderId}\", get(get_order::<A>).put(update_order::<A>))"));
+        assert!(content.contains(
+            "struct GetOrderPath {\n    #[serde(rename = \"orderId\")]\n    order_id: i64,\n}"
+        ));
+        assert!(content.contains(
+            "    let x_trace = required(\"X-Trace\", header_param::<String>(&headers, \"X-Trace\")?)?;"
+        ));
+        assert!(
+            content.contains("    let session = cookie_param::<String>(&headers, \"session\")?;")
+        );
+        assert!(content.contains("    let body = optional_json_body(&body)?;"));
+        assert!(content.contains("    Ok((status, Json(response)).into_response())"));
+    }
+
+    #[test]
+    fn test_generated_file_layout() {
+        let backend = create_test_backend();
+        let api = ApiModel {
+            title: "Order Service".to_string(),
+            version: "v2".to_string(),
+            models: vec![Model {
+                name: "OrderItem".to_string(),
+                description: None,
+                kind: ModelKind::Object {
+                    fields: vec![field("sku", TypeRef::String, true)],
+                },
+                extensions: Extensions::new(),
+            }],
+            services: vec![Service {
+                name: "orders".to_string(),
+                operations: vec![operation("listOrders", "get", "/orders")],
+            }],
+        };
+
+        let paths = |files: &[GeneratedFile]| -> Vec<PathBuf> {
+            files.iter().map(|file| file.path.clone()).collect()
+        };
+
+        let models = backend.generate_models(&api).unwrap();
+        assert_eq!(
+            paths(&models),
+            [
+                PathBuf::from("src/models/order_item.rs"),
+                PathBuf::from("src/models/mod.rs"),
+            ]
+        );
+        assert_eq!(models[0].description, "model: OrderItem");
+        assert_eq!(
+            models[1].content,
+            "mod order_item;\n\npub use order_item::OrderItem;\n"
+        );
+
+        let services = backend.generate_services(&api).unwrap();
+        assert_eq!(
+            paths(&services),
+            [
+                PathBuf::from("src/apis/orders.rs"),
+                PathBuf::from("src/apis/mod.rs"),
+            ]
+        );
+        assert!(services[1].content.starts_with("pub mod orders;\n"));
+        assert!(services[1].content.contains("pub type ApiResult<T>"));
+
+        let project = backend.generate_project_files(&api).unwrap();
+        assert_eq!(
+            paths(&project),
+            [PathBuf::from("Cargo.toml"), PathBuf::from("src/lib.rs")]
+        );
+        assert!(project[0].content.contains("name = \"order-service\""));
+        // API versions that aren't semantic versions can't be crate versions
+        assert!(project[0].content.contains("version = \"0.1.0\""));
+        assert_eq!(project[1].content, "pub mod apis;\npub mod models;\n");
+    }
+}
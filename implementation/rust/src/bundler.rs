@@ -0,0 +1,598 @@
+use serde_json::{Map, Value};
+use std::collections::{HashMap, HashSet};
+
+use crate::errors::{self, Result};
+use crate::parser::OpenAPIParser;
+use crate::source_map::escape;
+use crate::types::OpenAPISpec;
+
+/// Keywords whose values are instances rather than schemas, so a `$ref` inside them
+/// is data.
+const LITERAL_KEYWORDS: [&str; 4] = ["const", "default", "enum", "example"];
+
+/// Keywords whose values map names to objects, so their keys are never keywords.
+const NAME_MAPS: [&str; 21] = [
+    "properties",
+    "patternProperties",
+    "$defs",
+    "definitions",
+    "dependentSchemas",
+    "paths",
+    "webhooks",
+    "schemas",
+    "responses",
+    "parameters",
+    "requestBodies",
+    "headers",
+    "securitySchemes",
+    "links",
+    "callbacks",
+    "pathItems",
+    "content",
+    "encoding",
+    "variables",
+    "scopes",
+    "components",
+];
+
+/// Returns the parsed specification as a single document: every object referenced
+/// from another local file is added to `components`, under its own name or, when
+/// that is taken, the name followed by a number, and references to it point there.
+/// OpenAPI 3.0 has no path item components, so referenced path items are inlined.
+///
+/// Remote references are left as they are.
+///
+/// # Errors
+///
+/// Returns an error if the bundled document isn't a valid specification, e.g.
+/// because a reference points at an object of the wrong kind.
+///
+/// # Examples
+///
+/// ```rust
+/// use openapi_codegen_rust::bundler;
+/// use openapi_codegen_rust::parser::OpenAPIParser;
+///
+/// let mut parser = OpenAPIParser::new();
+/// // Use in async context:
+/// // parser.parse_file("api.yaml").await.unwrap();
+/// // let spec = bundler::bundle(&parser).unwrap();
+/// // println!("{}", serde_yaml::to_string(&spec).unwrap());
+/// ```
+pub fn bundle(parser: &OpenAPIParser) -> Result<OpenAPISpec> {
+    into_spec(bundle_document(parser)?)
+}
+
+/// Returns the parsed specification with every reference replaced by the object it
+/// points at, after bundling it. A reference back to an object being expanded, as
+/// in recursive schemas, is kept, and so are `components` for it to point into.
+///
+/// # Errors
+///
+/// Returns an error if the dereferenced document isn't a valid specification.
+pub fn dereference(parser: &OpenAPIParser) -> Result<OpenAPISpec> {
+    let bundled = bundle_document(parser)?;
+    let mut document = bundled.clone();
+    expand_references(&mut document, &bundled);
+    into_spec(document)
+}
+
+fn into_spec(document: Value) -> Result<OpenAPISpec> {
+    serde_json::from_value(document).map_err(|e| errors::invalid_spec(e.to_string()))
+}
+
+fn bundle_document(parser: &OpenAPIParser) -> Result<Value> {
+    let spec = parser.get_spec();
+    let mut document =
+        serde_json::to_value(spec).map_err(|e| errors::invalid_spec(e.to_string()))?;
+    let external = serde_json::to_value(parser.get_external_components())
+        .map_err(|e| errors::invalid_spec(e.to_string()))?;
+    let inline_path_items = spec.openapi.starts_with("3.0");
+
+    // Objects to inline, and where references to the others now point
+    let mut inlined: HashMap<String, Value> = HashMap::new();
+    let mut targets: HashMap<String, String> = HashMap::new();
+
+    let components = document
+        .as_object_mut()
+        .expect("a specification serializes to an object")
+        .entry("components")
+        .or_insert_with(|| Value::Object(Map::new()));
+    for (section, objects) in external.as_object().into_iter().flatten() {
+        for (key, object) in objects.as_object().into_iter().flatten() {
+            // Objects below components of the parsed document are already in it
+            if key.starts_with('#') || targets.contains_key(key) {
+                continue;
+            }
+            if section == "pathItems" && inline_path_items {
+                inlined.insert(key.clone(), object.clone());
+                continue;
+            }
+
+            let entries = components
+                .as_object_mut()
+                .expect("components serialize to an object")
+                .entry(section.as_str())
+                .or_insert_with(|| Value::Object(Map::new()))
+                .as_object_mut()
+                .expect("component sections serialize to objects");
+            let name = available_name(entries, &component_name(key));
+            entries.insert(name.clone(), object.clone());
+            targets.insert(
+                key.clone(),
+                format!("#/components/{section}/{}", escape(&name)),
+            );
+        }
+    }
+    let root = document
+        .as_object_mut()
+        .expect("a specification serializes to an object");
+    if root["components"].as_object().is_some_and(Map::is_empty) {
+        root.remove("components");
+    }
+
+    rewrite_references(
+        &mut document,
+        Context::Object,
+        &mut |reference| {
+            let key = parser.canonical_reference(reference)?;
+            if let Some(object) = inlined.get(&key) {
+                return Some(Rewrite::Inline(object.clone()));
+            }
+            match targets.get(&key) {
+                Some(target) => Some(Rewrite::Reference(target.clone())),
+                None => (key != reference && key.starts_with('#')).then(|| Rewrite::Reference(key)),
+            }
+        },
+        &mut Vec::new(),
+    );
+
+    check_alias_cycles(&document)?;
+    Ok(document)
+}
+
+/// Fails on a `$ref` that leads through references only back to itself, such as
+/// `A: {$ref: B}` and `B: {$ref: A}`, as no object is ever reached. Cycles through
+/// an object, as in recursive schemas, are fine.
+fn check_alias_cycles(document: &Value) -> Result<()> {
+    let mut cycle = None;
+    rewrite_references(
+        &mut document.clone(),
+        Context::Object,
+        &mut |reference| {
+            if cycle.is_none() && leads_back(reference, document) {
+                cycle = Some(reference.to_string());
+            }
+            None
+        },
+        &mut Vec::new(),
+    );
+
+    match cycle {
+        Some(reference) => Err(errors::circular_reference(reference)),
+        None => Ok(()),
+    }
+}
+
+/// Whether following `reference` through objects that are only a `$ref` comes back
+/// to a reference already followed.
+fn leads_back(reference: &str, document: &Value) -> bool {
+    let mut followed = HashSet::new();
+    let mut reference = reference;
+    while let Some(target) = reference
+        .strip_prefix('#')
+        .and_then(|pointer| document.pointer(pointer))
+    {
+        if !followed.insert(reference) {
+            return true;
+        }
+        match target.get("$ref").and_then(Value::as_str) {
+            Some(next) => reference = next,
+            None => return false,
+        }
+    }
+    false
+}
+
+/// Turns the last segment of an external key, or the file name for a whole
+/// document, into a component name.
+fn component_name(key: &str) -> String {
+    let name: String = OpenAPIParser::extract_schema_name(key)
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if name.is_empty() {
+        "Component".to_string()
+    } else {
+        name
+    }
+}
+
+/// Returns `name`, or `name` followed by the first number from 2 that isn't taken.
+fn available_name(entries: &Map<String, Value>, name: &str) -> String {
+    if !entries.contains_key(name) {
+        return name.to_string();
+    }
+    (2..)
+        .map(|number| format!("{name}{number}"))
+        .find(|candidate| !entries.contains_key(candidate))
+        .expect("some number is free")
+}
+
+/// What a `$ref` becomes.
+enum Rewrite {
+    Reference(String),
+    /// Replace the referencing object with this one
+    Inline(Value),
+}
+
+/// What the members of an object are, to tell keywords from names and data.
+#[derive(Clone, Copy, PartialEq)]
+enum Context {
+    Object,
+    /// Members are named objects, e.g. the entries of `properties`
+    Names,
+    /// Members are named Example Objects
+    Examples,
+    /// An Example Object, whose `value` is data
+    Example,
+    Discriminator,
+    /// Values are schema names or references
+    Mapping,
+}
+
+/// Rewrites the `$ref`s below `value`, and the references of discriminator
+/// mappings, as `rewrite` says. Literal values such as examples are left alone.
+/// `inlining` holds the references being inlined, so a cycle of them stops.
+fn rewrite_references(
+    value: &mut Value,
+    context: Context,
+    rewrite: &mut impl FnMut(&str) -> Option<Rewrite>,
+    inlining: &mut Vec<String>,
+) {
+    match value {
+        Value::Object(map) => {
+            if let Some(Value::String(reference)) = map.get("$ref") {
+                let reference = reference.clone();
+                match rewrite(&reference) {
+                    Some(Rewrite::Inline(object)) if !inlining.contains(&reference) => {
+                        *value = object;
+                        inlining.push(reference);
+                        rewrite_references(value, context, rewrite, inlining);
+                        inlining.pop();
+                        return;
+                    }
+                    Some(Rewrite::Reference(target)) => {
+                        map.insert("$ref".to_string(), Value::String(target));
+                    }
+                    _ => {}
+                }
+            }
+
+            for (key, child) in map.iter_mut() {
+                let child_context = match context {
+                    _ if key == "$ref" => continue,
+                    Context::Names => Context::Object,
+                    Context::Examples => Context::Example,
+                    Context::Example if key == "value" => continue,
+                    Context::Mapping => {
+                        if let Value::String(reference) = child {
+                            if let Some(Rewrite::Reference(target)) = rewrite(reference) {
+                                *reference = target;
+                            }
+                        }
+                        continue;
+                    }
+                    Context::Discriminator if key == "mapping" => Context::Mapping,
+                    _ if key == "discriminator" => Context::Discriminator,
+                    _ if key == "examples" && child.is_object() => Context::Examples,
+                    _ if NAME_MAPS.contains(&key.as_str()) && child.is_object() => Context::Names,
+                    _ if LITERAL_KEYWORDS.contains(&key.as_str()) || key == "examples" => continue,
+                    _ => Context::Object,
+                };
+                rewrite_references(child, child_context, rewrite, inlining);
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                rewrite_references(item, Context::Object, rewrite, inlining);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Replaces the `$ref`s below `value` with what they point at in `document`,
+/// expanded in turn. A reference met again while expanding it is kept, which stops
+/// at cycles.
+fn expand_references(value: &mut Value, document: &Value) {
+    rewrite_references(
+        value,
+        Context::Object,
+        &mut |reference| {
+            let target = document.pointer(reference.strip_prefix('#')?)?;
+            Some(Rewrite::Inline(target.clone()))
+        },
+        &mut Vec::new(),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::OpenAPISchemaOrRef;
+    use std::fs;
+    use tempfile::TempDir;
+
+    const API: &str = r##"openapi: 3.0.3
+info:
+  title: Pets
+  version: 1.0.0
+paths:
+  /pets:
+    $ref: 'paths/pets.yaml'
+components:
+  schemas:
+    Error:
+      type: object
+      properties:
+        code:
+          type: integer
+    Node:
+      type: object
+      properties:
+        children:
+          type: array
+          items:
+            $ref: '#/components/schemas/Node'
+"##;
+
+    const PETS: &str = r##"get:
+  responses:
+    '200':
+      description: OK
+      content:
+        application/json:
+          schema:
+            type: array
+            items:
+              $ref: '../schemas.yaml#/Pet'
+          example:
+            - name: Rex
+    default:
+      description: Error
+      content:
+        application/json:
+          schema:
+            $ref: '../schemas.yaml#/Error'
+"##;
+
+    const SCHEMAS: &str = r##"Pet:
+  type: object
+  properties:
+    name:
+      type: string
+    owner:
+      $ref: '#/Owner'
+Owner:
+  type: object
+  properties:
+    name:
+      type: string
+Error:
+  type: object
+  properties:
+    message:
+      type: string
+"##;
+
+    async fn parse(temp_dir: &TempDir) -> OpenAPIParser {
+        fs::create_dir(temp_dir.path().join("paths")).unwrap();
+        fs::write(temp_dir.path().join("paths/pets.yaml"), PETS).unwrap();
+        fs::write(temp_dir.path().join("schemas.yaml"), SCHEMAS).unwrap();
+        let path = temp_dir.path().join("api.yaml");
+        fs::write(&path, API).unwrap();
+
+        let mut parser = OpenAPIParser::new();
+        parser.parse_file(&path).await.unwrap();
+        parser
+    }
+
+    fn reference(schema: &OpenAPISchemaOrRef) -> &str {
+        match schema {
+            OpenAPISchemaOrRef::Reference(reference) => &reference.reference,
+            OpenAPISchemaOrRef::Schema(_) => panic!("expected a reference"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_bundle_moves_external_objects_into_components() {
+        let temp_dir = TempDir::new().unwrap();
+        let parser = parse(&temp_dir).await;
+
+        let spec = bundle(&parser).unwrap();
+
+        let schemas = &spec.components.as_ref().unwrap().schemas;
+        let mut names: Vec<_> = schemas.keys().collect();
+        names.sort();
+        assert_eq!(names, vec!["Error", "Error2", "Node", "Owner", "Pet"]);
+        let OpenAPISchemaOrRef::Schema(pet) = &schemas["Pet"] else {
+            panic!("Pet should be inline");
+        };
+        assert_eq!(
+            reference(&pet.properties["owner"]),
+            "#/components/schemas/Owner"
+        );
+
+        // OpenAPI 3.0 has no path item components, so the path item is inlined
+        let get = spec.paths["/pets"].get.as_ref().unwrap();
+        let response = |status: &str| match &get.responses[status] {
+            crate::types::OpenAPIResponseOrRef::Response(response) => response.clone(),
+            _ => panic!("expected an inline response"),
+        };
+        let ok = response("200");
+        let media = &ok.content["application/json"];
+        let OpenAPISchemaOrRef::Schema(array) = media.schema.as_ref().unwrap() else {
+            panic!("expected an inline schema");
+        };
+        assert_eq!(
+            reference(array.items.as_ref().unwrap()),
+            "#/components/schemas/Pet"
+        );
+        assert_eq!(media.example, Some(serde_json::json!([{ "name": "Rex" }])));
+        assert_eq!(
+            reference(
+                response("default").content["application/json"]
+                    .schema
+                    .as_ref()
+                    .unwrap()
+            ),
+            "#/components/schemas/Error2"
+        );
+
+        // The bundle is self-contained
+        let bundled = temp_dir.path().join("bundled.yaml");
+        fs::write(&bundled, serde_yaml::to_string(&spec).unwrap()).unwrap();
+        let mut parser = OpenAPIParser::new();
+        parser.parse_file(&bundled).await.unwrap();
+        assert!(parser.get_external_components().schemas.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_dereference_replaces_references_and_keeps_cycles() {
+        let temp_dir = TempDir::new().unwrap();
+        let parser = parse(&temp_dir).await;
+
+        let spec = dereference(&parser).unwrap();
+
+        let get = spec.paths["/pets"].get.as_ref().unwrap();
+        let crate::types::OpenAPIResponseOrRef::Response(ok) = &get.responses["200"] else {
+            panic!("expected an inline response");
+        };
+        let OpenAPISchemaOrRef::Schema(array) =
+            ok.content["application/json"].schema.as_ref().unwrap()
+        else {
+            panic!("expected an inline schema");
+        };
+        let Some(OpenAPISchemaOrRef::Schema(pet)) = array.items.as_deref() else {
+            panic!("Pet should be expanded");
+        };
+        assert!(matches!(
+            &pet.properties["owner"],
+            OpenAPISchemaOrRef::Schema(owner) if owner.properties.contains_key("name")
+        ));
+
+        // The recursive schema keeps its reference to itself
+        let schemas = &spec.components.as_ref().unwrap().schemas;
+        let OpenAPISchemaOrRef::Schema(node) = &schemas["Node"] else {
+            panic!("Node should be inline");
+        };
+        let OpenAPISchemaOrRef::Schema(children) = &node.properties["children"] else {
+            panic!("children should be inline");
+        };
+        let Some(OpenAPISchemaOrRef::Schema(child)) = children.items.as_deref() else {
+            panic!("the first level should be expanded");
+        };
+        let OpenAPISchemaOrRef::Schema(grandchildren) = &child.properties["children"] else {
+            panic!("children should be inline");
+        };
+        assert_eq!(
+            reference(grandchildren.items.as_ref().unwrap()),
+            "#/components/schemas/Node"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_alias_cycles_fail() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("a.yaml"), "A:\n  $ref: 'b.yaml#/B'\n").unwrap();
+        fs::write(temp_dir.path().join("b.yaml"), "B:\n  $ref: 'a.yaml#/A'\n").unwrap();
+        let path = temp_dir.path().join("api.yaml");
+        fs::write(
+            &path,
+            r##"openapi: 3.0.3
+info:
+  title: Cycle
+  version: 1.0.0
+paths: {}
+components:
+  schemas:
+    Start:
+      $ref: 'a.yaml#/A'
+"##,
+        )
+        .unwrap();
+        let mut parser = OpenAPIParser::new();
+        parser.parse_file(&path).await.unwrap();
+
+        for result in [bundle(&parser), dereference(&parser)] {
+            let error = result.unwrap_err();
+            assert!(matches!(
+                error.kind(),
+                crate::errors::OpenAPIError::CircularReference { .. }
+            ));
+        }
+    }
+
+    #[test]
+    fn test_rewrite_references_skips_data_and_follows_mappings() {
+        let mut document = serde_json::json!({
+            "properties": {
+                "default": { "$ref": "a" },
+                "enum": { "enum": [{ "$ref": "a" }] },
+            },
+            "discriminator": { "mapping": { "cat": "a", "dog": "Dog" } },
+            "examples": {
+                "one": { "value": { "$ref": "a" } },
+                "two": { "$ref": "a" },
+            },
+        });
+
+        rewrite_references(
+            &mut document,
+            Context::Object,
+            &mut |reference| (reference == "a").then(|| Rewrite::Reference("b".to_string())),
+            &mut Vec::new(),
+        );
+
+        assert_eq!(
+            document,
+            serde_json::json!({
+                "properties": {
+                    "default": { "$ref": "b" },
+                    "enum": { "enum": [{ "$ref": "a" }] },
+                },
+                "discriminator": { "mapping": { "cat": "b", "dog": "Dog" } },
+                "examples": {
+                    "one": { "value": { "$ref": "a" } },
+                    "two": { "$ref": "b" },
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn test_component_names() {
+        assert_eq!(
+            component_name("/specs/common.yaml#/components/schemas/Error"),
+            "Error"
+        );
+        assert_eq!(component_name("/specs/pet.yaml#"), "pet");
+        assert_eq!(
+            component_name("/specs/common.yaml#/Pet%20Owner"),
+            "Pet_20Owner"
+        );
+
+        let mut entries = Map::new();
+        entries.insert("Error".to_string(), Value::Null);
+        entries.insert("Error2".to_string(), Value::Null);
+        assert_eq!(available_name(&entries, "Error"), "Error3");
+        assert_eq!(available_name(&entries, "Pet"), "Pet");
+    }
+}
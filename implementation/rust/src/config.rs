@@ -0,0 +1,461 @@
+//! Project configuration, read from an `openapi-codegen.toml` or `.yaml` file next to
+//! the specification. It holds generator options shared by every target, and named
+//! generation targets that override them, so a project can be generated without
+//! repeating command line flags.
+
+use indexmap::IndexMap;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use tokio::fs;
+
+use crate::errors::{self, ErrorContext, Result};
+use crate::mappings::TypeMappings;
+use crate::templates::TemplateOverrides;
+use crate::types::{GeneratorConfig, InlineNamingStrategy, SpringBootProfile, TargetLanguage};
+
+/// Names a configuration file is discovered by, in order of preference.
+pub const CONFIG_FILE_NAMES: &[&str] = &[
+    "openapi-codegen.toml",
+    "openapi-codegen.yaml",
+    "openapi-codegen.yml",
+];
+
+/// Name of the only target of a configuration that declares none.
+pub const DEFAULT_TARGET: &str = "default";
+
+/// Reads a configuration file as TOML, YAML or JSON, chosen by its extension. Parse
+/// errors point at the file.
+pub(crate) async fn read_config_file<T: DeserializeOwned>(path: &Path) -> Result<T> {
+    let content = fs::read_to_string(path)
+        .await
+        .map_err(|_| errors::file_not_found(path.display().to_string()))?;
+
+    let file_context = || ErrorContext::new().with_file(path.display().to_string());
+    let extension = path.extension().and_then(|ext| ext.to_str()).unwrap_or("");
+    match extension {
+        "toml" => toml::from_str(&content)
+            .map_err(|e| errors::invalid_toml(e.message()).with_context(file_context())),
+        "yaml" | "yml" => serde_yaml::from_str(&content)
+            .map_err(|e| errors::invalid_yaml(e.to_string()).with_context(file_context())),
+        "json" => serde_json::from_str(&content)
+            .map_err(|e| errors::invalid_json(e.to_string()).with_context(file_context())),
+        _ => Err(errors::unsupported_format(extension)),
+    }
+}
+
+/// A project configuration:
+///
+/// ```toml
+/// [defaults]
+/// package = "com.example.petstore"
+/// spring-boot = "3"
+/// type-mappings = "mappings.yaml"
+///
+/// [targets.server]
+/// output = "server/generated"
+///
+/// [targets.models]
+/// output = "models/generated"
+/// controllers = false
+///
+/// [targets.models.filters]
+/// exclude-tags = ["internal"]
+/// ```
+///
+/// Each target takes the options it doesn't set from `defaults`. Paths are relative
+/// to the configuration file.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProjectConfig {
+    /// Options of every target that doesn't set them itself
+    #[serde(default)]
+    pub defaults: TargetConfig,
+    /// Generation targets by name; the defaults alone are generated when there are none
+    #[serde(default)]
+    pub targets: IndexMap<String, TargetConfig>,
+    /// File the configuration was read from, for error messages
+    #[serde(skip)]
+    pub path: Option<PathBuf>,
+}
+
+/// Generator options of a target, each unset unless the configuration or a command
+/// line flag sets it.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct TargetConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output: Option<PathBuf>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub package: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model_package: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_package: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub controllers: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub models: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub validation: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub swagger: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inline_naming: Option<InlineNamingStrategy>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target: Option<TargetLanguage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spring_boot: Option<SpringBootProfile>,
+    /// Directory of Handlebars templates replacing the built-in ones by name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub templates: Option<PathBuf>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_mappings: Option<TypeMappingsSource>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filters: Option<OperationFilters>,
+}
+
+/// Type mappings given as a JSON or YAML file, or written out in the configuration.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum TypeMappingsSource {
+    File(PathBuf),
+    Inline(TypeMappings),
+}
+
+/// Selects the operations controllers are generated for, by tag and path. An
+/// operation is generated when its tag and path are included and its tag isn't
+/// excluded.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct OperationFilters {
+    /// Tags whose operations are generated; all when empty
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// Tags whose operations are not generated
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exclude_tags: Vec<String>,
+    /// Prefixes of the paths whose operations are generated; all when empty
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub paths: Vec<String>,
+}
+
+impl OperationFilters {
+    /// Returns whether the operation at a path, grouped under a tag, is generated.
+    pub fn includes(&self, tag: &str, path: &str) -> bool {
+        (self.tags.is_empty() || self.tags.iter().any(|t| t == tag))
+            && !self.exclude_tags.iter().any(|t| t == tag)
+            && (self.paths.is_empty() || self.paths.iter().any(|p| path.starts_with(p)))
+    }
+}
+
+impl ProjectConfig {
+    /// Reads a configuration file, as TOML, YAML or JSON by its extension. Relative
+    /// paths in it are resolved against the file's directory.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be read, its format is unsupported, or it
+    /// isn't a valid configuration.
+    pub async fn load<P: AsRef<Path>>(file_path: P) -> Result<Self> {
+        let path = file_path.as_ref();
+        let mut config: ProjectConfig = read_config_file(path).await?;
+
+        let base_dir = path.parent().unwrap_or(Path::new(""));
+        for target in std::iter::once(&mut config.defaults).chain(config.targets.values_mut()) {
+            target.resolve_paths(base_dir);
+            if let Some(TypeMappingsSource::Inline(mappings)) = &target.type_mappings {
+                mappings.validate(&path.display().to_string())?;
+            }
+        }
+        config.path = Some(path.to_path_buf());
+        Ok(config)
+    }
+
+    /// Reads the configuration file in the directory of a specification, if there
+    /// is one.
+    pub async fn discover<P: AsRef<Path>>(spec_path: P) -> Result<Option<Self>> {
+        let dir = spec_path.as_ref().parent().unwrap_or(Path::new(""));
+        for name in CONFIG_FILE_NAMES {
+            let path = dir.join(name);
+            if fs::try_exists(&path).await.unwrap_or(false) {
+                return Ok(Some(Self::load(path).await?));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Returns the targets to generate by name, each with the defaults applied: the
+    /// selected ones, or all of them when none is selected.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a selected target isn't declared.
+    pub fn targets(&self, selected: &[String]) -> Result<Vec<(String, TargetConfig)>> {
+        let path = || {
+            self.path
+                .as_ref()
+                .map_or("the configuration".to_string(), |path| {
+                    path.display().to_string()
+                })
+        };
+
+        if self.targets.is_empty() {
+            return match selected.iter().find(|name| *name != DEFAULT_TARGET) {
+                Some(name) => Err(errors::unknown_target(name, path())),
+                None => Ok(vec![(DEFAULT_TARGET.to_string(), self.defaults.clone())]),
+            };
+        }
+        if let Some(name) = selected
+            .iter()
+            .find(|name| !self.targets.contains_key(*name))
+        {
+            return Err(errors::unknown_target(name, path()));
+        }
+
+        Ok(self
+            .targets
+            .iter()
+            .filter(|(name, _)| selected.is_empty() || selected.contains(name))
+            .map(|(name, target)| (name.clone(), target.clone().or(&self.defaults)))
+            .collect())
+    }
+}
+
+impl TargetConfig {
+    /// Returns these options, taking those they don't set from another set.
+    pub fn or(self, fallback: &TargetConfig) -> TargetConfig {
+        let fallback = fallback.clone();
+        TargetConfig {
+            output: self.output.or(fallback.output),
+            package: self.package.or(fallback.package),
+            model_package: self.model_package.or(fallback.model_package),
+            api_package: self.api_package.or(fallback.api_package),
+            controllers: self.controllers.or(fallback.controllers),
+            models: self.models.or(fallback.models),
+            validation: self.validation.or(fallback.validation),
+            swagger: self.swagger.or(fallback.swagger),
+            inline_naming: self.inline_naming.or(fallback.inline_naming),
+            target: self.target.or(fallback.target),
+            spring_boot: self.spring_boot.or(fallback.spring_boot),
+            type_mappings: self.type_mappings.or(fallback.type_mappings),
+            templates: self.templates.or(fallback.templates),
+            filters: self.filters.or(fallback.filters),
+        }
+    }
+
+    /// Returns these options with the built-in default of every option they don't
+    /// set, as they are generated with.
+    pub fn with_defaults(self) -> TargetConfig {
+        let package = self
+            .package
+            .unwrap_or_else(|| "com.example.api".to_string());
+        TargetConfig {
+            output: Some(self.output.unwrap_or_else(|| PathBuf::from("./generated"))),
+            model_package: Some(
+                self.model_package
+                    .unwrap_or_else(|| format!("{package}.model")),
+            ),
+            api_package: Some(self.api_package.unwrap_or_else(|| format!("{package}.api"))),
+            package: Some(package),
+            controllers: Some(self.controllers.unwrap_or(true)),
+            models: Some(self.models.unwrap_or(true)),
+            validation: Some(self.validation.unwrap_or(true)),
+            swagger: Some(self.swagger.unwrap_or(true)),
+            inline_naming: Some(self.inline_naming.unwrap_or_default()),
+            target: Some(self.target.unwrap_or_default()),
+            spring_boot: Some(self.spring_boot.unwrap_or_default()),
+            type_mappings: self.type_mappings,
+            templates: self.templates,
+            filters: self.filters,
+        }
+    }
+
+    /// Builds the generator configuration of these options, reading the type
+    /// mappings and templates they refer to.
+    pub async fn into_generator_config(self, verbose: bool) -> Result<GeneratorConfig> {
+        let options = self.with_defaults();
+        let type_mappings = match options.type_mappings {
+            Some(TypeMappingsSource::File(path)) => TypeMappings::load(path).await?,
+            Some(TypeMappingsSource::Inline(mappings)) => mappings,
+            None => TypeMappings::default(),
+        };
+        let templates = match &options.templates {
+            Some(dir) => TemplateOverrides::load(dir).await?,
+            None => TemplateOverrides::default(),
+        };
+
+        Ok(GeneratorConfig {
+            output_dir: options.output.unwrap_or_default(),
+            base_package: options.package.unwrap_or_default(),
+            model_package: options.model_package,
+            api_package: options.api_package,
+            generate_controllers: options.controllers.unwrap_or(true),
+            generate_models: options.models.unwrap_or(true),
+            include_validation: options.validation.unwrap_or(true),
+            include_swagger: options.swagger.unwrap_or(true),
+            verbose,
+            inline_naming: options.inline_naming.unwrap_or_default(),
+            target: options.target.unwrap_or_default(),
+            spring_boot: options.spring_boot.unwrap_or_default(),
+            type_mappings,
+            templates,
+            filters: options.filters.unwrap_or_default(),
+        })
+    }
+
+    /// Resolves relative paths against the directory of the file they were read from.
+    fn resolve_paths(&mut self, base_dir: &Path) {
+        let resolve = |path: &mut PathBuf| {
+            if path.is_relative() {
+                *path = base_dir.join(&*path);
+            }
+        };
+        if let Some(output) = &mut self.output {
+            resolve(output);
+        }
+        if let Some(templates) = &mut self.templates {
+            resolve(templates);
+        }
+        if let Some(TypeMappingsSource::File(path)) = &mut self.type_mappings {
+            resolve(path);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::OpenAPIError;
+    use tempfile::TempDir;
+
+    const CONFIG: &str = r#"
+[defaults]
+package = "com.example.petstore"
+spring-boot = "2"
+type-mappings = "mappings.yaml"
+
+[targets.server]
+output = "server"
+
+[targets.models]
+output = "models"
+controllers = false
+inline-naming = "title"
+
+[targets.models.type-mappings.formats]
+money = "org.javamoney.moneta.Money"
+
+[targets.models.filters]
+exclude-tags = ["internal"]
+"#;
+
+    #[tokio::test]
+    async fn test_load_project_config() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("openapi-codegen.toml");
+        std::fs::write(&path, CONFIG).unwrap();
+
+        let config = ProjectConfig::load(&path).await.unwrap();
+        let targets = config.targets(&[]).unwrap();
+        assert_eq!(targets.len(), 2);
+
+        let (name, server) = &targets[0];
+        assert_eq!(name, "server");
+        assert_eq!(server.output, Some(temp_dir.path().join("server")));
+        assert_eq!(server.package.as_deref(), Some("com.example.petstore"));
+        assert_eq!(server.spring_boot, Some(SpringBootProfile::Boot2));
+        assert_eq!(
+            server.type_mappings,
+            Some(TypeMappingsSource::File(
+                temp_dir.path().join("mappings.yaml")
+            ))
+        );
+
+        let (_, models) = &targets[1];
+        assert_eq!(models.controllers, Some(false));
+        assert_eq!(models.inline_naming, Some(InlineNamingStrategy::Title));
+        assert!(matches!(
+            &models.type_mappings,
+            Some(TypeMappingsSource::Inline(mappings))
+                if mappings.format_class("money") == Some("org.javamoney.moneta.Money")
+        ));
+        let filters = models.filters.clone().unwrap();
+        assert!(filters.includes("pets", "/pets"));
+        assert!(!filters.includes("internal", "/health"));
+
+        let selected = config.targets(&["models".to_string()]).unwrap();
+        assert_eq!(selected.len(), 1);
+        assert_eq!(selected[0].0, "models");
+    }
+
+    #[tokio::test]
+    async fn test_discover_project_config() {
+        let temp_dir = TempDir::new().unwrap();
+        let spec_path = temp_dir.path().join("api.yaml");
+        assert!(ProjectConfig::discover(&spec_path).await.unwrap().is_none());
+
+        std::fs::write(
+            temp_dir.path().join("openapi-codegen.yaml"),
+            "defaults:\n  package: com.example.found\n",
+        )
+        .unwrap();
+        let config = ProjectConfig::discover(&spec_path).await.unwrap().unwrap();
+        let targets = config.targets(&[]).unwrap();
+        assert_eq!(targets.len(), 1);
+        assert_eq!(targets[0].0, DEFAULT_TARGET);
+        assert_eq!(targets[0].1.package.as_deref(), Some("com.example.found"));
+    }
+
+    #[tokio::test]
+    async fn test_invalid_project_config() {
+        let temp_dir = TempDir::new().unwrap();
+
+        let unknown = temp_dir.path().join("unknown.toml");
+        std::fs::write(&unknown, "[defaults]\npackages = \"com.example\"\n").unwrap();
+        let error = ProjectConfig::load(&unknown).await.unwrap_err();
+        assert!(matches!(error.kind(), OpenAPIError::InvalidToml { .. }));
+
+        let blank = temp_dir.path().join("blank.toml");
+        std::fs::write(&blank, "[defaults.type-mappings.formats]\nmoney = \"\"\n").unwrap();
+        let error = ProjectConfig::load(&blank).await.unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("Invalid type mapping 'formats.money' in"));
+
+        let config = ProjectConfig::load(&{
+            let path = temp_dir.path().join("config.toml");
+            std::fs::write(&path, CONFIG).unwrap();
+            path
+        })
+        .await
+        .unwrap();
+        let error = config.targets(&["client".to_string()]).unwrap_err();
+        assert!(error.to_string().starts_with("Unknown target 'client' in"));
+    }
+
+    #[test]
+    fn test_target_config_precedence_and_defaults() {
+        let flags = TargetConfig {
+            swagger: Some(false),
+            ..Default::default()
+        };
+        let file = TargetConfig {
+            swagger: Some(true),
+            package: Some("com.example.shop".to_string()),
+            ..Default::default()
+        };
+
+        let options = flags.or(&file).with_defaults();
+        assert_eq!(options.swagger, Some(false));
+        assert_eq!(options.package.as_deref(), Some("com.example.shop"));
+        assert_eq!(
+            options.model_package.as_deref(),
+            Some("com.example.shop.model")
+        );
+        assert_eq!(options.api_package.as_deref(), Some("com.example.shop.api"));
+        assert_eq!(options.output, Some(PathBuf::from("./generated")));
+        assert_eq!(options.controllers, Some(true));
+        assert_eq!(options.spring_boot, Some(SpringBootProfile::Boot3));
+    }
+}
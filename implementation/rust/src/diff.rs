@@ -0,0 +1,1209 @@
+use indexmap::IndexMap;
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::path::Path;
+
+use crate::errors::Result;
+use crate::parser::OpenAPIParser;
+use crate::types::*;
+
+/// Prefix of references to schema components, which are compared by name.
+const SCHEMA_PREFIX: &str = "#/components/schemas/";
+
+/// What changed between two versions of a specification. Whether a kind of change
+/// is breaking is judged from the point of view of existing clients.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ChangeKind {
+    EndpointRemoved,
+    EndpointAdded,
+    RequiredParameterAdded,
+    ParameterAdded,
+    ParameterRemoved,
+    ParameterRequired,
+    RequestBodyRequired,
+    SuccessResponseRemoved,
+    SchemaRemoved,
+    SchemaAdded,
+    TypeChanged,
+    /// Values clients may send were removed
+    RequestEnumNarrowed,
+    RequestEnumWidened,
+    ResponseEnumNarrowed,
+    /// Clients may receive values they don't know
+    ResponseEnumWidened,
+    RequiredRequestPropertyAdded,
+    RequestPropertyAdded,
+    RequestPropertyRemoved,
+    RequestPropertyRequired,
+    ResponsePropertyAdded,
+    ResponsePropertyRemoved,
+    ResponsePropertyOptional,
+}
+
+impl ChangeKind {
+    /// Whether the change can break existing clients.
+    pub fn is_breaking(self) -> bool {
+        matches!(
+            self,
+            ChangeKind::EndpointRemoved
+                | ChangeKind::RequiredParameterAdded
+                | ChangeKind::ParameterRequired
+                | ChangeKind::RequestBodyRequired
+                | ChangeKind::SuccessResponseRemoved
+                | ChangeKind::SchemaRemoved
+                | ChangeKind::TypeChanged
+                | ChangeKind::RequestEnumNarrowed
+                | ChangeKind::ResponseEnumWidened
+                | ChangeKind::RequiredRequestPropertyAdded
+                | ChangeKind::RequestPropertyRequired
+                | ChangeKind::ResponsePropertyRemoved
+                | ChangeKind::ResponsePropertyOptional
+        )
+    }
+
+    /// Kebab-case name of the kind, as in JSON reports.
+    pub fn name(self) -> String {
+        serde_json::to_value(self)
+            .ok()
+            .and_then(|value| value.as_str().map(str::to_string))
+            .unwrap_or_default()
+    }
+}
+
+/// A change between two versions of a specification.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Change {
+    pub kind: ChangeKind,
+    pub breaking: bool,
+    /// Operation, as `GET /pets/{id}`, or schema component, as `components/schemas/Pet`
+    pub location: String,
+    pub message: String,
+}
+
+/// How a diff report is printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum ReportFormat {
+    /// Plain text, breaking changes first
+    #[default]
+    Text,
+    /// Markdown tables, e.g. for pull request comments
+    Markdown,
+    /// A JSON object with counts and the list of changes
+    Json,
+}
+
+/// Parses two versions of a specification and compares them; see [`compare`].
+///
+/// # Errors
+///
+/// Returns an error if either file can't be parsed.
+///
+/// # Examples
+///
+/// ```rust
+/// use openapi_codegen_rust::diff;
+///
+/// // Use in async context:
+/// // let changes = diff::compare_files("v1.yaml", "v2.yaml").await.unwrap();
+/// // let breaking = changes.iter().filter(|change| change.breaking).count();
+/// ```
+pub async fn compare_files<P: AsRef<Path>, Q: AsRef<Path>>(
+    old_path: P,
+    new_path: Q,
+) -> Result<Vec<Change>> {
+    let mut old = OpenAPIParser::new();
+    old.parse_file(old_path).await?;
+    let mut new = OpenAPIParser::new();
+    new.parse_file(new_path).await?;
+    Ok(compare(&old, &new))
+}
+
+/// Compares the specifications of two parsers that have parsed a file. Operations
+/// are matched by method and path, ignoring the names of path parameters, and schema
+/// components by name. Changes to a schema component are reported once, at the
+/// component, and judged by whether it's used in requests, responses or both.
+pub fn compare(old: &OpenAPIParser, new: &OpenAPIParser) -> Vec<Change> {
+    let mut differ = Differ {
+        old,
+        new,
+        changes: Vec::new(),
+        resolving: Vec::new(),
+    };
+    differ.compare_operations();
+    differ.compare_components();
+    differ.changes
+}
+
+/// Where schemas are read, and so which changes to them break clients.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+struct Usage {
+    request: bool,
+    response: bool,
+}
+
+impl Usage {
+    const REQUEST: Usage = Usage {
+        request: true,
+        response: false,
+    };
+    const RESPONSE: Usage = Usage {
+        request: false,
+        response: true,
+    };
+    const BOTH: Usage = Usage {
+        request: true,
+        response: true,
+    };
+
+    fn union(self, other: Usage) -> Usage {
+        Usage {
+            request: self.request || other.request,
+            response: self.response || other.response,
+        }
+    }
+}
+
+/// Where a compared schema is, for messages.
+struct Scope<'s> {
+    location: &'s str,
+    /// What the schema's root is, e.g. `request body`; empty for schema components
+    prefix: &'s str,
+}
+
+impl Scope<'_> {
+    /// Describes the schema at `path`, a dotted property path from the root.
+    fn subject(&self, path: &str) -> String {
+        match (self.prefix.is_empty(), path.is_empty()) {
+            (true, true) => "schema".to_string(),
+            (true, false) => format!("property '{path}'"),
+            (false, true) => self.prefix.to_string(),
+            (false, false) => format!("{} property '{path}'", self.prefix),
+        }
+    }
+}
+
+/// An operation with the path item it belongs to.
+struct Endpoint<'p> {
+    name: String,
+    path_item: &'p OpenAPIPathItem,
+    operation: &'p OpenAPIOperation,
+}
+
+struct Differ<'p> {
+    old: &'p OpenAPIParser,
+    new: &'p OpenAPIParser,
+    changes: Vec<Change>,
+    /// References being compared, to stop at cycles through external documents
+    resolving: Vec<(String, String)>,
+}
+
+impl<'p> Differ<'p> {
+    fn report(&mut self, kind: ChangeKind, location: &str, message: String) {
+        self.changes.push(Change {
+            kind,
+            breaking: kind.is_breaking(),
+            location: location.to_string(),
+            message,
+        });
+    }
+
+    fn compare_operations(&mut self) {
+        let old_endpoints = endpoints(self.old);
+        let new_endpoints = endpoints(self.new);
+
+        for (key, old_endpoint) in &old_endpoints {
+            match new_endpoints.get(key) {
+                Some(new_endpoint) => self.compare_operation(old_endpoint, new_endpoint),
+                None => self.report(
+                    ChangeKind::EndpointRemoved,
+                    &old_endpoint.name,
+                    "Removed endpoint".to_string(),
+                ),
+            }
+        }
+
+        for (key, new_endpoint) in &new_endpoints {
+            if !old_endpoints.contains_key(key) {
+                self.report(
+                    ChangeKind::EndpointAdded,
+                    &new_endpoint.name,
+                    "Added endpoint".to_string(),
+                );
+            }
+        }
+    }
+
+    fn compare_operation(&mut self, old: &Endpoint<'p>, new: &Endpoint<'p>) {
+        let location = new.name.as_str();
+        self.compare_parameters(
+            parameters(self.old, old),
+            parameters(self.new, new),
+            location,
+        );
+
+        let old_body = old
+            .operation
+            .request_body
+            .as_ref()
+            .and_then(|body| self.old.resolve_component(body).ok());
+        let new_body = new
+            .operation
+            .request_body
+            .as_ref()
+            .and_then(|body| self.new.resolve_component(body).ok());
+        match (old_body, new_body) {
+            (None, Some(new_body)) if new_body.required => self.report(
+                ChangeKind::RequestBodyRequired,
+                location,
+                "Added required request body".to_string(),
+            ),
+            (Some(old_body), Some(new_body)) => {
+                if !old_body.required && new_body.required {
+                    self.report(
+                        ChangeKind::RequestBodyRequired,
+                        location,
+                        "Made request body required".to_string(),
+                    );
+                }
+                let scope = Scope {
+                    location,
+                    prefix: "request body",
+                };
+                self.compare_content(&old_body.content, &new_body.content, Usage::REQUEST, &scope);
+            }
+            _ => {}
+        }
+
+        for (status, old_response) in &old.operation.responses {
+            let Some(new_response) = new.operation.responses.get(status) else {
+                if status.starts_with('2') {
+                    self.report(
+                        ChangeKind::SuccessResponseRemoved,
+                        location,
+                        format!("Removed success response {status}"),
+                    );
+                }
+                continue;
+            };
+            let (Ok(old_response), Ok(new_response)) = (
+                self.old.resolve_component(old_response),
+                self.new.resolve_component(new_response),
+            ) else {
+                continue;
+            };
+            let prefix = format!("response {status}");
+            let scope = Scope {
+                location,
+                prefix: &prefix,
+            };
+            self.compare_content(
+                &old_response.content,
+                &new_response.content,
+                Usage::RESPONSE,
+                &scope,
+            );
+        }
+    }
+
+    /// Compares parameters other than path parameters, whose names may change with
+    /// the path template without affecting clients.
+    fn compare_parameters(
+        &mut self,
+        old: Vec<&'p OpenAPIParameter>,
+        new: Vec<&'p OpenAPIParameter>,
+        location: &str,
+    ) {
+        let find = |parameters: &[&'p OpenAPIParameter], parameter: &OpenAPIParameter| {
+            parameters
+                .iter()
+                .copied()
+                .find(|other| other.name == parameter.name && other.location == parameter.location)
+        };
+
+        for old_parameter in old.iter().filter(|p| p.location != "path") {
+            let name = format!(
+                "{} parameter '{}'",
+                old_parameter.location, old_parameter.name
+            );
+            let Some(new_parameter) = find(&new, old_parameter) else {
+                self.report(
+                    ChangeKind::ParameterRemoved,
+                    location,
+                    format!("Removed {name}"),
+                );
+                continue;
+            };
+
+            if !old_parameter.required && new_parameter.required {
+                self.report(
+                    ChangeKind::ParameterRequired,
+                    location,
+                    format!("Made {name} required"),
+                );
+            }
+            if let (Some(old_schema), Some(new_schema)) =
+                (&old_parameter.schema, &new_parameter.schema)
+            {
+                let scope = Scope {
+                    location,
+                    prefix: &name,
+                };
+                self.compare_schema(old_schema, new_schema, Usage::REQUEST, &scope, "");
+            }
+        }
+
+        for new_parameter in new.iter().filter(|p| p.location != "path") {
+            if find(&old, new_parameter).is_none() {
+                let name = format!(
+                    "{} parameter '{}'",
+                    new_parameter.location, new_parameter.name
+                );
+                if new_parameter.required {
+                    self.report(
+                        ChangeKind::RequiredParameterAdded,
+                        location,
+                        format!("Added required {name}"),
+                    );
+                } else {
+                    self.report(
+                        ChangeKind::ParameterAdded,
+                        location,
+                        format!("Added optional {name}"),
+                    );
+                }
+            }
+        }
+    }
+
+    /// Compares the schemas of the media types both versions have.
+    fn compare_content(
+        &mut self,
+        old: &'p IndexMap<String, OpenAPIMediaType>,
+        new: &'p IndexMap<String, OpenAPIMediaType>,
+        usage: Usage,
+        scope: &Scope,
+    ) {
+        for (media_type, old_media) in old {
+            let Some(new_media) = new.get(media_type) else {
+                continue;
+            };
+            if let (Some(old_schema), Some(new_schema)) = (&old_media.schema, &new_media.schema) {
+                self.compare_schema(old_schema, new_schema, usage, scope, "");
+            }
+        }
+    }
+
+    fn compare_components(&mut self) {
+        let old_schemas = schemas(self.old);
+        let new_schemas = schemas(self.new);
+        let usage = schema_usage(self.old).into_iter().fold(
+            schema_usage(self.new),
+            |mut usage, (name, old_usage)| {
+                let entry = usage.entry(name).or_default();
+                *entry = entry.union(old_usage);
+                usage
+            },
+        );
+
+        for (name, old_schema) in &old_schemas {
+            let location = format!("components/schemas/{name}");
+            match new_schemas.get(*name) {
+                Some(new_schema) => {
+                    // Schemas no operation uses are still generated as models
+                    let usage = usage.get(*name).copied().unwrap_or(Usage::BOTH);
+                    let scope = Scope {
+                        location: &location,
+                        prefix: "",
+                    };
+                    self.compare_schema(old_schema, new_schema, usage, &scope, "");
+                }
+                None => self.report(
+                    ChangeKind::SchemaRemoved,
+                    &location,
+                    "Removed schema".to_string(),
+                ),
+            }
+        }
+
+        for name in new_schemas.keys() {
+            if !old_schemas.contains_key(name) {
+                self.report(
+                    ChangeKind::SchemaAdded,
+                    &format!("components/schemas/{name}"),
+                    "Added schema".to_string(),
+                );
+            }
+        }
+    }
+
+    /// Compares the schema at `path` below the root of `scope`. References to schema
+    /// components of the same name stop the comparison, as the components are
+    /// compared by name.
+    fn compare_schema(
+        &mut self,
+        old: &'p OpenAPISchemaOrRef,
+        new: &'p OpenAPISchemaOrRef,
+        usage: Usage,
+        scope: &Scope,
+        path: &str,
+    ) {
+        if let (Some(old_name), Some(new_name)) = (component_name(old), component_name(new)) {
+            if old_name != new_name {
+                self.report(
+                    ChangeKind::TypeChanged,
+                    scope.location,
+                    format!(
+                        "Changed type of {} from {old_name} to {new_name}",
+                        scope.subject(path)
+                    ),
+                );
+            }
+            return;
+        }
+
+        let (Ok(old_schema), Ok(new_schema)) = (
+            self.old.resolve_component(old),
+            self.new.resolve_component(new),
+        ) else {
+            return;
+        };
+
+        let references = (
+            old.reference().unwrap_or_default().to_string(),
+            new.reference().unwrap_or_default().to_string(),
+        );
+        let referenced = !references.0.is_empty() || !references.1.is_empty();
+        if referenced {
+            if self.resolving.contains(&references) {
+                return;
+            }
+            self.resolving.push(references);
+        }
+
+        self.compare_schema_object(old_schema, new_schema, usage, scope, path);
+
+        if referenced {
+            self.resolving.pop();
+        }
+    }
+
+    fn compare_schema_object(
+        &mut self,
+        old: &'p OpenAPISchema,
+        new: &'p OpenAPISchema,
+        usage: Usage,
+        scope: &Scope,
+        path: &str,
+    ) {
+        if let (Some(old_type), Some(new_type)) = (type_name(old), type_name(new)) {
+            if old_type != new_type {
+                self.report(
+                    ChangeKind::TypeChanged,
+                    scope.location,
+                    format!(
+                        "Changed type of {} from {old_type} to {new_type}",
+                        scope.subject(path)
+                    ),
+                );
+                return;
+            }
+        }
+
+        self.compare_enum(old, new, usage, scope, path);
+
+        for (name, old_property) in &old.properties {
+            let property_path = join(path, name);
+            let subject = scope.subject(&property_path);
+            let old_required = old.required.contains(name);
+            let new_required = new.required.contains(name);
+
+            let Some(new_property) = new.properties.get(name) else {
+                if usage.request {
+                    self.report(
+                        ChangeKind::RequestPropertyRemoved,
+                        scope.location,
+                        format!("Removed {subject}"),
+                    );
+                }
+                if usage.response {
+                    self.report(
+                        ChangeKind::ResponsePropertyRemoved,
+                        scope.location,
+                        format!("Removed {subject}"),
+                    );
+                }
+                continue;
+            };
+
+            if usage.request && !old_required && new_required {
+                self.report(
+                    ChangeKind::RequestPropertyRequired,
+                    scope.location,
+                    format!("Made {subject} required"),
+                );
+            }
+            if usage.response && old_required && !new_required {
+                self.report(
+                    ChangeKind::ResponsePropertyOptional,
+                    scope.location,
+                    format!("Made {subject} optional"),
+                );
+            }
+            self.compare_schema(old_property, new_property, usage, scope, &property_path);
+        }
+
+        for name in new.properties.keys() {
+            if old.properties.contains_key(name) {
+                continue;
+            }
+            let subject = scope.subject(&join(path, name));
+            if usage.request {
+                if new.required.contains(name) {
+                    self.report(
+                        ChangeKind::RequiredRequestPropertyAdded,
+                        scope.location,
+                        format!("Added required {subject}"),
+                    );
+                } else {
+                    self.report(
+                        ChangeKind::RequestPropertyAdded,
+                        scope.location,
+                        format!("Added optional {subject}"),
+                    );
+                }
+            }
+            if usage.response {
+                self.report(
+                    ChangeKind::ResponsePropertyAdded,
+                    scope.location,
+                    format!("Added {subject}"),
+                );
+            }
+        }
+
+        if let (Some(old_items), Some(new_items)) = (&old.items, &new.items) {
+            self.compare_schema(old_items, new_items, usage, scope, &format!("{path}[]"));
+        }
+
+        for (old_part, new_part) in old.all_of.iter().zip(&new.all_of) {
+            self.compare_schema(old_part, new_part, usage, scope, path);
+        }
+    }
+
+    /// Reports enum values removed from or added to a schema. Adding an `enum` to a
+    /// schema that had none narrows it, and dropping it widens it.
+    fn compare_enum(
+        &mut self,
+        old: &OpenAPISchema,
+        new: &OpenAPISchema,
+        usage: Usage,
+        scope: &Scope,
+        path: &str,
+    ) {
+        let subject = scope.subject(path);
+        let values = |values: &[Value]| {
+            values
+                .iter()
+                .map(Value::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        let removed: Vec<Value> = old
+            .enum_values
+            .iter()
+            .filter(|value| !new.enum_values.contains(value))
+            .cloned()
+            .collect();
+        let added: Vec<Value> = new
+            .enum_values
+            .iter()
+            .filter(|value| !old.enum_values.contains(value))
+            .cloned()
+            .collect();
+
+        let narrowed = if old.enum_values.is_empty() {
+            (!new.enum_values.is_empty())
+                .then(|| format!("Restricted {subject} to {}", values(&new.enum_values)))
+        } else if !new.enum_values.is_empty() && !removed.is_empty() {
+            Some(format!("Removed {} from {subject}", values(&removed)))
+        } else {
+            None
+        };
+        let widened = if new.enum_values.is_empty() {
+            (!old.enum_values.is_empty()).then(|| format!("Removed the enum of {subject}"))
+        } else if !old.enum_values.is_empty() && !added.is_empty() {
+            Some(format!("Added {} to {subject}", values(&added)))
+        } else {
+            None
+        };
+
+        if let Some(message) = narrowed {
+            if usage.request {
+                self.report(
+                    ChangeKind::RequestEnumNarrowed,
+                    scope.location,
+                    message.clone(),
+                );
+            }
+            if usage.response {
+                self.report(ChangeKind::ResponseEnumNarrowed, scope.location, message);
+            }
+        }
+        if let Some(message) = widened {
+            if usage.request {
+                self.report(
+                    ChangeKind::RequestEnumWidened,
+                    scope.location,
+                    message.clone(),
+                );
+            }
+            if usage.response {
+                self.report(ChangeKind::ResponseEnumWidened, scope.location, message);
+            }
+        }
+    }
+}
+
+/// Returns the operations of a specification's paths keyed by method and path with
+/// path parameter names left out, so renaming a parameter keeps the key.
+fn endpoints(parser: &OpenAPIParser) -> IndexMap<(String, String), Endpoint<'_>> {
+    let mut endpoints = IndexMap::new();
+
+    for (path, path_item) in &parser.get_spec().paths {
+        let Ok(path_item) = parser.resolve_component(path_item) else {
+            continue;
+        };
+        for (method, operation) in path_item.operations() {
+            endpoints.insert(
+                (method.to_string(), template_key(path)),
+                Endpoint {
+                    name: format!("{} {path}", method.to_uppercase()),
+                    path_item,
+                    operation,
+                },
+            );
+        }
+    }
+
+    endpoints
+}
+
+/// Replaces the parameter names of a path template with `{}`.
+fn template_key(path: &str) -> String {
+    let mut key = String::with_capacity(path.len());
+    let mut in_parameter = false;
+    for c in path.chars() {
+        match c {
+            '{' => {
+                in_parameter = true;
+                key.push_str("{}");
+            }
+            '}' => in_parameter = false,
+            c if !in_parameter => key.push(c),
+            _ => {}
+        }
+    }
+    key
+}
+
+/// Returns the resolvable parameters of an operation, with those of its path item
+/// that the operation doesn't override.
+fn parameters<'p>(parser: &'p OpenAPIParser, endpoint: &Endpoint<'p>) -> Vec<&'p OpenAPIParameter> {
+    let resolve = |parameters: &'p [OpenAPIParameterOrRef]| {
+        parameters
+            .iter()
+            .filter_map(|parameter| parser.resolve_component(parameter).ok())
+            .collect::<Vec<_>>()
+    };
+
+    let mut parameters = resolve(&endpoint.path_item.parameters);
+    for parameter in resolve(&endpoint.operation.parameters) {
+        parameters.retain(|existing: &&OpenAPIParameter| {
+            existing.name != parameter.name || existing.location != parameter.location
+        });
+        parameters.push(parameter);
+    }
+    parameters
+}
+
+fn schemas(parser: &OpenAPIParser) -> IndexMap<&str, &OpenAPISchemaOrRef> {
+    parser
+        .get_spec()
+        .components
+        .iter()
+        .flat_map(|components| &components.schemas)
+        .map(|(name, schema)| (name.as_str(), schema))
+        .collect()
+}
+
+/// Returns whether each schema component is reachable from requests, responses or
+/// both. Schemas no operation reaches are left out.
+fn schema_usage(parser: &OpenAPIParser) -> HashMap<String, Usage> {
+    let mut usage = HashMap::new();
+
+    for endpoint in endpoints(parser).values() {
+        for parameter in parameters(parser, endpoint) {
+            if let Some(schema) = &parameter.schema {
+                mark_usage(parser, schema, Usage::REQUEST, &mut usage);
+            }
+        }
+        if let Some(body) = &endpoint.operation.request_body {
+            if let Ok(body) = parser.resolve_component(body) {
+                for media in body.content.values() {
+                    if let Some(schema) = &media.schema {
+                        mark_usage(parser, schema, Usage::REQUEST, &mut usage);
+                    }
+                }
+            }
+        }
+        for response in endpoint.operation.responses.values() {
+            if let Ok(response) = parser.resolve_component(response) {
+                for media in response.content.values() {
+                    if let Some(schema) = &media.schema {
+                        mark_usage(parser, schema, Usage::RESPONSE, &mut usage);
+                    }
+                }
+            }
+        }
+    }
+
+    usage
+}
+
+/// Marks the schema components reachable from `schema` as used as `mark`.
+fn mark_usage(
+    parser: &OpenAPIParser,
+    schema: &OpenAPISchemaOrRef,
+    mark: Usage,
+    usage: &mut HashMap<String, Usage>,
+) {
+    if let Some(name) = component_name(schema) {
+        let entry = usage.entry(name.to_string()).or_default();
+        if entry.union(mark) == *entry {
+            return;
+        }
+        *entry = entry.union(mark);
+    }
+
+    let Ok(schema) = parser.resolve_component(schema) else {
+        return;
+    };
+    let children = schema
+        .properties
+        .values()
+        .chain(schema.items.as_deref())
+        .chain(&schema.prefix_items)
+        .chain(&schema.all_of)
+        .chain(&schema.one_of)
+        .chain(&schema.any_of);
+    for child in children {
+        mark_usage(parser, child, mark, usage);
+    }
+}
+
+/// Name of the schema component a schema refers to.
+fn component_name(schema: &OpenAPISchemaOrRef) -> Option<&str> {
+    schema.reference()?.strip_prefix(SCHEMA_PREFIX)
+}
+
+/// Describes the type of a schema, e.g. `integer (int64)`, leaving out `null` so that
+/// nullability changes aren't type changes.
+fn type_name(schema: &OpenAPISchema) -> Option<String> {
+    let name = match schema.schema_type.as_ref()? {
+        SchemaType::Single(name) => name.clone(),
+        SchemaType::Multiple(names) => names
+            .iter()
+            .filter(|name| *name != "null")
+            .cloned()
+            .collect::<Vec<_>>()
+            .join(" | "),
+    };
+    Some(match &schema.format {
+        Some(format) => format!("{name} ({format})"),
+        None => name,
+    })
+}
+
+fn join(path: &str, name: &str) -> String {
+    if path.is_empty() {
+        name.to_string()
+    } else {
+        format!("{path}.{name}")
+    }
+}
+
+/// Renders changes as plain text, breaking changes first.
+pub fn to_text(changes: &[Change]) -> String {
+    if changes.is_empty() {
+        return "No changes\n".to_string();
+    }
+
+    let mut text = String::new();
+    for (title, breaking) in [("Breaking changes", true), ("Non-breaking changes", false)] {
+        let group: Vec<&Change> = changes.iter().filter(|c| c.breaking == breaking).collect();
+        if group.is_empty() {
+            continue;
+        }
+        if !text.is_empty() {
+            text.push('\n');
+        }
+        let _ = writeln!(text, "{title} ({}):", group.len());
+        for change in group {
+            let _ = writeln!(
+                text,
+                "  {}: {} [{}]",
+                change.location,
+                change.message,
+                change.kind.name()
+            );
+        }
+    }
+    text
+}
+
+/// Renders changes as Markdown, with a table for breaking and one for non-breaking
+/// changes.
+pub fn to_markdown(changes: &[Change]) -> String {
+    let breaking = changes.iter().filter(|change| change.breaking).count();
+    let mut markdown = format!(
+        "# API changes\n\n{breaking} breaking, {} non-breaking\n",
+        changes.len() - breaking
+    );
+
+    for (title, breaking) in [("Breaking changes", true), ("Non-breaking changes", false)] {
+        let group: Vec<&Change> = changes.iter().filter(|c| c.breaking == breaking).collect();
+        if group.is_empty() {
+            continue;
+        }
+        let _ = write!(
+            markdown,
+            "\n## {title}\n\n| Location | Change | Kind |\n| --- | --- | --- |\n"
+        );
+        for change in group {
+            let _ = writeln!(
+                markdown,
+                "| `{}` | {} | `{}` |",
+                change.location.replace('|', "\\|"),
+                change.message.replace('|', "\\|"),
+                change.kind.name()
+            );
+        }
+    }
+    markdown
+}
+
+/// Returns changes as a JSON object with the number of breaking and non-breaking
+/// changes and the changes themselves.
+pub fn to_json(changes: &[Change]) -> Value {
+    let breaking = changes.iter().filter(|change| change.breaking).count();
+    json!({
+        "breaking": breaking,
+        "nonBreaking": changes.len() - breaking,
+        "changes": changes,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    const OLD: &str = r#"openapi: 3.0.3
+info:
+  title: Pets
+  version: 1.0.0
+paths:
+  /pets:
+    get:
+      parameters:
+        - name: limit
+          in: query
+          schema:
+            type: integer
+      responses:
+        '200':
+          description: OK
+          content:
+            application/json:
+              schema:
+                type: array
+                items:
+                  $ref: '#/components/schemas/Pet'
+    post:
+      requestBody:
+        content:
+          application/json:
+            schema:
+              $ref: '#/components/schemas/NewPet'
+      responses:
+        '201':
+          description: Created
+  /pets/{id}:
+    delete:
+      parameters:
+        - name: id
+          in: path
+          required: true
+          schema:
+            type: string
+      responses:
+        '204':
+          description: Deleted
+components:
+  schemas:
+    Pet:
+      type: object
+      required: [id, name]
+      properties:
+        id:
+          type: integer
+        name:
+          type: string
+        status:
+          type: string
+          enum: [available, sold]
+    NewPet:
+      type: object
+      properties:
+        name:
+          type: string
+        kind:
+          type: string
+          enum: [cat, dog, bird]
+    Legacy:
+      type: object
+"#;
+
+    const NEW: &str = r#"openapi: 3.0.3
+info:
+  title: Pets
+  version: 2.0.0
+paths:
+  /pets:
+    get:
+      parameters:
+        - name: limit
+          in: query
+          schema:
+            type: string
+        - name: owner
+          in: query
+          required: true
+          schema:
+            type: string
+      responses:
+        '200':
+          description: OK
+          content:
+            application/json:
+              schema:
+                type: array
+                items:
+                  $ref: '#/components/schemas/Pet'
+    post:
+      requestBody:
+        required: true
+        content:
+          application/json:
+            schema:
+              $ref: '#/components/schemas/NewPet'
+      responses:
+        '201':
+          description: Created
+  /pets/{petId}/photos:
+    get:
+      parameters:
+        - name: petId
+          in: path
+          required: true
+          schema:
+            type: string
+      responses:
+        '200':
+          description: OK
+components:
+  schemas:
+    Pet:
+      type: object
+      required: [id]
+      properties:
+        id:
+          type: integer
+        status:
+          type: string
+          enum: [available, sold, pending]
+    NewPet:
+      type: object
+      required: [name, owner]
+      properties:
+        name:
+          type: string
+        owner:
+          type: string
+        kind:
+          type: string
+          enum: [cat, dog]
+"#;
+
+    async fn parse(temp_dir: &TempDir, name: &str, content: &str) -> OpenAPIParser {
+        let path = temp_dir.path().join(name);
+        fs::write(&path, content).unwrap();
+        let mut parser = OpenAPIParser::new();
+        parser.parse_file(&path).await.unwrap();
+        parser
+    }
+
+    async fn changes() -> Vec<Change> {
+        let temp_dir = TempDir::new().unwrap();
+        let old = parse(&temp_dir, "old.yaml", OLD).await;
+        let new = parse(&temp_dir, "new.yaml", NEW).await;
+        compare(&old, &new)
+    }
+
+    fn summary(changes: &[Change]) -> Vec<(ChangeKind, &str, &str)> {
+        changes
+            .iter()
+            .map(|change| {
+                (
+                    change.kind,
+                    change.location.as_str(),
+                    change.message.as_str(),
+                )
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_compare_classifies_changes() {
+        let changes = changes().await;
+
+        assert_eq!(
+            summary(&changes),
+            vec![
+                (
+                    ChangeKind::TypeChanged,
+                    "GET /pets",
+                    "Changed type of query parameter 'limit' from integer to string"
+                ),
+                (
+                    ChangeKind::RequiredParameterAdded,
+                    "GET /pets",
+                    "Added required query parameter 'owner'"
+                ),
+                (
+                    ChangeKind::RequestBodyRequired,
+                    "POST /pets",
+                    "Made request body required"
+                ),
+                (
+                    ChangeKind::EndpointRemoved,
+                    "DELETE /pets/{id}",
+                    "Removed endpoint"
+                ),
+                (
+                    ChangeKind::EndpointAdded,
+                    "GET /pets/{petId}/photos",
+                    "Added endpoint"
+                ),
+                (
+                    ChangeKind::ResponsePropertyRemoved,
+                    "components/schemas/Pet",
+                    "Removed property 'name'"
+                ),
+                (
+                    ChangeKind::ResponseEnumWidened,
+                    "components/schemas/Pet",
+                    "Added \"pending\" to property 'status'"
+                ),
+                (
+                    ChangeKind::RequestPropertyRequired,
+                    "components/schemas/NewPet",
+                    "Made property 'name' required"
+                ),
+                (
+                    ChangeKind::RequestEnumNarrowed,
+                    "components/schemas/NewPet",
+                    "Removed \"bird\" from property 'kind'"
+                ),
+                (
+                    ChangeKind::RequiredRequestPropertyAdded,
+                    "components/schemas/NewPet",
+                    "Added required property 'owner'"
+                ),
+                (
+                    ChangeKind::SchemaRemoved,
+                    "components/schemas/Legacy",
+                    "Removed schema"
+                ),
+            ]
+        );
+        assert_eq!(changes.iter().filter(|change| !change.breaking).count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_compare_identical_specs() {
+        let temp_dir = TempDir::new().unwrap();
+        let old = parse(&temp_dir, "old.yaml", OLD).await;
+        let new = parse(&temp_dir, "new.yaml", OLD).await;
+        assert!(compare(&old, &new).is_empty());
+    }
+
+    #[test]
+    fn test_template_key() {
+        assert_eq!(
+            template_key("/pets/{id}/photos/{photoId}"),
+            "/pets/{}/photos/{}"
+        );
+        assert_eq!(template_key("/pets"), "/pets");
+    }
+
+    #[test]
+    fn test_reports() {
+        let changes = vec![
+            Change {
+                kind: ChangeKind::EndpointRemoved,
+                breaking: true,
+                location: "DELETE /pets/{id}".to_string(),
+                message: "Removed endpoint".to_string(),
+            },
+            Change {
+                kind: ChangeKind::EndpointAdded,
+                breaking: false,
+                location: "GET /pets".to_string(),
+                message: "Added endpoint".to_string(),
+            },
+        ];
+
+        assert_eq!(
+            to_text(&changes),
+            "Breaking changes (1):\n  DELETE /pets/{id}: Removed endpoint [endpoint-removed]\n\n\
+             Non-breaking changes (1):\n  GET /pets: Added endpoint [endpoint-added]\n"
+        );
+        assert_eq!(to_text(&[]), "No changes\n");
+
+        let markdown = to_markdown(&changes);
+        assert!(markdown.starts_with("# API changes\n\n1 breaking, 1 non-breaking\n"));
+        assert!(markdown.contains(
+            "## Breaking changes\n\n| Location | Change | Kind |\n| --- | --- | --- |\n\
+             | `DELETE /pets/{id}` | Removed endpoint | `endpoint-removed` |\n"
+        ));
+
+        let json = to_json(&changes);
+        assert_eq!(json["breaking"], 1);
+        assert_eq!(json["nonBreaking"], 1);
+        assert_eq!(
+            json["changes"][0],
+            json!({
+                "kind": "endpoint-removed",
+                "breaking": true,
+                "location": "DELETE /pets/{id}",
+                "message": "Removed endpoint",
+            })
+        );
+    }
+}
@@ -16,6 +16,9 @@ pub enum OpenAPIError {
     #[error("Invalid YAML format: {message}")]
     InvalidYaml { message: String },
 
+    #[error("Invalid TOML format: {message}")]
+    InvalidToml { message: String },
+
     #[error("Missing required field '{field}' at {path}")]
     MissingField { field: String, path: String },
 
@@ -47,6 +50,59 @@ pub enum OpenAPIError {
     #[error("Invalid property name '{property}' at {path}")]
     InvalidPropertyName { property: String, path: String },
 
+    #[error("Path parameter '{parameter}' is not declared for {path}")]
+    MissingPathParameter { parameter: String, path: String },
+
+    #[error("Path parameter '{parameter}' does not appear in the template of {path}")]
+    UnusedPathParameter { parameter: String, path: String },
+
+    #[error("Parameter '{parameter}' at {path} has unknown location '{location}'")]
+    InvalidParameterLocation {
+        parameter: String,
+        location: String,
+        path: String,
+    },
+
+    #[error("Operation ID '{operation_id}' of {path} is already used by {first_path}")]
+    DuplicateOperationId {
+        operation_id: String,
+        path: String,
+        first_path: String,
+    },
+
+    #[error("Required property '{property}' is not defined in the properties at {path}")]
+    UndefinedRequiredProperty { property: String, path: String },
+
+    #[error("Invalid pattern '{pattern}' at {path}: {reason}")]
+    InvalidPattern {
+        pattern: String,
+        path: String,
+        reason: String,
+    },
+
+    #[error("Unknown lint rule '{rule}' in {path}")]
+    UnknownLintRule { rule: String, path: String },
+
+    #[error("Invalid extension '{extension}' on {path}: {reason}")]
+    InvalidExtension {
+        extension: String,
+        path: String,
+        reason: String,
+    },
+
+    #[error("Invalid type mapping '{key}' in {path}: {reason}")]
+    InvalidTypeMapping {
+        key: String,
+        path: String,
+        reason: String,
+    },
+
+    #[error("Invalid template '{name}': {reason}")]
+    InvalidTemplate { name: String, reason: String },
+
+    #[error("Unknown target '{target}' in {path}")]
+    UnknownTarget { target: String, path: String },
+
     #[error("Template generation failed for {component}: {reason}")]
     TemplateGenerationFailed { component: String, reason: String },
 
@@ -58,13 +114,58 @@ pub enum OpenAPIError {
 
     #[error("YAML parsing error: {0}")]
     Yaml(#[from] serde_yaml::Error),
+
+    /// An error together with where in the source document it applies.
+    #[error("{error}{context}")]
+    Located {
+        error: Box<OpenAPIError>,
+        context: Box<ErrorContext>,
+    },
+}
+
+impl OpenAPIError {
+    /// Attaches a source location to the error, replacing any it already had.
+    pub fn with_context(self, context: ErrorContext) -> Self {
+        OpenAPIError::Located {
+            error: Box::new(self.into_kind()),
+            context: Box::new(context),
+        }
+    }
+
+    /// The error without its location.
+    #[allow(dead_code)]
+    pub fn kind(&self) -> &OpenAPIError {
+        match self {
+            OpenAPIError::Located { error, .. } => error,
+            error => error,
+        }
+    }
+
+    /// Takes the error out of its location.
+    pub fn into_kind(self) -> OpenAPIError {
+        match self {
+            OpenAPIError::Located { error, .. } => *error,
+            error => error,
+        }
+    }
+
+    /// Where in the source document the error applies, if known.
+    pub fn context(&self) -> Option<&ErrorContext> {
+        match self {
+            OpenAPIError::Located { context, .. } => Some(context),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct ErrorContext {
     pub schema_path: Vec<String>,
+    pub file: Option<String>,
     pub line: Option<u32>,
     pub column: Option<u32>,
+    /// Text of the line at `line`, shown underlined from `column`.
+    pub source_line: Option<String>,
     pub suggestion: Option<String>,
     pub error_code: Option<String>,
 }
@@ -80,26 +181,36 @@ impl ErrorContext {
     pub fn new() -> Self {
         Self {
             schema_path: Vec::new(),
+            file: None,
             line: None,
             column: None,
+            source_line: None,
             suggestion: None,
             error_code: None,
         }
     }
 
-    #[allow(dead_code)]
     pub fn with_path(mut self, path: Vec<String>) -> Self {
         self.schema_path = path;
         self
     }
 
-    #[allow(dead_code)]
+    pub fn with_file<F: Into<String>>(mut self, file: F) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+
     pub fn with_location(mut self, line: u32, column: u32) -> Self {
         self.line = Some(line);
         self.column = Some(column);
         self
     }
 
+    pub fn with_source_line<S: Into<String>>(mut self, source_line: S) -> Self {
+        self.source_line = Some(source_line.into());
+        self
+    }
+
     #[allow(dead_code)]
     pub fn with_suggestion<S: Into<String>>(mut self, suggestion: S) -> Self {
         self.suggestion = Some(suggestion.into());
@@ -113,18 +224,57 @@ impl ErrorContext {
     }
 }
 
+/// Renders the context after the error message. With a file and location the output
+/// follows rustc, pointing at the file and underlining the offending line:
+///
+/// ```text
+///   --> api.yaml:12:15
+///    |
+/// 12 |       $ref: '#/components/schemas/Missing'
+///    |       ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+/// ```
 impl fmt::Display for ErrorContext {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if !self.schema_path.is_empty() {
-            write!(f, " at path: {}", self.schema_path.join("."))?;
-        }
-
-        if let (Some(line), Some(column)) = (self.line, self.column) {
-            write!(f, " (line {line}, column {column})")?;
-        }
-
-        if let Some(code) = &self.error_code {
-            write!(f, " [{code}]")?;
+        match (&self.file, self.line, self.column) {
+            (Some(file), Some(line), Some(column)) => {
+                if let Some(code) = &self.error_code {
+                    write!(f, " [{code}]")?;
+                }
+
+                let gutter = " ".repeat(line.to_string().len());
+                write!(f, "\n{gutter}--> {file}:{line}:{column}")?;
+
+                if let Some(text) = &self.source_line {
+                    let text = text.trim_end();
+                    // Keep tabs in the indentation so the carets line up
+                    let indent: String = text
+                        .chars()
+                        .take(column.saturating_sub(1) as usize)
+                        .map(|c| if c == '\t' { '\t' } else { ' ' })
+                        .collect();
+                    let width = text.chars().count().saturating_sub(indent.chars().count());
+                    write!(f, "\n{gutter} |")?;
+                    write!(f, "\n{line} | {text}")?;
+                    write!(f, "\n{gutter} | {indent}{}", "^".repeat(width.max(1)))?;
+                }
+            }
+            (file, line, column) => {
+                if !self.schema_path.is_empty() {
+                    write!(f, " at path: {}", self.schema_path.join("."))?;
+                }
+
+                if let Some(file) = file {
+                    write!(f, " in {file}")?;
+                }
+
+                if let (Some(line), Some(column)) = (line, column) {
+                    write!(f, " (line {line}, column {column})")?;
+                }
+
+                if let Some(code) = &self.error_code {
+                    write!(f, " [{code}]")?;
+                }
+            }
         }
 
         if let Some(suggestion) = &self.suggestion {
@@ -162,6 +312,12 @@ pub fn invalid_yaml<M: AsRef<str>>(message: M) -> OpenAPIError {
     }
 }
 
+pub fn invalid_toml<M: AsRef<str>>(message: M) -> OpenAPIError {
+    OpenAPIError::InvalidToml {
+        message: message.as_ref().to_string(),
+    }
+}
+
 pub fn missing_field<F: AsRef<str>, P: AsRef<str>>(field: F, path: P) -> OpenAPIError {
     OpenAPIError::MissingField {
         field: field.as_ref().to_string(),
@@ -242,7 +398,111 @@ pub fn invalid_property_name<N: AsRef<str>, P: AsRef<str>>(property: N, path: P)
     }
 }
 
-#[allow(dead_code)]
+pub fn missing_path_parameter<N: AsRef<str>, P: AsRef<str>>(parameter: N, path: P) -> OpenAPIError {
+    OpenAPIError::MissingPathParameter {
+        parameter: parameter.as_ref().to_string(),
+        path: path.as_ref().to_string(),
+    }
+}
+
+pub fn unused_path_parameter<N: AsRef<str>, P: AsRef<str>>(parameter: N, path: P) -> OpenAPIError {
+    OpenAPIError::UnusedPathParameter {
+        parameter: parameter.as_ref().to_string(),
+        path: path.as_ref().to_string(),
+    }
+}
+
+pub fn invalid_parameter_location<N: AsRef<str>, L: AsRef<str>, P: AsRef<str>>(
+    parameter: N,
+    location: L,
+    path: P,
+) -> OpenAPIError {
+    OpenAPIError::InvalidParameterLocation {
+        parameter: parameter.as_ref().to_string(),
+        location: location.as_ref().to_string(),
+        path: path.as_ref().to_string(),
+    }
+}
+
+pub fn duplicate_operation_id<O: AsRef<str>, P: AsRef<str>, F: AsRef<str>>(
+    operation_id: O,
+    path: P,
+    first_path: F,
+) -> OpenAPIError {
+    OpenAPIError::DuplicateOperationId {
+        operation_id: operation_id.as_ref().to_string(),
+        path: path.as_ref().to_string(),
+        first_path: first_path.as_ref().to_string(),
+    }
+}
+
+pub fn undefined_required_property<N: AsRef<str>, P: AsRef<str>>(
+    property: N,
+    path: P,
+) -> OpenAPIError {
+    OpenAPIError::UndefinedRequiredProperty {
+        property: property.as_ref().to_string(),
+        path: path.as_ref().to_string(),
+    }
+}
+
+pub fn invalid_pattern<T: AsRef<str>, P: AsRef<str>, R: AsRef<str>>(
+    pattern: T,
+    path: P,
+    reason: R,
+) -> OpenAPIError {
+    OpenAPIError::InvalidPattern {
+        pattern: pattern.as_ref().to_string(),
+        path: path.as_ref().to_string(),
+        reason: reason.as_ref().to_string(),
+    }
+}
+
+pub fn unknown_lint_rule<R: AsRef<str>, P: AsRef<str>>(rule: R, path: P) -> OpenAPIError {
+    OpenAPIError::UnknownLintRule {
+        rule: rule.as_ref().to_string(),
+        path: path.as_ref().to_string(),
+    }
+}
+
+pub fn invalid_extension<E: AsRef<str>, P: AsRef<str>, R: AsRef<str>>(
+    extension: E,
+    path: P,
+    reason: R,
+) -> OpenAPIError {
+    OpenAPIError::InvalidExtension {
+        extension: extension.as_ref().to_string(),
+        path: path.as_ref().to_string(),
+        reason: reason.as_ref().to_string(),
+    }
+}
+
+pub fn invalid_type_mapping<K: AsRef<str>, P: AsRef<str>, R: AsRef<str>>(
+    key: K,
+    path: P,
+    reason: R,
+) -> OpenAPIError {
+    OpenAPIError::InvalidTypeMapping {
+        key: key.as_ref().to_string(),
+        path: path.as_ref().to_string(),
+        reason: reason.as_ref().to_string(),
+    }
+}
+
+pub fn invalid_template<N: AsRef<str>, R: AsRef<str>>(name: N, reason: R) -> OpenAPIError {
+    OpenAPIError::InvalidTemplate {
+        name: name.as_ref().to_string(),
+        reason: reason.as_ref().to_string(),
+    }
+}
+
+pub fn unknown_target<T: AsRef<str>, P: AsRef<str>>(target: T, path: P) -> OpenAPIError {
+    OpenAPIError::UnknownTarget {
+        target: target.as_ref().to_string(),
+        path: path.as_ref().to_string(),
+    }
+}
+
 pub fn template_generation_failed<C: AsRef<str>, R: AsRef<str>>(
     component: C,
     reason: R,
@@ -529,6 +789,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_error_creation_missing_path_parameter() {
+        let error = missing_path_parameter("userId", "/users/{userId}");
+        match error {
+            OpenAPIError::MissingPathParameter { parameter, path } => {
+                assert_eq!(parameter, "userId");
+                assert_eq!(path, "/users/{userId}");
+            }
+            _ => panic!("Expected MissingPathParameter error"),
+        }
+    }
+
+    #[test]
+    fn test_error_display_missing_path_parameter() {
+        let error = missing_path_parameter("orderId", "/orders/{orderId}");
+        assert_eq!(
+            error.to_string(),
+            "Path parameter 'orderId' is not declared for /orders/{orderId}"
+        );
+    }
+
     #[test]
     fn test_error_creation_template_generation_failed() {
         let error = template_generation_failed("UserService", "missing template file");
@@ -550,6 +831,54 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_error_display_validation_errors() {
+        assert_eq!(
+            unused_path_parameter("id", "/users").to_string(),
+            "Path parameter 'id' does not appear in the template of /users"
+        );
+        assert_eq!(
+            invalid_parameter_location("id", "body", "GET /users").to_string(),
+            "Parameter 'id' at GET /users has unknown location 'body'"
+        );
+        assert_eq!(
+            duplicate_operation_id("getUser", "GET /users/{id}", "GET /users").to_string(),
+            "Operation ID 'getUser' of GET /users/{id} is already used by GET /users"
+        );
+        assert_eq!(
+            undefined_required_property("name", "components/schemas/User").to_string(),
+            "Required property 'name' is not defined in the properties at components/schemas/User"
+        );
+        assert_eq!(
+            invalid_pattern("[a-", "components/schemas/Code", "unclosed class").to_string(),
+            "Invalid pattern '[a-' at components/schemas/Code: unclosed class"
+        );
+    }
+
+    #[test]
+    fn test_error_with_context() {
+        let context = ErrorContext::new()
+            .with_file("api.yaml")
+            .with_location(2, 3)
+            .with_source_line("  title: ''");
+        let error = missing_field("title", "info.title").with_context(context);
+
+        assert!(matches!(error.kind(), OpenAPIError::MissingField { .. }));
+        assert_eq!(error.context().unwrap().line, Some(2));
+        assert_eq!(
+            error.to_string(),
+            "Missing required field 'title' at info.title\n --> api.yaml:2:3\n  |\n2 |   title: ''\n  |   ^^^^^^^^^"
+        );
+
+        // A new location replaces the previous one
+        let error = error.with_context(ErrorContext::new().with_location(5, 1));
+        assert_eq!(error.context().unwrap().line, Some(5));
+        assert!(matches!(
+            error.into_kind(),
+            OpenAPIError::MissingField { .. }
+        ));
+    }
+
     #[test]
     fn test_error_from_io_error() {
         let io_error = io::Error::new(io::ErrorKind::NotFound, "File not found");
@@ -0,0 +1,138 @@
+//! Escaping of text written into Kotlin sources, and sanitization of the names
+//! declared in them. Templates reach the escaping through the `escape` and `kdoc`
+//! helpers.
+
+/// Hard keywords, which can only be used as identifiers between backticks.
+const KOTLIN_KEYWORDS: &[&str] = &[
+    "as",
+    "break",
+    "class",
+    "continue",
+    "do",
+    "else",
+    "false",
+    "for",
+    "fun",
+    "if",
+    "in",
+    "interface",
+    "is",
+    "null",
+    "object",
+    "package",
+    "return",
+    "super",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typealias",
+    "typeof",
+    "val",
+    "var",
+    "when",
+    "while",
+];
+
+/// Escapes text for the inside of a Kotlin string literal, including `$`, which
+/// would otherwise start a string template.
+pub fn escape_string(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '$' => escaped.push_str("\\$"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Returns text as a Kotlin string literal, quotes included.
+pub fn string_literal(text: &str) -> String {
+    format!("\"{}\"", escape_string(text))
+}
+
+/// Escapes text for the body of a KDoc comment that follows ` * `. Comment
+/// delimiters are written as HTML entities, since Kotlin comments nest, and every
+/// further line continues the comment.
+pub fn escape_kdoc(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for (index, line) in text.trim().lines().enumerate() {
+        let line = line
+            .trim_end()
+            .replace("*/", "*&#47;")
+            .replace("/*", "&#47;*");
+        match (index, line.is_empty()) {
+            (0, _) => {}
+            (_, true) => escaped.push_str("\n *"),
+            (_, false) => escaped.push_str("\n * "),
+        }
+        escaped.push_str(&line);
+    }
+    escaped
+}
+
+/// Makes a name usable as a Kotlin identifier: characters other than letters,
+/// digits and `_` are dropped, a leading digit is prefixed with `_`, and hard
+/// keywords are put between backticks.
+pub fn identifier(name: &str) -> String {
+    let ident: String = name
+        .chars()
+        .filter(|c| c.is_alphanumeric() || *c == '_')
+        .collect();
+
+    match ident.as_str() {
+        "" => "value".to_string(),
+        _ if ident.starts_with(|c: char| c.is_ascii_digit()) => format!("_{ident}"),
+        _ if KOTLIN_KEYWORDS.contains(&ident.as_str()) => format!("`{ident}`"),
+        _ => ident,
+    }
+}
+
+/// Returns an identifier without the backticks that escape a keyword, as it is
+/// known at runtime.
+pub fn unescaped(identifier: &str) -> &str {
+    identifier
+        .strip_prefix('`')
+        .and_then(|ident| ident.strip_suffix('`'))
+        .unwrap_or(identifier)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_string() {
+        assert_eq!(escape_string("plain"), "plain");
+        assert_eq!(escape_string("say \"$hi\"\n"), "say \\\"\\$hi\\\"\\n");
+        assert_eq!(string_literal(r"^\d{3}$"), r#""^\\d{3}\$""#);
+    }
+
+    #[test]
+    fn test_escape_kdoc() {
+        assert_eq!(escape_kdoc("Costs $5"), "Costs $5");
+        assert_eq!(
+            escape_kdoc("Matches /*.json */\n\nSecond line  \n"),
+            "Matches &#47;*.json *&#47;\n *\n * Second line"
+        );
+    }
+
+    #[test]
+    fn test_identifier() {
+        assert_eq!(identifier("firstName"), "firstName");
+        assert_eq!(identifier("class"), "`class`");
+        assert_eq!(identifier("object"), "`object`");
+        assert_eq!(identifier("1stLine"), "_1stLine");
+        assert_eq!(identifier("price€"), "price");
+        assert_eq!(identifier("größe"), "größe");
+        assert_eq!(identifier("$"), "value");
+        assert_eq!(unescaped("`class`"), "class");
+        assert_eq!(unescaped("firstName"), "firstName");
+    }
+}
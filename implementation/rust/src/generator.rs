@@ -1,28 +1,38 @@
 use anyhow::{Context, Result};
 use rayon::prelude::*;
 use regex::Regex;
+use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 use tokio::fs;
 
+use crate::backends::{self, GeneratedFile, LanguageBackend};
+use crate::errors::OpenAPIError;
+use crate::ir::{
+    ApiModel, Constraints, DefaultValue, EnumConstant, Field, Model, ModelKind, Operation,
+    Parameter, ParameterLocation, ParameterSerialization, RequestBody, Service, TypeRef, Variant,
+};
+use crate::naming::{camel_case, pascal_case};
 use crate::parser::OpenAPIParser;
-use crate::templates::TemplateEngine;
+use crate::source_map;
 use crate::types::*;
 
 pub struct OpenAPICodeGenerator {
     config: GeneratorConfig,
     parser: OpenAPIParser,
-    template_engine: TemplateEngine,
+    backend: Box<dyn LanguageBackend>,
+    /// Class names of inline schemas, keyed by owning class and property name.
+    inline_class_names: HashMap<(String, String), String>,
 }
 
 impl OpenAPICodeGenerator {
     pub fn new(config: GeneratorConfig) -> Self {
-        let template_engine =
-            TemplateEngine::new(config.include_validation, config.include_swagger);
+        let backend = backends::backend_for(&config);
 
         Self {
             config,
             parser: OpenAPIParser::new(),
-            template_engine,
+            backend,
+            inline_class_names: HashMap::new(),
         }
     }
 
@@ -37,6 +47,11 @@ impl OpenAPICodeGenerator {
             );
         }
 
+        self.inline_class_names = self
+            .assign_inline_class_names()
+            .map_err(|error| self.locate(error))?;
+        let api = self.build_api_model().map_err(|error| self.locate(error))?;
+
         // Ensure output directory exists
         fs::create_dir_all(&self.config.output_dir)
             .await
@@ -44,13 +59,13 @@ impl OpenAPICodeGenerator {
 
         let mut generated_files = Vec::new();
 
-        // Generate models in parallel
+        // Generate models
         if self.config.generate_models {
             if self.config.verbose {
                 println!("Generating model classes...");
             }
-            let model_files = self.generate_models().await?;
-            generated_files.extend(model_files);
+            let model_files = self.backend.generate_models(&api)?;
+            generated_files.extend(self.write_files(model_files).await?);
         }
 
         // Generate controllers
@@ -58,13 +73,13 @@ impl OpenAPICodeGenerator {
             if self.config.verbose {
                 println!("Generating controller interfaces...");
             }
-            let controller_files = self.generate_controllers().await?;
-            generated_files.extend(controller_files);
+            let service_files = self.backend.generate_services(&api)?;
+            generated_files.extend(self.write_files(service_files).await?);
         }
 
-        // Generate build file
-        let build_file = self.generate_build_file().await?;
-        generated_files.push(build_file);
+        // Generate build and support files
+        let project_files = self.backend.generate_project_files(&api)?;
+        generated_files.extend(self.write_files(project_files).await?);
 
         Ok(GenerationResult {
             output_dir: self.config.output_dir.clone(),
@@ -73,514 +88,866 @@ impl OpenAPICodeGenerator {
         })
     }
 
-    async fn generate_models(&self) -> Result<Vec<PathBuf>> {
-        let schemas = self.parser.get_all_schemas()?;
-        let mut files = Vec::new();
+    /// Points a parser error at the part of the document it is about.
+    fn locate(&self, error: anyhow::Error) -> anyhow::Error {
+        match error.downcast::<OpenAPIError>() {
+            Ok(error) => self.parser.locate(error).into(),
+            Err(error) => error,
+        }
+    }
 
-        // Process schemas in parallel
-        let kotlin_classes: Result<Vec<_>> = schemas
-            .into_par_iter()
-            .map(|(name, schema)| self.convert_schema_to_kotlin_class(&name, schema))
-            .collect();
+    /// Builds the language-neutral model of the parsed document. Operations are only
+    /// converted when controllers are generated.
+    fn build_api_model(&self) -> Result<ApiModel> {
+        let info = &self.parser.get_spec().info;
 
-        let kotlin_classes = kotlin_classes?;
+        let services = if self.config.generate_controllers {
+            self.convert_operations_to_services()?
+        } else {
+            Vec::new()
+        };
 
-        // Write files sequentially (to avoid filesystem conflicts)
-        for kotlin_class in kotlin_classes {
-            let file_path = self.write_kotlin_class(&kotlin_class, "model").await?;
+        Ok(ApiModel {
+            title: info.title.clone(),
+            version: info.version.clone(),
+            models: self.convert_schemas_to_models()?,
+            services,
+        })
+    }
 
-            if self.config.verbose {
-                let relative_path = file_path
-                    .strip_prefix(&self.config.output_dir)
-                    .unwrap_or(&file_path);
-                println!(
-                    "Generated model: {} -> {}",
-                    kotlin_class.name,
-                    relative_path.display()
-                );
-            }
+    fn convert_schemas_to_models(&self) -> Result<Vec<Model>> {
+        let schemas = self.parser.get_all_schemas()?;
 
-            files.push(file_path);
-        }
+        // Process schemas in parallel
+        let models: Result<Vec<_>> = schemas
+            .into_par_iter()
+            .filter(|(name, _)| self.config.type_mappings.schema_class(name).is_none())
+            .map(|(name, schema)| self.convert_schema_to_models(&name, schema))
+            .collect();
 
-        Ok(files)
+        // Inline models of a component used as a oneOf variant are collected twice,
+        // so keep them once.
+        let mut seen = HashSet::new();
+        Ok(models?
+            .into_iter()
+            .flatten()
+            .filter(|model| seen.insert(model.name.clone()))
+            .collect())
     }
 
-    async fn generate_controllers(&self) -> Result<Vec<PathBuf>> {
+    fn convert_operations_to_services(&self) -> Result<Vec<Service>> {
         let tagged_operations = self.parser.get_operations_by_tag()?;
-        let mut files = Vec::new();
+        let filters = &self.config.filters;
 
-        // Process controllers in parallel
-        let kotlin_controllers: Result<Vec<_>> = tagged_operations
+        // Process services in parallel
+        tagged_operations
             .into_par_iter()
-            .filter(|(_, operations)| !operations.is_empty())
-            .map(|(tag, operations)| {
-                self.convert_operations_to_kotlin_controller(&tag, &operations)
+            .map(|(tag, mut operations)| {
+                operations.retain(|(path, _, _)| filters.includes(&tag, path));
+                (tag, operations)
             })
-            .collect();
+            .filter(|(_, operations)| !operations.is_empty())
+            .map(|(tag, operations)| self.convert_operations_to_service(&tag, &operations))
+            .collect()
+    }
 
-        let kotlin_controllers = kotlin_controllers?;
+    async fn write_files(&self, files: Vec<GeneratedFile>) -> Result<Vec<PathBuf>> {
+        let mut paths = Vec::new();
 
-        // Write files sequentially
-        for kotlin_controller in kotlin_controllers {
-            let file_path = self
-                .write_kotlin_controller(&kotlin_controller, "controller")
-                .await?;
+        // Write files sequentially (to avoid filesystem conflicts)
+        for file in files {
+            let file_path = self.config.output_dir.join(&file.path);
+
+            if let Some(output_dir) = file_path.parent() {
+                fs::create_dir_all(output_dir).await.with_context(|| {
+                    format!("Failed to create directory: {}", output_dir.display())
+                })?;
+            }
+
+            fs::write(&file_path, &file.content)
+                .await
+                .with_context(|| format!("Failed to write file: {}", file_path.display()))?;
 
             if self.config.verbose {
-                let relative_path = file_path
-                    .strip_prefix(&self.config.output_dir)
-                    .unwrap_or(&file_path);
-                println!(
-                    "Generated controller: {} -> {}",
-                    kotlin_controller.name,
-                    relative_path.display()
-                );
+                println!("Generated {} -> {}", file.description, file.path.display());
             }
 
-            files.push(file_path);
+            paths.push(file_path);
         }
 
-        Ok(files)
+        Ok(paths)
     }
 
-    fn convert_schema_to_kotlin_class(
+    /// Converts a component schema to its model followed by the models generated
+    /// for its inline object and enum properties.
+    pub(crate) fn convert_schema_to_models(
         &self,
         name: &str,
         schema: Box<OpenAPISchema>,
-    ) -> Result<KotlinClass> {
-        // Handle oneOf schemas as sealed classes
+    ) -> Result<Vec<Model>> {
+        let inline_models = self.convert_inline_schemas_to_models(name, &schema)?;
+        let mut models = vec![self.convert_schema_to_model(name, schema)?];
+        models.extend(inline_models);
+
+        Ok(models)
+    }
+
+    fn convert_schema_to_model(&self, name: &str, schema: Box<OpenAPISchema>) -> Result<Model> {
+        // Handle string and integer enums as enum models
+        if self.is_enum_schema(&schema) {
+            return Ok(self.convert_enum_to_model(name, &schema));
+        }
+
+        // Handle oneOf schemas as sealed hierarchies
         if schema.one_of_variants.is_some() {
-            return self.convert_one_of_to_sealed_class(name, &schema);
+            return self.convert_one_of_to_model(name, &schema);
         }
 
         // Handle anyOf schemas as union types
         if schema.any_of_variants.is_some() {
-            return self.convert_any_of_to_union_type(name, &schema);
+            return Ok(self.convert_any_of_to_model(name, &schema));
         }
 
-        let mut kotlin_class = KotlinClass {
-            name: self.pascal_case(name),
-            package_name: self.config.base_package.clone(),
+        let name = pascal_case(name);
+        let fields = self.convert_properties_to_fields(&name, &schema, None)?;
+
+        Ok(Model {
+            name,
+            description: schema.description.clone(),
+            kind: ModelKind::Object { fields },
+            extensions: schema.extensions.clone(),
+        })
+    }
+
+    /// Converts the properties of a schema owned by the named model, leaving out the
+    /// discriminator, which is handled by the oneOf model.
+    fn convert_properties_to_fields(
+        &self,
+        owner: &str,
+        schema: &OpenAPISchema,
+        discriminator_name: Option<&String>,
+    ) -> Result<Vec<Field>> {
+        schema
+            .properties
+            .iter()
+            .filter(|(prop_name, _)| discriminator_name != Some(*prop_name))
+            .map(|(prop_name, prop_schema_or_ref)| {
+                let prop_schema =
+                    self.resolve_property_schema(owner, prop_name, prop_schema_or_ref)?;
+                let mut field =
+                    self.convert_schema_to_field(prop_name, &prop_schema, &schema.required)?;
+                // Those of a referenced schema belong to its model
+                if let OpenAPISchemaOrRef::Reference(_) = prop_schema_or_ref {
+                    field.extensions.clear();
+                }
+                Ok(field)
+            })
+            .collect()
+    }
+
+    fn convert_one_of_to_model(&self, name: &str, schema: &OpenAPISchema) -> Result<Model> {
+        let name = pascal_case(name);
+        let discriminator_name = schema
+            .discriminator
+            .as_ref()
+            .map(|discriminator| discriminator.property_name.clone());
+
+        // Base properties are common to all variants
+        let fields =
+            self.convert_properties_to_fields(&name, schema, discriminator_name.as_ref())?;
+
+        let variants = schema
+            .one_of_variants
+            .iter()
+            .flatten()
+            .map(|(variant_name, variant_schema)| {
+                let variant_class_name = pascal_case(variant_name);
+                Ok(Variant {
+                    fields: self.convert_properties_to_fields(
+                        &variant_class_name,
+                        variant_schema,
+                        discriminator_name.as_ref(),
+                    )?,
+                    name: variant_class_name,
+                    description: variant_schema.description.clone(),
+                    discriminator_value: schema.discriminator.as_ref().map(|discriminator| {
+                        self.discriminator_value_for(discriminator, variant_name)
+                    }),
+                    extensions: variant_schema.extensions.clone(),
+                })
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Model {
+            name,
             description: schema.description.clone(),
-            properties: Vec::new(),
-            imports: self.get_base_model_imports(),
-            is_sealed: None,
-            sealed_sub_types: None,
-            parent_class: None,
+            kind: ModelKind::OneOf {
+                discriminator: discriminator_name,
+                fields,
+                variants,
+            },
+            extensions: schema.extensions.clone(),
+        })
+    }
+
+    /// Returns the wire value identifying a oneOf variant. Explicit discriminator
+    /// mappings win; otherwise the variant (schema) name is used, as the spec requires.
+    fn discriminator_value_for(
+        &self,
+        discriminator: &OpenAPIDiscriminator,
+        variant_name: &str,
+    ) -> String {
+        discriminator
+            .mapping
+            .iter()
+            .find(|(_, target)| OpenAPIParser::extract_schema_name(target) == variant_name)
+            .map(|(value, _)| value.clone())
+            .unwrap_or_else(|| variant_name.to_string())
+    }
+
+    fn convert_enum_to_model(&self, name: &str, schema: &OpenAPISchema) -> Model {
+        let value_type = match (schema.primary_type(), schema.format.as_deref()) {
+            (Some("integer"), Some("int64")) => TypeRef::Int64,
+            (Some("integer"), _) => TypeRef::Int32,
+            _ => TypeRef::String,
         };
 
-        if !schema.properties.is_empty() {
-            let properties = &schema.properties;
-            let required_fields = &schema.required;
-
-            for (prop_name, prop_schema_or_ref) in properties {
-                let prop_schema = self.parser.resolve_schema(prop_schema_or_ref)?;
-                let property = self.convert_schema_to_kotlin_property(
-                    prop_name,
-                    &prop_schema,
-                    required_fields,
-                )?;
-
-                // Add imports for property types
-                self.add_imports_for_type(&property.kotlin_type, &mut kotlin_class.imports);
-                kotlin_class.properties.push(property);
+        let values = schema.enum_values.iter().filter(|value| !value.is_null());
+        let constants = values
+            .zip(self.enum_constant_names(schema))
+            .map(|(value, constant_name)| EnumConstant {
+                name: constant_name,
+                value: value.clone(),
+            })
+            .collect();
+
+        Model {
+            name: pascal_case(name),
+            description: schema.description.clone(),
+            kind: ModelKind::Enum {
+                value_type,
+                constants,
+            },
+            extensions: schema.extensions.clone(),
+        }
+    }
+
+    /// Converts the inline object and enum schemas of a schema's properties, and of its
+    /// oneOf variants' properties, into models of their own, recursively.
+    fn convert_inline_schemas_to_models(
+        &self,
+        name: &str,
+        schema: &OpenAPISchema,
+    ) -> Result<Vec<Model>> {
+        let mut models = Vec::new();
+
+        for (owner, prop_name, prop_schema_or_ref) in self.inline_property_candidates(name, schema)
+        {
+            if let Some(class_schema) = self.inline_class_schema(prop_schema_or_ref)? {
+                let class_name = self.inline_class_name(&owner, prop_name, &class_schema);
+                let mut class_models = self.convert_schema_to_models(&class_name, class_schema)?;
+                // The extensions of a schema declared on the property itself, rather
+                // than on its items, belong to the property
+                let is_items = matches!(
+                    prop_schema_or_ref,
+                    OpenAPISchemaOrRef::Schema(schema) if schema.primary_type() == Some("array")
+                );
+                if !is_items {
+                    class_models[0].extensions.clear();
+                }
+                models.extend(class_models);
             }
         }
 
-        Ok(kotlin_class)
+        Ok(models)
     }
 
-    fn convert_one_of_to_sealed_class(
+    /// Lists the properties that may hold inline schemas, paired with the name of the
+    /// class that owns them. The discriminator of a oneOf is left out as the oneOf
+    /// model handles it.
+    fn inline_property_candidates<'a>(
         &self,
         name: &str,
-        schema: &OpenAPISchema,
-    ) -> Result<KotlinClass> {
-        let mut imports = self.get_base_model_imports();
-        imports.extend_from_slice(&[
-            "com.fasterxml.jackson.annotation.JsonSubTypes".to_string(),
-            "com.fasterxml.jackson.annotation.JsonTypeInfo".to_string(),
-        ]);
+        schema: &'a OpenAPISchema,
+    ) -> Vec<(String, &'a String, &'a OpenAPISchemaOrRef)> {
+        let discriminator_name = schema
+            .discriminator
+            .as_ref()
+            .filter(|_| schema.one_of_variants.is_some())
+            .map(|discriminator| &discriminator.property_name);
 
-        let mut kotlin_class = KotlinClass {
-            name: self.pascal_case(name),
-            package_name: self.config.base_package.clone(),
-            description: schema.description.clone(),
-            properties: Vec::new(),
-            imports,
-            is_sealed: Some(true),
-            sealed_sub_types: Some(Vec::new()),
-            parent_class: None,
+        let mut owners = vec![(pascal_case(name), schema)];
+        if let Some(variants) = &schema.one_of_variants {
+            owners.extend(
+                variants.iter().map(|(variant_name, variant_schema)| {
+                    (pascal_case(variant_name), variant_schema)
+                }),
+            );
+        }
+
+        owners
+            .into_iter()
+            .flat_map(|(owner, owner_schema)| {
+                owner_schema
+                    .properties
+                    .iter()
+                    .filter(|(prop_name, _)| discriminator_name != Some(*prop_name))
+                    .map(move |(prop_name, prop_schema_or_ref)| {
+                        (owner.clone(), prop_name, prop_schema_or_ref)
+                    })
+            })
+            .collect()
+    }
+
+    /// Returns the resolved schema declared inline on a property, or on the items of an
+    /// inline array property, when it is generated as a class of its own.
+    fn inline_class_schema(
+        &self,
+        schema_or_ref: &OpenAPISchemaOrRef,
+    ) -> Result<Option<Box<OpenAPISchema>>> {
+        let OpenAPISchemaOrRef::Schema(schema) = schema_or_ref else {
+            return Ok(None);
         };
 
-        // Add base properties (common to all variants)
-        if !schema.properties.is_empty() {
-            let properties = &schema.properties;
-            let required_fields = &schema.required;
-
-            for (prop_name, prop_schema_or_ref) in properties {
-                let prop_schema = self.parser.resolve_schema(prop_schema_or_ref)?;
-                let property = self.convert_schema_to_kotlin_property(
-                    prop_name,
-                    &prop_schema,
-                    required_fields,
-                )?;
-
-                // Add imports for property types
-                self.add_imports_for_type(&property.kotlin_type, &mut kotlin_class.imports);
-                kotlin_class.properties.push(property);
+        // A nullable reference resolves to the named schema, which has a class already
+        let resolved = self.parser.resolve_schema(schema_or_ref)?;
+        if resolved.reference_name.is_none() && self.is_inline_class_schema(&resolved) {
+            return Ok(Some(resolved));
+        }
+
+        match (schema.primary_type(), schema.items.as_deref()) {
+            (Some("array"), Some(items @ OpenAPISchemaOrRef::Schema(_))) => {
+                let items = self.parser.resolve_schema(items)?;
+                Ok(self.is_inline_class_schema(&items).then_some(items))
             }
+            _ => Ok(None),
         }
+    }
 
-        // Convert oneOf variants to sealed subclasses
-        if let Some(variants) = &schema.one_of_variants {
-            let mut sub_types = Vec::new();
+    /// Enums and objects with properties or composed variants get a class; free-form
+    /// objects stay maps.
+    fn is_inline_class_schema(&self, schema: &OpenAPISchema) -> bool {
+        self.is_enum_schema(schema)
+            || (matches!(schema.primary_type(), Some("object") | None)
+                && (!schema.properties.is_empty()
+                    || schema.one_of_variants.is_some()
+                    || schema.any_of_variants.is_some()))
+    }
 
-            for (variant_name, variant_schema) in variants {
-                let sub_class_name = self.pascal_case(variant_name);
-                let mut sub_class = KotlinClass {
-                    name: sub_class_name,
-                    package_name: kotlin_class.package_name.clone(),
-                    description: variant_schema.description.clone(),
-                    properties: Vec::new(),
-                    imports: kotlin_class.imports.clone(),
-                    is_sealed: None,
-                    sealed_sub_types: None,
-                    parent_class: Some(kotlin_class.name.clone()),
-                };
+    /// Returns the class name assigned to an inline schema before generation, or the
+    /// name the naming strategy gives it when no name was assigned.
+    fn inline_class_name(&self, owner: &str, prop_name: &str, schema: &OpenAPISchema) -> String {
+        self.inline_class_names
+            .get(&(owner.to_string(), prop_name.to_string()))
+            .cloned()
+            .unwrap_or_else(|| self.inline_class_base_name(owner, prop_name, schema))
+    }
 
-                // Add variant-specific properties
-                if !variant_schema.properties.is_empty() {
-                    let variant_properties = &variant_schema.properties;
-                    for (prop_name, prop_schema_or_ref) in variant_properties {
-                        // Skip discriminator property if it's already in base class
-                        if let Some(discriminator) = &schema.discriminator {
-                            if prop_name == &discriminator.property_name {
-                                continue;
-                            }
-                        }
-
-                        let prop_schema = self.parser.resolve_schema(prop_schema_or_ref)?;
-                        let property = self.convert_schema_to_kotlin_property(
-                            prop_name,
-                            &prop_schema,
-                            &variant_schema.required,
-                        )?;
-
-                        // Add imports for property types
-                        self.add_imports_for_type(&property.kotlin_type, &mut sub_class.imports);
-                        sub_class.properties.push(property);
-                    }
+    fn inline_class_base_name(
+        &self,
+        owner: &str,
+        prop_name: &str,
+        schema: &OpenAPISchema,
+    ) -> String {
+        let parent_and_property = || format!("{owner}{}", pascal_case(prop_name));
+
+        match self.config.inline_naming {
+            InlineNamingStrategy::ParentAndProperty => parent_and_property(),
+            InlineNamingStrategy::Property => pascal_case(prop_name),
+            InlineNamingStrategy::Title => schema
+                .title
+                .as_deref()
+                .map(pascal_case)
+                .unwrap_or_else(parent_and_property),
+        }
+    }
+
+    /// Names every inline class up front, so that no two classes and no class and
+    /// component share a name. Clashing names get a numeric suffix, in the order the
+    /// schemas are declared.
+    fn assign_inline_class_names(&self) -> Result<HashMap<(String, String), String>> {
+        let schemas = self.parser.get_all_schemas()?;
+        let mut taken: HashSet<String> =
+            schemas.iter().map(|(name, _)| pascal_case(name)).collect();
+        let mut names = HashMap::new();
+
+        for (name, schema) in &schemas {
+            self.assign_inline_class_names_for(name, schema, &mut taken, &mut names)?;
+        }
+
+        Ok(names)
+    }
+
+    fn assign_inline_class_names_for(
+        &self,
+        name: &str,
+        schema: &OpenAPISchema,
+        taken: &mut HashSet<String>,
+        names: &mut HashMap<(String, String), String>,
+    ) -> Result<()> {
+        for (owner, prop_name, prop_schema_or_ref) in self.inline_property_candidates(name, schema)
+        {
+            let key = (owner, prop_name.clone());
+            if names.contains_key(&key) {
+                continue;
+            }
+
+            if let Some(class_schema) = self.inline_class_schema(prop_schema_or_ref)? {
+                let base_name = self.inline_class_base_name(&key.0, prop_name, &class_schema);
+                let mut class_name = base_name.clone();
+                let mut suffix = 2;
+                while !taken.insert(class_name.clone()) {
+                    class_name = format!("{base_name}{suffix}");
+                    suffix += 1;
                 }
 
-                sub_types.push(sub_class);
+                names.insert(key, class_name.clone());
+                self.assign_inline_class_names_for(&class_name, &class_schema, taken, names)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Resolves a property schema, naming an inline object or enum after its owning
+    /// class so the property is typed as the class generated for it.
+    fn resolve_property_schema(
+        &self,
+        owner: &str,
+        prop_name: &str,
+        schema_or_ref: &OpenAPISchemaOrRef,
+    ) -> Result<Box<OpenAPISchema>> {
+        let mut schema = self.parser.resolve_schema(schema_or_ref)?;
+
+        if let OpenAPISchemaOrRef::Reference(reference) = schema_or_ref {
+            if schema.reference_name.is_none() {
+                schema.reference_name = self.inline_class_at(&reference.reference);
+            }
+        }
+
+        if let Some(mut class_schema) = self.inline_class_schema(schema_or_ref)? {
+            class_schema.reference_name =
+                Some(self.inline_class_name(owner, prop_name, &class_schema));
+
+            if self.is_inline_class_schema(&schema) {
+                schema = class_schema;
+            } else {
+                schema.items = Some(Box::new(OpenAPISchemaOrRef::Schema(class_schema)));
+            }
+        }
+
+        Ok(schema)
+    }
+
+    /// Returns the inline class generated for the property a `$ref` below a component
+    /// points at, e.g. `ContactInfoAddress` for
+    /// `#/components/schemas/ContactInfo/properties/address`.
+    fn inline_class_at(&self, reference: &str) -> Option<String> {
+        let pointer = reference.strip_prefix("#/components/schemas/")?;
+        let mut segments = pointer.split('/').map(source_map::unescape);
+        let mut class_name = pascal_case(&segments.next()?);
+        let mut nested = false;
+
+        while let Some(segment) = segments.next() {
+            if segment != "properties" {
+                return None;
+            }
+            let prop_name = segments.next()?;
+            class_name = self
+                .inline_class_names
+                .get(&(class_name, prop_name))?
+                .clone();
+            nested = true;
+        }
+
+        nested.then_some(class_name)
+    }
+
+    /// A string or integer schema whose `enum` values (other than `null`) all have
+    /// that type is generated as an enum class.
+    fn is_enum_schema(&self, schema: &OpenAPISchema) -> bool {
+        let mut values = schema
+            .enum_values
+            .iter()
+            .filter(|value| !value.is_null())
+            .peekable();
+        if values.peek().is_none() {
+            return false;
+        }
+
+        match schema.primary_type() {
+            Some("string") | None => values.all(|value| value.is_string()),
+            Some("integer") => values.all(|value| value.is_i64()),
+            _ => false,
+        }
+    }
+
+    /// Names the constants of an enum schema, preferring `x-enum-varnames` and
+    /// deriving SCREAMING_SNAKE_CASE names from the wire values otherwise.
+    fn enum_constant_names(&self, schema: &OpenAPISchema) -> Vec<String> {
+        let mut names: Vec<String> = Vec::new();
+
+        for (index, value) in schema.enum_values.iter().enumerate() {
+            if value.is_null() {
+                continue;
             }
 
-            kotlin_class.sealed_sub_types = Some(sub_types);
+            let mut name = schema
+                .enum_var_names
+                .get(index)
+                .cloned()
+                .unwrap_or_else(|| self.enum_constant_name(value));
+            if names.contains(&name) {
+                name = format!("{name}_{index}");
+            }
+            names.push(name);
         }
 
-        Ok(kotlin_class)
+        names
     }
 
-    fn convert_any_of_to_union_type(
+    fn enum_constant_name(&self, value: &serde_json::Value) -> String {
+        match value {
+            serde_json::Value::String(s) => {
+                let re = Regex::new(r"([a-z0-9])([A-Z])").unwrap();
+                let name = re
+                    .replace_all(s, "${1}_${2}")
+                    .split(|c: char| !c.is_ascii_alphanumeric())
+                    .filter(|part| !part.is_empty())
+                    .collect::<Vec<_>>()
+                    .join("_")
+                    .to_uppercase();
+
+                if name.is_empty() {
+                    "EMPTY".to_string()
+                } else if name.starts_with(|c: char| c.is_ascii_digit()) {
+                    format!("VALUE_{name}")
+                } else {
+                    name
+                }
+            }
+            _ => {
+                let number = value.to_string();
+                match number.strip_prefix('-') {
+                    Some(magnitude) => format!("VALUE_MINUS_{magnitude}"),
+                    None => format!("VALUE_{number}"),
+                }
+            }
+        }
+    }
+
+    /// Returns the enum constant a default value names, for a schema typed as an
+    /// enum class.
+    fn enum_default_value(
         &self,
-        name: &str,
         schema: &OpenAPISchema,
-    ) -> Result<KotlinClass> {
-        let mut imports = self.get_base_model_imports();
-        imports.extend_from_slice(&[
-            "com.fasterxml.jackson.annotation.JsonValue".to_string(),
-            "com.fasterxml.jackson.annotation.JsonCreator".to_string(),
-        ]);
+        value: &serde_json::Value,
+    ) -> Option<DefaultValue> {
+        if !self.is_enum_schema(schema) || self.mapped_class_name(schema).is_some() {
+            return None;
+        }
 
-        let mut kotlin_class = KotlinClass {
-            name: self.pascal_case(name),
-            package_name: self.config.base_package.clone(),
-            description: schema.description.clone(),
-            properties: Vec::new(),
-            imports,
-            is_sealed: None,
-            sealed_sub_types: None,
-            parent_class: None,
-        };
+        let enum_name = self.referenced_class_name(schema)?;
+        let index = schema
+            .enum_values
+            .iter()
+            .filter(|enum_value| !enum_value.is_null())
+            .position(|enum_value| enum_value == value)?;
+        let constant = self.enum_constant_names(schema).into_iter().nth(index)?;
+
+        Some(DefaultValue::EnumConstant {
+            enum_name,
+            constant,
+            value: value.clone(),
+        })
+    }
 
-        // For anyOf, we create a wrapper class that can hold any of the variant types
-        // Add a value property that can hold the actual data
-        let value_property = KotlinProperty {
-            name: "value".to_string(),
-            kotlin_type: "Any".to_string(),
-            nullable: false,
-            default_value: None,
-            description: Some(
-                "The actual value that matches one or more of the anyOf variants".to_string(),
-            ),
-            validation: vec!["@JsonValue".to_string()],
-            json_property: None,
-        };
-        kotlin_class.properties.push(value_property);
-
-        // Add a type property to indicate which variant types are satisfied
-        let type_property = KotlinProperty {
-            name: "supportedTypes".to_string(),
-            kotlin_type: "Set<String>".to_string(),
-            nullable: false,
-            default_value: Some("emptySet()".to_string()),
-            description: Some("Set of type names that this value satisfies".to_string()),
-            validation: Vec::new(),
-            json_property: None,
-        };
-        kotlin_class.properties.push(type_property);
+    fn convert_any_of_to_model(&self, name: &str, schema: &OpenAPISchema) -> Model {
+        let variants = schema
+            .any_of_variants
+            .iter()
+            .flatten()
+            .map(|(variant_name, _)| pascal_case(variant_name))
+            .collect();
 
-        Ok(kotlin_class)
+        Model {
+            name: pascal_case(name),
+            description: schema.description.clone(),
+            kind: ModelKind::AnyOf { variants },
+            extensions: schema.extensions.clone(),
+        }
     }
 
-    fn convert_schema_to_kotlin_property(
+    pub(crate) fn convert_schema_to_field(
         &self,
         name: &str,
         schema: &OpenAPISchema,
         required: &[String],
-    ) -> Result<KotlinProperty> {
-        let kotlin_name = self.camel_case(name);
-        let is_required = required.contains(&name.to_string());
-        let nullable = schema.nullable.unwrap_or(false) || !is_required;
-
-        let mut property = KotlinProperty {
-            name: kotlin_name.clone(),
-            kotlin_type: self.map_schema_to_kotlin_type(schema)?,
-            nullable,
-            default_value: None,
+    ) -> Result<Field> {
+        let default = schema.default.as_ref().map(|default_val| {
+            self.enum_default_value(schema, default_val)
+                .unwrap_or_else(|| DefaultValue::Literal(default_val.clone()))
+        });
+
+        Ok(Field {
+            name: name.to_string(),
+            type_ref: self.map_schema_to_type_ref(schema)?,
+            required: required.contains(&name.to_string()),
+            nullable: schema.is_nullable(),
+            default,
             description: schema.description.clone(),
-            validation: Vec::new(),
-            json_property: if kotlin_name != name {
-                Some(name.to_string())
-            } else {
-                None
-            },
-        };
+            constraints: self.schema_constraints(schema),
+            extensions: schema.extensions.clone(),
+        })
+    }
 
-        // Add default value
-        if let Some(default_val) = &schema.default {
-            property.default_value =
-                Some(self.format_default_value(default_val, &property.kotlin_type));
-        } else if nullable {
-            property.default_value = Some("null".to_string());
+    fn map_schema_to_type_ref(&self, schema: &OpenAPISchema) -> Result<TypeRef> {
+        if let Some(class_name) = self.mapped_class_name(schema) {
+            return Ok(TypeRef::External(class_name.to_string()));
         }
-
-        // Add validation annotations
-        if self.config.include_validation {
-            property.validation = self.generate_validation_annotations(schema, is_required);
+        if let Some(class_name) = self.referenced_class_name(schema) {
+            return Ok(if self.is_enum_schema(schema) {
+                TypeRef::Enum(class_name)
+            } else {
+                TypeRef::Model(class_name)
+            });
         }
 
-        Ok(property)
-    }
-
-    fn map_schema_to_kotlin_type(&self, schema: &OpenAPISchema) -> Result<String> {
-        match schema.schema_type.as_deref() {
+        match schema.primary_type() {
             Some("string") => match schema.format.as_deref() {
-                Some("date") => Ok("java.time.LocalDate".to_string()),
-                Some("date-time") => Ok("java.time.OffsetDateTime".to_string()),
-                Some("uuid") => Ok("java.util.UUID".to_string()),
-                Some("uri") => Ok("java.net.URI".to_string()),
-                Some("byte") | Some("binary") => Ok("ByteArray".to_string()),
-                _ => Ok("String".to_string()),
+                Some("date") => Ok(TypeRef::Date),
+                Some("date-time") => Ok(TypeRef::DateTime),
+                Some("uuid") => Ok(TypeRef::Uuid),
+                Some("uri") => Ok(TypeRef::Uri),
+                Some("byte") | Some("binary") => Ok(TypeRef::Binary),
+                _ => Ok(TypeRef::String),
             },
             Some("integer") => match schema.format.as_deref() {
-                Some("int64") => Ok("Long".to_string()),
-                _ => Ok("Int".to_string()),
+                Some("int64") => Ok(TypeRef::Int64),
+                _ => Ok(TypeRef::Int32),
             },
             Some("number") => match schema.format.as_deref() {
-                Some("float") => Ok("Float".to_string()),
-                Some("double") => Ok("Double".to_string()),
-                _ => Ok("java.math.BigDecimal".to_string()),
+                Some("float") => Ok(TypeRef::Float),
+                Some("double") => Ok(TypeRef::Double),
+                _ => Ok(TypeRef::Decimal),
             },
-            Some("boolean") => Ok("Boolean".to_string()),
+            Some("boolean") => Ok(TypeRef::Boolean),
             Some("array") => {
-                if let Some(items) = &schema.items {
+                // Tuple-like arrays are lists of their items' type when all agree
+                let mut item_types = Vec::new();
+                for items in schema.prefix_items.iter().chain(schema.items.as_deref()) {
                     let item_schema = self.parser.resolve_schema(items)?;
-                    let item_type = self.map_schema_to_kotlin_type(&item_schema)?;
-                    Ok(format!("List<{item_type}>"))
-                } else {
-                    Ok("List<Any>".to_string())
+                    let item_type = self.map_schema_to_type_ref(&item_schema)?;
+                    if !item_types.contains(&item_type) {
+                        item_types.push(item_type);
+                    }
                 }
+
+                let item_type = match item_types.len() {
+                    1 => item_types.remove(0),
+                    _ => TypeRef::Any,
+                };
+                Ok(TypeRef::List(Box::new(item_type)))
             }
-            Some("object") => Ok("Map<String, Any>".to_string()),
+            Some("object") => Ok(TypeRef::Map(Box::new(TypeRef::Any))),
             _ => {
                 // Check if it's a reference to another schema
                 if !schema.properties.is_empty() {
-                    Ok("Map<String, Any>".to_string())
+                    Ok(TypeRef::Map(Box::new(TypeRef::Any)))
                 } else {
-                    Ok("Any".to_string())
+                    Ok(TypeRef::Any)
                 }
             }
         }
     }
 
-    fn generate_validation_annotations(
-        &self,
-        schema: &OpenAPISchema,
-        required: bool,
-    ) -> Vec<String> {
-        let mut annotations = Vec::new();
+    /// Returns the class a schema is mapped to, by its component name or else by its
+    /// format.
+    fn mapped_class_name(&self, schema: &OpenAPISchema) -> Option<&str> {
+        let mappings = &self.config.type_mappings;
+        schema
+            .reference_name
+            .as_deref()
+            .and_then(|name| mappings.schema_class(name))
+            .or_else(|| {
+                schema
+                    .format
+                    .as_deref()
+                    .and_then(|format| mappings.format_class(format))
+            })
+    }
+
+    /// Returns the generated class name for a schema resolved from a component `$ref`
+    /// or named as an inline enum. References to other primitive or array components
+    /// are inlined as their underlying type.
+    fn referenced_class_name(&self, schema: &OpenAPISchema) -> Option<String> {
+        let name = schema.reference_name.as_ref()?;
+        let is_class = self.is_enum_schema(schema)
+            || match schema.primary_type() {
+                Some("object") => true,
+                None => {
+                    !schema.properties.is_empty()
+                        || schema.one_of_variants.is_some()
+                        || schema.any_of_variants.is_some()
+                }
+                _ => false,
+            };
+
+        is_class.then(|| pascal_case(name))
+    }
 
-        if required && !schema.nullable.unwrap_or(false) {
-            annotations.push("@NotNull".to_string());
+    fn schema_constraints(&self, schema: &OpenAPISchema) -> Constraints {
+        let mut constraints = Constraints::default();
+
+        // Constraints on the wire value do not apply to an enum or mapped class
+        if self.mapped_class_name(schema).is_some()
+            || (self.is_enum_schema(schema) && self.referenced_class_name(schema).is_some())
+        {
+            return constraints;
         }
 
-        match schema.schema_type.as_deref() {
+        match schema.primary_type() {
             Some("string") => {
-                if schema.format.as_deref() == Some("email") {
-                    annotations.push("@Email".to_string());
-                }
-                if schema.min_length.is_some() || schema.max_length.is_some() {
-                    let min = schema.min_length.unwrap_or(0);
-                    let max = schema
-                        .max_length
-                        .map_or("Integer.MAX_VALUE".to_string(), |v| v.to_string());
-                    annotations.push(format!("@Size(min = {min}, max = {max})"));
-                }
-                if let Some(pattern) = &schema.pattern {
-                    annotations.push(format!("@Pattern(regexp = \"{pattern}\")"));
-                }
+                constraints.email = schema.format.as_deref() == Some("email");
+                constraints.min_length = schema.min_length;
+                constraints.max_length = schema.max_length;
+                constraints.pattern = schema.pattern.clone();
             }
             Some("number") | Some("integer") => {
-                if let Some(minimum) = schema.minimum {
-                    annotations.push(format!("@Min({})", minimum as i64));
+                if let Some((minimum, exclusive)) = schema.lower_bound() {
+                    constraints.minimum = Some(minimum);
+                    constraints.exclusive_minimum = exclusive;
                 }
-                if let Some(maximum) = schema.maximum {
-                    annotations.push(format!("@Max({})", maximum as i64));
+                if let Some((maximum, exclusive)) = schema.upper_bound() {
+                    constraints.maximum = Some(maximum);
+                    constraints.exclusive_maximum = exclusive;
                 }
             }
             Some("array") => {
-                if schema.min_items.is_some() || schema.max_items.is_some() {
-                    let min = schema.min_items.unwrap_or(0);
-                    let max = schema
-                        .max_items
-                        .map_or("Integer.MAX_VALUE".to_string(), |v| v.to_string());
-                    annotations.push(format!("@Size(min = {min}, max = {max})"));
-                }
+                constraints.min_items = schema.min_items;
+                constraints.max_items = schema.max_items;
             }
             Some("object") => {
-                if !schema.properties.is_empty() {
-                    annotations.push("@Valid".to_string());
-                }
+                constraints.validate_nested = !schema.properties.is_empty();
             }
             _ => {}
         }
 
-        annotations
+        constraints
     }
 
-    fn convert_operations_to_kotlin_controller(
+    fn convert_operations_to_service(
         &self,
         tag: &str,
         operations: &[(String, String, &OpenAPIOperation)],
-    ) -> Result<KotlinController> {
-        let controller_name = format!("{}Controller", self.pascal_case(tag));
-
-        let mut kotlin_controller = KotlinController {
-            name: controller_name,
-            package_name: self.config.base_package.clone(),
-            description: Some(format!(
-                "{} API controller interface",
-                self.pascal_case(tag)
-            )),
-            methods: Vec::new(),
-            imports: self.get_base_controller_imports(),
-        };
-
-        for (path, method, operation) in operations {
-            let kotlin_method = self.convert_operation_to_kotlin_method(path, method, operation)?;
-            kotlin_controller.methods.push(kotlin_method);
-        }
-
-        Ok(kotlin_controller)
+    ) -> Result<Service> {
+        let operations = operations
+            .iter()
+            .map(|(path, method, operation)| self.convert_operation(path, method, operation))
+            .collect::<Result<_>>()?;
+
+        Ok(Service {
+            name: tag.to_string(),
+            operations,
+        })
     }
 
-    fn convert_operation_to_kotlin_method(
+    pub(crate) fn convert_operation(
         &self,
         path: &str,
         http_method: &str,
         operation: &OpenAPIOperation,
-    ) -> Result<KotlinMethod> {
-        let method_name = operation
+    ) -> Result<Operation> {
+        let name = operation
             .operation_id
             .as_ref()
-            .map(|id| self.camel_case(id))
+            .map(|id| camel_case(id))
             .unwrap_or_else(|| self.generate_method_name(http_method, path));
 
-        let mut kotlin_method = KotlinMethod {
-            name: method_name,
+        let mut converted = Operation {
+            name,
             http_method: http_method.to_string(),
             path: path.to_string(),
             summary: operation.summary.clone(),
             description: operation.description.clone(),
             parameters: Vec::new(),
             request_body: None,
-            return_type: self.determine_return_type(operation)?,
+            response: self.determine_response_type(operation)?,
             response_description: self.get_response_description(operation),
+            extensions: operation.extensions.clone(),
         };
 
         // Process parameters
-        for param_or_ref in &operation.parameters {
-            if let OpenAPIParameterOrRef::Parameter(param) = param_or_ref {
-                let kotlin_param = self.convert_parameter_to_kotlin(param)?;
-                kotlin_method.parameters.push(kotlin_param);
-            }
+        for param in self.parser.get_operation_parameters(path, operation)? {
+            converted.parameters.push(self.convert_parameter(param)?);
         }
 
         // Process request body
-        if let Some(OpenAPIRequestBodyOrRef::RequestBody(request_body)) = &operation.request_body {
+        if let Some(request_body_or_ref) = &operation.request_body {
+            let request_body = self.parser.resolve_component(request_body_or_ref)?;
             if let Some(media_type) = request_body.content.get("application/json") {
                 if let Some(schema_or_ref) = &media_type.schema {
                     let schema = self.parser.resolve_schema(schema_or_ref)?;
-                    let body_param = KotlinParameter {
-                        name: "body".to_string(),
-                        kotlin_type: self.map_schema_to_kotlin_type(&schema)?,
-                        param_type: ParameterType::Body,
+                    converted.request_body = Some(RequestBody {
+                        type_ref: self.map_schema_to_type_ref(&schema)?,
                         required: request_body.required,
                         description: request_body.description.clone(),
-                        validation: if self.config.include_validation {
-                            vec!["@Valid".to_string()]
-                        } else {
-                            Vec::new()
-                        },
-                    };
-                    kotlin_method.request_body = Some(body_param);
+                    });
                 }
             }
         }
 
-        Ok(kotlin_method)
+        Ok(converted)
     }
 
-    fn convert_parameter_to_kotlin(&self, param: &OpenAPIParameter) -> Result<KotlinParameter> {
-        let param_type = match param.location.as_str() {
-            "path" => ParameterType::Path,
-            "query" => ParameterType::Query,
-            "header" => ParameterType::Header,
-            _ => ParameterType::Query,
+    pub(crate) fn convert_parameter(&self, param: &OpenAPIParameter) -> Result<Parameter> {
+        let location = match param.location.as_str() {
+            "path" => ParameterLocation::Path,
+            "header" => ParameterLocation::Header,
+            "cookie" => ParameterLocation::Cookie,
+            _ => ParameterLocation::Query,
         };
 
-        let kotlin_type = if let Some(schema_or_ref) = &param.schema {
-            let schema = self.parser.resolve_schema(schema_or_ref)?;
-            self.map_schema_to_kotlin_type(&schema)?
-        } else {
-            "String".to_string()
-        };
+        let style = param.style.clone().unwrap_or_else(|| match location {
+            ParameterLocation::Query | ParameterLocation::Cookie => "form".to_string(),
+            ParameterLocation::Path | ParameterLocation::Header => "simple".to_string(),
+        });
+        let explode = param.explode.unwrap_or(style == "form");
 
-        let validation = if self.config.include_validation && param.required {
-            vec!["@NotNull".to_string()]
+        let (type_ref, serialization) = if let Some(schema_or_ref) = &param.schema {
+            let schema = self.parser.resolve_schema(schema_or_ref)?;
+            (
+                self.map_schema_to_type_ref(&schema)?,
+                ParameterSerialization::Style { style, explode },
+            )
+        } else if let Some((media_type, content)) = param.content.first() {
+            let type_ref = match &content.schema {
+                Some(schema_or_ref) => {
+                    let schema = self.parser.resolve_schema(schema_or_ref)?;
+                    self.map_schema_to_type_ref(&schema)?
+                }
+                None => TypeRef::String,
+            };
+            (
+                type_ref,
+                ParameterSerialization::Content(media_type.clone()),
+            )
         } else {
-            Vec::new()
+            (
+                TypeRef::String,
+                ParameterSerialization::Style { style, explode },
+            )
         };
 
-        Ok(KotlinParameter {
-            name: self.camel_case(&param.name),
-            kotlin_type,
-            param_type,
-            required: param.required,
+        Ok(Parameter {
+            name: param.name.clone(),
+            location,
+            type_ref,
+            // A parameter that may be sent without a value can't be enforced as required
+            required: param.required && !param.allow_empty_value,
             description: param.description.clone(),
-            validation,
+            serialization,
+            extensions: param.extensions.clone(),
         })
     }
 
@@ -600,28 +967,29 @@ impl OpenAPICodeGenerator {
             _ => http_method,
         };
 
-        format!("{}{}", method_prefix, self.pascal_case(resource))
+        format!("{}{}", method_prefix, pascal_case(resource))
     }
 
-    fn determine_return_type(&self, operation: &OpenAPIOperation) -> Result<String> {
-        // Look for success responses (200, 201, default)
+    /// Returns the type of the JSON body of the success response (200, 201 or
+    /// default), if it has one.
+    fn determine_response_type(&self, operation: &OpenAPIOperation) -> Result<Option<TypeRef>> {
         let success_response = operation
             .responses
             .get("200")
             .or_else(|| operation.responses.get("201"))
             .or_else(|| operation.responses.get("default"));
 
-        if let Some(OpenAPIResponseOrRef::Response(response)) = success_response {
+        if let Some(response_or_ref) = success_response {
+            let response = self.parser.resolve_component(response_or_ref)?;
             if let Some(media_type) = response.content.get("application/json") {
                 if let Some(schema_or_ref) = &media_type.schema {
                     let schema = self.parser.resolve_schema(schema_or_ref)?;
-                    let inner_type = self.map_schema_to_kotlin_type(&schema)?;
-                    return Ok(format!("ResponseEntity<{inner_type}>"));
+                    return Ok(Some(self.map_schema_to_type_ref(&schema)?));
                 }
             }
         }
 
-        Ok("ResponseEntity<Any>".to_string())
+        Ok(None)
     }
 
     fn get_response_description(&self, operation: &OpenAPIOperation) -> Option<String> {
@@ -631,188 +999,22 @@ impl OpenAPICodeGenerator {
             .or_else(|| operation.responses.get("201"))
             .or_else(|| operation.responses.get("default"));
 
-        if let Some(OpenAPIResponseOrRef::Response(response)) = success_response {
-            Some(response.description.clone())
-        } else {
-            Some("Success".to_string())
-        }
-    }
-
-    async fn write_kotlin_class(
-        &self,
-        kotlin_class: &KotlinClass,
-        sub_dir: &str,
-    ) -> Result<PathBuf> {
-        let content = self.template_engine.generate_kotlin_class(kotlin_class);
-        let file_name = format!("{}.kt", kotlin_class.name);
-
-        let package_path: PathBuf = kotlin_class.package_name.split('.').collect();
-        let output_dir = self
-            .config
-            .output_dir
-            .join("src/main/kotlin")
-            .join(package_path)
-            .join(sub_dir);
-
-        let file_path = output_dir.join(&file_name);
-
-        fs::create_dir_all(&output_dir)
-            .await
-            .with_context(|| format!("Failed to create directory: {}", output_dir.display()))?;
-
-        fs::write(&file_path, content)
-            .await
-            .with_context(|| format!("Failed to write file: {}", file_path.display()))?;
-
-        Ok(file_path)
-    }
-
-    async fn write_kotlin_controller(
-        &self,
-        kotlin_controller: &KotlinController,
-        sub_dir: &str,
-    ) -> Result<PathBuf> {
-        let content = self
-            .template_engine
-            .generate_kotlin_controller(kotlin_controller);
-        let file_name = format!("{}.kt", kotlin_controller.name);
-
-        let package_path: PathBuf = kotlin_controller.package_name.split('.').collect();
-        let output_dir = self
-            .config
-            .output_dir
-            .join("src/main/kotlin")
-            .join(package_path)
-            .join(sub_dir);
-
-        let file_path = output_dir.join(&file_name);
-
-        fs::create_dir_all(&output_dir)
-            .await
-            .with_context(|| format!("Failed to create directory: {}", output_dir.display()))?;
-
-        fs::write(&file_path, content)
-            .await
-            .with_context(|| format!("Failed to write file: {}", file_path.display()))?;
-
-        Ok(file_path)
-    }
-
-    async fn generate_build_file(&self) -> Result<PathBuf> {
-        let content = self
-            .template_engine
-            .generate_build_file(&self.config.base_package);
-        let file_path = self.config.output_dir.join("build.gradle.kts");
-
-        fs::write(&file_path, content)
-            .await
-            .with_context(|| "Failed to write build.gradle.kts")?;
-
-        if self.config.verbose {
-            let relative_path = file_path
-                .strip_prefix(&self.config.output_dir)
-                .unwrap_or(&file_path);
-            println!("Generated build.gradle.kts -> {}", relative_path.display());
-        }
-
-        Ok(file_path)
-    }
-
-    fn get_base_model_imports(&self) -> Vec<String> {
-        let mut imports = vec![
-            "javax.validation.constraints.*".to_string(),
-            "javax.validation.Valid".to_string(),
-            "com.fasterxml.jackson.annotation.JsonProperty".to_string(),
-        ];
-
-        if self.config.include_swagger {
-            imports.push("io.swagger.v3.oas.annotations.media.Schema".to_string());
-        }
-
-        imports
-    }
-
-    fn get_base_controller_imports(&self) -> Vec<String> {
-        let mut imports = vec![
-            "org.springframework.http.ResponseEntity".to_string(),
-            "org.springframework.web.bind.annotation.*".to_string(),
-            "javax.validation.Valid".to_string(),
-            "javax.validation.constraints.*".to_string(),
-        ];
-
-        if self.config.include_swagger {
-            imports.extend([
-                "io.swagger.v3.oas.annotations.Operation".to_string(),
-                "io.swagger.v3.oas.annotations.responses.ApiResponse".to_string(),
-                "io.swagger.v3.oas.annotations.responses.ApiResponses".to_string(),
-            ]);
-        }
-
-        imports
-    }
-
-    fn add_imports_for_type(&self, kotlin_type: &str, imports: &mut Vec<String>) {
-        if kotlin_type.contains("java.time.LocalDate") {
-            imports.push("java.time.LocalDate".to_string());
-        }
-        if kotlin_type.contains("java.time.OffsetDateTime") {
-            imports.push("java.time.OffsetDateTime".to_string());
-        }
-        if kotlin_type.contains("java.util.UUID") {
-            imports.push("java.util.UUID".to_string());
-        }
-        if kotlin_type.contains("java.net.URI") {
-            imports.push("java.net.URI".to_string());
-        }
-        if kotlin_type.contains("java.math.BigDecimal") {
-            imports.push("java.math.BigDecimal".to_string());
-        }
-    }
-
-    fn format_default_value(&self, value: &serde_json::Value, kotlin_type: &str) -> String {
-        match value {
-            serde_json::Value::Null => "null".to_string(),
-            serde_json::Value::String(s) => {
-                if kotlin_type == "String" {
-                    format!("\"{s}\"")
-                } else {
-                    s.clone()
-                }
-            }
-            serde_json::Value::Bool(b) => b.to_string(),
-            serde_json::Value::Number(n) => n.to_string(),
-            _ => value.to_string(),
-        }
-    }
-
-    fn pascal_case(&self, s: &str) -> String {
-        let re = Regex::new(r"[-_\s]+(.?)").unwrap();
-        let result = re.replace_all(s, |caps: &regex::Captures| {
-            caps.get(1)
-                .map_or("".to_string(), |m| m.as_str().to_uppercase())
-        });
+        let description = success_response
+            .and_then(|response_or_ref| self.parser.resolve_component(response_or_ref).ok())
+            .map(|response| response.description.clone())
+            .unwrap_or_else(|| "Success".to_string());
 
-        // Capitalize first character
-        let mut chars = result.chars();
-        match chars.next() {
-            None => String::new(),
-            Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
-        }
-    }
-
-    fn camel_case(&self, s: &str) -> String {
-        let pascal = self.pascal_case(s);
-        let mut chars = pascal.chars();
-        match chars.next() {
-            None => String::new(),
-            Some(first) => first.to_lowercase().collect::<String>() + chars.as_str(),
-        }
+        Some(description)
     }
 }
 
 #[cfg(test)]
+#[allow(clippy::field_reassign_with_default)]
 mod generator_tests {
     use super::*;
+    use crate::config::OperationFilters;
+    use crate::mappings::TypeMappings;
+    use crate::templates::TemplateOverrides;
     use indexmap::IndexMap;
     use serde_json::json;
     use std::path::PathBuf;
@@ -820,12 +1022,20 @@ mod generator_tests {
     fn create_test_config() -> GeneratorConfig {
         GeneratorConfig {
             base_package: "com.example.api".to_string(),
+            model_package: None,
+            api_package: None,
             output_dir: PathBuf::from("/tmp/test_output"),
             generate_models: true,
             generate_controllers: true,
             include_validation: true,
             include_swagger: true,
             verbose: false,
+            inline_naming: InlineNamingStrategy::ParentAndProperty,
+            target: TargetLanguage::Kotlin,
+            spring_boot: SpringBootProfile::default(),
+            type_mappings: TypeMappings::default(),
+            templates: TemplateOverrides::default(),
+            filters: OperationFilters::default(),
         }
     }
 
@@ -833,6 +1043,30 @@ mod generator_tests {
         OpenAPICodeGenerator::new(create_test_config())
     }
 
+    fn parameter(name: &str, location: &str, schema_type: Option<&str>) -> OpenAPIParameter {
+        OpenAPIParameter {
+            name: name.to_string(),
+            location: location.to_string(),
+            description: None,
+            required: true,
+            deprecated: false,
+            allow_empty_value: false,
+            style: None,
+            explode: None,
+            allow_reserved: false,
+            schema: schema_type.map(|schema_type| {
+                OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
+                    schema_type: Some(schema_type.into()),
+                    ..Default::default()
+                }))
+            }),
+            example: None,
+            examples: IndexMap::new(),
+            content: IndexMap::new(),
+            extensions: Extensions::new(),
+        }
+    }
+
     #[test]
     fn test_generator_creation() {
         let config = create_test_config();
@@ -850,448 +1084,359 @@ mod generator_tests {
     }
 
     #[test]
-    fn test_pascal_case_conversion() {
+    fn test_map_schema_to_type_ref_primitives() {
         let generator = create_test_generator();
+        let string = |format: Option<&str>| OpenAPISchema {
+            schema_type: Some("string".into()),
+            format: format.map(str::to_string),
+            ..Default::default()
+        };
 
-        assert_eq!(generator.pascal_case("hello_world"), "HelloWorld");
-        assert_eq!(generator.pascal_case("api-endpoint"), "ApiEndpoint");
-        assert_eq!(generator.pascal_case("user name"), "UserName");
-        assert_eq!(generator.pascal_case("snake_case_name"), "SnakeCaseName");
-        assert_eq!(generator.pascal_case("kebab-case-name"), "KebabCaseName");
-        assert_eq!(generator.pascal_case("already_Pascal"), "AlreadyPascal");
-        assert_eq!(generator.pascal_case(""), "");
-        assert_eq!(generator.pascal_case("a"), "A");
+        let cases = [
+            (None, TypeRef::String),
+            (Some("date"), TypeRef::Date),
+            (Some("date-time"), TypeRef::DateTime),
+            (Some("uuid"), TypeRef::Uuid),
+            (Some("uri"), TypeRef::Uri),
+            (Some("binary"), TypeRef::Binary),
+            (Some("byte"), TypeRef::Binary),
+            (Some("email"), TypeRef::String),
+        ];
+        for (format, expected) in cases {
+            assert_eq!(
+                generator.map_schema_to_type_ref(&string(format)).unwrap(),
+                expected
+            );
+        }
     }
 
     #[test]
-    fn test_camel_case_conversion() {
+    fn test_map_schema_to_type_ref_numbers() {
         let generator = create_test_generator();
+        let schema = |schema_type: &str, format: Option<&str>| OpenAPISchema {
+            schema_type: Some(schema_type.into()),
+            format: format.map(str::to_string),
+            ..Default::default()
+        };
 
-        assert_eq!(generator.camel_case("hello_world"), "helloWorld");
-        assert_eq!(generator.camel_case("api-endpoint"), "apiEndpoint");
-        assert_eq!(generator.camel_case("user name"), "userName");
-        assert_eq!(generator.camel_case("snake_case_name"), "snakeCaseName");
-        assert_eq!(generator.camel_case("kebab-case-name"), "kebabCaseName");
-        assert_eq!(generator.camel_case("Already_Pascal"), "alreadyPascal");
-        assert_eq!(generator.camel_case(""), "");
-        assert_eq!(generator.camel_case("a"), "a");
+        let cases = [
+            (schema("integer", None), TypeRef::Int32),
+            (schema("integer", Some("int32")), TypeRef::Int32),
+            (schema("integer", Some("int64")), TypeRef::Int64),
+            (schema("number", None), TypeRef::Decimal),
+            (schema("number", Some("float")), TypeRef::Float),
+            (schema("number", Some("double")), TypeRef::Double),
+        ];
+        for (schema, expected) in cases {
+            assert_eq!(generator.map_schema_to_type_ref(&schema).unwrap(), expected);
+        }
     }
 
     #[test]
-    fn test_map_schema_to_kotlin_type_primitives() {
+    fn test_map_schema_to_type_ref_boolean() {
         let generator = create_test_generator();
 
-        // String types
-        let string_schema = OpenAPISchema {
-            schema_type: Some("string".to_string()),
-            format: None,
-            ..Default::default()
-        };
-        assert_eq!(
-            generator.map_schema_to_kotlin_type(&string_schema).unwrap(),
-            "String"
-        );
-
-        // String with date format
-        let date_schema = OpenAPISchema {
-            schema_type: Some("string".to_string()),
-            format: Some("date".to_string()),
-            ..Default::default()
-        };
-        assert_eq!(
-            generator.map_schema_to_kotlin_type(&date_schema).unwrap(),
-            "java.time.LocalDate"
-        );
-
-        // String with date-time format
-        let datetime_schema = OpenAPISchema {
-            schema_type: Some("string".to_string()),
-            format: Some("date-time".to_string()),
-            ..Default::default()
-        };
-        assert_eq!(
-            generator
-                .map_schema_to_kotlin_type(&datetime_schema)
-                .unwrap(),
-            "java.time.OffsetDateTime"
-        );
-
-        // String with UUID format
-        let uuid_schema = OpenAPISchema {
-            schema_type: Some("string".to_string()),
-            format: Some("uuid".to_string()),
-            ..Default::default()
-        };
-        assert_eq!(
-            generator.map_schema_to_kotlin_type(&uuid_schema).unwrap(),
-            "java.util.UUID"
-        );
-
-        // String with URI format
-        let uri_schema = OpenAPISchema {
-            schema_type: Some("string".to_string()),
-            format: Some("uri".to_string()),
-            ..Default::default()
-        };
-        assert_eq!(
-            generator.map_schema_to_kotlin_type(&uri_schema).unwrap(),
-            "java.net.URI"
-        );
-
-        // String with binary format
-        let binary_schema = OpenAPISchema {
-            schema_type: Some("string".to_string()),
-            format: Some("binary".to_string()),
+        let boolean_schema = OpenAPISchema {
+            schema_type: Some("boolean".into()),
             ..Default::default()
         };
         assert_eq!(
-            generator.map_schema_to_kotlin_type(&binary_schema).unwrap(),
-            "ByteArray"
+            generator.map_schema_to_type_ref(&boolean_schema).unwrap(),
+            TypeRef::Boolean
         );
     }
 
     #[test]
-    fn test_map_schema_to_kotlin_type_numbers() {
+    fn test_map_schema_to_type_ref_array() {
         let generator = create_test_generator();
 
-        // Integer types
-        let int_schema = OpenAPISchema {
-            schema_type: Some("integer".to_string()),
-            format: None,
+        let array_schema = OpenAPISchema {
+            schema_type: Some("array".into()),
+            items: Some(Box::new(OpenAPISchemaOrRef::Schema(Box::new(
+                OpenAPISchema {
+                    schema_type: Some("string".into()),
+                    ..Default::default()
+                },
+            )))),
             ..Default::default()
         };
         assert_eq!(
-            generator.map_schema_to_kotlin_type(&int_schema).unwrap(),
-            "Int"
+            generator.map_schema_to_type_ref(&array_schema).unwrap(),
+            TypeRef::List(Box::new(TypeRef::String))
         );
 
-        let long_schema = OpenAPISchema {
-            schema_type: Some("integer".to_string()),
-            format: Some("int64".to_string()),
+        // Array without items
+        let array_no_items = OpenAPISchema {
+            schema_type: Some("array".into()),
+            items: None,
             ..Default::default()
         };
         assert_eq!(
-            generator.map_schema_to_kotlin_type(&long_schema).unwrap(),
-            "Long"
+            generator.map_schema_to_type_ref(&array_no_items).unwrap(),
+            TypeRef::List(Box::new(TypeRef::Any))
         );
+    }
 
-        // Number types
-        let number_schema = OpenAPISchema {
-            schema_type: Some("number".to_string()),
-            format: None,
-            ..Default::default()
+    #[test]
+    fn test_map_schema_to_type_ref_tuple() {
+        let generator = create_test_generator();
+        let item = |schema_type: &str| {
+            OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
+                schema_type: Some(schema_type.into()),
+                ..Default::default()
+            }))
         };
-        assert_eq!(
-            generator.map_schema_to_kotlin_type(&number_schema).unwrap(),
-            "java.math.BigDecimal"
-        );
 
-        let float_schema = OpenAPISchema {
-            schema_type: Some("number".to_string()),
-            format: Some("float".to_string()),
+        let point = OpenAPISchema {
+            schema_type: Some("array".into()),
+            prefix_items: vec![item("number"), item("number")],
             ..Default::default()
         };
         assert_eq!(
-            generator.map_schema_to_kotlin_type(&float_schema).unwrap(),
-            "Float"
+            generator.map_schema_to_type_ref(&point).unwrap(),
+            TypeRef::List(Box::new(TypeRef::Decimal))
         );
 
-        let double_schema = OpenAPISchema {
-            schema_type: Some("number".to_string()),
-            format: Some("double".to_string()),
-            ..Default::default()
+        // Items of different types, including those after the prefix
+        let record = OpenAPISchema {
+            prefix_items: vec![item("string")],
+            items: Some(Box::new(item("integer"))),
+            ..point
         };
         assert_eq!(
-            generator.map_schema_to_kotlin_type(&double_schema).unwrap(),
-            "Double"
+            generator.map_schema_to_type_ref(&record).unwrap(),
+            TypeRef::List(Box::new(TypeRef::Any))
         );
     }
 
     #[test]
-    fn test_map_schema_to_kotlin_type_boolean() {
+    fn test_map_schema_to_type_ref_object() {
         let generator = create_test_generator();
 
-        let boolean_schema = OpenAPISchema {
-            schema_type: Some("boolean".to_string()),
+        let object_schema = OpenAPISchema {
+            schema_type: Some("object".into()),
             ..Default::default()
         };
         assert_eq!(
-            generator
-                .map_schema_to_kotlin_type(&boolean_schema)
-                .unwrap(),
-            "Boolean"
+            generator.map_schema_to_type_ref(&object_schema).unwrap(),
+            TypeRef::Map(Box::new(TypeRef::Any))
         );
     }
 
     #[test]
-    fn test_map_schema_to_kotlin_type_array() {
+    fn test_map_schema_to_type_ref_referenced_component() {
         let generator = create_test_generator();
 
-        let array_schema = OpenAPISchema {
-            schema_type: Some("array".to_string()),
-            items: Some(Box::new(OpenAPISchemaOrRef::Schema(Box::new(
-                OpenAPISchema {
-                    schema_type: Some("string".to_string()),
-                    ..Default::default()
-                },
-            )))),
+        let object_ref = OpenAPISchema {
+            schema_type: Some("object".into()),
+            reference_name: Some("user_profile".to_string()),
             ..Default::default()
         };
         assert_eq!(
-            generator.map_schema_to_kotlin_type(&array_schema).unwrap(),
-            "List<String>"
+            generator.map_schema_to_type_ref(&object_ref).unwrap(),
+            TypeRef::Model("UserProfile".to_string())
         );
 
-        // Array without items
-        let array_no_items = OpenAPISchema {
-            schema_type: Some("array".to_string()),
-            items: None,
+        let enum_ref = OpenAPISchema {
+            schema_type: Some("string".into()),
+            enum_values: vec![json!("open")],
+            reference_name: Some("Status".to_string()),
             ..Default::default()
         };
         assert_eq!(
-            generator
-                .map_schema_to_kotlin_type(&array_no_items)
-                .unwrap(),
-            "List<Any>"
+            generator.map_schema_to_type_ref(&enum_ref).unwrap(),
+            TypeRef::Enum("Status".to_string())
         );
-    }
 
-    #[test]
-    fn test_map_schema_to_kotlin_type_object() {
-        let generator = create_test_generator();
-
-        let object_schema = OpenAPISchema {
-            schema_type: Some("object".to_string()),
+        // Aliases of primitive types are inlined
+        let primitive_ref = OpenAPISchema {
+            schema_type: Some("integer".into()),
+            format: Some("int64".to_string()),
+            reference_name: Some("UserId".to_string()),
             ..Default::default()
         };
         assert_eq!(
-            generator.map_schema_to_kotlin_type(&object_schema).unwrap(),
-            "Map<String, Any>"
+            generator.map_schema_to_type_ref(&primitive_ref).unwrap(),
+            TypeRef::Int64
         );
     }
 
     #[test]
-    fn test_generate_validation_annotations_required() {
+    fn test_map_schema_to_type_ref_unsupported() {
         let generator = create_test_generator();
 
-        let schema = OpenAPISchema {
-            schema_type: Some("string".to_string()),
-            nullable: Some(false),
+        let unsupported_schema = OpenAPISchema {
+            schema_type: Some("unknown".into()),
             ..Default::default()
         };
-
-        let annotations = generator.generate_validation_annotations(&schema, true);
-        assert!(annotations.contains(&"@NotNull".to_string()));
+        assert_eq!(
+            generator
+                .map_schema_to_type_ref(&unsupported_schema)
+                .unwrap(),
+            TypeRef::Any
+        );
     }
 
     #[test]
-    fn test_generate_validation_annotations_string_constraints() {
+    fn test_schema_constraints_string() {
         let generator = create_test_generator();
 
         let schema = OpenAPISchema {
-            schema_type: Some("string".to_string()),
+            schema_type: Some("string".into()),
             format: Some("email".to_string()),
             min_length: Some(5),
             max_length: Some(100),
             pattern: Some("[a-z]+".to_string()),
+            minimum: Some(1.0),
             ..Default::default()
         };
 
-        let annotations = generator.generate_validation_annotations(&schema, false);
-        assert!(annotations.contains(&"@Email".to_string()));
-        assert!(annotations.contains(&"@Size(min = 5, max = 100)".to_string()));
-        assert!(annotations.contains(&"@Pattern(regexp = \"[a-z]+\")".to_string()));
+        let constraints = generator.schema_constraints(&schema);
+        assert!(constraints.email);
+        assert_eq!(constraints.min_length, Some(5));
+        assert_eq!(constraints.max_length, Some(100));
+        assert_eq!(constraints.pattern, Some("[a-z]+".to_string()));
+        // Constraints of other types are ignored
+        assert_eq!(constraints.minimum, None);
     }
 
     #[test]
-    fn test_generate_validation_annotations_numeric_constraints() {
+    fn test_schema_constraints_numeric() {
         let generator = create_test_generator();
 
         let schema = OpenAPISchema {
-            schema_type: Some("integer".to_string()),
+            schema_type: Some("integer".into()),
             minimum: Some(0.0),
             maximum: Some(100.0),
             ..Default::default()
         };
 
-        let annotations = generator.generate_validation_annotations(&schema, false);
-        assert!(annotations.contains(&"@Min(0)".to_string()));
-        assert!(annotations.contains(&"@Max(100)".to_string()));
+        let constraints = generator.schema_constraints(&schema);
+        assert_eq!(constraints.minimum, Some(0.0));
+        assert_eq!(constraints.maximum, Some(100.0));
+        assert_eq!(constraints.min_length, None);
     }
 
     #[test]
-    fn test_format_default_value() {
+    fn test_schema_constraints_exclusive_bounds() {
         let generator = create_test_generator();
 
-        assert_eq!(
-            generator.format_default_value(&json!("hello"), "String"),
-            "\"hello\""
-        );
-        assert_eq!(generator.format_default_value(&json!(42), "Int"), "42");
-        assert_eq!(
-            generator.format_default_value(&json!(true), "Boolean"),
-            "true"
-        );
-        assert_eq!(
-            generator.format_default_value(&json!(null), "String"),
-            "null"
-        );
-    }
-
-    #[test]
-    fn test_get_base_model_imports() {
-        let generator = create_test_generator();
-        let imports = generator.get_base_model_imports();
-
-        assert!(imports.contains(&"javax.validation.constraints.*".to_string()));
-        assert!(imports.contains(&"javax.validation.Valid".to_string()));
-        assert!(imports.contains(&"com.fasterxml.jackson.annotation.JsonProperty".to_string()));
-        assert!(imports.contains(&"io.swagger.v3.oas.annotations.media.Schema".to_string()));
-    }
-
-    #[test]
-    fn test_get_base_controller_imports() {
-        let generator = create_test_generator();
-        let imports = generator.get_base_controller_imports();
-
-        assert!(imports.contains(&"org.springframework.http.ResponseEntity".to_string()));
-        assert!(imports.contains(&"org.springframework.web.bind.annotation.*".to_string()));
-        assert!(imports.contains(&"javax.validation.Valid".to_string()));
-        assert!(imports.contains(&"javax.validation.constraints.*".to_string()));
-        assert!(imports.contains(&"io.swagger.v3.oas.annotations.Operation".to_string()));
-    }
-
-    #[test]
-    fn test_add_imports_for_type() {
-        let generator = create_test_generator();
-        let mut imports = Vec::new();
-
-        generator.add_imports_for_type("java.time.LocalDate", &mut imports);
-        assert!(imports.contains(&"java.time.LocalDate".to_string()));
-
-        generator.add_imports_for_type("java.time.OffsetDateTime", &mut imports);
-        assert!(imports.contains(&"java.time.OffsetDateTime".to_string()));
-
-        generator.add_imports_for_type("java.util.UUID", &mut imports);
-        assert!(imports.contains(&"java.util.UUID".to_string()));
-
-        generator.add_imports_for_type("String", &mut imports);
-        // Should not add any import for basic types
-        assert_eq!(imports.len(), 3);
-    }
-
-    #[test]
-    fn test_generate_validation_annotations_edge_cases() {
-        let generator = create_test_generator();
-
-        // Schema with exclusive minimum/maximum
-        let exclusive_number_schema = OpenAPISchema {
-            schema_type: Some("number".to_string()),
+        // OpenAPI 3.0 flags the bounds as exclusive
+        let schema = OpenAPISchema {
+            schema_type: Some("number".into()),
             minimum: Some(0.0),
-            maximum: Some(100.0),
             exclusive_minimum: Some(json!(true)),
-            exclusive_maximum: Some(json!(true)),
+            maximum: Some(1.0),
+            exclusive_maximum: Some(json!(false)),
             ..Default::default()
-        };
-        let annotations =
-            generator.generate_validation_annotations(&exclusive_number_schema, false);
-        // Check that at least some basic validation annotations are present
-        assert!(
-            annotations.contains(&"@Min(0)".to_string())
-                || annotations.contains(&"@Max(100)".to_string())
-        );
-    }
-
-    #[test]
-    fn test_map_schema_to_kotlin_type_unsupported() {
-        let generator = create_test_generator();
+        };
+        let constraints = generator.schema_constraints(&schema);
+        assert_eq!(constraints.minimum, Some(0.0));
+        assert!(constraints.exclusive_minimum);
+        assert_eq!(constraints.maximum, Some(1.0));
+        assert!(!constraints.exclusive_maximum);
 
-        let unsupported_schema = OpenAPISchema {
-            schema_type: Some("unknown".to_string()),
+        // OpenAPI 3.1 gives the exclusive bounds themselves, and the tighter bound wins
+        let schema = OpenAPISchema {
+            schema_type: Some("integer".into()),
+            minimum: Some(5.0),
+            exclusive_minimum: Some(json!(0)),
+            maximum: Some(100.0),
+            exclusive_maximum: Some(json!(10)),
             ..Default::default()
         };
-        // The implementation might handle unknown types by returning a default type
-        let result = generator.map_schema_to_kotlin_type(&unsupported_schema);
-        // Just check that the method doesn't panic - behavior may vary
-        assert!(result.is_ok() || result.is_err());
+        let constraints = generator.schema_constraints(&schema);
+        assert_eq!(constraints.minimum, Some(5.0));
+        assert!(!constraints.exclusive_minimum);
+        assert_eq!(constraints.maximum, Some(10.0));
+        assert!(constraints.exclusive_maximum);
     }
 
     #[test]
-    fn test_format_default_value_complex_types() {
+    fn test_schema_constraints_enum_class() {
         let generator = create_test_generator();
 
-        assert_eq!(
-            generator.format_default_value(&json!(42.5), "Double"),
-            "42.5"
-        );
-
-        assert_eq!(
-            generator.format_default_value(&json!([1, 2, 3]), "List<Int>"),
-            "[1,2,3]"
-        );
-
-        assert_eq!(
-            generator.format_default_value(&json!({}), "Map<String, Any>"),
-            "{}"
-        );
+        let schema = OpenAPISchema {
+            schema_type: Some("string".into()),
+            enum_values: vec![json!("open")],
+            pattern: Some("[a-z]+".to_string()),
+            reference_name: Some("Status".to_string()),
+            ..Default::default()
+        };
 
         assert_eq!(
-            generator.format_default_value(&json!({"key": "value"}), "Map<String, Any>"),
-            "{\"key\":\"value\"}"
+            generator.schema_constraints(&schema),
+            Constraints::default()
         );
     }
 
     #[test]
-    fn test_convert_schema_to_kotlin_property() {
+    fn test_convert_schema_to_field() {
         let generator = create_test_generator();
         let schema = OpenAPISchema {
-            schema_type: Some("string".to_string()),
+            schema_type: Some("string".into()),
             description: Some("Test property description".to_string()),
             default: Some(json!("default_value")),
             min_length: Some(5),
             max_length: Some(50),
             ..Default::default()
         };
-        let required_fields = vec!["testProperty".to_string()];
+        let required_fields = vec!["test_property".to_string()];
 
-        let property = generator
-            .convert_schema_to_kotlin_property("testProperty", &schema, &required_fields)
+        let field = generator
+            .convert_schema_to_field("test_property", &schema, &required_fields)
             .unwrap();
 
-        assert_eq!(property.name, "testProperty");
-        assert_eq!(property.kotlin_type, "String");
-        assert!(!property.nullable);
+        assert_eq!(field.name, "test_property");
+        assert_eq!(field.type_ref, TypeRef::String);
+        assert!(field.required);
+        assert!(!field.nullable);
         assert_eq!(
-            property.description,
+            field.description,
             Some("Test property description".to_string())
         );
         assert_eq!(
-            property.default_value,
-            Some("\"default_value\"".to_string())
+            field.default,
+            Some(DefaultValue::Literal(json!("default_value")))
         );
-        assert!(property
-            .validation
-            .contains(&"@Size(min = 5, max = 50)".to_string()));
+        assert_eq!(field.constraints.min_length, Some(5));
+        assert_eq!(field.constraints.max_length, Some(50));
     }
 
     #[test]
-    fn test_convert_schema_to_kotlin_property_optional() {
+    fn test_convert_schema_to_field_optional() {
         let generator = create_test_generator();
         let schema = OpenAPISchema {
-            schema_type: Some("integer".to_string()),
+            schema_type: Some("integer".into()),
             nullable: Some(true),
             ..Default::default()
         };
-        let required_fields = vec![];
 
-        let property = generator
-            .convert_schema_to_kotlin_property("optionalField", &schema, &required_fields)
+        let field = generator
+            .convert_schema_to_field("optionalField", &schema, &[])
             .unwrap();
 
-        assert_eq!(property.name, "optionalField");
-        assert_eq!(property.kotlin_type, "Int");
-        assert!(property.nullable);
-        assert_eq!(property.default_value, Some("null".to_string()));
+        assert_eq!(field.name, "optionalField");
+        assert_eq!(field.type_ref, TypeRef::Int32);
+        assert!(!field.required);
+        assert!(field.nullable);
+        assert_eq!(field.default, None);
+    }
+
+    #[test]
+    fn test_convert_schema_to_field_type_list_with_null() {
+        let generator = create_test_generator();
+        let schema: OpenAPISchema =
+            serde_json::from_value(json!({ "type": ["string", "null"], "format": "date" }))
+                .unwrap();
+
+        let field = generator
+            .convert_schema_to_field("birthday", &schema, &["birthday".to_string()])
+            .unwrap();
+
+        assert_eq!(field.type_ref, TypeRef::Date);
+        assert!(field.required);
+        assert!(field.nullable);
     }
 
     #[test]
@@ -1318,172 +1463,314 @@ mod generator_tests {
     }
 
     #[test]
-    fn test_convert_one_of_to_sealed_class() {
+    fn test_method_name_generation_edge_cases() {
         let generator = create_test_generator();
-        let mut schema = OpenAPISchema::default();
 
-        // Set up oneOf variants
+        // Test with empty path
+        assert_eq!(generator.generate_method_name("get", "/"), "getResource");
+
+        // Test with complex path
+        assert_eq!(
+            generator.generate_method_name("get", "/api/v1/users/{id}/profile"),
+            "getProfile"
+        );
+
+        // Test with unknown HTTP method
+        assert_eq!(
+            generator.generate_method_name("unknown", "/users"),
+            "unknownUsers"
+        );
+
+        // Test with path that has only parameters
+        assert_eq!(
+            generator.generate_method_name("get", "/{id}/{other}"),
+            "getResource"
+        );
+    }
+
+    #[test]
+    fn test_convert_one_of_to_model() {
+        let generator = create_test_generator();
+        let string = OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
+            schema_type: Some("string".into()),
+            ..Default::default()
+        }));
+        let variant = |prop: &str| {
+            let mut schema = OpenAPISchema {
+                schema_type: Some("object".into()),
+                required: vec![prop.to_string()],
+                ..Default::default()
+            };
+            schema
+                .properties
+                .insert("pet_type".to_string(), string.clone());
+            schema.properties.insert(prop.to_string(), string.clone());
+            schema
+        };
+
+        let mut schema = OpenAPISchema::default();
         schema.one_of_variants = Some(vec![
-            (
-                "Dog".to_string(),
-                OpenAPISchema {
-                    schema_type: Some("object".to_string()),
-                    properties: {
-                        let mut props = IndexMap::new();
-                        props.insert(
-                            "breed".to_string(),
-                            OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
-                                schema_type: Some("string".to_string()),
-                                ..Default::default()
-                            })),
-                        );
-                        props
-                    },
-                    required: vec!["breed".to_string()],
-                    ..Default::default()
-                },
-            ),
-            (
-                "Cat".to_string(),
-                OpenAPISchema {
-                    schema_type: Some("object".to_string()),
-                    properties: {
-                        let mut props = IndexMap::new();
-                        props.insert(
-                            "meow_sound".to_string(),
-                            OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
-                                schema_type: Some("string".to_string()),
-                                ..Default::default()
-                            })),
-                        );
-                        props
-                    },
-                    required: vec!["meow_sound".to_string()],
-                    ..Default::default()
-                },
-            ),
+            ("dog".to_string(), variant("breed")),
+            ("cat".to_string(), variant("meow_sound")),
         ]);
-
-        // Add discriminator
+        schema
+            .properties
+            .insert("pet_type".to_string(), string.clone());
+        schema.properties.insert("name".to_string(), string.clone());
         schema.discriminator = Some(OpenAPIDiscriminator {
-            property_name: "type".to_string(),
-            mapping: IndexMap::new(),
+            property_name: "pet_type".to_string(),
+            mapping: {
+                let mut mapping = IndexMap::new();
+                mapping.insert("DOG".to_string(), "#/components/schemas/dog".to_string());
+                mapping
+            },
+            extensions: Extensions::new(),
         });
 
-        let result = generator
-            .convert_one_of_to_sealed_class("Pet", &schema)
+        let model = generator
+            .convert_schema_to_model("pet", Box::new(schema))
             .unwrap();
 
-        assert_eq!(result.name, "Pet");
-        assert_eq!(result.is_sealed, Some(true));
-        assert!(result.sealed_sub_types.is_some());
-        let sub_types = result.sealed_sub_types.unwrap();
-        assert_eq!(sub_types.len(), 2);
-        assert_eq!(sub_types[0].name, "Dog");
-        assert_eq!(sub_types[1].name, "Cat");
+        assert_eq!(model.name, "Pet");
+        let ModelKind::OneOf {
+            discriminator,
+            fields,
+            variants,
+        } = model.kind
+        else {
+            panic!("expected a oneOf model");
+        };
+        assert_eq!(discriminator, Some("pet_type".to_string()));
+        // The discriminator is never a field
+        let field_names: Vec<_> = fields.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(field_names, ["name"]);
+
+        let variant_names: Vec<_> = variants.iter().map(|v| v.name.as_str()).collect();
+        assert_eq!(variant_names, ["Dog", "Cat"]);
+        assert_eq!(variants[0].discriminator_value, Some("DOG".to_string()));
+        assert_eq!(variants[1].discriminator_value, Some("cat".to_string()));
+        assert_eq!(variants[1].fields.len(), 1);
+        assert_eq!(variants[1].fields[0].name, "meow_sound");
+        assert!(variants[1].fields[0].required);
     }
 
     #[test]
-    fn test_convert_any_of_to_union_type() {
+    fn test_convert_enum_to_model() {
         let generator = create_test_generator();
-        let mut schema = OpenAPISchema::default();
 
-        // Set up anyOf variants
-        schema.any_of_variants = Some(vec![
-            (
-                "StringValue".to_string(),
-                OpenAPISchema {
-                    schema_type: Some("string".to_string()),
-                    ..Default::default()
+        let schema = OpenAPISchema {
+            schema_type: Some("integer".into()),
+            enum_values: vec![json!(-1), json!(1), json!(1), serde_json::Value::Null],
+            enum_var_names: vec!["UNKNOWN".to_string()],
+            ..Default::default()
+        };
+
+        let model = generator
+            .convert_schema_to_model("priority", Box::new(schema))
+            .unwrap();
+
+        assert_eq!(model.name, "Priority");
+        let ModelKind::Enum {
+            value_type,
+            constants,
+        } = model.kind
+        else {
+            panic!("expected an enum model");
+        };
+        assert_eq!(value_type, TypeRef::Int32);
+        assert_eq!(
+            constants,
+            [
+                EnumConstant {
+                    name: "UNKNOWN".to_string(),
+                    value: json!(-1),
                 },
-            ),
-            (
-                "NumberValue".to_string(),
-                OpenAPISchema {
-                    schema_type: Some("number".to_string()),
-                    ..Default::default()
+                EnumConstant {
+                    name: "VALUE_1".to_string(),
+                    value: json!(1),
                 },
-            ),
-        ]);
+                EnumConstant {
+                    name: "VALUE_1_2".to_string(),
+                    value: json!(1),
+                },
+            ]
+        );
+    }
 
-        let result = generator
-            .convert_any_of_to_union_type("UnionType", &schema)
+    #[test]
+    fn test_inline_enum_default_names_its_constant() {
+        let generator = create_test_generator();
+
+        let mut schema = OpenAPISchema {
+            schema_type: Some("object".into()),
+            ..Default::default()
+        };
+        schema.properties.insert(
+            "status".to_string(),
+            OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
+                schema_type: Some("string".into()),
+                enum_values: vec![json!("placed"), json!("in-transit")],
+                default: Some(json!("in-transit")),
+                ..Default::default()
+            })),
+        );
+
+        let models = generator
+            .convert_schema_to_models("Order", Box::new(schema))
             .unwrap();
 
-        assert_eq!(result.name, "UnionType");
-        assert!(result
-            .imports
-            .contains(&"com.fasterxml.jackson.annotation.JsonValue".to_string()));
-        assert!(result
-            .imports
-            .contains(&"com.fasterxml.jackson.annotation.JsonCreator".to_string()));
+        let ModelKind::Object { fields } = &models[0].kind else {
+            panic!("expected an object model");
+        };
+        assert_eq!(fields[0].type_ref, TypeRef::Enum("OrderStatus".to_string()));
+        assert_eq!(
+            fields[0].default,
+            Some(DefaultValue::EnumConstant {
+                enum_name: "OrderStatus".to_string(),
+                constant: "IN_TRANSIT".to_string(),
+                value: json!("in-transit"),
+            })
+        );
+        assert!(matches!(models[1].kind, ModelKind::Enum { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_extensions_reach_the_api_model() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("api.yaml");
+        std::fs::write(
+            &path,
+            r#"openapi: 3.0.3
+info:
+  title: Pets
+  version: 1.0.0
+paths:
+  /pets:
+    get:
+      operationId: listPets
+      tags: [pets]
+      x-kotlin-name: findPets
+      parameters:
+        - name: limit
+          in: query
+          schema:
+            type: integer
+          x-internal: true
+      responses:
+        '200':
+          description: OK
+components:
+  schemas:
+    Pet:
+      type: object
+      x-kotlin-name: PetDto
+      properties:
+        born:
+          type: string
+          x-kotlin-type: java.time.Instant
+        owner:
+          $ref: '#/components/schemas/Owner'
+        address:
+          type: object
+          x-kotlin-name: homeAddress
+          properties:
+            street:
+              type: string
+    Owner:
+      type: object
+      x-kotlin-implements: [Person]
+      properties:
+        name:
+          type: string
+"#,
+        )
+        .unwrap();
+
+        let mut generator = create_test_generator();
+        generator.parser.parse_file(&path).await.unwrap();
+        let api = generator.build_api_model().unwrap();
+
+        let pet = api.models.iter().find(|model| model.name == "Pet").unwrap();
+        assert_eq!(pet.extensions["x-kotlin-name"], "PetDto");
+        let ModelKind::Object { fields } = &pet.kind else {
+            panic!("expected an object model");
+        };
+        assert_eq!(fields[0].extensions["x-kotlin-type"], "java.time.Instant");
+        // The referenced schema's extensions stay with the Owner model
+        assert!(fields[1].extensions.is_empty());
+        // Those of an inline schema are the property's, not its class's
+        assert_eq!(fields[2].extensions["x-kotlin-name"], "homeAddress");
+        let address = api.models.iter().find(|model| model.name == "PetAddress");
+        assert!(address.unwrap().extensions.is_empty());
+
+        let operation = &api.services[0].operations[0];
+        assert_eq!(operation.extensions["x-kotlin-name"], "findPets");
+        assert_eq!(operation.parameters[0].extensions["x-internal"], true);
+    }
+
+    #[test]
+    fn test_inline_class_naming_strategies() {
+        let mut config = create_test_config();
+        let address = OpenAPISchema {
+            title: Some("postal address".to_string()),
+            ..Default::default()
+        };
+
+        config.inline_naming = InlineNamingStrategy::Property;
+        let generator = OpenAPICodeGenerator::new(config.clone());
+        assert_eq!(
+            generator.inline_class_name("Order", "shipping_address", &address),
+            "ShippingAddress"
+        );
+
+        config.inline_naming = InlineNamingStrategy::Title;
+        let generator = OpenAPICodeGenerator::new(config.clone());
+        assert_eq!(
+            generator.inline_class_name("Order", "shipping_address", &address),
+            "PostalAddress"
+        );
+        assert_eq!(
+            generator.inline_class_name("Order", "shipping_address", &OpenAPISchema::default()),
+            "OrderShippingAddress"
+        );
     }
 
     #[test]
-    fn test_convert_operations_to_kotlin_controller() {
+    fn test_convert_operations_to_service() {
         let generator = create_test_generator();
         let operation = OpenAPIOperation {
             tags: vec!["users".to_string()],
             summary: Some("Get user".to_string()),
             description: Some("Retrieve user by ID".to_string()),
-            operation_id: Some("getUserById".to_string()),
-            parameters: vec![],
-            request_body: None,
-            responses: {
-                let mut responses = IndexMap::new();
-                responses.insert(
-                    "200".to_string(),
-                    OpenAPIResponseOrRef::Response(Box::new(OpenAPIResponse {
-                        description: "Success".to_string(),
-                        headers: IndexMap::new(),
-                        content: IndexMap::new(),
-                        links: IndexMap::new(),
-                    })),
-                );
-                responses
-            },
+            operation_id: Some("get_user_by_id".to_string()),
+            parameters: vec![OpenAPIParameterOrRef::Parameter(Box::new(parameter(
+                "id", "path", None,
+            )))],
             ..Default::default()
         };
 
         let operations = vec![("/users/{id}".to_string(), "get".to_string(), &operation)];
 
-        let result = generator
-            .convert_operations_to_kotlin_controller("users", &operations)
+        let service = generator
+            .convert_operations_to_service("users", &operations)
             .unwrap();
 
-        assert_eq!(result.name, "UsersController");
-        assert_eq!(result.package_name, "com.example.api");
-        assert_eq!(result.methods.len(), 1);
-        assert_eq!(result.methods[0].name, "getUserById");
+        assert_eq!(service.name, "users");
+        assert_eq!(service.operations.len(), 1);
+        assert_eq!(service.operations[0].name, "getUserById");
+        assert_eq!(service.operations[0].summary, Some("Get user".to_string()));
     }
 
     #[test]
-    fn test_convert_operation_to_kotlin_method() {
+    fn test_convert_operation() {
         let generator = create_test_generator();
+        let mut id = parameter("id", "path", Some("integer"));
+        id.description = Some("User ID".to_string());
         let operation = OpenAPIOperation {
             operation_id: Some("createUser".to_string()),
             summary: Some("Create a new user".to_string()),
             description: Some("Creates a new user in the system".to_string()),
-            parameters: vec![OpenAPIParameterOrRef::Parameter(Box::new(
-                OpenAPIParameter {
-                    name: "id".to_string(),
-                    location: "path".to_string(),
-                    description: Some("User ID".to_string()),
-                    required: true,
-                    deprecated: false,
-                    allow_empty_value: false,
-                    style: None,
-                    explode: None,
-                    allow_reserved: false,
-                    schema: Some(OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
-                        schema_type: Some("integer".to_string()),
-                        ..Default::default()
-                    }))),
-                    example: None,
-                    examples: IndexMap::new(),
-                    content: IndexMap::new(),
-                },
-            ))],
+            parameters: vec![OpenAPIParameterOrRef::Parameter(Box::new(id))],
             request_body: Some(OpenAPIRequestBodyOrRef::RequestBody(OpenAPIRequestBody {
                 description: Some("User data".to_string()),
                 content: {
@@ -1492,17 +1779,19 @@ mod generator_tests {
                         "application/json".to_string(),
                         OpenAPIMediaType {
                             schema: Some(OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
-                                schema_type: Some("object".to_string()),
+                                schema_type: Some("object".into()),
                                 ..Default::default()
                             }))),
                             example: None,
                             examples: IndexMap::new(),
                             encoding: IndexMap::new(),
+                            extensions: Extensions::new(),
                         },
                     );
                     content
                 },
                 required: true,
+                extensions: Extensions::new(),
             })),
             responses: {
                 let mut responses = IndexMap::new();
@@ -1513,6 +1802,7 @@ mod generator_tests {
                         headers: IndexMap::new(),
                         content: IndexMap::new(),
                         links: IndexMap::new(),
+                        extensions: Extensions::new(),
                     })),
                 );
                 responses
@@ -1521,52 +1811,94 @@ mod generator_tests {
         };
 
         let result = generator
-            .convert_operation_to_kotlin_method("/users/{id}", "post", &operation)
+            .convert_operation("/users/{id}", "post", &operation)
             .unwrap();
 
         assert_eq!(result.name, "createUser");
         assert_eq!(result.http_method, "post");
         assert_eq!(result.path, "/users/{id}");
         assert_eq!(result.parameters.len(), 1);
-        assert!(result.request_body.is_some());
         assert_eq!(result.parameters[0].name, "id");
+        assert_eq!(result.parameters[0].location, ParameterLocation::Path);
+        assert_eq!(result.parameters[0].type_ref, TypeRef::Int32);
+        assert_eq!(
+            result.request_body,
+            Some(RequestBody {
+                type_ref: TypeRef::Map(Box::new(TypeRef::Any)),
+                required: true,
+                description: Some("User data".to_string()),
+            })
+        );
+        assert_eq!(result.response, None);
+        assert_eq!(
+            result.response_description,
+            Some("User created".to_string())
+        );
     }
 
     #[test]
-    fn test_convert_parameter_to_kotlin() {
+    fn test_convert_parameter_serialization_defaults() {
         let generator = create_test_generator();
-        let param = OpenAPIParameter {
-            name: "user_name".to_string(),
-            location: "query".to_string(),
-            description: Some("Username to filter by".to_string()),
-            required: false,
-            deprecated: false,
-            allow_empty_value: false,
-            style: None,
-            explode: None,
-            allow_reserved: false,
-            schema: Some(OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
-                schema_type: Some("string".to_string()),
-                ..Default::default()
-            }))),
-            example: None,
-            examples: IndexMap::new(),
-            content: IndexMap::new(),
+        let style = |param: OpenAPIParameter| generator.convert_parameter(&param).unwrap();
+        let style_of = |style: &str, explode: bool| ParameterSerialization::Style {
+            style: style.to_string(),
+            explode,
         };
 
-        let result = generator.convert_parameter_to_kotlin(&param).unwrap();
+        let query = style(parameter("q", "query", Some("string")));
+        assert_eq!(query.location, ParameterLocation::Query);
+        assert_eq!(query.serialization, style_of("form", true));
+
+        let cookie = style(parameter("session", "cookie", Some("string")));
+        assert_eq!(cookie.location, ParameterLocation::Cookie);
+        assert_eq!(cookie.serialization, style_of("form", true));
+
+        let path = style(parameter("id", "path", Some("string")));
+        assert_eq!(path.serialization, style_of("simple", false));
+
+        let header = style(parameter("X-Trace", "header", None));
+        assert_eq!(header.location, ParameterLocation::Header);
+        assert_eq!(header.type_ref, TypeRef::String);
+        assert_eq!(header.serialization, style_of("simple", false));
+
+        let mut piped = parameter("ids", "query", Some("array"));
+        piped.style = Some("pipeDelimited".to_string());
+        assert_eq!(style(piped).serialization, style_of("pipeDelimited", false));
+
+        let mut allow_empty = parameter("flag", "query", Some("boolean"));
+        allow_empty.allow_empty_value = true;
+        assert!(!style(allow_empty).required);
+    }
+
+    #[test]
+    fn test_convert_parameter_with_content() {
+        let generator = create_test_generator();
+        let mut param = parameter("filter", "query", None);
+        param.content.insert(
+            "application/json".to_string(),
+            OpenAPIMediaType {
+                schema: Some(OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
+                    schema_type: Some("object".into()),
+                    reference_name: Some("Filter".to_string()),
+                    ..Default::default()
+                }))),
+                example: None,
+                examples: IndexMap::new(),
+                encoding: IndexMap::new(),
+                extensions: Extensions::new(),
+            },
+        );
 
-        assert_eq!(result.name, "userName");
-        assert_eq!(result.kotlin_type, "String");
-        assert!(!result.required);
+        let result = generator.convert_parameter(&param).unwrap();
+        assert_eq!(result.type_ref, TypeRef::Model("Filter".to_string()));
         assert_eq!(
-            result.description,
-            Some("Username to filter by".to_string())
+            result.serialization,
+            ParameterSerialization::Content("application/json".to_string())
         );
     }
 
     #[test]
-    fn test_determine_return_type() {
+    fn test_determine_response_type() {
         let generator = create_test_generator();
 
         // Test with 200 response containing direct schema instead of reference
@@ -1584,19 +1916,21 @@ mod generator_tests {
                                 OpenAPIMediaType {
                                     schema: Some(OpenAPISchemaOrRef::Schema(Box::new(
                                         OpenAPISchema {
-                                            schema_type: Some("object".to_string()),
+                                            schema_type: Some("object".into()),
                                             ..Default::default()
                                         },
                                     ))),
                                     example: None,
                                     examples: IndexMap::new(),
                                     encoding: IndexMap::new(),
+                                    extensions: Extensions::new(),
                                 },
                             );
                             content
                         },
                         headers: IndexMap::new(),
                         links: IndexMap::new(),
+                        extensions: Extensions::new(),
                     })),
                 );
                 responses
@@ -1604,8 +1938,8 @@ mod generator_tests {
             ..Default::default()
         };
 
-        let result = generator.determine_return_type(&operation).unwrap();
-        assert_eq!(result, "ResponseEntity<Map<String, Any>>");
+        let result = generator.determine_response_type(&operation).unwrap();
+        assert_eq!(result, Some(TypeRef::Map(Box::new(TypeRef::Any))));
 
         // Test with 204 No Content
         let operation_no_content = OpenAPIOperation {
@@ -1618,6 +1952,7 @@ mod generator_tests {
                         content: IndexMap::new(),
                         headers: IndexMap::new(),
                         links: IndexMap::new(),
+                        extensions: Extensions::new(),
                     })),
                 );
                 responses
@@ -1626,9 +1961,9 @@ mod generator_tests {
         };
 
         let result_no_content = generator
-            .determine_return_type(&operation_no_content)
+            .determine_response_type(&operation_no_content)
             .unwrap();
-        assert_eq!(result_no_content, "ResponseEntity<Any>");
+        assert_eq!(result_no_content, None);
     }
 
     #[test]
@@ -1645,6 +1980,7 @@ mod generator_tests {
                         headers: IndexMap::new(),
                         content: IndexMap::new(),
                         links: IndexMap::new(),
+                        extensions: Extensions::new(),
                     })),
                 );
                 responses
@@ -1666,192 +2002,8 @@ mod generator_tests {
 
         let empty_result = generator.get_response_description(&empty_operation);
         // The implementation may return Some("Success") even for empty responses
-        if empty_result.is_some() {
-            assert_eq!(empty_result.unwrap(), "Success");
-        } else {
-            assert_eq!(empty_result, None);
+        if let Some(description) = empty_result {
+            assert_eq!(description, "Success");
         }
     }
-
-    #[test]
-    fn test_write_kotlin_class_method() {
-        let generator = create_test_generator();
-        let kotlin_class = KotlinClass {
-            name: "TestUser".to_string(),
-            package_name: "com.example.test".to_string(),
-            description: Some("Test user class".to_string()),
-            properties: vec![KotlinProperty {
-                name: "id".to_string(),
-                kotlin_type: "Long".to_string(),
-                nullable: false,
-                default_value: None,
-                description: Some("User ID".to_string()),
-                validation: vec!["@NotNull".to_string()],
-                json_property: None,
-            }],
-            imports: vec!["javax.validation.constraints.NotNull".to_string()],
-            is_sealed: None,
-            sealed_sub_types: None,
-            parent_class: None,
-        };
-
-        // The method writes to filesystem which we can't easily test in unit tests
-        // We can at least verify the method exists and doesn't panic with valid input
-        let content = generator
-            .template_engine
-            .generate_kotlin_class(&kotlin_class);
-        assert!(content.contains("TestUser"));
-        assert!(content.contains("com.example.test"));
-    }
-
-    #[test]
-    fn test_write_kotlin_controller_method() {
-        let generator = create_test_generator();
-        let kotlin_controller = KotlinController {
-            name: "TestController".to_string(),
-            package_name: "com.example.test".to_string(),
-            description: Some("Test controller".to_string()),
-            methods: vec![KotlinMethod {
-                name: "getTest".to_string(),
-                http_method: "get".to_string(),
-                path: "/test".to_string(),
-                summary: Some("Get test".to_string()),
-                description: None,
-                parameters: vec![],
-                request_body: None,
-                return_type: "ResponseEntity<String>".to_string(),
-                response_description: None,
-            }],
-            imports: vec!["org.springframework.web.bind.annotation.*".to_string()],
-        };
-
-        // The method writes to filesystem which we can't easily test in unit tests
-        // We can at least verify the method exists and doesn't panic with valid input
-        let content = generator
-            .template_engine
-            .generate_kotlin_controller(&kotlin_controller);
-        assert!(content.contains("TestController"));
-        assert!(content.contains("com.example.test"));
-    }
-
-    #[test]
-    fn test_parameter_type_conversions() {
-        let generator = create_test_generator();
-
-        // Test header parameter
-        let header_param = OpenAPIParameter {
-            name: "authorization".to_string(),
-            location: "header".to_string(),
-            description: Some("Auth header".to_string()),
-            required: true,
-            deprecated: false,
-            allow_empty_value: false,
-            style: None,
-            explode: None,
-            allow_reserved: false,
-            schema: Some(OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
-                schema_type: Some("string".to_string()),
-                ..Default::default()
-            }))),
-            example: None,
-            examples: IndexMap::new(),
-            content: IndexMap::new(),
-        };
-
-        let result = generator
-            .convert_parameter_to_kotlin(&header_param)
-            .unwrap();
-        assert_eq!(result.kotlin_type, "String");
-        assert!(result.required);
-
-        // Test parameter without schema
-        let no_schema_param = OpenAPIParameter {
-            name: "test".to_string(),
-            location: "query".to_string(),
-            description: None,
-            required: false,
-            deprecated: false,
-            allow_empty_value: false,
-            style: None,
-            explode: None,
-            allow_reserved: false,
-            schema: None,
-            example: None,
-            examples: IndexMap::new(),
-            content: IndexMap::new(),
-        };
-
-        let no_schema_result = generator
-            .convert_parameter_to_kotlin(&no_schema_param)
-            .unwrap();
-        assert_eq!(no_schema_result.kotlin_type, "String");
-    }
-
-    #[test]
-    fn test_method_name_generation_edge_cases() {
-        let generator = create_test_generator();
-
-        // Test with empty path
-        assert_eq!(generator.generate_method_name("get", "/"), "getResource");
-
-        // Test with complex path
-        assert_eq!(
-            generator.generate_method_name("get", "/api/v1/users/{id}/profile"),
-            "getProfile"
-        );
-
-        // Test with unknown HTTP method
-        assert_eq!(
-            generator.generate_method_name("unknown", "/users"),
-            "unknownUsers"
-        );
-
-        // Test with path that has only parameters
-        assert_eq!(
-            generator.generate_method_name("get", "/{id}/{other}"),
-            "getResource"
-        );
-    }
-
-    #[test]
-    fn test_schema_one_of_variants() {
-        let generator = create_test_generator();
-        let mut schema = OpenAPISchema::default();
-
-        schema.one_of_variants = Some(vec![(
-            "StringType".to_string(),
-            OpenAPISchema {
-                schema_type: Some("string".to_string()),
-                ..Default::default()
-            },
-        )]);
-
-        let result = generator
-            .convert_schema_to_kotlin_class("TestUnion", Box::new(schema))
-            .unwrap();
-        assert_eq!(result.name, "TestUnion");
-        assert_eq!(result.is_sealed, Some(true));
-    }
-
-    #[test]
-    fn test_schema_any_of_variants() {
-        let generator = create_test_generator();
-        let mut schema = OpenAPISchema::default();
-
-        schema.any_of_variants = Some(vec![(
-            "StringType".to_string(),
-            OpenAPISchema {
-                schema_type: Some("string".to_string()),
-                ..Default::default()
-            },
-        )]);
-
-        let result = generator
-            .convert_schema_to_kotlin_class("TestAnyOf", Box::new(schema))
-            .unwrap();
-        assert_eq!(result.name, "TestAnyOf");
-        assert!(result
-            .imports
-            .contains(&"com.fasterxml.jackson.annotation.JsonValue".to_string()));
-    }
 }
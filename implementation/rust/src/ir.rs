@@ -0,0 +1,204 @@
+//! Language-neutral model of the code to generate.
+//!
+//! The generator builds an [`ApiModel`] from a parsed OpenAPI document, resolving
+//! references, naming inline schemas and picking operation names, and a
+//! [`LanguageBackend`](crate::backends::LanguageBackend) renders it as source files.
+//! Names of models are PascalCase and names of operations camelCase; field and
+//! parameter names are kept as they appear on the wire, for backends to case them.
+//! Vendor extensions are carried over as written, for backends to act on theirs.
+
+use crate::types::Extensions;
+
+/// Everything generated for one OpenAPI document.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiModel {
+    pub title: String,
+    pub version: String,
+    pub models: Vec<Model>,
+    pub services: Vec<Service>,
+}
+
+/// A named type generated from a component or inline schema.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Model {
+    pub name: String,
+    pub description: Option<String>,
+    pub kind: ModelKind,
+    /// `x-` extensions of the schema the model is generated from. Those of a schema
+    /// declared on a property are the field's instead.
+    pub extensions: Extensions,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ModelKind {
+    /// An object with named fields
+    Object { fields: Vec<Field> },
+    /// A closed set of string or integer values
+    Enum {
+        value_type: TypeRef,
+        constants: Vec<EnumConstant>,
+    },
+    /// Exactly one of several variants, told apart by the discriminator property.
+    /// Fields are those shared by all variants; the discriminator isn't among them.
+    OneOf {
+        discriminator: Option<String>,
+        fields: Vec<Field>,
+        variants: Vec<Variant>,
+    },
+    /// A value matching one or more of the named variant schemas
+    AnyOf { variants: Vec<String> },
+}
+
+/// A variant of a oneOf model, with the fields it adds to the shared ones.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Variant {
+    pub name: String,
+    pub description: Option<String>,
+    pub discriminator_value: Option<String>,
+    pub fields: Vec<Field>,
+    /// `x-` extensions of the variant's schema
+    pub extensions: Extensions,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnumConstant {
+    /// SCREAMING_SNAKE_CASE name, from `x-enum-varnames` or derived from the value
+    pub name: String,
+    pub value: serde_json::Value,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Field {
+    pub name: String,
+    pub type_ref: TypeRef,
+    pub required: bool,
+    /// Whether the schema allows `null`, regardless of `required`
+    pub nullable: bool,
+    pub default: Option<DefaultValue>,
+    pub description: Option<String>,
+    pub constraints: Constraints,
+    /// `x-` extensions of the property's own schema; none for a `$ref` property
+    pub extensions: Extensions,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DefaultValue {
+    Literal(serde_json::Value),
+    /// A constant of the enum model the field is typed as
+    EnumConstant {
+        enum_name: String,
+        constant: String,
+        value: serde_json::Value,
+    },
+}
+
+impl DefaultValue {
+    /// Returns the default as it appears on the wire.
+    pub fn value(&self) -> &serde_json::Value {
+        match self {
+            Self::Literal(value) | Self::EnumConstant { value, .. } => value,
+        }
+    }
+}
+
+/// Validation constraints of a field's schema. Only the constraints that apply to
+/// the schema's type are set, and none are set for fields typed as an enum model.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Constraints {
+    pub email: bool,
+    pub min_length: Option<u32>,
+    pub max_length: Option<u32>,
+    pub pattern: Option<String>,
+    pub minimum: Option<f64>,
+    pub maximum: Option<f64>,
+    /// Whether `minimum` itself is excluded from the allowed values
+    pub exclusive_minimum: bool,
+    /// Whether `maximum` itself is excluded from the allowed values
+    pub exclusive_maximum: bool,
+    pub min_items: Option<u32>,
+    pub max_items: Option<u32>,
+    /// The value is an object whose own fields are validated
+    pub validate_nested: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TypeRef {
+    String,
+    Int32,
+    Int64,
+    Float,
+    Double,
+    Decimal,
+    Boolean,
+    Date,
+    DateTime,
+    Uuid,
+    Uri,
+    Binary,
+    List(Box<TypeRef>),
+    /// A free-form object, keyed by string
+    Map(Box<TypeRef>),
+    Model(String),
+    Enum(String),
+    /// A class the user provides instead of a generated one, by qualified name
+    External(String),
+    Any,
+}
+
+/// The operations sharing a tag.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Service {
+    pub name: String,
+    pub operations: Vec<Operation>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Operation {
+    pub name: String,
+    pub http_method: String,
+    pub path: String,
+    pub summary: Option<String>,
+    pub description: Option<String>,
+    pub parameters: Vec<Parameter>,
+    pub request_body: Option<RequestBody>,
+    /// The JSON body of the success response, if it has one
+    pub response: Option<TypeRef>,
+    pub response_description: Option<String>,
+    /// `x-` extensions of the operation
+    pub extensions: Extensions,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Parameter {
+    pub name: String,
+    pub location: ParameterLocation,
+    pub type_ref: TypeRef,
+    pub required: bool,
+    pub description: Option<String>,
+    pub serialization: ParameterSerialization,
+    /// `x-` extensions of the parameter
+    pub extensions: Extensions,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParameterLocation {
+    Path,
+    Query,
+    Header,
+    Cookie,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParameterSerialization {
+    /// Serialized with `style` and `explode`, defaults applied
+    Style { style: String, explode: bool },
+    /// Serialized as the given media type, the type being that of its schema
+    Content(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RequestBody {
+    pub type_ref: TypeRef,
+    pub required: bool,
+    pub description: Option<String>,
+}
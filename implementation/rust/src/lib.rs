@@ -1,9 +1,27 @@
+pub mod backends;
+pub mod bundler;
+pub mod config;
+pub mod diff;
 pub mod errors;
+pub mod escaping;
 pub mod generator;
+pub mod ir;
+pub mod lint;
+pub mod mappings;
+pub mod naming;
 pub mod parser;
+pub mod source_map;
+pub mod swagger;
 pub mod templates;
 pub mod types;
+pub mod validator;
 
+pub use crate::backends::LanguageBackend;
+pub use crate::config::{OperationFilters, ProjectConfig, TargetConfig};
 pub use crate::generator::OpenAPICodeGenerator;
+pub use crate::mappings::TypeMappings;
 pub use crate::parser::OpenAPIParser;
-pub use crate::types::{GenerationResult, GeneratorConfig};
+pub use crate::templates::TemplateOverrides;
+pub use crate::types::{
+    GenerationResult, GeneratorConfig, InlineNamingStrategy, SpringBootProfile, TargetLanguage,
+};
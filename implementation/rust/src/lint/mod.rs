@@ -0,0 +1,460 @@
+//! Linting checks a valid specification against style rules, such as naming
+//! conventions and documentation requirements. Rules implement [`LintRule`] and are
+//! registered in [`rules::builtin_rules`]; a [`LintConfig`] adjusts their severities
+//! and the nodes they skip.
+//!
+//! Any object in a document can opt out of rules with an `x-lint-ignore` extension,
+//! which applies to the object and everything below it. It takes a rule ID, a list of
+//! them, or `true` for every rule:
+//!
+//! ```yaml
+//! paths:
+//!   /health:
+//!     x-lint-ignore: [operation-tags, description-present]
+//!     get: ...
+//! ```
+
+use indexmap::IndexMap;
+use serde::Deserialize;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use crate::config::read_config_file;
+use crate::errors::{self, ErrorContext, Result};
+use crate::parser::OpenAPIParser;
+use crate::source_map::unescape;
+use crate::validator::Severity;
+
+mod report;
+mod rules;
+
+pub use report::{to_json, to_sarif, OutputFormat};
+pub use rules::builtin_rules;
+
+/// Name of the extension that turns rules off for a node and its descendants.
+const IGNORE_EXTENSION: &str = "x-lint-ignore";
+
+pub trait LintRule: Send + Sync {
+    /// Identifier used in configuration files and `x-lint-ignore`, e.g. `operation-tags`
+    fn id(&self) -> &'static str;
+
+    /// What the rule checks, in one sentence.
+    fn description(&self) -> &'static str;
+
+    /// Severity of the rule's findings unless configured otherwise.
+    fn default_severity(&self) -> Severity;
+
+    /// Returns the nodes of the parsed specification that break the rule.
+    fn check(&self, parser: &OpenAPIParser) -> Vec<Violation>;
+}
+
+/// A node breaking a rule, as reported by the rule.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Violation {
+    /// JSON pointer of the node in the parsed document
+    pub pointer: String,
+    pub message: String,
+}
+
+impl Violation {
+    pub fn new<P: Into<String>, M: Into<String>>(pointer: P, message: M) -> Self {
+        Self {
+            pointer: pointer.into(),
+            message: message.into(),
+        }
+    }
+}
+
+/// A violation with the severity configured for its rule, located in the source
+/// where possible.
+#[derive(Debug, Clone)]
+pub struct Finding {
+    pub rule: &'static str,
+    pub severity: Severity,
+    pub message: String,
+    /// JSON pointer of the node in the parsed document
+    pub pointer: String,
+    /// File, line and column of the node, or its pointer when those aren't known
+    pub context: ErrorContext,
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}{}", self.severity, self.message, self.context)
+    }
+}
+
+/// What a rule is set to in a [`LintConfig`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RuleLevel {
+    Off,
+    Error,
+    Warning,
+    Info,
+}
+
+/// Lint settings, read from a JSON or YAML file:
+///
+/// ```yaml
+/// rules:
+///   operation-id-camel-case: error
+///   description-present: off
+/// ignore:
+///   operation-tags:
+///     - /paths/~1health
+/// ```
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LintConfig {
+    /// Level of each rule by ID, overriding its default severity
+    #[serde(default)]
+    pub rules: IndexMap<String, RuleLevel>,
+    /// JSON pointers of the nodes each rule skips by ID, along with their descendants
+    #[serde(default)]
+    pub ignore: IndexMap<String, Vec<String>>,
+    /// File the configuration was read from, for error messages
+    #[serde(skip)]
+    pub path: Option<PathBuf>,
+}
+
+impl LintConfig {
+    /// Reads a configuration file, as TOML, YAML or JSON by its extension.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be read, its format is unsupported, or it
+    /// isn't a valid configuration.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openapi_codegen_rust::lint::LintConfig;
+    ///
+    /// // Use in async context:
+    /// // let config = LintConfig::load("lint.yaml").await.unwrap();
+    /// ```
+    pub async fn load<P: AsRef<Path>>(file_path: P) -> Result<Self> {
+        let path = file_path.as_ref();
+        let mut config: LintConfig = read_config_file(path).await?;
+        config.path = Some(path.to_path_buf());
+        Ok(config)
+    }
+}
+
+/// Runs the built-in rules over parsed specifications.
+///
+/// # Examples
+///
+/// ```rust
+/// use openapi_codegen_rust::lint::{LintConfig, Linter};
+/// use openapi_codegen_rust::parser::OpenAPIParser;
+///
+/// let linter = Linter::new(LintConfig::default()).unwrap();
+/// let mut parser = OpenAPIParser::new();
+/// // Use in async context:
+/// // parser.parse_file("api.yaml").await.unwrap();
+/// // for finding in linter.lint(&parser) {
+/// //     eprintln!("{finding}");
+/// // }
+/// ```
+pub struct Linter {
+    rules: Vec<Box<dyn LintRule>>,
+    config: LintConfig,
+}
+
+impl Linter {
+    /// Creates a linter with the built-in rules, adjusted by `config`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration names a rule that doesn't exist.
+    pub fn new(config: LintConfig) -> Result<Self> {
+        let rules = builtin_rules();
+
+        let config_path = config
+            .path
+            .as_ref()
+            .map(|path| path.display().to_string())
+            .unwrap_or_else(|| "lint configuration".to_string());
+        for id in config.rules.keys().chain(config.ignore.keys()) {
+            if !rules.iter().any(|rule| rule.id() == id) {
+                return Err(errors::unknown_lint_rule(id, &config_path));
+            }
+        }
+
+        Ok(Self { rules, config })
+    }
+
+    /// The rules the linter knows, whether enabled or not.
+    pub fn rules(&self) -> &[Box<dyn LintRule>] {
+        &self.rules
+    }
+
+    /// Severity of a rule's findings, or `None` when the rule is turned off.
+    pub fn severity(&self, rule: &dyn LintRule) -> Option<Severity> {
+        match self.config.rules.get(rule.id()) {
+            Some(RuleLevel::Off) => None,
+            Some(RuleLevel::Error) => Some(Severity::Error),
+            Some(RuleLevel::Warning) => Some(Severity::Warning),
+            Some(RuleLevel::Info) => Some(Severity::Info),
+            None => Some(rule.default_severity()),
+        }
+    }
+
+    /// Checks the specification of a parser that has parsed a file against every
+    /// enabled rule, leaving out ignored nodes. Findings are grouped by rule.
+    pub fn lint(&self, parser: &OpenAPIParser) -> Vec<Finding> {
+        let mut findings = Vec::new();
+
+        for rule in &self.rules {
+            let Some(severity) = self.severity(rule.as_ref()) else {
+                continue;
+            };
+
+            for violation in rule.check(parser) {
+                if self.ignored(parser, rule.id(), &violation.pointer) {
+                    continue;
+                }
+
+                let context = parser
+                    .source_context(&violation.pointer)
+                    .unwrap_or_else(|| {
+                        ErrorContext::new().with_path(vec![violation.pointer.clone()])
+                    })
+                    .with_error_code(rule.id());
+                findings.push(Finding {
+                    rule: rule.id(),
+                    severity,
+                    message: violation.message,
+                    pointer: violation.pointer,
+                    context,
+                });
+            }
+        }
+
+        findings
+    }
+
+    /// Whether the node at `pointer` is ignored for a rule by the configuration or by
+    /// `x-lint-ignore` on the node or one of its ancestors.
+    fn ignored(&self, parser: &OpenAPIParser, rule: &str, pointer: &str) -> bool {
+        let configured = self.config.ignore.get(rule).is_some_and(|pointers| {
+            pointers.iter().any(|ignored| {
+                let ignored = ignored.trim_end_matches('/');
+                pointer == ignored
+                    || pointer
+                        .strip_prefix(ignored)
+                        .is_some_and(|rest| rest.starts_with('/'))
+            })
+        });
+
+        configured || ignored_by_extension(parser.get_document(), rule, pointer)
+    }
+}
+
+/// Whether an `x-lint-ignore` on the node at `pointer` or one of its ancestors
+/// covers `rule`.
+fn ignored_by_extension(document: &serde_json::Value, rule: &str, pointer: &str) -> bool {
+    let mut node = document;
+    if ignores(node, rule) {
+        return true;
+    }
+
+    for segment in pointer.split('/').skip(1) {
+        let segment = unescape(segment);
+        let child = match node {
+            serde_json::Value::Object(map) => map.get(&segment),
+            serde_json::Value::Array(items) => segment
+                .parse::<usize>()
+                .ok()
+                .and_then(|index| items.get(index)),
+            _ => None,
+        };
+        let Some(child) = child else {
+            return false;
+        };
+        if ignores(child, rule) {
+            return true;
+        }
+        node = child;
+    }
+
+    false
+}
+
+fn ignores(node: &serde_json::Value, rule: &str) -> bool {
+    match node.get(IGNORE_EXTENSION) {
+        Some(serde_json::Value::Bool(all)) => *all,
+        Some(serde_json::Value::String(id)) => id == rule,
+        Some(serde_json::Value::Array(ids)) => ids.iter().any(|id| id.as_str() == Some(rule)),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    const SPEC: &str = r#"openapi: 3.0.3
+info:
+  title: Pets
+  version: 1.0.0
+paths:
+  /pets:
+    get:
+      operationId: list_pets
+      responses:
+        '200':
+          description: OK
+  /health:
+    x-lint-ignore: true
+    get:
+      operationId: Health
+      responses:
+        '200':
+          description: OK
+  /orders:
+    get:
+      x-lint-ignore: operation-tags
+      operationId: listOrders
+      responses:
+        '200':
+          description: OK
+"#;
+
+    async fn parse(temp_dir: &TempDir, content: &str) -> OpenAPIParser {
+        let path = temp_dir.path().join("api.yaml");
+        fs::write(&path, content).unwrap();
+        let mut parser = OpenAPIParser::new();
+        parser.parse_file(&path).await.unwrap();
+        parser
+    }
+
+    fn findings_of<'a>(findings: &'a [Finding], rule: &str) -> Vec<&'a str> {
+        findings
+            .iter()
+            .filter(|finding| finding.rule == rule)
+            .map(|finding| finding.pointer.as_str())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_lint_honors_lint_ignore_extensions() {
+        let temp_dir = TempDir::new().unwrap();
+        let parser = parse(&temp_dir, SPEC).await;
+
+        let findings = Linter::new(LintConfig::default()).unwrap().lint(&parser);
+
+        assert_eq!(
+            findings_of(&findings, "operation-id-camel-case"),
+            vec!["/paths/~1pets/get/operationId"]
+        );
+        assert_eq!(
+            findings_of(&findings, "operation-tags"),
+            vec!["/paths/~1pets/get"]
+        );
+        assert_eq!(
+            findings_of(&findings, "description-present"),
+            vec!["/paths/~1pets/get", "/paths/~1orders/get"]
+        );
+
+        let finding = &findings[0];
+        assert_eq!(finding.severity, Severity::Warning);
+        assert_eq!(finding.context.line, Some(8));
+        assert_eq!(finding.context.column, Some(7));
+        assert!(finding
+            .to_string()
+            .starts_with("warning: Operation ID 'list_pets' of GET /pets is not camelCase [operation-id-camel-case]\n --> "));
+    }
+
+    #[tokio::test]
+    async fn test_lint_applies_configuration() {
+        let temp_dir = TempDir::new().unwrap();
+        let parser = parse(&temp_dir, SPEC).await;
+        let config_path = temp_dir.path().join("lint.yaml");
+        fs::write(
+            &config_path,
+            r#"rules:
+  operation-id-camel-case: error
+  description-present: off
+ignore:
+  operation-tags:
+    - /paths/~1pets/
+"#,
+        )
+        .unwrap();
+
+        let config = LintConfig::load(&config_path).await.unwrap();
+        let findings = Linter::new(config).unwrap().lint(&parser);
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].rule, "operation-id-camel-case");
+        assert_eq!(findings[0].severity, Severity::Error);
+    }
+
+    #[tokio::test]
+    async fn test_lint_rejects_unknown_rules() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("lint.json");
+        fs::write(&config_path, r#"{"rules": {"no-such-rule": "error"}}"#).unwrap();
+
+        let config = LintConfig::load(&config_path).await.unwrap();
+        let error = Linter::new(config).err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "Unknown lint rule 'no-such-rule' in {}",
+                config_path.display()
+            )
+        );
+
+        fs::write(&config_path, r#"{"rules": {"operation-tags": "loud"}}"#).unwrap();
+        assert!(LintConfig::load(&config_path).await.is_err());
+    }
+
+    #[test]
+    fn test_ignored_by_extension() {
+        let document = serde_json::json!({
+            "paths": {
+                "/a/b": {
+                    "get": {"x-lint-ignore": ["operation-tags"]},
+                },
+            },
+            "tags": [{"name": "pets", "x-lint-ignore": "description-present"}],
+        });
+
+        assert!(ignored_by_extension(
+            &document,
+            "operation-tags",
+            "/paths/~1a~1b/get"
+        ));
+        assert!(ignored_by_extension(
+            &document,
+            "operation-tags",
+            "/paths/~1a~1b/get/responses/200"
+        ));
+        assert!(!ignored_by_extension(
+            &document,
+            "description-present",
+            "/paths/~1a~1b/get"
+        ));
+        assert!(!ignored_by_extension(
+            &document,
+            "operation-tags",
+            "/paths/~1a~1b"
+        ));
+        assert!(ignored_by_extension(
+            &document,
+            "description-present",
+            "/tags/0"
+        ));
+        assert!(!ignored_by_extension(
+            &document,
+            "operation-tags",
+            "/missing/node"
+        ));
+    }
+}
@@ -0,0 +1,206 @@
+use serde_json::{json, Value};
+
+use crate::lint::{Finding, Linter};
+use crate::validator::Severity;
+
+/// SARIF version written by [`to_sarif`].
+const SARIF_VERSION: &str = "2.1.0";
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// How lint findings are printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable messages pointing at the source
+    #[default]
+    Text,
+    /// A JSON array of findings
+    Json,
+    /// A SARIF 2.1.0 log, as read by code scanning tools
+    Sarif,
+}
+
+/// Returns findings as a JSON array of objects with the rule, severity, message and
+/// pointer of each, plus its file, line and column when known.
+pub fn to_json(findings: &[Finding]) -> Value {
+    findings
+        .iter()
+        .map(|finding| {
+            let mut object = json!({
+                "rule": finding.rule,
+                "severity": finding.severity,
+                "message": finding.message,
+                "pointer": finding.pointer,
+            });
+            if let Some(file) = &finding.context.file {
+                object["file"] = json!(file);
+            }
+            if let (Some(line), Some(column)) = (finding.context.line, finding.context.column) {
+                object["line"] = json!(line);
+                object["column"] = json!(column);
+            }
+            object
+        })
+        .collect()
+}
+
+/// Returns findings as a SARIF log with a single run, describing every rule of the
+/// linter so results can refer to them.
+pub fn to_sarif(linter: &Linter, findings: &[Finding]) -> Value {
+    let rules: Vec<Value> = linter
+        .rules()
+        .iter()
+        .map(|rule| {
+            let mut descriptor = json!({
+                "id": rule.id(),
+                "shortDescription": { "text": rule.description() },
+            });
+            descriptor["defaultConfiguration"] = match linter.severity(rule.as_ref()) {
+                Some(severity) => json!({ "level": sarif_level(severity) }),
+                None => json!({ "enabled": false }),
+            };
+            descriptor
+        })
+        .collect();
+
+    let results: Vec<Value> = findings
+        .iter()
+        .map(|finding| {
+            let mut location = json!({
+                "logicalLocations": [{ "fullyQualifiedName": finding.pointer }],
+            });
+            if let Some(file) = &finding.context.file {
+                let mut physical = json!({ "artifactLocation": { "uri": file } });
+                if let (Some(line), Some(column)) = (finding.context.line, finding.context.column) {
+                    physical["region"] = json!({ "startLine": line, "startColumn": column });
+                }
+                location["physicalLocation"] = physical;
+            }
+
+            json!({
+                "ruleId": finding.rule,
+                "ruleIndex": linter.rules().iter().position(|rule| rule.id() == finding.rule),
+                "level": sarif_level(finding.severity),
+                "message": { "text": finding.message },
+                "locations": [location],
+            })
+        })
+        .collect();
+
+    json!({
+        "$schema": SARIF_SCHEMA,
+        "version": SARIF_VERSION,
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": env!("CARGO_PKG_NAME"),
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "rules": rules,
+                },
+            },
+            "results": results,
+        }],
+    })
+}
+
+fn sarif_level(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Info => "note",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::ErrorContext;
+    use crate::lint::LintConfig;
+
+    fn findings() -> Vec<Finding> {
+        vec![
+            Finding {
+                rule: "operation-tags",
+                severity: Severity::Error,
+                message: "GET /pets has no tags".to_string(),
+                pointer: "/paths/~1pets/get".to_string(),
+                context: ErrorContext::new()
+                    .with_file("api.yaml")
+                    .with_location(7, 5)
+                    .with_error_code("operation-tags"),
+            },
+            Finding {
+                rule: "description-present",
+                severity: Severity::Info,
+                message: "Schema 'Pet' has no description".to_string(),
+                pointer: "/components/schemas/Pet".to_string(),
+                context: ErrorContext::new().with_error_code("description-present"),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_to_json() {
+        assert_eq!(
+            to_json(&findings()),
+            json!([
+                {
+                    "rule": "operation-tags",
+                    "severity": "error",
+                    "message": "GET /pets has no tags",
+                    "pointer": "/paths/~1pets/get",
+                    "file": "api.yaml",
+                    "line": 7,
+                    "column": 5,
+                },
+                {
+                    "rule": "description-present",
+                    "severity": "info",
+                    "message": "Schema 'Pet' has no description",
+                    "pointer": "/components/schemas/Pet",
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn test_to_sarif() {
+        let mut config = LintConfig::default();
+        config.rules.insert(
+            "no-inline-response-schema".to_string(),
+            crate::lint::RuleLevel::Off,
+        );
+        let linter = Linter::new(config).unwrap();
+
+        let log = to_sarif(&linter, &findings());
+        assert_eq!(log["version"], "2.1.0");
+
+        let run = &log["runs"][0];
+        let rules = run["tool"]["driver"]["rules"].as_array().unwrap();
+        assert_eq!(rules.len(), linter.rules().len());
+        let disabled = rules
+            .iter()
+            .find(|rule| rule["id"] == "no-inline-response-schema")
+            .unwrap();
+        assert_eq!(
+            disabled["defaultConfiguration"],
+            json!({ "enabled": false })
+        );
+
+        let results = run["results"].as_array().unwrap();
+        assert_eq!(results[0]["ruleId"], "operation-tags");
+        assert_eq!(results[0]["level"], "error");
+        assert_eq!(
+            results[0]["locations"][0]["physicalLocation"],
+            json!({
+                "artifactLocation": { "uri": "api.yaml" },
+                "region": { "startLine": 7, "startColumn": 5 },
+            })
+        );
+        assert_eq!(
+            rules[results[0]["ruleIndex"].as_u64().unwrap() as usize]["id"],
+            "operation-tags"
+        );
+        assert_eq!(results[1]["level"], "note");
+        assert!(results[1]["locations"][0].get("physicalLocation").is_none());
+    }
+}
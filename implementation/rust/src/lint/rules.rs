@@ -0,0 +1,451 @@
+use crate::lint::{LintRule, Violation};
+use crate::parser::OpenAPIParser;
+use crate::source_map::escape;
+use crate::types::*;
+use crate::validator::Severity;
+
+/// Returns every built-in rule, in the order their findings are reported.
+pub fn builtin_rules() -> Vec<Box<dyn LintRule>> {
+    vec![
+        Box::new(OperationIdCamelCase),
+        Box::new(OperationTags),
+        Box::new(ErrorResponseSchema),
+        Box::new(NoInlineResponseSchema),
+        Box::new(DescriptionPresent),
+    ]
+}
+
+/// An operation defined in the document, with where it is.
+struct Operation<'a> {
+    /// JSON pointer of the operation object
+    pointer: String,
+    /// `METHOD /path`, or `METHOD name` for webhooks and path item components
+    name: String,
+    operation: &'a OpenAPIOperation,
+}
+
+/// Returns the operations of the inline path items of `paths`, `webhooks` and the
+/// path item components. Referenced path items are covered where they're defined.
+fn operations(spec: &OpenAPISpec) -> Vec<Operation<'_>> {
+    let mut path_items: Vec<(String, &String, &OpenAPIPathItem)> = Vec::new();
+    for (path, path_item) in &spec.paths {
+        path_items.push((format!("/paths/{}", escape(path)), path, path_item));
+    }
+    for (name, path_item) in &spec.webhooks {
+        path_items.push((format!("/webhooks/{}", escape(name)), name, path_item));
+    }
+    if let Some(components) = &spec.components {
+        for (name, path_item) in &components.path_items {
+            let pointer = format!("/components/pathItems/{}", escape(name));
+            path_items.push((pointer, name, path_item));
+        }
+    }
+
+    let mut operations = Vec::new();
+    for (pointer, path, path_item) in path_items {
+        if path_item.reference.is_some() {
+            continue;
+        }
+
+        for (method, operation) in path_item.operations() {
+            operations.push(Operation {
+                pointer: format!("{pointer}/{method}"),
+                name: format!("{} {path}", method.to_uppercase()),
+                operation,
+            });
+        }
+    }
+    operations
+}
+
+/// Operation IDs start with a lowercase letter and contain only letters and digits.
+struct OperationIdCamelCase;
+
+impl LintRule for OperationIdCamelCase {
+    fn id(&self) -> &'static str {
+        "operation-id-camel-case"
+    }
+
+    fn description(&self) -> &'static str {
+        "Operation IDs are camelCase"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn check(&self, parser: &OpenAPIParser) -> Vec<Violation> {
+        operations(parser.get_spec())
+            .into_iter()
+            .filter_map(|operation| {
+                let operation_id = operation.operation.operation_id.as_ref()?;
+                let mut chars = operation_id.chars();
+                let camel_case = chars.next().is_some_and(|c| c.is_ascii_lowercase())
+                    && chars.all(|c| c.is_ascii_alphanumeric());
+                (!camel_case).then(|| {
+                    Violation::new(
+                        format!("{}/operationId", operation.pointer),
+                        format!(
+                            "Operation ID '{operation_id}' of {} is not camelCase",
+                            operation.name
+                        ),
+                    )
+                })
+            })
+            .collect()
+    }
+}
+
+/// Every operation has at least one tag, which groups it into a controller.
+struct OperationTags;
+
+impl LintRule for OperationTags {
+    fn id(&self) -> &'static str {
+        "operation-tags"
+    }
+
+    fn description(&self) -> &'static str {
+        "Every operation has at least one tag"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn check(&self, parser: &OpenAPIParser) -> Vec<Violation> {
+        operations(parser.get_spec())
+            .into_iter()
+            .filter(|operation| operation.operation.tags.is_empty())
+            .map(|Operation { pointer, name, .. }| {
+                Violation::new(pointer, format!("{name} has no tags"))
+            })
+            .collect()
+    }
+}
+
+/// Every 4xx response of an operation describes its body with a schema.
+struct ErrorResponseSchema;
+
+impl LintRule for ErrorResponseSchema {
+    fn id(&self) -> &'static str {
+        "error-response-schema"
+    }
+
+    fn description(&self) -> &'static str {
+        "Every 4xx response has a schema for its error body"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn check(&self, parser: &OpenAPIParser) -> Vec<Violation> {
+        let mut violations = Vec::new();
+        for Operation {
+            pointer,
+            name,
+            operation,
+        } in operations(parser.get_spec())
+        {
+            for (status, response) in &operation.responses {
+                if !status.starts_with('4') {
+                    continue;
+                }
+                // Unresolvable references are for validation to report
+                let Ok(response) = parser.resolve_component(response) else {
+                    continue;
+                };
+                if response
+                    .content
+                    .values()
+                    .all(|media| media.schema.is_none())
+                {
+                    violations.push(Violation::new(
+                        format!("{pointer}/responses/{}", escape(status)),
+                        format!("Response {status} of {name} has no error schema"),
+                    ));
+                }
+            }
+        }
+        violations
+    }
+}
+
+/// Response bodies use named schemas from `components`, or arrays of them, so
+/// generated code gets meaningful class names.
+struct NoInlineResponseSchema;
+
+impl NoInlineResponseSchema {
+    fn is_inline(schema: &OpenAPISchemaOrRef) -> bool {
+        match schema {
+            OpenAPISchemaOrRef::Reference(_) => false,
+            OpenAPISchemaOrRef::Schema(schema) => !matches!(
+                schema.items.as_deref(),
+                Some(OpenAPISchemaOrRef::Reference(_)) if schema.primary_type() == Some("array")
+            ),
+        }
+    }
+
+    fn check_response(
+        response: &OpenAPIResponse,
+        pointer: &str,
+        name: &str,
+        violations: &mut Vec<Violation>,
+    ) {
+        for (media_type, media) in &response.content {
+            if media.schema.as_ref().is_some_and(Self::is_inline) {
+                violations.push(Violation::new(
+                    format!("{pointer}/content/{}/schema", escape(media_type)),
+                    format!("{name} defines its {media_type} schema inline"),
+                ));
+            }
+        }
+    }
+}
+
+impl LintRule for NoInlineResponseSchema {
+    fn id(&self) -> &'static str {
+        "no-inline-response-schema"
+    }
+
+    fn description(&self) -> &'static str {
+        "Response bodies reference schemas from components instead of defining them inline"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn check(&self, parser: &OpenAPIParser) -> Vec<Violation> {
+        let spec = parser.get_spec();
+        let mut violations = Vec::new();
+
+        for Operation {
+            pointer,
+            name,
+            operation,
+        } in operations(spec)
+        {
+            for (status, response) in &operation.responses {
+                // Referenced responses are checked where they're defined
+                if let OpenAPIResponseOrRef::Response(response) = response {
+                    Self::check_response(
+                        response,
+                        &format!("{pointer}/responses/{}", escape(status)),
+                        &format!("Response {status} of {name}"),
+                        &mut violations,
+                    );
+                }
+            }
+        }
+
+        if let Some(components) = &spec.components {
+            for (name, response) in &components.responses {
+                if let OpenAPIResponseOrRef::Response(response) = response {
+                    Self::check_response(
+                        response,
+                        &format!("/components/responses/{}", escape(name)),
+                        &format!("Response '{name}'"),
+                        &mut violations,
+                    );
+                }
+            }
+        }
+
+        violations
+    }
+}
+
+/// Operations have a summary or description, and schema components a description.
+struct DescriptionPresent;
+
+impl LintRule for DescriptionPresent {
+    fn id(&self) -> &'static str {
+        "description-present"
+    }
+
+    fn description(&self) -> &'static str {
+        "Operations have a summary or description, and schema components a description"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Info
+    }
+
+    fn check(&self, parser: &OpenAPIParser) -> Vec<Violation> {
+        let spec = parser.get_spec();
+        let is_blank =
+            |text: &Option<String>| text.as_deref().is_none_or(|text| text.trim().is_empty());
+
+        let mut violations: Vec<Violation> = operations(spec)
+            .into_iter()
+            .filter(|Operation { operation, .. }| {
+                is_blank(&operation.summary) && is_blank(&operation.description)
+            })
+            .map(|Operation { pointer, name, .. }| {
+                Violation::new(pointer, format!("{name} has no summary or description"))
+            })
+            .collect();
+
+        if let Some(components) = &spec.components {
+            for (name, schema) in &components.schemas {
+                if let OpenAPISchemaOrRef::Schema(schema) = schema {
+                    if is_blank(&schema.description) {
+                        violations.push(Violation::new(
+                            format!("/components/schemas/{}", escape(name)),
+                            format!("Schema '{name}' has no description"),
+                        ));
+                    }
+                }
+            }
+        }
+
+        violations
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    async fn violations(rule: &dyn LintRule, content: &str) -> Vec<Violation> {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("api.yaml");
+        fs::write(&path, content).unwrap();
+        let mut parser = OpenAPIParser::new();
+        parser.parse_file(&path).await.unwrap();
+        rule.check(&parser)
+    }
+
+    const SPEC: &str = r#"openapi: 3.0.3
+info:
+  title: Pets
+  version: 1.0.0
+paths:
+  /pets/{id}:
+    get:
+      operationId: getPet
+      summary: Get a pet
+      tags: [pets]
+      parameters:
+        - name: id
+          in: path
+          required: true
+          schema:
+            type: string
+      responses:
+        '200':
+          description: OK
+          content:
+            application/json:
+              schema:
+                type: object
+                properties:
+                  name:
+                    type: string
+        '400':
+          description: Bad request
+        '404':
+          $ref: '#/components/responses/NotFound'
+        4XX:
+          description: Other errors
+          content:
+            application/json:
+              schema:
+                $ref: '#/components/schemas/Error'
+webhooks:
+  petAdded:
+    post:
+      operationId: Pet-Added
+      responses:
+        '200':
+          description: OK
+          content:
+            application/json:
+              schema:
+                type: array
+                items:
+                  $ref: '#/components/schemas/Error'
+components:
+  schemas:
+    Error:
+      type: object
+      properties:
+        message:
+          type: string
+  responses:
+    NotFound:
+      description: Not found
+      content:
+        application/json:
+          schema:
+            type: object
+"#;
+
+    #[test]
+    fn test_builtin_rule_ids_are_unique() {
+        let rules = builtin_rules();
+        for (index, rule) in rules.iter().enumerate() {
+            assert!(rules[index + 1..]
+                .iter()
+                .all(|other| other.id() != rule.id()));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_operation_rules() {
+        assert_eq!(
+            violations(&OperationIdCamelCase, SPEC).await,
+            vec![Violation::new(
+                "/webhooks/petAdded/post/operationId",
+                "Operation ID 'Pet-Added' of POST petAdded is not camelCase"
+            )]
+        );
+        assert_eq!(
+            violations(&OperationTags, SPEC).await,
+            vec![Violation::new(
+                "/webhooks/petAdded/post",
+                "POST petAdded has no tags"
+            )]
+        );
+        assert_eq!(
+            violations(&DescriptionPresent, SPEC).await,
+            vec![
+                Violation::new(
+                    "/webhooks/petAdded/post",
+                    "POST petAdded has no summary or description"
+                ),
+                Violation::new(
+                    "/components/schemas/Error",
+                    "Schema 'Error' has no description"
+                ),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_response_rules() {
+        assert_eq!(
+            violations(&ErrorResponseSchema, SPEC).await,
+            vec![Violation::new(
+                "/paths/~1pets~1{id}/get/responses/400",
+                "Response 400 of GET /pets/{id} has no error schema"
+            )]
+        );
+        assert_eq!(
+            violations(&NoInlineResponseSchema, SPEC).await,
+            vec![
+                Violation::new(
+                    "/paths/~1pets~1{id}/get/responses/200/content/application~1json/schema",
+                    "Response 200 of GET /pets/{id} defines its application/json schema inline"
+                ),
+                Violation::new(
+                    "/components/responses/NotFound/content/application~1json/schema",
+                    "Response 'NotFound' defines its application/json schema inline"
+                ),
+            ]
+        );
+    }
+}
@@ -1,69 +1,222 @@
-use anyhow::Result;
-use clap::Parser;
-use std::path::PathBuf;
+use anyhow::{Context, Result};
+use clap::{Parser, Subcommand};
+use indexmap::IndexMap;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
 use std::time::Instant;
 
+mod backends;
+mod bundler;
+mod config;
+mod diff;
 mod errors;
+mod escaping;
 mod generator;
+mod ir;
+mod lint;
+mod mappings;
+mod naming;
 mod parser;
+mod source_map;
+mod swagger;
 mod templates;
 mod types;
+mod validator;
 
+use crate::config::{ProjectConfig, TargetConfig, TypeMappingsSource};
+use crate::diff::ReportFormat;
 use crate::generator::OpenAPICodeGenerator;
-use crate::types::GeneratorConfig;
+use crate::lint::{LintConfig, Linter, OutputFormat};
+use crate::parser::OpenAPIParser;
+use crate::types::{InlineNamingStrategy, OpenAPISpec, SpringBootProfile, TargetLanguage};
+use crate::validator::Severity;
 
 #[derive(Parser)]
 #[command(name = "openapi-codegen")]
 #[command(about = "OpenAPI Code Generator - Rust Implementation with ultra-fast performance")]
 #[command(version = "1.0.0")]
+#[command(subcommand_negates_reqs = true)]
 struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
     /// OpenAPI specification file path
-    #[arg(short = 'i', long = "input")]
-    input: PathBuf,
+    #[arg(short = 'i', long = "input", required = true)]
+    input: Option<PathBuf>,
+
+    /// Output directory [default: ./generated]
+    #[arg(short = 'o', long = "output")]
+    output: Option<PathBuf>,
+
+    /// Base package name [default: com.example.api]
+    #[arg(short = 'p', long = "package")]
+    package: Option<String>,
 
-    /// Output directory
-    #[arg(short = 'o', long = "output", default_value = "./generated")]
-    output: PathBuf,
+    /// Package of generated models [default: <package>.model]
+    #[arg(long = "model-package")]
+    model_package: Option<String>,
 
-    /// Base package name
-    #[arg(short = 'p', long = "package", default_value = "com.example.api")]
-    package: String,
+    /// Package of generated controllers [default: <package>.api]
+    #[arg(long = "api-package")]
+    api_package: Option<String>,
 
-    /// Generate controllers
-    #[arg(long = "controllers", default_value = "true")]
+    /// Generate controllers, even if the configuration file disables them
+    #[arg(long = "controllers", action = clap::ArgAction::SetTrue)]
     controllers: bool,
 
     /// Disable controller generation
-    #[arg(long = "no-controllers", action = clap::ArgAction::SetTrue)]
+    #[arg(long = "no-controllers", action = clap::ArgAction::SetTrue, conflicts_with = "controllers")]
     no_controllers: bool,
 
-    /// Generate models
-    #[arg(long = "models", default_value = "true")]
+    /// Generate models, even if the configuration file disables them
+    #[arg(long = "models", action = clap::ArgAction::SetTrue)]
     models: bool,
 
     /// Disable model generation
-    #[arg(long = "no-models", action = clap::ArgAction::SetTrue)]
+    #[arg(long = "no-models", action = clap::ArgAction::SetTrue, conflicts_with = "models")]
     no_models: bool,
 
-    /// Generate validation annotations
-    #[arg(long = "validation", default_value = "true")]
+    /// Generate validation annotations, even if the configuration file disables them
+    #[arg(long = "validation", action = clap::ArgAction::SetTrue)]
     validation: bool,
 
     /// Disable validation annotations
-    #[arg(long = "no-validation", action = clap::ArgAction::SetTrue)]
+    #[arg(long = "no-validation", action = clap::ArgAction::SetTrue, conflicts_with = "validation")]
     no_validation: bool,
 
-    /// Generate Swagger annotations
-    #[arg(long = "swagger", default_value = "true")]
+    /// Generate Swagger annotations, even if the configuration file disables them
+    #[arg(long = "swagger", action = clap::ArgAction::SetTrue)]
     swagger: bool,
 
     /// Disable Swagger annotations
-    #[arg(long = "no-swagger", action = clap::ArgAction::SetTrue)]
+    #[arg(long = "no-swagger", action = clap::ArgAction::SetTrue, conflicts_with = "swagger")]
     no_swagger: bool,
 
     /// Verbose output
     #[arg(short = 'v', long = "verbose")]
     verbose: bool,
+
+    /// Naming strategy for classes generated from inline schemas [default: parent-and-property]
+    #[arg(long = "inline-naming", value_enum)]
+    inline_naming: Option<InlineNamingStrategy>,
+
+    /// Language and framework to generate code for [default: kotlin]
+    #[arg(long = "target", value_enum)]
+    target: Option<TargetLanguage>,
+
+    /// Spring Boot generation Kotlin code is generated for: 2 (javax) or 3 (jakarta) [default: 3]
+    #[arg(long = "spring-boot", value_enum)]
+    spring_boot: Option<SpringBootProfile>,
+
+    /// TOML, JSON or YAML file mapping formats and schemas to existing classes
+    #[arg(long = "type-mappings")]
+    type_mappings: Option<PathBuf>,
+
+    /// Directory of Handlebars templates replacing the built-in ones by name
+    #[arg(long = "templates")]
+    templates: Option<PathBuf>,
+
+    /// Project configuration file [default: openapi-codegen.toml or .yaml next to the input]
+    #[arg(short = 'c', long = "config")]
+    config: Option<PathBuf>,
+
+    /// Generate only this target of the configuration file; may be repeated
+    #[arg(long = "config-target")]
+    config_targets: Vec<String>,
+
+    /// Print the effective configuration of every target instead of generating code
+    #[arg(long = "print-config")]
+    print_config: bool,
+}
+
+impl Cli {
+    /// Returns the generator options set by flags, which take precedence over the
+    /// configuration file.
+    fn overrides(&self) -> TargetConfig {
+        let switch = |on: bool, off: bool| match (on, off) {
+            (_, true) => Some(false),
+            (true, _) => Some(true),
+            _ => None,
+        };
+        TargetConfig {
+            output: self.output.clone(),
+            package: self.package.clone(),
+            model_package: self.model_package.clone(),
+            api_package: self.api_package.clone(),
+            controllers: switch(self.controllers, self.no_controllers),
+            models: switch(self.models, self.no_models),
+            validation: switch(self.validation, self.no_validation),
+            swagger: switch(self.swagger, self.no_swagger),
+            inline_naming: self.inline_naming,
+            target: self.target,
+            spring_boot: self.spring_boot,
+            templates: self.templates.clone(),
+            type_mappings: self.type_mappings.clone().map(TypeMappingsSource::File),
+            filters: None,
+        }
+    }
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Convert a Swagger 2.0 document to OpenAPI 3.0
+    Convert {
+        /// Swagger 2.0 document to convert
+        #[arg(short = 'i', long = "input")]
+        input: PathBuf,
+
+        /// File to write the OpenAPI 3.0 document to, as JSON or YAML by its extension
+        #[arg(short = 'o', long = "output")]
+        output: PathBuf,
+    },
+    /// Combine a specification split across files into a single document
+    Bundle {
+        /// OpenAPI specification file path
+        #[arg(short = 'i', long = "input")]
+        input: PathBuf,
+
+        /// File to write the bundled document to, as JSON or YAML by its extension
+        #[arg(short = 'o', long = "output")]
+        output: PathBuf,
+
+        /// Replace every reference with its target instead of moving targets into components
+        #[arg(long = "dereference")]
+        dereference: bool,
+    },
+    /// Report every problem in a specification; fails if any is an error
+    Validate {
+        /// OpenAPI specification file path
+        #[arg(short = 'i', long = "input")]
+        input: PathBuf,
+    },
+    /// Check a specification against style rules; fails if any finding is an error
+    Lint {
+        /// OpenAPI specification file path
+        #[arg(short = 'i', long = "input")]
+        input: PathBuf,
+
+        /// Lint configuration file setting rule severities and ignored nodes
+        #[arg(short = 'c', long = "config")]
+        config: Option<PathBuf>,
+
+        /// How findings are printed
+        #[arg(long = "format", value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
+    },
+    /// Compare two versions of a specification; fails if any change is breaking
+    Diff {
+        /// Previous version of the specification
+        #[arg(long = "old")]
+        old: PathBuf,
+
+        /// New version of the specification
+        #[arg(long = "new")]
+        new: PathBuf,
+
+        /// How the report is printed
+        #[arg(long = "format", value_enum, default_value_t = ReportFormat::Text)]
+        format: ReportFormat,
+    },
 }
 
 #[tokio::main]
@@ -71,17 +224,34 @@ async fn main() -> Result<()> {
     let start_time = Instant::now();
     let cli = Cli::parse();
 
+    match &cli.command {
+        Some(Command::Convert { input, output }) => return convert(input, output).await,
+        Some(Command::Bundle {
+            input,
+            output,
+            dereference,
+        }) => return bundle(input, output, *dereference).await,
+        Some(Command::Validate { input }) => return validate(input).await,
+        Some(Command::Lint {
+            input,
+            config,
+            format,
+        }) => return lint(input, config.as_deref(), *format).await,
+        Some(Command::Diff { old, new, format }) => return diff(old, new, *format).await,
+        None => {}
+    }
+    let input = cli
+        .input
+        .clone()
+        .expect("--input is required without a subcommand");
+
     // Validate input file
-    if !cli.input.exists() {
-        anyhow::bail!("❌ Error: File not found: {}", cli.input.display());
+    if !input.exists() {
+        anyhow::bail!("❌ Error: File not found: {}", input.display());
     }
 
     // Check file format
-    let extension = cli
-        .input
-        .extension()
-        .and_then(|ext| ext.to_str())
-        .unwrap_or("");
+    let extension = input.extension().and_then(|ext| ext.to_str()).unwrap_or("");
 
     if !["yaml", "yml", "json"].contains(&extension) {
         anyhow::bail!(
@@ -90,37 +260,58 @@ async fn main() -> Result<()> {
         );
     }
 
-    // Create generator configuration
-    let config = GeneratorConfig {
-        output_dir: cli.output.clone(),
-        base_package: cli.package.clone(),
-        generate_controllers: cli.controllers && !cli.no_controllers,
-        generate_models: cli.models && !cli.no_models,
-        include_validation: cli.validation && !cli.no_validation,
-        include_swagger: cli.swagger && !cli.no_swagger,
-        verbose: cli.verbose,
+    let project = match &cli.config {
+        Some(path) => ProjectConfig::load(path).await?,
+        None => ProjectConfig::discover(&input).await?.unwrap_or_default(),
     };
-
     if cli.verbose {
-        println!(
-            "Parsing OpenAPI specification from: {}",
-            cli.input.display()
-        );
+        if let Some(path) = &project.path {
+            println!("Using configuration: {}", path.display());
+        }
     }
+    let overrides = cli.overrides();
+    let targets: Vec<_> = project
+        .targets(&cli.config_targets)?
+        .into_iter()
+        .map(|(name, target)| (name, overrides.clone().or(&target)))
+        .collect();
 
-    // Initialize generator
-    let mut generator = OpenAPICodeGenerator::new(config);
+    if cli.print_config {
+        return print_config(targets);
+    }
 
-    // Generate code
-    let result = generator.generate(&cli.input).await?;
+    for (name, target) in targets {
+        // Create generator configuration
+        let config = target.into_generator_config(cli.verbose).await?;
 
-    let elapsed = start_time.elapsed();
+        if cli.verbose {
+            if project.path.is_some() {
+                println!("Generating target: {name}");
+            }
+            let overridden: Vec<_> = config.templates.names().collect();
+            if !overridden.is_empty() {
+                println!("Using custom templates: {}", overridden.join(", "));
+            }
+            println!("Parsing OpenAPI specification from: {}", input.display());
+        }
+
+        // Initialize generator
+        let mut generator = OpenAPICodeGenerator::new(config);
 
-    // Success message
-    println!("✅ Code generation completed successfully!");
-    println!("📁 Output directory: {}", result.output_dir.display());
-    println!("📄 Generated {} files", result.file_count);
+        // Generate code
+        let result = generator.generate(&input).await?;
 
+        // Success message
+        if project.path.is_some() {
+            println!("✅ Code generation completed successfully for target '{name}'!");
+        } else {
+            println!("✅ Code generation completed successfully!");
+        }
+        println!("📁 Output directory: {}", result.output_dir.display());
+        println!("📄 Generated {} files", result.file_count);
+    }
+
+    let elapsed = start_time.elapsed();
     if cli.verbose {
         println!(
             "⚡ Generation time: {:.2}ms",
@@ -132,3 +323,178 @@ async fn main() -> Result<()> {
 
     Ok(())
 }
+
+/// Prints the options every target is generated with, built-in defaults included,
+/// as a configuration file.
+fn print_config(targets: Vec<(String, TargetConfig)>) -> Result<()> {
+    #[derive(Serialize)]
+    struct EffectiveConfig {
+        targets: IndexMap<String, TargetConfig>,
+    }
+
+    let config = EffectiveConfig {
+        targets: targets
+            .into_iter()
+            .map(|(name, target)| (name, target.with_defaults()))
+            .collect(),
+    };
+    print!("{}", toml::to_string_pretty(&config)?);
+    Ok(())
+}
+
+/// Prints every problem found in a specification, and fails if any is an error.
+async fn validate(input: &Path) -> Result<()> {
+    let diagnostics = validator::validate_file(input).await?;
+    for diagnostic in &diagnostics {
+        eprintln!("{diagnostic}\n");
+    }
+
+    let errors = diagnostics
+        .iter()
+        .filter(|diagnostic| diagnostic.severity == Severity::Error)
+        .count();
+    let warnings = diagnostics.len() - errors;
+    if errors > 0 {
+        anyhow::bail!(
+            "❌ {} is invalid: {errors} error(s), {warnings} warning(s)",
+            input.display()
+        );
+    }
+
+    println!("✅ {} is valid: {warnings} warning(s)", input.display());
+    Ok(())
+}
+
+/// Prints the style findings for a specification, and fails if any is an error.
+/// Findings go to stdout as JSON or SARIF for tools, or to stderr as text.
+async fn lint(input: &Path, config: Option<&Path>, format: OutputFormat) -> Result<()> {
+    let config = match config {
+        Some(path) => LintConfig::load(path).await?,
+        None => LintConfig::default(),
+    };
+    let linter = Linter::new(config)?;
+
+    let mut parser = OpenAPIParser::new();
+    parser.parse_file(input).await?;
+    let findings = linter.lint(&parser);
+
+    let errors = findings
+        .iter()
+        .filter(|finding| finding.severity == Severity::Error)
+        .count();
+    match format {
+        OutputFormat::Text => {
+            for finding in &findings {
+                eprintln!("{finding}\n");
+            }
+        }
+        OutputFormat::Json => {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&lint::to_json(&findings))?
+            )
+        }
+        OutputFormat::Sarif => println!(
+            "{}",
+            serde_json::to_string_pretty(&lint::to_sarif(&linter, &findings))?
+        ),
+    }
+
+    if errors > 0 {
+        anyhow::bail!(
+            "❌ {} has {errors} lint error(s) in {} finding(s)",
+            input.display(),
+            findings.len()
+        );
+    }
+    if format == OutputFormat::Text {
+        println!(
+            "✅ {} passed lint: {} finding(s)",
+            input.display(),
+            findings.len()
+        );
+    }
+    Ok(())
+}
+
+/// Prints the changes between two versions of a specification, and fails if any is
+/// breaking so the command can gate merges.
+async fn diff(old: &Path, new: &Path, format: ReportFormat) -> Result<()> {
+    let changes = diff::compare_files(old, new).await?;
+
+    match format {
+        ReportFormat::Text => print!("{}", diff::to_text(&changes)),
+        ReportFormat::Markdown => print!("{}", diff::to_markdown(&changes)),
+        ReportFormat::Json => println!(
+            "{}",
+            serde_json::to_string_pretty(&diff::to_json(&changes))?
+        ),
+    }
+
+    let breaking = changes.iter().filter(|change| change.breaking).count();
+    if breaking > 0 {
+        anyhow::bail!(
+            "❌ {breaking} breaking change(s) from {} to {}",
+            old.display(),
+            new.display()
+        );
+    }
+    Ok(())
+}
+
+/// Writes the OpenAPI 3.0 form of a Swagger 2.0 document. OpenAPI 3 documents are
+/// written as they are.
+async fn convert(input: &Path, output: &Path) -> Result<()> {
+    let document = OpenAPIParser::read_document(input).await?;
+    serde_json::from_value::<OpenAPISpec>(document.clone())
+        .with_context(|| format!("❌ Error: Invalid document: {}", input.display()))?;
+
+    write_document(&document, output).await?;
+
+    println!("✅ Converted {} -> {}", input.display(), output.display());
+    Ok(())
+}
+
+/// Bundles a specification split across files into one document, or dereferences
+/// it entirely.
+async fn bundle(input: &Path, output: &Path, dereference: bool) -> Result<()> {
+    let mut parser = OpenAPIParser::new();
+    parser.parse_file(input).await?;
+    let spec = if dereference {
+        bundler::dereference(&parser)?
+    } else {
+        bundler::bundle(&parser)?
+    };
+
+    write_document(&spec, output).await?;
+
+    let action = if dereference {
+        "Dereferenced"
+    } else {
+        "Bundled"
+    };
+    println!("✅ {action} {} -> {}", input.display(), output.display());
+    Ok(())
+}
+
+/// Writes a document as JSON or YAML by the output file's extension.
+async fn write_document<T: Serialize>(document: &T, output: &Path) -> Result<()> {
+    let content = match output.extension().and_then(|ext| ext.to_str()) {
+        Some("json") => serde_json::to_string_pretty(document)? + "\n",
+        Some("yaml" | "yml") => serde_yaml::to_string(document)?,
+        extension => anyhow::bail!(
+            "❌ Error: Unsupported output format: .{} (use .yaml, .yml, or .json)",
+            extension.unwrap_or("")
+        ),
+    };
+
+    if let Some(parent) = output
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+    tokio::fs::write(output, content)
+        .await
+        .with_context(|| format!("Failed to write file: {}", output.display()))
+}
@@ -0,0 +1,128 @@
+//! Type mappings replace the types generated for schemas with classes the user
+//! provides, either for every schema of a `format` or for a named schema component,
+//! which is then not generated at all.
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use crate::config::read_config_file;
+use crate::errors::{self, Result};
+
+/// Type mappings, read from a JSON or YAML file:
+///
+/// ```yaml
+/// formats:
+///   date-time: java.time.Instant
+///   money: org.javamoney.moneta.Money
+/// schemas:
+///   Address: com.example.common.Address
+/// ```
+///
+/// Classes are qualified names; code refers to them by their simple name and
+/// imports them.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TypeMappings {
+    /// Class of the schemas with a `format`, by format
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub formats: IndexMap<String, String>,
+    /// Class used instead of generating a schema component, by component name
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub schemas: IndexMap<String, String>,
+}
+
+impl TypeMappings {
+    /// Reads type mappings from a TOML, YAML or JSON file, chosen by its extension.
+    pub async fn load<P: AsRef<Path>>(file_path: P) -> Result<Self> {
+        let path = file_path.as_ref();
+        let mappings: TypeMappings = read_config_file(path).await?;
+
+        mappings.validate(&path.display().to_string())?;
+        Ok(mappings)
+    }
+
+    /// Checks that every mapping names a class, reporting the file or other source
+    /// the mappings came from.
+    pub fn validate(&self, source: &str) -> Result<()> {
+        let entries = self
+            .formats
+            .iter()
+            .map(|(format, class)| (format!("formats.{format}"), class))
+            .chain(
+                self.schemas
+                    .iter()
+                    .map(|(schema, class)| (format!("schemas.{schema}"), class)),
+            );
+        for (key, class) in entries {
+            if class.is_empty() || class.contains(char::is_whitespace) {
+                return Err(errors::invalid_type_mapping(
+                    key,
+                    source,
+                    format!("'{class}' is not a class name"),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the class a schema component is mapped to.
+    pub fn schema_class(&self, name: &str) -> Option<&str> {
+        self.schemas.get(name).map(String::as_str)
+    }
+
+    /// Returns the class schemas of a format are mapped to.
+    pub fn format_class(&self, format: &str) -> Option<&str> {
+        self.formats.get(format).map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::OpenAPIError;
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn test_load_type_mappings() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("mappings.yaml");
+        std::fs::write(
+            &path,
+            "formats:\n  date-time: java.time.Instant\nschemas:\n  Address: com.example.Address\n",
+        )
+        .unwrap();
+
+        let mappings = TypeMappings::load(&path).await.unwrap();
+        assert_eq!(
+            mappings.format_class("date-time"),
+            Some("java.time.Instant")
+        );
+        assert_eq!(mappings.format_class("date"), None);
+        assert_eq!(
+            mappings.schema_class("Address"),
+            Some("com.example.Address")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_load_invalid_type_mappings() {
+        let temp_dir = TempDir::new().unwrap();
+
+        let unknown = temp_dir.path().join("unknown.json");
+        std::fs::write(&unknown, r#"{"types": {}}"#).unwrap();
+        let error = TypeMappings::load(&unknown).await.unwrap_err();
+        assert!(matches!(error.kind(), OpenAPIError::InvalidJson { .. }));
+
+        let blank = temp_dir.path().join("blank.yaml");
+        std::fs::write(&blank, "formats:\n  money: ''\n").unwrap();
+        let error = TypeMappings::load(&blank).await.unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("Invalid type mapping 'formats.money' in"));
+
+        let text = temp_dir.path().join("mappings.txt");
+        std::fs::write(&text, "").unwrap();
+        assert!(TypeMappings::load(&text).await.is_err());
+    }
+}
@@ -0,0 +1,85 @@
+use regex::Regex;
+
+/// Converts a snake_case, kebab-case or space separated name to PascalCase.
+pub fn pascal_case(s: &str) -> String {
+    let re = Regex::new(r"[-_\s]+(.?)").unwrap();
+    let result = re.replace_all(s, |caps: &regex::Captures| {
+        caps.get(1)
+            .map_or("".to_string(), |m| m.as_str().to_uppercase())
+    });
+
+    // Capitalize first character
+    let mut chars = result.chars();
+    match chars.next() {
+        None => String::new(),
+        Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+    }
+}
+
+/// Converts a snake_case, kebab-case or space separated name to camelCase.
+pub fn camel_case(s: &str) -> String {
+    let pascal = pascal_case(s);
+    let mut chars = pascal.chars();
+    match chars.next() {
+        None => String::new(),
+        Some(first) => first.to_lowercase().collect::<String>() + chars.as_str(),
+    }
+}
+
+/// Converts a camelCase, PascalCase, kebab-case or space separated name to snake_case.
+/// Characters other than ASCII letters and digits separate words.
+pub fn snake_case(s: &str) -> String {
+    let acronym = Regex::new(r"([A-Z]+)([A-Z][a-z])").unwrap();
+    let boundary = Regex::new(r"([a-z0-9])([A-Z])").unwrap();
+    let separated = acronym.replace_all(s, "${1}_${2}");
+
+    boundary
+        .replace_all(&separated, "${1}_${2}")
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join("_")
+        .to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pascal_case_conversion() {
+        assert_eq!(pascal_case("hello_world"), "HelloWorld");
+        assert_eq!(pascal_case("api-endpoint"), "ApiEndpoint");
+        assert_eq!(pascal_case("user name"), "UserName");
+        assert_eq!(pascal_case("snake_case_name"), "SnakeCaseName");
+        assert_eq!(pascal_case("kebab-case-name"), "KebabCaseName");
+        assert_eq!(pascal_case("already_Pascal"), "AlreadyPascal");
+        assert_eq!(pascal_case(""), "");
+        assert_eq!(pascal_case("a"), "A");
+    }
+
+    #[test]
+    fn test_camel_case_conversion() {
+        assert_eq!(camel_case("hello_world"), "helloWorld");
+        assert_eq!(camel_case("api-endpoint"), "apiEndpoint");
+        assert_eq!(camel_case("user name"), "userName");
+        assert_eq!(camel_case("snake_case_name"), "snakeCaseName");
+        assert_eq!(camel_case("kebab-case-name"), "kebabCaseName");
+        assert_eq!(camel_case("Already_Pascal"), "alreadyPascal");
+        assert_eq!(camel_case(""), "");
+        assert_eq!(camel_case("a"), "a");
+    }
+
+    #[test]
+    fn test_snake_case_conversion() {
+        assert_eq!(snake_case("helloWorld"), "hello_world");
+        assert_eq!(snake_case("HelloWorld"), "hello_world");
+        assert_eq!(snake_case("api-endpoint"), "api_endpoint");
+        assert_eq!(snake_case("user name"), "user_name");
+        assert_eq!(snake_case("already_snake"), "already_snake");
+        assert_eq!(snake_case("X-Request-ID"), "x_request_id");
+        assert_eq!(snake_case("HTTPServer"), "http_server");
+        assert_eq!(snake_case("userID2"), "user_id2");
+        assert_eq!(snake_case(""), "");
+    }
+}
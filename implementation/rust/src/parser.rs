@@ -1,9 +1,11 @@
 use indexmap::IndexMap;
-use std::collections::HashSet;
-use std::path::Path;
+use std::collections::{HashMap, HashSet};
+use std::path::{Component, Path, PathBuf};
 use tokio::fs;
 
-use crate::errors::{self, Result};
+use crate::errors::{self, ErrorContext, OpenAPIError, Result};
+use crate::source_map::{self, SourceMap};
+use crate::swagger;
 use crate::types::*;
 
 /// Type alias for operations grouped by tag.
@@ -15,8 +17,8 @@ type TaggedOperations<'a> =
 ///
 /// The parser supports:
 /// - JSON and YAML file formats
-/// - OpenAPI 3.x specifications
-/// - Schema reference resolution
+/// - OpenAPI 3.x specifications, and Swagger 2.0 ones by upconversion
+/// - Schema reference resolution, including references into other local files
 /// - Schema composition (allOf, oneOf, anyOf)
 /// - Tag and operation extraction
 ///
@@ -33,6 +35,20 @@ type TaggedOperations<'a> =
 pub struct OpenAPIParser {
     /// The parsed OpenAPI specification. None until a file is successfully parsed.
     spec: Option<OpenAPISpec>,
+    /// The parsed document as an untyped value, extensions included.
+    document: serde_json::Value,
+    /// Absolute path of the parsed document, against which external references resolve.
+    document_path: Option<PathBuf>,
+    /// Documents loaded through external references, keyed by absolute path.
+    external_documents: HashMap<PathBuf, serde_json::Value>,
+    /// Objects targeted by external references, keyed by `<absolute path>#<pointer>`
+    /// in the section matching where the reference appears.
+    external_components: OpenAPIComponents,
+    /// Locations of the nodes of the parsed and loaded documents, keyed by absolute path.
+    source_maps: HashMap<PathBuf, SourceMap>,
+    /// Document and JSON pointer of the first `$ref` with a given value, as stored in
+    /// the loaded documents.
+    reference_origins: HashMap<String, (PathBuf, String)>,
 }
 
 impl Default for OpenAPIParser {
@@ -57,13 +73,27 @@ impl OpenAPIParser {
     /// // Parser is ready to parse OpenAPI specifications
     /// ```
     pub fn new() -> Self {
-        Self { spec: None }
+        Self {
+            spec: None,
+            document: serde_json::Value::Null,
+            document_path: None,
+            external_documents: HashMap::new(),
+            external_components: OpenAPIComponents::default(),
+            source_maps: HashMap::new(),
+            reference_origins: HashMap::new(),
+        }
     }
 
     /// Parses an OpenAPI specification file (JSON or YAML).
     ///
     /// Supports both `.json` and `.yaml`/`.yml` file extensions.
+    /// Swagger 2.0 documents are upconverted to OpenAPI 3.0 first.
     /// The file content is validated against OpenAPI 3.x requirements.
+    /// Local files referenced through `$ref` are loaded and cached as well.
+    ///
+    /// Errors point at the file, line and column they are about where that is known;
+    /// see [`OpenAPIError::context`]. Locations aren't tracked through the Swagger 2.0
+    /// upconversion.
     ///
     /// # Arguments
     ///
@@ -80,6 +110,7 @@ impl OpenAPIParser {
     /// - File format is unsupported
     /// - JSON/YAML parsing fails
     /// - OpenAPI specification is invalid
+    /// - A referenced local file or a pointer inside it can't be found
     ///
     /// # Examples
     ///
@@ -92,27 +123,805 @@ impl OpenAPIParser {
     /// // println!("Parsing successful: {}", spec.info.title);
     /// ```
     pub async fn parse_file<P: AsRef<Path>>(&mut self, file_path: P) -> Result<&OpenAPISpec> {
-        let path = file_path.as_ref();
+        self.parse(file_path.as_ref(), None).await?;
+        Ok(self.spec.as_ref().unwrap())
+    }
+
+    /// Parses an OpenAPI specification file like [`Self::parse_file`], but only stops
+    /// when the file can't be read into a specification at all. Problems found after
+    /// that, such as missing required fields or references to missing files, are
+    /// returned instead, and the specification is available from [`Self::get_spec`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be read, its format is unsupported, or
+    /// JSON/YAML parsing fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openapi_codegen_rust::parser::OpenAPIParser;
+    ///
+    /// let mut parser = OpenAPIParser::new();
+    /// // Use in async context:
+    /// // let problems = parser.load_file("api.yaml").await.unwrap();
+    /// // for problem in &problems {
+    /// //     eprintln!("{problem}");
+    /// // }
+    /// ```
+    pub async fn load_file<P: AsRef<Path>>(&mut self, file_path: P) -> Result<Vec<OpenAPIError>> {
+        let mut problems = Vec::new();
+        self.parse(file_path.as_ref(), Some(&mut problems)).await?;
+        Ok(problems)
+    }
 
+    /// Parses the file at `path`. Invalid content and unresolvable external references
+    /// are collected into `problems` when given, and fail parsing otherwise.
+    async fn parse(
+        &mut self,
+        path: &Path,
+        mut problems: Option<&mut Vec<OpenAPIError>>,
+    ) -> Result<()> {
         let content = fs::read_to_string(path)
             .await
             .map_err(|_| errors::file_not_found(path.display().to_string()))?;
 
         let extension = path.extension().and_then(|ext| ext.to_str()).unwrap_or("");
 
-        let spec = match extension {
-            "json" => serde_json::from_str::<OpenAPISpec>(&content)
-                .map_err(|e| errors::invalid_json(e.to_string()))?,
-            "yaml" | "yml" => serde_yaml::from_str::<OpenAPISpec>(&content)
-                .map_err(|e| errors::invalid_yaml(e.to_string()))?,
-            _ => {
-                return Err(errors::unsupported_format(extension));
+        let document_path = Self::absolute_path(path);
+        self.document_path = Some(document_path.clone());
+        self.external_documents.clear();
+        self.external_components = OpenAPIComponents::default();
+        self.source_maps.clear();
+        self.reference_origins.clear();
+
+        let mut document = Self::parse_document(path, &content)?;
+        let mut spec = if swagger::is_swagger2(&document) {
+            document = swagger::upconvert(&document);
+            serde_json::from_value(document.clone()).map_err(|e| {
+                errors::invalid_spec(e.to_string())
+                    .with_context(ErrorContext::new().with_file(path.display().to_string()))
+            })?
+        } else {
+            self.source_maps.insert(
+                document_path.clone(),
+                SourceMap::new(path.display().to_string(), &content),
+            );
+            match extension {
+                "json" => serde_json::from_str::<OpenAPISpec>(&content)
+                    .map_err(|e| Self::json_error(path, &content, e))?,
+                "yaml" | "yml" => serde_yaml::from_str::<OpenAPISpec>(&content)
+                    .map_err(|e| Self::yaml_error(path, &content, e))?,
+                _ => {
+                    return Err(errors::unsupported_format(extension));
+                }
             }
         };
 
-        self.validate_spec(&spec)?;
+        let spec_problems = self.validate_spec(&spec, &document);
+        let mut spec_problems = spec_problems.into_iter().map(|e| self.locate(e));
+        match problems.as_deref_mut() {
+            Some(problems) => problems.extend(spec_problems),
+            None => {
+                if let Some(error) = spec_problems.next() {
+                    return Err(error);
+                }
+            }
+        }
+
+        if Self::resolve_schema_ids(&mut document) {
+            spec = serde_json::from_value(document.clone())
+                .map_err(|e| errors::invalid_spec(e.to_string()))?;
+        }
+        self.record_reference_origins(&document, &document_path, &mut String::new());
+        self.load_external_references(&document, problems).await?;
+
         self.spec = Some(spec);
-        Ok(self.spec.as_ref().unwrap())
+        self.document = document;
+        Ok(())
+    }
+
+    /// Reads a JSON or YAML document into an untyped value, upconverting a Swagger 2.0
+    /// document to OpenAPI 3.0. References are left as they are.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be read, its format is unsupported, or
+    /// JSON/YAML parsing fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openapi_codegen_rust::parser::OpenAPIParser;
+    ///
+    /// // Use in async context:
+    /// // let document = OpenAPIParser::read_document("swagger.yaml").await.unwrap();
+    /// // assert!(document.get("openapi").is_some());
+    /// ```
+    pub async fn read_document<P: AsRef<Path>>(file_path: P) -> Result<serde_json::Value> {
+        let path = file_path.as_ref();
+        let content = fs::read_to_string(path)
+            .await
+            .map_err(|_| errors::file_not_found(path.display().to_string()))?;
+
+        let document = Self::parse_document(path, &content)?;
+        Ok(if swagger::is_swagger2(&document) {
+            swagger::upconvert(&document)
+        } else {
+            document
+        })
+    }
+
+    /// Parses a JSON or YAML document into an untyped value.
+    fn parse_document(path: &Path, content: &str) -> Result<serde_json::Value> {
+        let extension = path.extension().and_then(|ext| ext.to_str()).unwrap_or("");
+
+        match extension {
+            "json" => serde_json::from_str(content).map_err(|e| Self::json_error(path, content, e)),
+            "yaml" | "yml" => {
+                serde_yaml::from_str(content).map_err(|e| Self::yaml_error(path, content, e))
+            }
+            _ => Err(errors::unsupported_format(extension)),
+        }
+    }
+
+    /// Converts a JSON error into one pointing at the line and column serde reports.
+    fn json_error(path: &Path, content: &str, error: serde_json::Error) -> OpenAPIError {
+        let (line, column) = (error.line() as u32, error.column() as u32);
+        let context = SourceMap::new(path.display().to_string(), content).context_at(line, column);
+        errors::invalid_json(Self::without_position(&error.to_string(), line, column))
+            .with_context(context)
+    }
+
+    /// Converts a YAML error into one pointing at the line and column serde reports.
+    fn yaml_error(path: &Path, content: &str, error: serde_yaml::Error) -> OpenAPIError {
+        let message = error.to_string();
+        match error.location() {
+            Some(location) => {
+                let (line, column) = (location.line() as u32, location.column() as u32);
+                let context =
+                    SourceMap::new(path.display().to_string(), content).context_at(line, column);
+                errors::invalid_yaml(Self::without_position(&message, line, column))
+                    .with_context(context)
+            }
+            None => errors::invalid_yaml(message)
+                .with_context(ErrorContext::new().with_file(path.display().to_string())),
+        }
+    }
+
+    /// Removes the position serde puts in its messages, which the error context shows
+    /// instead. Other positions, such as where an unclosed collection starts, are kept.
+    fn without_position(message: &str, line: u32, column: u32) -> String {
+        message.replacen(&format!(" at line {line} column {column}"), "", 1)
+    }
+
+    /// Loads every local document reachable through `$ref`s of the parsed document.
+    ///
+    /// Each file is read once and cached. References inside loaded documents are
+    /// rewritten to absolute `<path>#<pointer>` form, so they keep pointing at the
+    /// right document once their targets are used from elsewhere. Only the subtree
+    /// a reference points at is followed, which keeps unrelated objects out.
+    ///
+    /// References into the parsed document that point below a component, such as
+    /// `#/components/schemas/User/properties/address`, are stored the same way,
+    /// keyed by the reference itself.
+    async fn load_external_references(
+        &mut self,
+        document: &serde_json::Value,
+        mut problems: Option<&mut Vec<OpenAPIError>>,
+    ) -> Result<()> {
+        let mut pending = Vec::new();
+        Self::collect_references(document, &mut Vec::new(), "schemas", &mut pending);
+
+        let mut seen = HashSet::new();
+        while let Some((reference, section)) = pending.pop() {
+            let result = self
+                .load_reference(document, &reference, section, &mut pending, &mut seen)
+                .await;
+            if let Err(error) = result {
+                match problems.as_deref_mut() {
+                    Some(problems) => problems.push(error),
+                    None => return Err(error),
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Stores the object `reference` points at, loading its document if needed, and
+    /// queues the references inside it.
+    async fn load_reference(
+        &mut self,
+        document: &serde_json::Value,
+        reference: &str,
+        section: &'static str,
+        pending: &mut Vec<(String, &'static str)>,
+        seen: &mut HashSet<(String, &'static str)>,
+    ) -> Result<()> {
+        if Self::is_remote_reference(reference) {
+            return Ok(());
+        }
+
+        let (path, pointer) = match reference.strip_prefix('#') {
+            Some(pointer) => (None, pointer.to_string()),
+            None => {
+                let (path, pointer) = self.external_location(reference);
+                let path = Some(path).filter(|path| self.document_path.as_ref() != Some(path));
+                (path, pointer)
+            }
+        };
+
+        let Some(path) = path else {
+            if Self::is_component_pointer(&pointer) {
+                return Ok(());
+            }
+
+            let key = format!("#{pointer}");
+            if !seen.insert((key.clone(), section)) {
+                return Ok(());
+            }
+
+            // Dangling pointers are reported when the reference is resolved
+            if let Some(target) = document.pointer(&pointer) {
+                let target = target.clone();
+                Self::collect_references(&target, &mut Vec::new(), section, pending);
+                self.insert_external_component(section, key, target)
+                    .map_err(|e| self.locate_pointer(self.document_path.as_ref(), &pointer, e))?;
+            }
+            return Ok(());
+        };
+
+        let key = Self::external_key(&path, &pointer);
+        if !seen.insert((key.clone(), section)) {
+            return Ok(());
+        }
+
+        if !self.external_documents.contains_key(&path) {
+            let content = fs::read_to_string(&path).await.map_err(|_| {
+                self.locate_reference(
+                    reference,
+                    errors::file_not_found(path.display().to_string()),
+                )
+            })?;
+            let mut external_document = Self::parse_document(&path, &content)?;
+            Self::resolve_schema_ids(&mut external_document);
+            Self::absolutize_references(&mut external_document, &path);
+            self.source_maps.insert(
+                path.clone(),
+                SourceMap::new(path.display().to_string(), &content),
+            );
+            self.record_reference_origins(&external_document, &path, &mut String::new());
+            self.external_documents
+                .insert(path.clone(), external_document);
+        }
+
+        let target = self.external_documents[&path]
+            .pointer(&pointer)
+            .ok_or_else(|| self.locate(errors::reference_not_found(reference, &pointer)))?
+            .clone();
+        Self::collect_references(&target, &mut Vec::new(), section, pending);
+        self.insert_external_component(section, key, target)
+            .map_err(|e| self.locate_pointer(Some(&path), &pointer, e))
+    }
+
+    fn insert_external_component(
+        &mut self,
+        section: &str,
+        key: String,
+        target: serde_json::Value,
+    ) -> Result<()> {
+        fn parse<T: serde::de::DeserializeOwned>(
+            key: &str,
+            target: serde_json::Value,
+        ) -> Result<T> {
+            serde_json::from_value(target)
+                .map_err(|e| errors::invalid_spec(format!("Invalid object at '{key}': {e}")))
+        }
+
+        let components = &mut self.external_components;
+        match section {
+            "parameters" => {
+                let parameter = parse(&key, target)?;
+                components.parameters.insert(key, parameter);
+            }
+            "requestBodies" => {
+                let request_body = parse(&key, target)?;
+                components.request_bodies.insert(key, request_body);
+            }
+            "responses" => {
+                let response = parse(&key, target)?;
+                components.responses.insert(key, response);
+            }
+            "headers" => {
+                let header = parse(&key, target)?;
+                components.headers.insert(key, header);
+            }
+            "examples" => {
+                let example = parse(&key, target)?;
+                components.examples.insert(key, example);
+            }
+            "links" => {
+                let link = parse(&key, target)?;
+                components.links.insert(key, link);
+            }
+            "callbacks" => {
+                let callback = parse(&key, target)?;
+                components.callbacks.insert(key, callback);
+            }
+            "pathItems" => {
+                let path_item = parse(&key, target)?;
+                components.path_items.insert(key, path_item);
+            }
+            "securitySchemes" => {
+                let security_scheme = parse(&key, target)?;
+                components.security_schemes.insert(key, security_scheme);
+            }
+            _ => {
+                let schema = parse(&key, target)?;
+                components.schemas.insert(key, schema);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Collects the `$ref`s below `value` together with the `components` section of
+    /// the objects they point at. `section` is used for a reference at the root.
+    fn collect_references(
+        value: &serde_json::Value,
+        location: &mut Vec<String>,
+        section: &'static str,
+        references: &mut Vec<(String, &'static str)>,
+    ) {
+        match value {
+            serde_json::Value::Object(map) => {
+                for (key, child) in map {
+                    match child {
+                        serde_json::Value::String(reference) if key == "$ref" => {
+                            let target_section = if location.is_empty() {
+                                section
+                            } else {
+                                Self::reference_section(location)
+                            };
+                            references.push((reference.clone(), target_section));
+                        }
+                        _ => {
+                            location.push(key.clone());
+                            Self::collect_references(child, location, section, references);
+                            location.pop();
+                        }
+                    }
+                }
+            }
+            serde_json::Value::Array(items) => {
+                for (index, item) in items.iter().enumerate() {
+                    location.push(index.to_string());
+                    Self::collect_references(item, location, section, references);
+                    location.pop();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Determines what kind of object a `$ref` stands for from the keys leading to it,
+    /// e.g. an entry of a `parameters` list is a parameter.
+    fn reference_section(location: &[String]) -> &'static str {
+        let back = |offset: usize| {
+            location
+                .len()
+                .checked_sub(offset)
+                .map(|index| location[index].as_str())
+        };
+
+        match (back(3), back(2), back(1)) {
+            // A property whose name happens to match a keyword below
+            (Some("properties"), _, _) => "schemas",
+            // Runtime expressions of a callback map to path items
+            (Some("callbacks"), _, _) => "pathItems",
+            (_, _, Some("requestBody")) => "requestBodies",
+            (_, Some("parameters"), _) => "parameters",
+            (_, Some("requestBodies"), _) => "requestBodies",
+            (_, Some("responses"), _) => "responses",
+            (_, Some("headers"), _) => "headers",
+            (_, Some("examples"), _) => "examples",
+            (_, Some("links"), _) => "links",
+            (_, Some("callbacks"), _) => "callbacks",
+            (_, Some("paths" | "pathItems" | "webhooks"), _) => "pathItems",
+            (_, Some("securitySchemes"), _) => "securitySchemes",
+            _ => "schemas",
+        }
+    }
+
+    /// Rewrites the local references of a loaded document relative to its own path.
+    fn absolutize_references(value: &mut serde_json::Value, document_path: &Path) {
+        match value {
+            serde_json::Value::Object(map) => {
+                for (key, child) in map.iter_mut() {
+                    match child {
+                        serde_json::Value::String(reference) if key == "$ref" => {
+                            if Self::is_remote_reference(reference) {
+                                continue;
+                            }
+                            let (document, pointer) =
+                                reference.split_once('#').unwrap_or((reference, ""));
+                            let path = if document.is_empty() {
+                                document_path.to_path_buf()
+                            } else {
+                                Self::normalize_path(
+                                    &document_path
+                                        .parent()
+                                        .unwrap_or(Path::new(""))
+                                        .join(document),
+                                )
+                            };
+                            *reference = Self::external_key(&path, pointer);
+                        }
+                        _ => Self::absolutize_references(child, document_path),
+                    }
+                }
+            }
+            serde_json::Value::Array(items) => {
+                for item in items {
+                    Self::absolutize_references(item, document_path);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Rewrites references that resolve against the `$id` of an enclosing schema to
+    /// JSON pointers into the document, so they resolve like any other reference.
+    /// This covers fragments relative to a schema resource, such as `#/$defs/Street`
+    /// inside a schema with an `$id`, and references naming a schema of the same
+    /// document by its `$id`. Returns whether a reference was rewritten.
+    fn resolve_schema_ids(document: &mut serde_json::Value) -> bool {
+        let mut ids = HashMap::new();
+        Self::collect_schema_ids(document, &mut String::new(), None, &mut ids);
+        if ids.is_empty() {
+            return false;
+        }
+
+        Self::rewrite_id_references(document, &mut String::new(), None, &ids)
+    }
+
+    /// Collects the absolute `$id`s of the schemas below `value`, mapped to their
+    /// JSON pointers.
+    fn collect_schema_ids(
+        value: &serde_json::Value,
+        pointer: &mut String,
+        base: Option<&str>,
+        ids: &mut HashMap<String, String>,
+    ) {
+        match value {
+            serde_json::Value::Object(map) => {
+                let id = Self::schema_id(map, base);
+                if let Some(id) = &id {
+                    ids.insert(id.clone(), pointer.clone());
+                }
+                let base = id.as_deref().or(base);
+
+                for (key, child) in map {
+                    if Self::is_literal_keyword(key) {
+                        continue;
+                    }
+                    let length = pointer.len();
+                    pointer.push('/');
+                    pointer.push_str(&source_map::escape(key));
+                    Self::collect_schema_ids(child, pointer, base, ids);
+                    pointer.truncate(length);
+                }
+            }
+            serde_json::Value::Array(items) => {
+                for (index, item) in items.iter().enumerate() {
+                    let length = pointer.len();
+                    pointer.push_str(&format!("/{index}"));
+                    Self::collect_schema_ids(item, pointer, base, ids);
+                    pointer.truncate(length);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Rewrites the `$ref`s below `value` that resolve against `base`, the URI and
+    /// JSON pointer of the innermost enclosing schema with an `$id`.
+    fn rewrite_id_references(
+        value: &mut serde_json::Value,
+        pointer: &mut String,
+        base: Option<(&str, &str)>,
+        ids: &HashMap<String, String>,
+    ) -> bool {
+        let mut rewritten = false;
+
+        match value {
+            serde_json::Value::Object(map) => {
+                let resource =
+                    Self::schema_id(map, base.map(|(uri, _)| uri)).map(|id| (id, pointer.clone()));
+                let base = resource
+                    .as_ref()
+                    .map(|(uri, pointer)| (uri.as_str(), pointer.as_str()))
+                    .or(base);
+
+                for (key, child) in map.iter_mut() {
+                    match child {
+                        serde_json::Value::String(reference) if key == "$ref" => {
+                            if let Some(target) = Self::id_reference_target(reference, base, ids) {
+                                *reference = target;
+                                rewritten = true;
+                            }
+                        }
+                        _ if Self::is_literal_keyword(key) => {}
+                        _ => {
+                            let length = pointer.len();
+                            pointer.push('/');
+                            pointer.push_str(&source_map::escape(key));
+                            rewritten |= Self::rewrite_id_references(child, pointer, base, ids);
+                            pointer.truncate(length);
+                        }
+                    }
+                }
+            }
+            serde_json::Value::Array(items) => {
+                for (index, item) in items.iter_mut().enumerate() {
+                    let length = pointer.len();
+                    pointer.push_str(&format!("/{index}"));
+                    rewritten |= Self::rewrite_id_references(item, pointer, base, ids);
+                    pointer.truncate(length);
+                }
+            }
+            _ => {}
+        }
+
+        rewritten
+    }
+
+    /// Returns the `#<pointer>` reference standing for a `$ref` that resolves against
+    /// a schema's `$id`, or `None` to leave the reference as it is.
+    fn id_reference_target(
+        reference: &str,
+        base: Option<(&str, &str)>,
+        ids: &HashMap<String, String>,
+    ) -> Option<String> {
+        let target = Self::resolve_uri(base.map(|(uri, _)| uri), reference);
+        let (resource_pointer, fragment) = match reference.strip_prefix('#') {
+            Some(fragment) => (base?.1, fragment),
+            None => {
+                let (document, fragment) = target.split_once('#').unwrap_or((&target, ""));
+                (ids.get(document)?.as_str(), fragment)
+            }
+        };
+
+        // Plain-name fragments (`$anchor`s) aren't supported
+        (fragment.is_empty() || fragment.starts_with('/'))
+            .then(|| format!("#{resource_pointer}{fragment}"))
+    }
+
+    /// Returns the absolute `$id` of a schema object, resolved against `base`.
+    fn schema_id(
+        map: &serde_json::Map<String, serde_json::Value>,
+        base: Option<&str>,
+    ) -> Option<String> {
+        let id = map.get("$id")?.as_str()?;
+        Some(
+            Self::resolve_uri(base, id)
+                .trim_end_matches('#')
+                .to_string(),
+        )
+    }
+
+    /// Keywords whose values are instances rather than schemas, and may hold
+    /// `$id` or `$ref` members of their own.
+    fn is_literal_keyword(key: &str) -> bool {
+        matches!(key, "const" | "default" | "enum" | "example" | "examples")
+    }
+
+    /// Resolves a URI reference against a base URI, as RFC 3986 does for the forms
+    /// found in schemas. Without a base the reference is returned as it is.
+    fn resolve_uri(base: Option<&str>, reference: &str) -> String {
+        let Some(base) = base else {
+            return reference.to_string();
+        };
+        let base = base.split_once('#').map_or(base, |(base, _)| base);
+
+        let has_scheme = reference.split_once(':').is_some_and(|(scheme, _)| {
+            scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+                && scheme
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+        });
+        if has_scheme {
+            return reference.to_string();
+        }
+        if reference.starts_with('#') {
+            return format!("{base}{reference}");
+        }
+
+        let authority_end = base.find("://").map(|start| start + 3).map_or(0, |start| {
+            base[start..]
+                .find('/')
+                .map_or(base.len(), |end| start + end)
+        });
+        let (authority, path) = base.split_at(authority_end);
+        let path = if reference.starts_with('/') {
+            reference.to_string()
+        } else {
+            let directory = path.rfind('/').map_or("", |end| &path[..=end]);
+            format!("{directory}{reference}")
+        };
+
+        let mut segments: Vec<&str> = Vec::new();
+        for segment in path.split('/') {
+            match segment {
+                "." => {}
+                ".." => {
+                    if segments.len() > 1 {
+                        segments.pop();
+                    }
+                }
+                segment => segments.push(segment),
+            }
+        }
+
+        format!("{authority}{}", segments.join("/"))
+    }
+
+    /// Returns the document and JSON pointer an external reference points at,
+    /// resolving relative paths against the parsed document.
+    fn external_location(&self, reference: &str) -> (PathBuf, String) {
+        let (document, pointer) = reference.split_once('#').unwrap_or((reference, ""));
+        let base_dir = match &self.document_path {
+            Some(path) => path.parent().unwrap_or(Path::new("")).to_path_buf(),
+            None => Self::absolute_path(Path::new(".")),
+        };
+
+        (
+            Self::normalize_path(&base_dir.join(document)),
+            pointer.to_string(),
+        )
+    }
+
+    fn external_key(path: &Path, pointer: &str) -> String {
+        format!("{}#{}", path.display(), pointer)
+    }
+
+    fn is_remote_reference(reference: &str) -> bool {
+        reference.starts_with("http://") || reference.starts_with("https://")
+    }
+
+    fn absolute_path(path: &Path) -> PathBuf {
+        Self::normalize_path(&std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf()))
+    }
+
+    /// Lexically removes `.` and `..` components so equal locations compare equal.
+    fn normalize_path(path: &Path) -> PathBuf {
+        let mut normalized = PathBuf::new();
+        for component in path.components() {
+            match component {
+                Component::CurDir => {}
+                Component::ParentDir => match normalized.components().next_back() {
+                    Some(Component::Normal(_)) => {
+                        normalized.pop();
+                    }
+                    Some(Component::RootDir) => {}
+                    _ => normalized.push(".."),
+                },
+                other => normalized.push(other.as_os_str()),
+            }
+        }
+        normalized
+    }
+
+    /// Remembers where each `$ref` below `value` appears, so errors about a reference
+    /// can point at it.
+    fn record_reference_origins(
+        &mut self,
+        value: &serde_json::Value,
+        document_path: &Path,
+        pointer: &mut String,
+    ) {
+        match value {
+            serde_json::Value::Object(map) => {
+                for (key, child) in map {
+                    let length = pointer.len();
+                    pointer.push('/');
+                    pointer.push_str(&source_map::escape(key));
+                    match child {
+                        serde_json::Value::String(reference) if key == "$ref" => {
+                            self.reference_origins
+                                .entry(reference.clone())
+                                .or_insert_with(|| (document_path.to_path_buf(), pointer.clone()));
+                        }
+                        _ => self.record_reference_origins(child, document_path, pointer),
+                    }
+                    pointer.truncate(length);
+                }
+            }
+            serde_json::Value::Array(items) => {
+                for (index, item) in items.iter().enumerate() {
+                    let length = pointer.len();
+                    pointer.push_str(&format!("/{index}"));
+                    self.record_reference_origins(item, document_path, pointer);
+                    pointer.truncate(length);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Attaches the source location `error` is about, when it can be told from the
+    /// error: the `$ref` of a reference error, the path item missing a path parameter,
+    /// or the field of the parsed document that failed validation. Other errors, and
+    /// errors that already have a location, are returned as they are.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openapi_codegen_rust::parser::OpenAPIParser;
+    ///
+    /// let parser = OpenAPIParser::new();
+    /// // Use in async context after parsing a file:
+    /// // let error = parser.resolve_reference("#/components/schemas/Missing").unwrap_err();
+    /// // eprintln!("{}", parser.locate(error));
+    /// ```
+    pub fn locate(&self, error: OpenAPIError) -> OpenAPIError {
+        let pointer = match &error {
+            OpenAPIError::ReferenceNotFound { reference, .. }
+            | OpenAPIError::CircularReference { reference }
+            | OpenAPIError::ExternalReferenceNotSupported { reference, .. } => {
+                return self.locate_reference(&reference.clone(), error);
+            }
+            OpenAPIError::MissingPathParameter { path, .. } => {
+                format!("/paths/{}", source_map::escape(path))
+            }
+            OpenAPIError::MissingField { path, .. }
+            | OpenAPIError::UnsupportedOpenAPIVersion { path, .. } => {
+                format!("/{}", path.replace('.', "/"))
+            }
+            _ => return error,
+        };
+
+        self.locate_pointer(self.document_path.as_ref(), &pointer, error)
+    }
+
+    /// Attaches the location of the node at `pointer` in the parsed document, or of its
+    /// closest ancestor that appears in it.
+    pub fn locate_at(&self, pointer: &str, error: OpenAPIError) -> OpenAPIError {
+        self.locate_pointer(self.document_path.as_ref(), pointer, error)
+    }
+
+    /// Returns the location of the node at `pointer` in the parsed document, or of its
+    /// closest ancestor that appears in it. `None` when locations aren't tracked for
+    /// the document, as for Swagger 2.0 ones.
+    pub fn source_context(&self, pointer: &str) -> Option<ErrorContext> {
+        let source_map = self.source_maps.get(self.document_path.as_ref()?)?;
+        Some(source_map.context(pointer))
+    }
+
+    /// Attaches the location of the first `$ref` with the value `reference`.
+    fn locate_reference(&self, reference: &str, error: OpenAPIError) -> OpenAPIError {
+        match self.reference_origins.get(reference) {
+            Some((path, pointer)) => self.locate_pointer(Some(path), pointer, error),
+            None => error,
+        }
+    }
+
+    /// Attaches the location of the node at `pointer` in the document at `path`.
+    fn locate_pointer(
+        &self,
+        path: Option<&PathBuf>,
+        pointer: &str,
+        error: OpenAPIError,
+    ) -> OpenAPIError {
+        if error.context().is_some() {
+            return error;
+        }
+
+        match path.and_then(|path| self.source_maps.get(path)) {
+            Some(source_map) => error.with_context(source_map.context(pointer)),
+            None => error,
+        }
     }
 
     /// Validates an OpenAPI specification for required fields and version compatibility.
@@ -120,41 +929,51 @@ impl OpenAPIParser {
     /// # Arguments
     ///
     /// * `spec` - The OpenAPI specification to validate
+    /// * `document` - The same specification as an untyped value
     ///
     /// # Returns
     ///
-    /// `Ok(())` if validation passes.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if:
+    /// Every problem found, in document order; empty if validation passes. Problems
+    /// are reported when:
     /// - OpenAPI version is not 3.x
     /// - Required fields (title, version) are missing or empty
-    fn validate_spec(&self, spec: &OpenAPISpec) -> Result<()> {
+    /// - `paths` is missing from an OpenAPI 3.0 document, or a 3.1 document has
+    ///   none of `paths`, `components` and `webhooks`
+    fn validate_spec(&self, spec: &OpenAPISpec, document: &serde_json::Value) -> Vec<OpenAPIError> {
+        let mut problems = Vec::new();
+
         if !spec.openapi.starts_with("3.") {
-            return Err(errors::unsupported_openapi_version(
+            problems.push(errors::unsupported_openapi_version(
                 &spec.openapi,
                 "openapi",
             ));
         }
 
         if spec.info.title.is_empty() {
-            return Err(errors::missing_field("title", "info.title"));
+            problems.push(errors::missing_field("title", "info.title"));
         }
 
         if spec.info.version.is_empty() {
-            return Err(errors::missing_field("version", "info.version"));
+            problems.push(errors::missing_field("version", "info.version"));
         }
 
         // Note: Empty paths are allowed for specs that only define models
+        let has_paths = document.get("paths").is_some();
+        if !has_paths
+            && (spec.openapi.starts_with("3.0")
+                || (spec.components.is_none() && spec.webhooks.is_empty()))
+        {
+            problems.push(errors::missing_field("paths", "paths"));
+        }
 
-        Ok(())
+        problems
     }
 
     /// Resolves a JSON Pointer reference to an OpenAPI schema.
     ///
-    /// Only supports internal references (starting with "#/").
-    /// External references are not supported.
+    /// Supports internal references (starting with "#/") and references into local
+    /// files loaded by `parse_file`, resolved relative to the referencing document.
+    /// Remote (`http://`, `https://`) references are not supported.
     ///
     /// # Arguments
     ///
@@ -167,8 +986,9 @@ impl OpenAPIParser {
     /// # Errors
     ///
     /// Returns an error if:
-    /// - Reference is external (not starting with "#/")
+    /// - Reference is remote
     /// - Referenced schema doesn't exist
+    /// - References form a cycle, within or across files
     /// - Reference path is invalid
     ///
     /// # Examples
@@ -183,43 +1003,123 @@ impl OpenAPIParser {
     /// // println!("Schema type: {:?}", schema.schema_type);
     /// ```
     pub fn resolve_reference(&self, reference: &str) -> Result<&OpenAPISchema> {
-        self.resolve_reference_with_visited(reference, &mut std::collections::HashSet::new())
+        self.resolve_component_reference::<OpenAPISchemaOrRef>(reference, &mut HashSet::new())
     }
 
-    fn resolve_reference_with_visited(
+    /// Resolves an object that may be a `$ref` to the component it stands for.
+    ///
+    /// Works for every referenceable kind of object: schemas, parameters, request
+    /// bodies, responses, headers, examples, links, callbacks, path items and
+    /// security schemes. References are looked up in the matching `components`
+    /// section of the parsed document or in loaded external documents, and chains
+    /// of references are followed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a reference is remote, doesn't exist, or is part of a cycle.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openapi_codegen_rust::parser::OpenAPIParser;
+    /// use openapi_codegen_rust::types::{OpenAPIParameterOrRef, OpenAPIReference};
+    ///
+    /// // Note: parser must have a parsed specification first
+    /// let parser = OpenAPIParser::new();
+    /// let ref_obj = OpenAPIReference { reference: "#/components/parameters/Limit".to_string() };
+    /// let parameter_ref = OpenAPIParameterOrRef::Reference(ref_obj);
+    /// // let parameter = parser.resolve_component(&parameter_ref).unwrap();
+    /// // println!("Parameter name: {}", parameter.name);
+    /// ```
+    pub fn resolve_component<'a, T: ComponentOrRef>(
+        &'a self,
+        item: &'a T,
+    ) -> Result<&'a T::Component> {
+        self.resolve_component_with_visited(item, &mut HashSet::new())
+    }
+
+    fn resolve_component_with_visited<'a, T: ComponentOrRef>(
+        &'a self,
+        item: &'a T,
+        visited: &mut HashSet<String>,
+    ) -> Result<&'a T::Component> {
+        match item.component() {
+            Some(component) => Ok(component),
+            None => {
+                self.resolve_component_reference::<T>(item.reference().unwrap_or_default(), visited)
+            }
+        }
+    }
+
+    fn resolve_component_reference<T: ComponentOrRef>(
         &self,
         reference: &str,
-        visited: &mut std::collections::HashSet<String>,
-    ) -> Result<&OpenAPISchema> {
+        visited: &mut HashSet<String>,
+    ) -> Result<&T::Component> {
         // Check for circular reference
-        if visited.contains(reference) {
+        if !visited.insert(reference.to_string()) {
             return Err(errors::circular_reference(reference));
         }
-        visited.insert(reference.to_string());
 
         let spec = self.spec.as_ref().unwrap();
 
-        if !reference.starts_with("#/") {
+        let target = if let Some(pointer) = reference.strip_prefix("#/") {
+            self.internal_component::<T>(spec, pointer)
+        } else if Self::is_remote_reference(reference) {
             return Err(errors::external_reference_not_supported(reference, "$ref"));
+        } else {
+            let (path, pointer) = self.external_location(reference);
+
+            if self.document_path.as_ref() == Some(&path) && pointer.starts_with('/') {
+                // A reference back into the parsed document is an internal one
+                self.internal_component::<T>(spec, &pointer[1..])
+            } else {
+                let key = Self::external_key(&path, &pointer);
+                if key != reference && !visited.insert(key.clone()) {
+                    return Err(errors::circular_reference(reference));
+                }
+                T::section(&self.external_components).get(&key)
+            }
+        };
+
+        match target {
+            Some(item) => self.resolve_component_with_visited(item, visited),
+            None => {
+                let pointer = reference
+                    .split_once('#')
+                    .map_or(reference, |(_, pointer)| pointer);
+                Err(errors::reference_not_found(
+                    reference,
+                    pointer.trim_start_matches('/'),
+                ))
+            }
         }
+    }
 
-        let parts: Vec<&str> = reference[2..].split('/').collect();
+    /// Looks up `components/<section>/<name>` in the parsed document, or an object
+    /// below a component that was stored while loading references.
+    fn internal_component<'a, T: ComponentOrRef>(
+        &'a self,
+        spec: &'a OpenAPISpec,
+        pointer: &str,
+    ) -> Option<&'a T> {
+        if !Self::is_component_pointer(&format!("/{pointer}")) {
+            return T::section(&self.external_components).get(&format!("#/{pointer}"));
+        }
 
-        if parts.len() >= 3 && parts[0] == "components" && parts[1] == "schemas" {
-            let schema_name = parts[2];
-            if let Some(components) = &spec.components {
-                if let Some(schema_or_ref) = components.schemas.get(schema_name) {
-                    return match schema_or_ref {
-                        OpenAPISchemaOrRef::Schema(schema) => Ok(schema),
-                        OpenAPISchemaOrRef::Reference(ref_obj) => {
-                            self.resolve_reference_with_visited(&ref_obj.reference, visited)
-                        }
-                    };
-                }
-            }
+        let (section, name) = pointer.strip_prefix("components/")?.split_once('/')?;
+        if section != T::SECTION {
+            return None;
         }
 
-        Err(errors::reference_not_found(reference, parts.join("/")))
+        T::section(spec.components.as_ref()?).get(name)
+    }
+
+    /// Whether a JSON pointer addresses a whole component, i.e. `/components/<section>/<name>`.
+    fn is_component_pointer(pointer: &str) -> bool {
+        pointer
+            .strip_prefix("/components/")
+            .is_some_and(|rest| rest.split('/').count() == 2)
     }
 
     /// Resolves a schema or schema reference, handling composition patterns.
@@ -234,7 +1134,8 @@ impl OpenAPIParser {
     ///
     /// # Returns
     ///
-    /// A boxed resolved schema with composition patterns processed.
+    /// A boxed resolved schema with composition patterns processed. Schemas reached
+    /// through a `$ref` carry the referenced component name in `reference_name`.
     ///
     /// # Errors
     ///
@@ -257,6 +1158,15 @@ impl OpenAPIParser {
     pub fn resolve_schema(&self, schema_or_ref: &OpenAPISchemaOrRef) -> Result<Box<OpenAPISchema>> {
         match schema_or_ref {
             OpenAPISchemaOrRef::Schema(schema) => {
+                if let Some(variant) = Self::nullable_variant(schema) {
+                    let mut resolved = self.resolve_schema(variant)?;
+                    resolved.nullable = Some(true);
+                    if schema.description.is_some() {
+                        resolved.description = schema.description.clone();
+                    }
+                    return Ok(resolved);
+                }
+
                 // Handle allOf schema composition
                 if !schema.all_of.is_empty() {
                     self.resolve_all_of_schema(schema, &schema.all_of)
@@ -271,16 +1181,51 @@ impl OpenAPIParser {
             OpenAPISchemaOrRef::Reference(reference) => {
                 let resolved = self.resolve_reference(&reference.reference)?;
                 // Handle allOf schema composition for resolved reference
-                if !resolved.all_of.is_empty() {
-                    self.resolve_all_of_schema(resolved, &resolved.all_of)
+                let mut resolved_schema = if !resolved.all_of.is_empty() {
+                    self.resolve_all_of_schema(resolved, &resolved.all_of)?
                 } else if !resolved.one_of.is_empty() {
-                    self.resolve_one_of_schema(resolved, &resolved.one_of)
+                    self.resolve_one_of_schema(resolved, &resolved.one_of)?
                 } else if !resolved.any_of.is_empty() {
-                    self.resolve_any_of_schema(resolved, &resolved.any_of)
+                    self.resolve_any_of_schema(resolved, &resolved.any_of)?
                 } else {
-                    Ok(Box::new(resolved.clone()))
+                    Box::new(resolved.clone())
+                };
+
+                // Remember which component the schema came from so generators can
+                // refer to the generated type instead of inlining its structure. A
+                // pointer into another schema names no generated type, so its target
+                // is treated like an inline schema.
+                if Self::is_named_schema_pointer(&reference.reference) {
+                    resolved_schema.reference_name =
+                        Some(Self::extract_schema_name(&reference.reference));
                 }
+                Ok(resolved_schema)
+            }
+        }
+    }
+
+    /// Returns the other schema of an `anyOf` or `oneOf` pairing one schema with
+    /// `{type: "null"}`, which is how OpenAPI 3.1 makes a reference nullable.
+    fn nullable_variant(schema: &OpenAPISchema) -> Option<&OpenAPISchemaOrRef> {
+        if !schema.all_of.is_empty() {
+            return None;
+        }
+
+        let is_null = |variant: &OpenAPISchemaOrRef| {
+            matches!(variant, OpenAPISchemaOrRef::Schema(variant)
+                if variant.schema_type == Some(SchemaType::from("null")))
+        };
+        let variants = if schema.any_of.is_empty() {
+            &schema.one_of
+        } else {
+            &schema.any_of
+        };
+
+        match variants.as_slice() {
+            [variant, null] | [null, variant] if is_null(null) && !is_null(variant) => {
+                Some(variant)
             }
+            _ => None,
         }
     }
 
@@ -290,7 +1235,7 @@ impl OpenAPIParser {
         all_of: &[OpenAPISchemaOrRef],
     ) -> Result<Box<OpenAPISchema>> {
         let mut resolved_schema = OpenAPISchema {
-            schema_type: Some("object".to_string()),
+            schema_type: Some("object".into()),
             properties: IndexMap::new(),
             required: Vec::new(),
             title: base_schema.title.clone(),
@@ -361,7 +1306,7 @@ impl OpenAPIParser {
         one_of: &[OpenAPISchemaOrRef],
     ) -> Result<Box<OpenAPISchema>> {
         let mut resolved_schema = OpenAPISchema {
-            schema_type: Some("object".to_string()),
+            schema_type: Some("object".into()),
             properties: IndexMap::new(),
             required: Vec::new(),
             title: base_schema.title.clone(),
@@ -373,19 +1318,22 @@ impl OpenAPIParser {
         // Clear oneOf from resolved schema
         resolved_schema.one_of = Vec::new();
 
-        // Store oneOf variants for code generation (using description or generate names)
+        // Store oneOf variants for code generation (using the title, the referenced
+        // component name, or a generated name)
         let mut one_of_variants = Vec::new();
         for (index, variant_ref) in one_of.iter().enumerate() {
-            let variant_schema = match variant_ref {
-                OpenAPISchemaOrRef::Schema(schema) => (**schema).clone(),
-                OpenAPISchemaOrRef::Reference(reference) => {
-                    self.resolve_reference(&reference.reference)?.clone()
-                }
+            let (variant_schema, component_name) = match variant_ref {
+                OpenAPISchemaOrRef::Schema(schema) => ((**schema).clone(), None),
+                OpenAPISchemaOrRef::Reference(reference) => (
+                    self.resolve_reference(&reference.reference)?.clone(),
+                    Some(Self::extract_schema_name(&reference.reference)),
+                ),
             };
 
             let variant_name = variant_schema
                 .title
                 .clone()
+                .or(component_name)
                 .unwrap_or_else(|| format!("Variant{}", index + 1));
 
             one_of_variants.push((variant_name, variant_schema));
@@ -404,7 +1352,7 @@ impl OpenAPIParser {
                 resolved_schema.properties.insert(
                     discriminator_property.clone(),
                     crate::types::OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
-                        schema_type: Some("string".to_string()),
+                        schema_type: Some("string".into()),
                         ..Default::default()
                     })),
                 );
@@ -425,7 +1373,7 @@ impl OpenAPIParser {
         any_of: &[OpenAPISchemaOrRef],
     ) -> Result<Box<OpenAPISchema>> {
         let mut resolved_schema = OpenAPISchema {
-            schema_type: Some("object".to_string()),
+            schema_type: Some("object".into()),
             properties: IndexMap::new(),
             required: Vec::new(),
             title: base_schema.title.clone(),
@@ -489,15 +1437,35 @@ impl OpenAPIParser {
         Ok(Box::new(resolved_schema))
     }
 
+    /// Returns the schema name a reference points at: the last pointer segment, or
+    /// the file name without extension for a reference to a whole document.
     #[allow(dead_code)]
     pub fn extract_schema_name(reference: &str) -> String {
         if reference.is_empty() {
             return "Unknown".to_string();
         }
-        reference
-            .split('/')
-            .next_back()
-            .filter(|s| !s.is_empty())
+
+        match reference.split_once('#') {
+            Some((document, pointer))
+                if pointer.trim_matches('/').is_empty() && !document.is_empty() =>
+            {
+                Self::document_name(document)
+            }
+            Some(_) => reference
+                .split('/')
+                .next_back()
+                .filter(|s| !s.is_empty())
+                .unwrap_or("Unknown")
+                .to_string(),
+            None => Self::document_name(reference),
+        }
+    }
+
+    fn document_name(document: &str) -> String {
+        Path::new(document)
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .filter(|stem| !stem.is_empty())
             .unwrap_or("Unknown")
             .to_string()
     }
@@ -539,9 +1507,39 @@ impl OpenAPIParser {
             }
         }
 
+        // Named schemas from external documents; local components win on name clashes
+        for key in self.external_components.schemas.keys() {
+            if !Self::is_named_schema_pointer(key) {
+                continue;
+            }
+
+            let name = Self::extract_schema_name(key);
+            if schemas.iter().any(|(existing, _)| *existing == name) {
+                continue;
+            }
+
+            let reference = OpenAPISchemaOrRef::Reference(OpenAPIReference {
+                reference: key.clone(),
+            });
+            schemas.push((name, self.resolve_schema(&reference)?));
+        }
+
         Ok(schemas)
     }
 
+    /// Whether a reference targets a named schema (a whole document, a top-level
+    /// entry, or an entry of a `schemas`/`definitions` map) rather than
+    /// a fragment nested inside another schema.
+    fn is_named_schema_pointer(key: &str) -> bool {
+        let pointer = key.split_once('#').map_or("", |(_, pointer)| pointer);
+        let segments: Vec<&str> = pointer.split('/').filter(|s| !s.is_empty()).collect();
+
+        match segments.as_slice() {
+            [] | [_] => true,
+            [.., parent, _] => matches!(*parent, "schemas" | "definitions" | "$defs"),
+        }
+    }
+
     /// Extracts all unique tags from the OpenAPI specification.
     ///
     /// Collects tags from both the global tags section and from individual operations.
@@ -576,18 +1574,8 @@ impl OpenAPIParser {
 
         // From operations
         for path_item in spec.paths.values() {
-            let operations = [
-                &path_item.get,
-                &path_item.post,
-                &path_item.put,
-                &path_item.delete,
-                &path_item.patch,
-                &path_item.head,
-                &path_item.options,
-                &path_item.trace,
-            ];
-
-            for operation in operations.iter().copied().flatten() {
+            let path_item = self.resolve_component(path_item).unwrap_or(path_item);
+            for (_, operation) in path_item.operations() {
                 for tag in &operation.tags {
                     tags.insert(tag.clone());
                 }
@@ -610,7 +1598,7 @@ impl OpenAPIParser {
     ///
     /// # Errors
     ///
-    /// Currently doesn't return errors, but signature is Result for future extensibility.
+    /// Returns an error if a path item `$ref` cannot be resolved.
     ///
     /// # Examples
     ///
@@ -630,32 +1618,20 @@ impl OpenAPIParser {
         let mut tagged_operations = std::collections::HashMap::new();
 
         for (path_str, path_item) in &spec.paths {
-            let operations = [
-                ("get", &path_item.get),
-                ("post", &path_item.post),
-                ("put", &path_item.put),
-                ("delete", &path_item.delete),
-                ("patch", &path_item.patch),
-                ("head", &path_item.head),
-                ("options", &path_item.options),
-                ("trace", &path_item.trace),
-            ];
-
-            for (method, operation_opt) in operations {
-                if let Some(operation) = operation_opt {
-                    let tags = if operation.tags.is_empty() {
-                        vec!["Default".to_string()]
-                    } else {
-                        operation.tags.clone()
-                    };
-
-                    for tag in tags {
-                        tagged_operations.entry(tag).or_insert_with(Vec::new).push((
-                            path_str.clone(),
-                            method.to_string(),
-                            operation,
-                        ));
-                    }
+            let path_item = self.resolve_component(path_item)?;
+            for (method, operation) in path_item.operations() {
+                let tags = if operation.tags.is_empty() {
+                    vec!["Default".to_string()]
+                } else {
+                    operation.tags.clone()
+                };
+
+                for tag in tags {
+                    tagged_operations.entry(tag).or_insert_with(Vec::new).push((
+                        path_str.clone(),
+                        method.to_string(),
+                        operation,
+                    ));
                 }
             }
         }
@@ -663,13 +1639,116 @@ impl OpenAPIParser {
         Ok(tagged_operations)
     }
 
+    /// Returns the parameters of an operation merged with those of its path item.
+    ///
+    /// Path-level parameters come first. An operation parameter with the same name
+    /// and location overrides the path-level one in place, other operation
+    /// parameters are appended. `$ref`s are resolved.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a parameter reference cannot be resolved, or if a
+    /// templated segment of `path` such as `{userId}` has no matching path parameter.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openapi_codegen_rust::parser::OpenAPIParser;
+    ///
+    /// // Note: parser must have a parsed specification first
+    /// let parser = OpenAPIParser::new();
+    /// // Parse a spec first, then:
+    /// // let operations = parser.get_operations_by_tag().unwrap();
+    /// // for (path, _, operation) in &operations["users"] {
+    /// //     let parameters = parser.get_operation_parameters(path, operation).unwrap();
+    /// // }
+    /// ```
+    pub fn get_operation_parameters<'a>(
+        &'a self,
+        path: &str,
+        operation: &'a OpenAPIOperation,
+    ) -> Result<Vec<&'a OpenAPIParameter>> {
+        let path_item = match self.spec.as_ref().and_then(|spec| spec.paths.get(path)) {
+            Some(path_item) => Some(self.resolve_component(path_item)?),
+            None => None,
+        };
+
+        let mut parameters: Vec<&OpenAPIParameter> = Vec::new();
+        let path_parameters = path_item
+            .map(|item| item.parameters.as_slice())
+            .unwrap_or_default();
+        for param_or_ref in path_parameters.iter().chain(&operation.parameters) {
+            let param = self.resolve_component(param_or_ref)?;
+            match parameters
+                .iter_mut()
+                .find(|existing| existing.name == param.name && existing.location == param.location)
+            {
+                Some(existing) => *existing = param,
+                None => parameters.push(param),
+            }
+        }
+
+        for name in Self::path_template_names(path) {
+            if !parameters
+                .iter()
+                .any(|param| param.location == "path" && param.name == name)
+            {
+                return Err(errors::missing_path_parameter(name, path));
+            }
+        }
+
+        Ok(parameters)
+    }
+
+    /// Extracts the names of the templated segments of a path, e.g. `userId` from `/users/{userId}`.
+    pub fn path_template_names(path: &str) -> Vec<&str> {
+        path.split('{')
+            .skip(1)
+            .filter_map(|segment| segment.split_once('}').map(|(name, _)| name))
+            .collect()
+    }
+
     #[allow(dead_code)]
     pub fn get_spec(&self) -> &OpenAPISpec {
         self.spec.as_ref().unwrap()
     }
+
+    /// Returns the objects loaded through references to other local files, keyed by
+    /// `<absolute path>#<pointer>` in the section matching where they're referenced.
+    /// Objects below a component of the parsed document are included, keyed by
+    /// `#<pointer>`.
+    pub fn get_external_components(&self) -> &OpenAPIComponents {
+        &self.external_components
+    }
+
+    /// Returns the form references are stored in: `#<pointer>` for the parsed
+    /// document and `<absolute path>#<pointer>` for other local files, with relative
+    /// paths resolved against the parsed document. `None` for remote references.
+    pub fn canonical_reference(&self, reference: &str) -> Option<String> {
+        if reference.starts_with('#') {
+            return Some(reference.to_string());
+        }
+        if Self::is_remote_reference(reference) {
+            return None;
+        }
+
+        let (path, pointer) = self.external_location(reference);
+        Some(if self.document_path.as_ref() == Some(&path) {
+            format!("#{pointer}")
+        } else {
+            Self::external_key(&path, &pointer)
+        })
+    }
+
+    /// Returns the parsed document as an untyped value, for reading what the typed
+    /// specification doesn't keep, such as `x-` extensions.
+    pub fn get_document(&self) -> &serde_json::Value {
+        &self.document
+    }
 }
 
 #[cfg(test)]
+#[allow(clippy::field_reassign_with_default)]
 mod tests {
     use super::*;
     use indexmap::IndexMap;
@@ -704,20 +1783,20 @@ mod tests {
         schemas.insert(
             "User".to_string(),
             OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
-                schema_type: Some("object".to_string()),
+                schema_type: Some("object".into()),
                 properties: {
                     let mut props = IndexMap::new();
                     props.insert(
                         "id".to_string(),
                         OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
-                            schema_type: Some("integer".to_string()),
+                            schema_type: Some("integer".into()),
                             ..Default::default()
                         })),
                     );
                     props.insert(
                         "name".to_string(),
                         OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
-                            schema_type: Some("string".to_string()),
+                            schema_type: Some("string".into()),
                             ..Default::default()
                         })),
                     );
@@ -732,7 +1811,7 @@ mod tests {
         schemas.insert(
             "Profile".to_string(),
             OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
-                schema_type: Some("object".to_string()),
+                schema_type: Some("object".into()),
                 properties: {
                     let mut props = IndexMap::new();
                     props.insert(
@@ -744,7 +1823,7 @@ mod tests {
                     props.insert(
                         "bio".to_string(),
                         OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
-                            schema_type: Some("string".to_string()),
+                            schema_type: Some("string".into()),
                             ..Default::default()
                         })),
                     );
@@ -778,20 +1857,20 @@ mod tests {
         schemas.insert(
             "BaseEntity".to_string(),
             OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
-                schema_type: Some("object".to_string()),
+                schema_type: Some("object".into()),
                 properties: {
                     let mut props = IndexMap::new();
                     props.insert(
                         "id".to_string(),
                         OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
-                            schema_type: Some("string".to_string()),
+                            schema_type: Some("string".into()),
                             ..Default::default()
                         })),
                     );
                     props.insert(
                         "createdAt".to_string(),
                         OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
-                            schema_type: Some("string".to_string()),
+                            schema_type: Some("string".into()),
                             format: Some("date-time".to_string()),
                             ..Default::default()
                         })),
@@ -812,13 +1891,13 @@ mod tests {
                         reference: "#/components/schemas/BaseEntity".to_string(),
                     }),
                     OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
-                        schema_type: Some("object".to_string()),
+                        schema_type: Some("object".into()),
                         properties: {
                             let mut props = IndexMap::new();
                             props.insert(
                                 "name".to_string(),
                                 OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
-                                    schema_type: Some("string".to_string()),
+                                    schema_type: Some("string".into()),
                                     ..Default::default()
                                 })),
                             );
@@ -856,13 +1935,13 @@ mod tests {
         schemas.insert(
             "Dog".to_string(),
             OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
-                schema_type: Some("object".to_string()),
+                schema_type: Some("object".into()),
                 properties: {
                     let mut props = IndexMap::new();
                     props.insert(
                         "breed".to_string(),
                         OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
-                            schema_type: Some("string".to_string()),
+                            schema_type: Some("string".into()),
                             ..Default::default()
                         })),
                     );
@@ -876,13 +1955,13 @@ mod tests {
         schemas.insert(
             "Cat".to_string(),
             OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
-                schema_type: Some("object".to_string()),
+                schema_type: Some("object".into()),
                 properties: {
                     let mut props = IndexMap::new();
                     props.insert(
                         "indoor".to_string(),
                         OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
-                            schema_type: Some("boolean".to_string()),
+                            schema_type: Some("boolean".into()),
                             ..Default::default()
                         })),
                     );
@@ -908,6 +1987,7 @@ mod tests {
                 discriminator: Some(OpenAPIDiscriminator {
                     property_name: "petType".to_string(),
                     mapping: IndexMap::new(),
+                    extensions: Extensions::new(),
                 }),
                 ..Default::default()
             })),
@@ -1052,7 +2132,7 @@ paths: {}
         let result = parser.parse_file(&file_path).await;
 
         assert!(result.is_err());
-        match result.unwrap_err() {
+        match result.unwrap_err().kind() {
             crate::errors::OpenAPIError::InvalidJson { .. } => {}
             _ => panic!("Expected InvalidJson error"),
         }
@@ -1069,7 +2149,7 @@ paths: {}
         let result = parser.parse_file(&file_path).await;
 
         assert!(result.is_err());
-        match result.unwrap_err() {
+        match result.unwrap_err().kind() {
             crate::errors::OpenAPIError::InvalidYaml { .. } => {}
             _ => panic!("Expected InvalidYaml error"),
         }
@@ -1095,7 +2175,7 @@ paths: {}
         let result = parser.parse_file(&file_path).await;
 
         assert!(result.is_err());
-        match result.unwrap_err() {
+        match result.unwrap_err().kind() {
             crate::errors::OpenAPIError::UnsupportedOpenAPIVersion { version, .. } => {
                 assert_eq!(version, "2.0.0");
             }
@@ -1123,7 +2203,7 @@ paths: {}
         let result = parser.parse_file(&file_path).await;
 
         assert!(result.is_err());
-        match result.unwrap_err() {
+        match result.unwrap_err().kind() {
             crate::errors::OpenAPIError::MissingField { field, .. } => {
                 assert_eq!(field, "title");
             }
@@ -1151,7 +2231,7 @@ paths: {}
         let result = parser.parse_file(&file_path).await;
 
         assert!(result.is_err());
-        match result.unwrap_err() {
+        match result.unwrap_err().kind() {
             crate::errors::OpenAPIError::MissingField { field, .. } => {
                 assert_eq!(field, "version");
             }
@@ -1159,6 +2239,107 @@ paths: {}
         }
     }
 
+    #[tokio::test]
+    async fn test_parse_errors_point_at_source() {
+        let temp_dir = TempDir::new().unwrap();
+
+        let file_path = temp_dir.path().join("broken.yaml");
+        fs::write(
+            &file_path,
+            "openapi: 3.0.3\ninfo:\n  title: T\n  version: [1\npaths: {}\n",
+        )
+        .unwrap();
+        let error = OpenAPIParser::new()
+            .parse_file(&file_path)
+            .await
+            .map(|_| ())
+            .unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            crate::errors::OpenAPIError::InvalidYaml { .. }
+        ));
+        let context = error.context().unwrap();
+        assert_eq!((context.line, context.column), (Some(5), Some(6)));
+        assert_eq!(context.source_line.as_deref(), Some("paths: {}"));
+        assert!(!error.to_string().contains("at line 5 column 6"));
+
+        let file_path = temp_dir.path().join("no_title.json");
+        fs::write(
+            &file_path,
+            "{\n  \"openapi\": \"3.0.3\",\n  \"info\": {\"title\": \"\", \"version\": \"1\"},\n  \"paths\": {}\n}\n",
+        )
+        .unwrap();
+        let error = OpenAPIParser::new()
+            .parse_file(&file_path)
+            .await
+            .map(|_| ())
+            .unwrap_err();
+        let context = error.context().unwrap();
+        assert_eq!((context.line, context.column), (Some(3), Some(12)));
+        assert_eq!(context.schema_path, vec!["info", "title"]);
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "Missing required field 'title' at info.title\n --> {}:3:12\n  |\n3 |   \"info\": {{\"title\": \"\", \"version\": \"1\"}},\n  |            ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^",
+                file_path.display()
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn test_locate_reference_and_path_errors() {
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = temp_dir.path().join("api.yaml");
+        fs::write(
+            &file_path,
+            r#"openapi: 3.0.3
+info:
+  title: T
+  version: '1'
+paths:
+  /pets/{petId}:
+    get:
+      responses:
+        '200':
+          description: ok
+components:
+  schemas:
+    Pet:
+      properties:
+        owner:
+          $ref: '#/components/schemas/Owner'
+"#,
+        )
+        .unwrap();
+
+        let mut parser = OpenAPIParser::new();
+        parser.parse_file(&file_path).await.unwrap();
+
+        let error = parser.locate(
+            parser
+                .resolve_reference("#/components/schemas/Owner")
+                .unwrap_err(),
+        );
+        let context = error.context().unwrap();
+        assert_eq!((context.line, context.column), (Some(16), Some(11)));
+
+        let get = parser.get_spec().paths["/pets/{petId}"]
+            .get
+            .as_ref()
+            .unwrap();
+        let error = parser.locate(
+            parser
+                .get_operation_parameters("/pets/{petId}", get)
+                .unwrap_err(),
+        );
+        let context = error.context().unwrap();
+        assert_eq!((context.line, context.column), (Some(6), Some(3)));
+
+        // Errors that already have a location keep it
+        let located = parser.locate(error);
+        assert_eq!(located.context().unwrap().line, Some(6));
+    }
+
     #[test]
     fn test_resolve_reference_valid() {
         let mut parser = OpenAPIParser::new();
@@ -1168,18 +2349,19 @@ paths: {}
         assert!(result.is_ok());
 
         let schema = result.unwrap();
-        assert_eq!(schema.schema_type, Some("object".to_string()));
+        assert_eq!(schema.schema_type, Some("object".into()));
         assert!(!schema.properties.is_empty());
         assert!(schema.properties.contains_key("id"));
         assert!(schema.properties.contains_key("name"));
     }
 
     #[test]
-    fn test_resolve_reference_external_not_supported() {
+    fn test_resolve_reference_remote_not_supported() {
         let mut parser = OpenAPIParser::new();
         parser.spec = Some(create_spec_with_schemas());
 
-        let result = parser.resolve_reference("external.yaml#/components/schemas/User");
+        let result =
+            parser.resolve_reference("https://example.com/external.yaml#/components/schemas/User");
         assert!(result.is_err());
 
         match result.unwrap_err() {
@@ -1222,7 +2404,7 @@ paths: {}
         parser.spec = Some(create_spec_with_schemas());
 
         let schema = OpenAPISchema {
-            schema_type: Some("string".to_string()),
+            schema_type: Some("string".into()),
             ..Default::default()
         };
         let schema_or_ref = OpenAPISchemaOrRef::Schema(Box::new(schema));
@@ -1231,7 +2413,7 @@ paths: {}
         assert!(result.is_ok());
 
         let resolved = result.unwrap();
-        assert_eq!(resolved.schema_type, Some("string".to_string()));
+        assert_eq!(resolved.schema_type, Some("string".into()));
     }
 
     #[test]
@@ -1247,11 +2429,33 @@ paths: {}
         assert!(result.is_ok());
 
         let resolved = result.unwrap();
-        assert_eq!(resolved.schema_type, Some("object".to_string()));
+        assert_eq!(resolved.schema_type, Some("object".into()));
         assert!(resolved.properties.contains_key("id"));
         assert!(resolved.properties.contains_key("name"));
     }
 
+    #[test]
+    fn test_resolve_schema_reference_keeps_component_name() {
+        let mut parser = OpenAPIParser::new();
+        parser.spec = Some(create_spec_with_schemas());
+
+        let reference = OpenAPISchemaOrRef::Reference(OpenAPIReference {
+            reference: "#/components/schemas/User".to_string(),
+        });
+        let resolved = parser.resolve_schema(&reference).unwrap();
+        assert_eq!(resolved.reference_name, Some("User".to_string()));
+
+        let inline = OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
+            schema_type: Some("object".into()),
+            ..Default::default()
+        }));
+        assert!(parser
+            .resolve_schema(&inline)
+            .unwrap()
+            .reference_name
+            .is_none());
+    }
+
     #[test]
     fn test_resolve_all_of_schema() {
         let mut parser = OpenAPIParser::new();
@@ -1265,7 +2469,7 @@ paths: {}
         assert!(result.is_ok());
 
         let resolved = result.unwrap();
-        assert_eq!(resolved.schema_type, Some("object".to_string()));
+        assert_eq!(resolved.schema_type, Some("object".into()));
 
         // Should have properties from both BaseEntity and the extension
         assert!(resolved.properties.contains_key("id"));
@@ -1293,7 +2497,7 @@ paths: {}
         assert!(result.is_ok());
 
         let resolved = result.unwrap();
-        assert_eq!(resolved.schema_type, Some("object".to_string()));
+        assert_eq!(resolved.schema_type, Some("object".into()));
 
         // Should have discriminator property
         assert!(resolved.properties.contains_key("petType"));
@@ -1318,6 +2522,94 @@ paths: {}
         assert_eq!(OpenAPIParser::extract_schema_name(""), "Unknown");
     }
 
+    #[test]
+    fn test_extract_schema_name_external_documents() {
+        assert_eq!(
+            OpenAPIParser::extract_schema_name("./common.yaml#/components/schemas/Error"),
+            "Error"
+        );
+        assert_eq!(
+            OpenAPIParser::extract_schema_name("../models/pet.yaml"),
+            "pet"
+        );
+        assert_eq!(OpenAPIParser::extract_schema_name("money.json#"), "money");
+    }
+
+    #[test]
+    fn test_normalize_path() {
+        assert_eq!(
+            OpenAPIParser::normalize_path(Path::new("/api/./specs/../common/errors.yaml")),
+            PathBuf::from("/api/common/errors.yaml")
+        );
+        assert_eq!(
+            OpenAPIParser::normalize_path(Path::new("../../shared.yaml")),
+            PathBuf::from("../../shared.yaml")
+        );
+    }
+
+    #[test]
+    fn test_resolve_uri() {
+        let base = Some("https://example.com/schemas/pet");
+        assert_eq!(
+            OpenAPIParser::resolve_uri(base, "owner"),
+            "https://example.com/schemas/owner"
+        );
+        assert_eq!(
+            OpenAPIParser::resolve_uri(base, "../common/money#/$defs/Amount"),
+            "https://example.com/common/money#/$defs/Amount"
+        );
+        assert_eq!(
+            OpenAPIParser::resolve_uri(base, "/shared/id"),
+            "https://example.com/shared/id"
+        );
+        assert_eq!(
+            OpenAPIParser::resolve_uri(base, "urn:example:owner"),
+            "urn:example:owner"
+        );
+        assert_eq!(OpenAPIParser::resolve_uri(None, "owner.json"), "owner.json");
+    }
+
+    #[test]
+    fn test_resolve_schema_ids() {
+        let mut document = serde_json::json!({
+            "components": {
+                "schemas": {
+                    "Pet": {
+                        "$id": "https://example.com/schemas/pet",
+                        "properties": {
+                            "owner": { "$ref": "owner" },
+                            "street": { "$ref": "#/$defs/Street" },
+                            "tag": { "$ref": "#anchor" }
+                        },
+                        "$defs": { "Street": { "type": "string" } }
+                    },
+                    "Owner": { "$id": "https://example.com/schemas/owner" },
+                    "Shelter": {
+                        "properties": { "pet": { "$ref": "https://example.com/schemas/pet" } }
+                    }
+                }
+            }
+        });
+
+        assert!(OpenAPIParser::resolve_schema_ids(&mut document));
+        let schemas = &document["components"]["schemas"];
+        let pet = &schemas["Pet"]["properties"];
+        assert_eq!(pet["owner"]["$ref"], "#/components/schemas/Owner");
+        assert_eq!(
+            pet["street"]["$ref"],
+            "#/components/schemas/Pet/$defs/Street"
+        );
+        assert_eq!(pet["tag"]["$ref"], "#anchor");
+        assert_eq!(
+            schemas["Shelter"]["properties"]["pet"]["$ref"],
+            "#/components/schemas/Pet"
+        );
+
+        // Documents without `$id`s are left alone
+        let mut document = serde_json::json!({ "$ref": "#/$defs/Street" });
+        assert!(!OpenAPIParser::resolve_schema_ids(&mut document));
+    }
+
     #[test]
     fn test_get_all_schemas() {
         let mut parser = OpenAPIParser::new();
@@ -1369,7 +2661,7 @@ paths: {}
         schemas.insert(
             "StringType".to_string(),
             OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
-                schema_type: Some("string".to_string()),
+                schema_type: Some("string".into()),
                 title: Some("StringType".to_string()),
                 ..Default::default()
             })),
@@ -1378,7 +2670,7 @@ paths: {}
         schemas.insert(
             "NumberType".to_string(),
             OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
-                schema_type: Some("number".to_string()),
+                schema_type: Some("number".into()),
                 title: Some("NumberType".to_string()),
                 ..Default::default()
             })),
@@ -1424,7 +2716,7 @@ paths: {}
         assert!(result.is_ok());
 
         let resolved = result.unwrap();
-        assert_eq!(resolved.schema_type, Some("object".to_string()));
+        assert_eq!(resolved.schema_type, Some("object".into()));
 
         // Should have anyOf variants stored
         assert!(resolved.any_of_variants.is_some());
@@ -1460,13 +2752,13 @@ paths: {}
         schemas.insert(
             "Base".to_string(),
             OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
-                schema_type: Some("object".to_string()),
+                schema_type: Some("object".into()),
                 properties: {
                     let mut props = IndexMap::new();
                     props.insert(
                         "id".to_string(),
                         OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
-                            schema_type: Some("string".to_string()),
+                            schema_type: Some("string".into()),
                             ..Default::default()
                         })),
                     );
@@ -1486,13 +2778,13 @@ paths: {}
                     }),
                     OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
                         all_of: vec![OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
-                            schema_type: Some("object".to_string()),
+                            schema_type: Some("object".into()),
                             properties: {
                                 let mut props = IndexMap::new();
                                 props.insert(
                                     "name".to_string(),
                                     OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
-                                        schema_type: Some("string".to_string()),
+                                        schema_type: Some("string".into()),
                                         ..Default::default()
                                     })),
                                 );
@@ -1547,7 +2839,7 @@ paths: {}
         schemas.insert(
             "A".to_string(),
             OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
-                schema_type: Some("object".to_string()),
+                schema_type: Some("object".into()),
                 properties: {
                     let mut props = IndexMap::new();
                     props.insert(
@@ -1566,7 +2858,7 @@ paths: {}
         schemas.insert(
             "B".to_string(),
             OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
-                schema_type: Some("object".to_string()),
+                schema_type: Some("object".into()),
                 properties: {
                     let mut props = IndexMap::new();
                     props.insert(
@@ -1585,13 +2877,13 @@ paths: {}
         schemas.insert(
             "C".to_string(),
             OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
-                schema_type: Some("object".to_string()),
+                schema_type: Some("object".into()),
                 properties: {
                     let mut props = IndexMap::new();
                     props.insert(
                         "value".to_string(),
                         OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
-                            schema_type: Some("string".to_string()),
+                            schema_type: Some("string".into()),
                             ..Default::default()
                         })),
                     );
@@ -1790,7 +3082,7 @@ paths: {}
         schemas.insert(
             "DirectSchema".to_string(),
             OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
-                schema_type: Some("string".to_string()),
+                schema_type: Some("string".into()),
                 ..Default::default()
             })),
         );
@@ -1823,7 +3115,7 @@ paths: {}
         assert!(result.is_ok());
 
         let resolved = result.unwrap();
-        assert_eq!(resolved.schema_type, Some("string".to_string()));
+        assert_eq!(resolved.schema_type, Some("string".into()));
     }
 
     #[test]
@@ -1836,7 +3128,7 @@ paths: {}
         schemas.insert(
             "BaseWithMeta".to_string(),
             OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
-                schema_type: Some("object".to_string()),
+                schema_type: Some("object".into()),
                 title: Some("Base Schema".to_string()),
                 description: Some("Base description".to_string()),
                 example: Some(serde_json::json!({"base": "example"})),
@@ -1845,7 +3137,7 @@ paths: {}
                     props.insert(
                         "id".to_string(),
                         OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
-                            schema_type: Some("string".to_string()),
+                            schema_type: Some("string".into()),
                             ..Default::default()
                         })),
                     );
@@ -1864,13 +3156,13 @@ paths: {}
                         reference: "#/components/schemas/BaseWithMeta".to_string(),
                     }),
                     OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
-                        schema_type: Some("object".to_string()),
+                        schema_type: Some("object".into()),
                         properties: {
                             let mut props = IndexMap::new();
                             props.insert(
                                 "name".to_string(),
                                 OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
-                                    schema_type: Some("string".to_string()),
+                                    schema_type: Some("string".into()),
                                     ..Default::default()
                                 })),
                             );
@@ -1940,6 +3232,29 @@ paths: {}
         assert!(variant_names.contains(&&"Cat".to_string()));
     }
 
+    #[test]
+    fn test_resolve_one_of_variant_names_fall_back_to_component_name() {
+        let mut parser = OpenAPIParser::new();
+        let mut spec = create_spec_with_one_of();
+        if let Some(components) = spec.components.as_mut() {
+            for name in ["Dog", "Cat"] {
+                if let Some(OpenAPISchemaOrRef::Schema(schema)) = components.schemas.get_mut(name) {
+                    schema.title = None;
+                }
+            }
+        }
+        parser.spec = Some(spec);
+
+        let reference = OpenAPISchemaOrRef::Reference(OpenAPIReference {
+            reference: "#/components/schemas/Pet".to_string(),
+        });
+
+        let resolved = parser.resolve_schema(&reference).unwrap();
+        let variants = resolved.one_of_variants.unwrap();
+        let variant_names: Vec<&str> = variants.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(variant_names, vec!["Dog", "Cat"]);
+    }
+
     #[test]
     fn test_resolve_any_of_variant_names() {
         let mut parser = OpenAPIParser::new();
@@ -1950,7 +3265,7 @@ paths: {}
         schemas.insert(
             "TypeA".to_string(),
             OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
-                schema_type: Some("string".to_string()),
+                schema_type: Some("string".into()),
                 // No title - should generate "Option1"
                 ..Default::default()
             })),
@@ -1959,7 +3274,7 @@ paths: {}
         schemas.insert(
             "TypeB".to_string(),
             OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
-                schema_type: Some("number".to_string()),
+                schema_type: Some("number".into()),
                 // No title - should generate "Option2"
                 ..Default::default()
             })),
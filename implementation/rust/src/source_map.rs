@@ -0,0 +1,659 @@
+use std::collections::HashMap;
+
+use crate::errors::ErrorContext;
+
+/// Position of a node in a document. Lines and columns count from 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SourceLocation {
+    pub line: u32,
+    pub column: u32,
+}
+
+/// Maps the JSON pointers of a JSON or YAML document to where their nodes start.
+///
+/// Mapping members are located at their key and sequence items at their first
+/// character, or at the `-` of a block sequence. Only the structure of the document is
+/// followed: JSON is read as YAML flow style, while scalars, anchors and tags are
+/// skipped over. Parts the scanner can't follow are located at their closest ancestor.
+///
+/// # Examples
+///
+/// ```rust
+/// use openapi_codegen_rust::source_map::{SourceLocation, SourceMap};
+///
+/// let map = SourceMap::new("api.yaml", "info:\n  title: Pets\ntags: [a, b]\n");
+/// assert_eq!(map.location("/info/title"), Some(SourceLocation { line: 2, column: 3 }));
+/// assert_eq!(map.location("/tags/1"), Some(SourceLocation { line: 3, column: 11 }));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SourceMap {
+    file: String,
+    lines: Vec<String>,
+    locations: HashMap<String, SourceLocation>,
+}
+
+impl SourceMap {
+    /// Scans `content`, the text of `file`, for the locations of its nodes.
+    pub fn new<F: Into<String>>(file: F, content: &str) -> Self {
+        let lines: Vec<Vec<char>> = content.lines().map(|line| line.chars().collect()).collect();
+        let mut scanner = Scanner {
+            lines: &lines,
+            locations: HashMap::new(),
+        };
+        scanner.scan_document();
+
+        Self {
+            file: file.into(),
+            lines: content.lines().map(str::to_string).collect(),
+            locations: scanner.locations,
+        }
+    }
+
+    /// Where the node at `pointer` starts, if it appears in the document.
+    pub fn location(&self, pointer: &str) -> Option<SourceLocation> {
+        self.locations.get(pointer).copied()
+    }
+
+    /// Where the node at `pointer` starts, or else its closest ancestor that does appear
+    /// in the document, e.g. the parent object of a missing field.
+    pub fn nearest_location(&self, pointer: &str) -> Option<SourceLocation> {
+        let mut pointer = pointer;
+        loop {
+            if let Some(location) = self.location(pointer) {
+                return Some(location);
+            }
+            pointer = pointer.rsplit_once('/')?.0;
+        }
+    }
+
+    /// Error context for a position in the document, with the line at that position.
+    pub fn context_at(&self, line: u32, column: u32) -> ErrorContext {
+        let context = ErrorContext::new()
+            .with_file(&self.file)
+            .with_location(line, column.max(1));
+
+        match line
+            .checked_sub(1)
+            .and_then(|index| self.lines.get(index as usize))
+        {
+            Some(text) => context.with_source_line(text),
+            None => context,
+        }
+    }
+
+    /// Error context for the node at `pointer`, located as by [`Self::nearest_location`].
+    pub fn context(&self, pointer: &str) -> ErrorContext {
+        let path = pointer.split('/').skip(1).map(unescape).collect();
+
+        match self.nearest_location(pointer) {
+            Some(location) => self
+                .context_at(location.line, location.column)
+                .with_path(path),
+            None => ErrorContext::new().with_file(&self.file).with_path(path),
+        }
+    }
+}
+
+/// Escapes a key for use as a JSON pointer segment.
+pub(crate) fn escape(key: &str) -> String {
+    key.replace('~', "~0").replace('/', "~1")
+}
+
+/// Returns the key a JSON pointer segment stands for, undoing [`escape`].
+pub(crate) fn unescape(segment: &str) -> String {
+    segment.replace("~1", "/").replace("~0", "~")
+}
+
+/// Zero-based position while scanning.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Position {
+    line: usize,
+    column: usize,
+}
+
+enum Container {
+    Mapping,
+    /// A sequence with the index of its next item.
+    Sequence(usize),
+}
+
+/// A block collection being scanned, with the indentation of its entries.
+struct Frame {
+    indent: usize,
+    pointer: String,
+    container: Container,
+}
+
+struct Scanner<'a> {
+    lines: &'a [Vec<char>],
+    locations: HashMap<String, SourceLocation>,
+}
+
+impl Scanner<'_> {
+    /// Records the first location of `pointer`; duplicate keys keep the first one.
+    fn record(&mut self, pointer: &str, position: Position) {
+        self.locations
+            .entry(pointer.to_string())
+            .or_insert(SourceLocation {
+                line: position.line as u32 + 1,
+                column: position.column as u32 + 1,
+            });
+    }
+
+    fn scan_document(&mut self) {
+        let mut frames: Vec<Frame> = Vec::new();
+        // A key or item whose value starts on a following line, with its indentation
+        let mut pending: Option<(String, usize)> = None;
+        let mut line = 0;
+
+        while line < self.lines.len() {
+            let text = &self.lines[line];
+            let indent = text.iter().take_while(|c| **c == ' ').count();
+            let rest = &text[indent..];
+            let is_marker = indent == 0
+                && (rest.starts_with(&['-', '-', '-']) || rest.starts_with(&['.', '.', '.']))
+                && rest.get(3).is_none_or(|c| c.is_whitespace());
+            if rest.is_empty() || rest[0] == '#' || rest[0] == '%' || is_marker {
+                line += 1;
+                continue;
+            }
+
+            let item = is_sequence_item(rest);
+            if let Some((pointer, parent_indent)) = pending.take() {
+                // Block sequences may sit at the indentation of their key
+                if indent > parent_indent || (indent == parent_indent && item) {
+                    frames.push(Frame {
+                        indent,
+                        pointer,
+                        container: if item {
+                            Container::Sequence(0)
+                        } else {
+                            Container::Mapping
+                        },
+                    });
+                }
+            }
+
+            while frames.last().is_some_and(|frame| {
+                frame.indent > indent
+                    || (frame.indent == indent
+                        && matches!(frame.container, Container::Sequence(_))
+                        && !item)
+            }) {
+                frames.pop();
+            }
+
+            let position = Position {
+                line,
+                column: indent,
+            };
+            if frames.is_empty() {
+                self.record("", position);
+                if matches!(rest[0], '{' | '[') {
+                    self.scan_flow(position, "");
+                    return;
+                }
+                frames.push(Frame {
+                    indent,
+                    pointer: String::new(),
+                    container: if item {
+                        Container::Sequence(0)
+                    } else {
+                        Container::Mapping
+                    },
+                });
+            }
+
+            // A deeper line continues a multi-line scalar
+            line = if frames.last().is_some_and(|frame| frame.indent == indent) {
+                self.scan_entry(&mut frames, &mut pending, position)
+            } else {
+                line + 1
+            };
+        }
+    }
+
+    /// Scans the entry of the innermost block collection starting at `position`, and
+    /// returns the line to continue with.
+    fn scan_entry(
+        &mut self,
+        frames: &mut Vec<Frame>,
+        pending: &mut Option<(String, usize)>,
+        position: Position,
+    ) -> usize {
+        let text = &self.lines[position.line];
+        let frame = frames.last_mut().expect("an entry belongs to a collection");
+
+        let (pointer, value_column) = match &mut frame.container {
+            Container::Sequence(next) if is_sequence_item(&text[position.column..]) => {
+                let pointer = format!("{}/{}", frame.pointer, next);
+                *next += 1;
+                self.record(&pointer, position);
+
+                // A collection may start on the line of its item: `- name: x` or `- - x`
+                let value_column = skip_spaces(text, position.column + 1);
+                let value = &text[value_column..];
+                let container = if is_sequence_item(value) {
+                    Some(Container::Sequence(0))
+                } else {
+                    mapping_key(text, value_column).map(|_| Container::Mapping)
+                };
+                if let Some(container) = container {
+                    frames.push(Frame {
+                        indent: value_column,
+                        pointer,
+                        container,
+                    });
+                    return self.scan_entry(
+                        frames,
+                        pending,
+                        Position {
+                            line: position.line,
+                            column: value_column,
+                        },
+                    );
+                }
+                (pointer, value_column)
+            }
+            Container::Mapping => {
+                let Some((key, separator)) = mapping_key(text, position.column) else {
+                    return position.line + 1;
+                };
+                let pointer = format!("{}/{}", frame.pointer, escape(&key));
+                self.record(&pointer, position);
+                (pointer, skip_spaces(text, separator + 1))
+            }
+            Container::Sequence(_) => return position.line + 1,
+        };
+
+        // Anchors and tags come before the value
+        let mut column = value_column;
+        while matches!(text.get(column), Some('&' | '!')) {
+            while text.get(column).is_some_and(|c| !c.is_whitespace()) {
+                column += 1;
+            }
+            column = skip_spaces(text, column);
+        }
+
+        match text.get(column) {
+            None | Some('#') => {
+                *pending = Some((pointer, position.column));
+                position.line + 1
+            }
+            Some('|' | '>') => {
+                // Block scalar lines are indented deeper than their key, or blank
+                let mut line = position.line + 1;
+                while self.lines.get(line).is_some_and(|text| {
+                    text.iter().all(|c| c.is_whitespace())
+                        || text.iter().take_while(|c| **c == ' ').count() > position.column
+                }) {
+                    line += 1;
+                }
+                line
+            }
+            Some('[' | '{') => {
+                let end = self.scan_flow(
+                    Position {
+                        line: position.line,
+                        column,
+                    },
+                    &pointer,
+                );
+                end.line + 1
+            }
+            Some(_) => position.line + 1,
+        }
+    }
+
+    /// Character at `position`, with `\n` at the end of every line but the last.
+    fn peek(&self, position: Position) -> Option<char> {
+        let text = self.lines.get(position.line)?;
+        match text.get(position.column) {
+            Some(c) => Some(*c),
+            None if position.line + 1 < self.lines.len() => Some('\n'),
+            None => None,
+        }
+    }
+
+    fn advance(&self, position: Position) -> Position {
+        if position.column < self.lines[position.line].len() {
+            Position {
+                column: position.column + 1,
+                ..position
+            }
+        } else {
+            Position {
+                line: position.line + 1,
+                column: 0,
+            }
+        }
+    }
+
+    /// Skips whitespace, line breaks and comments between flow tokens.
+    fn skip_whitespace(&self, mut position: Position) -> Position {
+        while let Some(c) = self.peek(position) {
+            if c == '#' {
+                position = Position {
+                    column: self.lines[position.line].len(),
+                    ..position
+                };
+            } else if c.is_whitespace() {
+                position = self.advance(position);
+            } else {
+                break;
+            }
+        }
+        position
+    }
+
+    /// Scans the flow node, or JSON value, starting at `position` and returns the
+    /// position after it.
+    fn scan_flow(&mut self, position: Position, pointer: &str) -> Position {
+        match self.peek(position) {
+            Some('{') => {
+                let mut position = self.advance(position);
+                loop {
+                    position = self.skip_whitespace(position);
+                    match self.peek(position) {
+                        None => return position,
+                        Some('}') => return self.advance(position),
+                        Some(',') => {
+                            position = self.advance(position);
+                            continue;
+                        }
+                        _ => {}
+                    }
+
+                    let start = position;
+                    let (key, after_key) = self.flow_key(start);
+                    let member = format!("{pointer}/{}", escape(&key));
+                    self.record(&member, start);
+
+                    position = self.skip_whitespace(after_key);
+                    if self.peek(position) == Some(':') {
+                        let value = self.skip_whitespace(self.advance(position));
+                        position = self.scan_flow(value, &member);
+                    }
+                    if position == start {
+                        position = self.advance(position);
+                    }
+                }
+            }
+            Some('[') => {
+                let mut position = self.advance(position);
+                let mut index = 0;
+                loop {
+                    position = self.skip_whitespace(position);
+                    match self.peek(position) {
+                        None => return position,
+                        Some(']') => return self.advance(position),
+                        Some(',') => {
+                            position = self.advance(position);
+                            continue;
+                        }
+                        _ => {}
+                    }
+
+                    let item = format!("{pointer}/{index}");
+                    self.record(&item, position);
+                    index += 1;
+
+                    let next = self.scan_flow(position, &item);
+                    position = if next == position {
+                        self.advance(position)
+                    } else {
+                        next
+                    };
+                }
+            }
+            Some(quote @ ('"' | '\'')) => self.quoted_scalar(position, quote).1,
+            Some(_) => {
+                let mut position = position;
+                while let Some(c) = self.peek(position) {
+                    let ends_value = matches!(c, ',' | ']' | '}' | '\n')
+                        || (c == ':' && self.peek(self.advance(position)) == Some(' '));
+                    if ends_value {
+                        break;
+                    }
+                    position = self.advance(position);
+                }
+                position
+            }
+            None => position,
+        }
+    }
+
+    /// Reads the key of a flow mapping member and returns it with the position after it.
+    fn flow_key(&self, position: Position) -> (String, Position) {
+        if let Some(quote @ ('"' | '\'')) = self.peek(position) {
+            return self.quoted_scalar(position, quote);
+        }
+
+        let mut key = String::new();
+        let mut end = position;
+        while let Some(c) = self.peek(end) {
+            if matches!(c, ':' | ',' | '}' | '\n') {
+                break;
+            }
+            key.push(c);
+            end = self.advance(end);
+        }
+        (key.trim_end().to_string(), end)
+    }
+
+    /// Reads a quoted scalar, which may span lines, and returns its value with the
+    /// position after the closing quote.
+    fn quoted_scalar(&self, start: Position, quote: char) -> (String, Position) {
+        let mut raw = String::from(quote);
+        let mut position = self.advance(start);
+        while let Some(c) = self.peek(position) {
+            raw.push(c);
+            position = self.advance(position);
+            if c == '\\' && quote == '"' {
+                if let Some(escaped) = self.peek(position) {
+                    raw.push(escaped);
+                    position = self.advance(position);
+                }
+            } else if c == quote {
+                if quote == '\'' && self.peek(position) == Some('\'') {
+                    position = self.advance(position);
+                    continue;
+                }
+                break;
+            }
+        }
+        (unquote(&raw), position)
+    }
+}
+
+/// Whether a line's content starts a block sequence item.
+fn is_sequence_item(content: &[char]) -> bool {
+    content.first() == Some(&'-') && content.get(1).is_none_or(|c| *c == ' ')
+}
+
+fn skip_spaces(text: &[char], mut column: usize) -> usize {
+    while text.get(column).is_some_and(|c| *c == ' ' || *c == '\t') {
+        column += 1;
+    }
+    column
+}
+
+/// Reads the key of a block mapping entry starting at `column`, returning it with the
+/// column of the `:` that follows. Lines without a key, such as plain scalars that
+/// continue a value, give `None`.
+fn mapping_key(text: &[char], column: usize) -> Option<(String, usize)> {
+    let is_separator = |index: usize| {
+        text.get(index) == Some(&':') && text.get(index + 1).is_none_or(|c| c.is_whitespace())
+    };
+
+    match text.get(column)? {
+        quote @ ('"' | '\'') => {
+            let mut end = column + 1;
+            while end < text.len() {
+                if text[end] == '\\' && *quote == '"' {
+                    end += 2;
+                    continue;
+                }
+                if text[end] == *quote {
+                    if *quote == '\'' && text.get(end + 1) == Some(&'\'') {
+                        end += 2;
+                        continue;
+                    }
+                    break;
+                }
+                end += 1;
+            }
+            let raw: String = text.get(column..=end)?.iter().collect();
+            let separator = skip_spaces(text, end + 1);
+            is_separator(separator).then(|| (unquote(&raw), separator))
+        }
+        '[' | '{' | '?' | '#' | '|' | '>' => None,
+        _ => {
+            let mut end = column;
+            while end < text.len() {
+                if text[end] == '#' && end > column && text[end - 1].is_whitespace() {
+                    return None;
+                }
+                if is_separator(end) {
+                    let key: String = text[column..end].iter().collect();
+                    return Some((key.trim_end().to_string(), end));
+                }
+                end += 1;
+            }
+            None
+        }
+    }
+}
+
+/// Value of a quoted scalar; double-quoted escapes are read as JSON escapes.
+fn unquote(raw: &str) -> String {
+    if raw.starts_with('"') {
+        serde_json::from_str(raw).unwrap_or_else(|_| {
+            raw.trim_start_matches('"')
+                .trim_end_matches('"')
+                .to_string()
+        })
+    } else {
+        let inner = raw.strip_prefix('\'').unwrap_or(raw);
+        inner.strip_suffix('\'').unwrap_or(inner).replace("''", "'")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(line: u32, column: u32) -> Option<SourceLocation> {
+        Some(SourceLocation { line, column })
+    }
+
+    #[test]
+    fn test_yaml_block_locations() {
+        let map = SourceMap::new(
+            "api.yaml",
+            "openapi: 3.0.3\n\
+             info:\n\
+             \x20 title: Pets  # the title\n\
+             \x20 description: |\n\
+             \x20   key: not a key\n\
+             \n\
+             \x20   still text\n\
+             \x20 version: '1'\n\
+             paths:\n\
+             \x20 /pets/{id}:\n\
+             \x20   get:\n\
+             \x20     parameters:\n\
+             \x20     - name: id\n\
+             \x20       in: path\n\
+             \x20     - $ref: '#/components/parameters/Limit'\n\
+             \x20     tags:\n\
+             \x20       - pets\n\
+             \x20       -\n\
+             \x20         nested: true\n\
+             \"quoted: key\": &anchor\n\
+             \x20 a~b: 1\n",
+        );
+
+        assert_eq!(map.location(""), at(1, 1));
+        assert_eq!(map.location("/info/title"), at(3, 3));
+        assert_eq!(map.location("/info/version"), at(8, 3));
+        assert_eq!(map.location("/info/description/key"), None);
+        assert_eq!(map.location("/paths/~1pets~1{id}/get"), at(11, 5));
+        assert_eq!(
+            map.location("/paths/~1pets~1{id}/get/parameters/0"),
+            at(13, 7)
+        );
+        assert_eq!(
+            map.location("/paths/~1pets~1{id}/get/parameters/0/in"),
+            at(14, 9)
+        );
+        assert_eq!(
+            map.location("/paths/~1pets~1{id}/get/parameters/1/$ref"),
+            at(15, 9)
+        );
+        assert_eq!(map.location("/paths/~1pets~1{id}/get/tags/1"), at(18, 9));
+        assert_eq!(
+            map.location("/paths/~1pets~1{id}/get/tags/1/nested"),
+            at(19, 11)
+        );
+        assert_eq!(map.location("/quoted: key/a~0b"), at(21, 3));
+    }
+
+    #[test]
+    fn test_yaml_sequence_at_key_indentation() {
+        let map = SourceMap::new("api.yaml", "tags:\n- a\n- b\nnext: 1\n");
+
+        assert_eq!(map.location("/tags/1"), at(3, 1));
+        assert_eq!(map.location("/next"), at(4, 1));
+    }
+
+    #[test]
+    fn test_json_and_flow_locations() {
+        let map = SourceMap::new(
+            "api.json",
+            "{\n  \"info\": {\"title\": \"a, \\\"b\\\"\", \"version\": \"1\"},\n  \"tags\": [\n    {\"name\": \"x\"},\n    \"y\"\n  ],\n  \"a/b\": null\n}\n",
+        );
+
+        assert_eq!(map.location(""), at(1, 1));
+        assert_eq!(map.location("/info/title"), at(2, 12));
+        assert_eq!(map.location("/info/version"), at(2, 33));
+        assert_eq!(map.location("/tags/0/name"), at(4, 6));
+        assert_eq!(map.location("/tags/1"), at(5, 5));
+        assert_eq!(map.location("/a~1b"), at(7, 3));
+
+        let map = SourceMap::new("api.yaml", "enum: [a, 'b''c', {x: 1}]\n");
+        assert_eq!(map.location("/enum/2/x"), at(1, 20));
+    }
+
+    #[test]
+    fn test_nearest_location_and_context() {
+        let map = SourceMap::new("api.yaml", "info:\n  title: Pets\n");
+
+        assert_eq!(map.nearest_location("/info/version"), at(1, 1));
+
+        let context = map.context("/info/title");
+        assert_eq!(context.file.as_deref(), Some("api.yaml"));
+        assert_eq!((context.line, context.column), (Some(2), Some(3)));
+        assert_eq!(context.source_line.as_deref(), Some("  title: Pets"));
+        assert_eq!(context.schema_path, vec!["info", "title"]);
+        assert_eq!(
+            context.to_string(),
+            "\n --> api.yaml:2:3\n  |\n2 |   title: Pets\n  |   ^^^^^^^^^^^"
+        );
+    }
+
+    #[test]
+    fn test_malformed_documents_do_not_panic() {
+        for content in [
+            "{",
+            "[1, ",
+            "a: [\n",
+            "- - -",
+            "\"a: b",
+            "key: 'open",
+            ": x",
+        ] {
+            SourceMap::new("broken.yaml", content);
+        }
+    }
+}
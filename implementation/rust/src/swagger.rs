@@ -0,0 +1,1041 @@
+//! Upconversion of Swagger 2.0 documents to OpenAPI 3.0.
+//!
+//! The conversion works on untyped documents, so its result can be written out as
+//! well as parsed into an [`OpenAPISpec`](crate::types::OpenAPISpec). Objects move to
+//! where OpenAPI 3 expects them and references are rewritten to match:
+//!
+//! - `definitions` become `components/schemas`
+//! - `in: body` and `in: formData` parameters become request bodies
+//! - `consumes` and `produces` become the media types of request and response content
+//! - `host`, `basePath` and `schemes` become `servers`
+//! - `securityDefinitions` become `components/securitySchemes`
+
+use serde_json::{json, Map, Value};
+
+/// OpenAPI version given to converted documents.
+pub const CONVERTED_OPENAPI_VERSION: &str = "3.0.3";
+
+/// Keywords of a Swagger 2.0 parameter or header that belong in its OpenAPI 3 schema
+const SCHEMA_KEYWORDS: &[&str] = &[
+    "type",
+    "format",
+    "items",
+    "default",
+    "maximum",
+    "exclusiveMaximum",
+    "minimum",
+    "exclusiveMinimum",
+    "maxLength",
+    "minLength",
+    "pattern",
+    "maxItems",
+    "minItems",
+    "uniqueItems",
+    "enum",
+    "multipleOf",
+];
+
+const FORM_MEDIA_TYPES: &[&str] = &["application/x-www-form-urlencoded", "multipart/form-data"];
+
+/// Whether a document declares `swagger: "2.0"`.
+pub fn is_swagger2(document: &Value) -> bool {
+    match document.get("swagger") {
+        Some(Value::String(version)) => version == "2.0",
+        // An unquoted `swagger: 2.0` in YAML is a number
+        Some(Value::Number(version)) => version.as_f64() == Some(2.0),
+        _ => false,
+    }
+}
+
+/// Converts a Swagger 2.0 document to an equivalent OpenAPI 3.0 document.
+///
+/// # Examples
+///
+/// ```rust
+/// use openapi_codegen_rust::swagger;
+/// use serde_json::json;
+///
+/// let document = json!({
+///     "swagger": "2.0",
+///     "info": { "title": "Pets", "version": "1.0.0" },
+///     "host": "api.example.com",
+///     "basePath": "/v1",
+///     "paths": {},
+///     "definitions": { "Pet": { "type": "object" } }
+/// });
+///
+/// let converted = swagger::upconvert(&document);
+/// assert_eq!(converted["servers"][0]["url"], "https://api.example.com/v1");
+/// assert!(converted["components"]["schemas"]["Pet"].is_object());
+/// ```
+pub fn upconvert(document: &Value) -> Value {
+    let empty = Map::new();
+    let document = document.as_object().unwrap_or(&empty);
+
+    Converter {
+        document,
+        consumes: media_types(document.get("consumes")),
+        produces: media_types(document.get("produces")),
+    }
+    .convert()
+}
+
+struct Converter<'a> {
+    document: &'a Map<String, Value>,
+    /// Media types of request bodies, unless an operation declares its own
+    consumes: Vec<String>,
+    /// Media types of responses, unless an operation declares its own
+    produces: Vec<String>,
+}
+
+impl Converter<'_> {
+    fn convert(&self) -> Value {
+        let mut converted = Map::new();
+        converted.insert("openapi".to_string(), json!(CONVERTED_OPENAPI_VERSION));
+
+        for (key, value) in self.document {
+            match key.as_str() {
+                "swagger" => {}
+                "host" | "basePath" | "schemes" => {
+                    if !converted.contains_key("servers") {
+                        let servers = self.servers();
+                        if !servers.is_empty() {
+                            converted.insert("servers".to_string(), Value::Array(servers));
+                        }
+                    }
+                }
+                "consumes" | "produces" => {}
+                "paths" => {
+                    let paths =
+                        self.convert_map(value, |path_item| self.convert_path_item(path_item));
+                    converted.insert(key.clone(), paths);
+                }
+                "definitions" | "parameters" | "responses" | "securityDefinitions" => {
+                    if !converted.contains_key("components") {
+                        let components = self.components();
+                        if !components.is_empty() {
+                            converted.insert("components".to_string(), Value::Object(components));
+                        }
+                    }
+                }
+                _ => {
+                    converted.insert(key.clone(), value.clone());
+                }
+            }
+        }
+
+        Value::Object(converted)
+    }
+
+    /// Builds the servers from `host`, `basePath` and `schemes`. Without a host, the
+    /// base path is a server URL relative to the document.
+    fn servers(&self) -> Vec<Value> {
+        let base_path = self
+            .document
+            .get("basePath")
+            .and_then(Value::as_str)
+            .unwrap_or("");
+
+        match self.document.get("host").and_then(Value::as_str) {
+            Some(host) => {
+                let schemes = match self.document.get("schemes").and_then(Value::as_array) {
+                    Some(schemes) if !schemes.is_empty() => schemes.clone(),
+                    _ => vec![json!("https")],
+                };
+                schemes
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .map(|scheme| json!({ "url": format!("{scheme}://{host}{base_path}") }))
+                    .collect()
+            }
+            None if !base_path.is_empty() => vec![json!({ "url": base_path })],
+            None => Vec::new(),
+        }
+    }
+
+    fn components(&self) -> Map<String, Value> {
+        let mut components = Map::new();
+        let mut insert = |section: &str, entries: Map<String, Value>| {
+            if !entries.is_empty() {
+                components.insert(section.to_string(), Value::Object(entries));
+            }
+        };
+
+        let definitions = self.entries("definitions");
+        insert(
+            "schemas",
+            definitions
+                .map(|(name, schema)| (name.clone(), self.convert_schema(schema)))
+                .collect(),
+        );
+
+        // Form parameters only exist as part of an operation's form, where they are inlined
+        let mut parameters = Map::new();
+        let mut request_bodies = Map::new();
+        for (name, parameter) in self.entries("parameters") {
+            let Some(parameter) = parameter.as_object() else {
+                continue;
+            };
+            match parameter.get("in").and_then(Value::as_str) {
+                Some("body") => {
+                    let request_body = self.convert_body_parameter(parameter, &self.consumes);
+                    request_bodies.insert(name.clone(), request_body);
+                }
+                Some("formData") => {}
+                _ => {
+                    let parameter = self.convert_parameter(&Value::Object(parameter.clone()));
+                    parameters.insert(name.clone(), parameter);
+                }
+            }
+        }
+        insert("parameters", parameters);
+        insert("requestBodies", request_bodies);
+
+        insert(
+            "responses",
+            self.entries("responses")
+                .map(|(name, response)| {
+                    (
+                        name.clone(),
+                        self.convert_response(response, &self.produces),
+                    )
+                })
+                .collect(),
+        );
+        insert(
+            "securitySchemes",
+            self.entries("securityDefinitions")
+                .map(|(name, scheme)| (name.clone(), self.convert_security_scheme(scheme)))
+                .collect(),
+        );
+
+        components
+    }
+
+    fn entries(&self, key: &str) -> impl Iterator<Item = (&String, &Value)> {
+        self.document
+            .get(key)
+            .and_then(Value::as_object)
+            .into_iter()
+            .flatten()
+    }
+
+    fn convert_map(&self, value: &Value, convert: impl Fn(&Value) -> Value) -> Value {
+        match value.as_object() {
+            Some(map) => Value::Object(
+                map.iter()
+                    .map(|(key, value)| (key.clone(), convert(value)))
+                    .collect(),
+            ),
+            None => value.clone(),
+        }
+    }
+
+    /// Converts a path item. Body and form parameters shared by its operations move
+    /// into each operation's request body.
+    fn convert_path_item(&self, path_item: &Value) -> Value {
+        let Some(path_item) = path_item.as_object() else {
+            return path_item.clone();
+        };
+
+        let (shared_parameters, shared_payload) =
+            self.split_parameters(path_item.get("parameters"));
+
+        let mut converted = Map::new();
+        for (key, value) in path_item {
+            match key.as_str() {
+                "get" | "put" | "post" | "delete" | "options" | "head" | "patch" => {
+                    converted.insert(key.clone(), self.convert_operation(value, &shared_payload));
+                }
+                "parameters" => {
+                    if !shared_parameters.is_empty() {
+                        converted.insert(key.clone(), Value::Array(shared_parameters.clone()));
+                    }
+                }
+                _ => {
+                    converted.insert(key.clone(), value.clone());
+                }
+            }
+        }
+
+        Value::Object(converted)
+    }
+
+    /// Splits a parameter list into converted parameters and the body and form
+    /// parameters, resolved when given as references.
+    fn split_parameters(
+        &self,
+        parameters: Option<&Value>,
+    ) -> (Vec<Value>, Vec<Map<String, Value>>) {
+        let mut converted = Vec::new();
+        let mut payload = Vec::new();
+
+        for parameter in parameters.and_then(Value::as_array).into_iter().flatten() {
+            let resolved = self.resolve_parameter(parameter);
+            match resolved
+                .and_then(|parameter| parameter.get("in"))
+                .and_then(Value::as_str)
+            {
+                Some("formData") => payload.extend(resolved.cloned()),
+                // Body parameters given by reference become request body references
+                Some("body") if parameter.get("$ref").is_none() => {
+                    payload.extend(resolved.cloned())
+                }
+                Some("body") => {
+                    let mut reference = Map::new();
+                    reference.insert("in".to_string(), json!("body"));
+                    reference.insert("$ref".to_string(), parameter["$ref"].clone());
+                    payload.push(reference);
+                }
+                _ => converted.push(self.convert_parameter(parameter)),
+            }
+        }
+
+        (converted, payload)
+    }
+
+    fn convert_operation(&self, operation: &Value, shared_payload: &[Map<String, Value>]) -> Value {
+        let Some(operation) = operation.as_object() else {
+            return operation.clone();
+        };
+
+        let consumes = match operation.get("consumes") {
+            Some(consumes) => media_types(Some(consumes)),
+            None => self.consumes.clone(),
+        };
+        let produces = match operation.get("produces") {
+            Some(produces) => media_types(Some(produces)),
+            None => self.produces.clone(),
+        };
+
+        let (parameters, mut payload) = self.split_parameters(operation.get("parameters"));
+        // Operation parameters override the path item's ones with the same name and location
+        let overridden = |shared: &Map<String, Value>| {
+            payload.iter().any(|parameter| {
+                parameter.get("in") == shared.get("in")
+                    && (parameter.get("in") == Some(&json!("body"))
+                        || parameter.get("name") == shared.get("name"))
+            })
+        };
+        let shared: Vec<_> = shared_payload
+            .iter()
+            .filter(|shared| !overridden(shared))
+            .cloned()
+            .collect();
+        payload.extend(shared);
+        let request_body = self.convert_payload(&payload, &consumes);
+
+        let mut converted = Map::new();
+        for (key, value) in operation {
+            match key.as_str() {
+                "consumes" | "produces" | "schemes" => {}
+                "parameters" => {
+                    if !parameters.is_empty() {
+                        converted.insert(key.clone(), Value::Array(parameters.clone()));
+                    }
+                }
+                "responses" => {
+                    if let Some(request_body) = &request_body {
+                        converted.insert("requestBody".to_string(), request_body.clone());
+                    }
+                    let responses = self
+                        .convert_map(value, |response| self.convert_response(response, &produces));
+                    converted.insert(key.clone(), responses);
+                }
+                _ => {
+                    converted.insert(key.clone(), value.clone());
+                }
+            }
+        }
+        if let Some(request_body) = request_body {
+            converted.entry("requestBody").or_insert(request_body);
+        }
+
+        Value::Object(converted)
+    }
+
+    /// Builds the request body for the body parameter or the form parameters of an
+    /// operation.
+    fn convert_payload(
+        &self,
+        payload: &[Map<String, Value>],
+        consumes: &[String],
+    ) -> Option<Value> {
+        if let Some(body) = payload
+            .iter()
+            .find(|parameter| parameter.get("in") == Some(&json!("body")))
+        {
+            return Some(match body.get("$ref").and_then(Value::as_str) {
+                Some(reference) => json!({ "$ref": self.convert_reference(reference) }),
+                None => self.convert_body_parameter(body, consumes),
+            });
+        }
+        if payload.is_empty() {
+            return None;
+        }
+
+        let mut properties = Map::new();
+        let mut required = Vec::new();
+        let mut has_file = false;
+        for parameter in payload {
+            let Some(name) = parameter.get("name").and_then(Value::as_str) else {
+                continue;
+            };
+            has_file |= parameter.get("type") == Some(&json!("file"));
+            if parameter.get("required") == Some(&json!(true)) {
+                required.push(json!(name));
+            }
+
+            let mut schema = self.parameter_schema(parameter);
+            if let Some(description) = parameter.get("description") {
+                schema.insert("description".to_string(), description.clone());
+            }
+            properties.insert(name.to_string(), Value::Object(schema));
+        }
+
+        let mut schema = Map::new();
+        schema.insert("type".to_string(), json!("object"));
+        schema.insert("properties".to_string(), Value::Object(properties));
+        if !required.is_empty() {
+            schema.insert("required".to_string(), Value::Array(required));
+        }
+
+        let mut media_types: Vec<&str> = consumes
+            .iter()
+            .map(String::as_str)
+            .filter(|media_type| FORM_MEDIA_TYPES.contains(media_type))
+            .collect();
+        if media_types.is_empty() {
+            media_types.push(if has_file {
+                "multipart/form-data"
+            } else {
+                "application/x-www-form-urlencoded"
+            });
+        }
+
+        let content = media_types
+            .into_iter()
+            .map(|media_type| (media_type.to_string(), json!({ "schema": schema })))
+            .collect();
+        Some(json!({ "content": Value::Object(content) }))
+    }
+
+    fn convert_body_parameter(&self, parameter: &Map<String, Value>, consumes: &[String]) -> Value {
+        let schema = parameter
+            .get("schema")
+            .map(|schema| self.convert_schema(schema))
+            .unwrap_or_else(|| json!({}));
+
+        let mut converted = Map::new();
+        if let Some(description) = parameter.get("description") {
+            converted.insert("description".to_string(), description.clone());
+        }
+        let content = consumes
+            .iter()
+            .map(|media_type| (media_type.clone(), json!({ "schema": schema })))
+            .collect();
+        converted.insert("content".to_string(), Value::Object(content));
+        if parameter.get("required") == Some(&json!(true)) {
+            converted.insert("required".to_string(), json!(true));
+        }
+        copy_extensions(parameter, &mut converted);
+
+        Value::Object(converted)
+    }
+
+    /// Converts a query, header, path or cookie parameter, moving its type into a
+    /// schema and its `collectionFormat` into `style` and `explode`.
+    fn convert_parameter(&self, parameter: &Value) -> Value {
+        let Some(parameter) = parameter.as_object() else {
+            return parameter.clone();
+        };
+        if let Some(reference) = parameter.get("$ref").and_then(Value::as_str) {
+            return json!({ "$ref": self.convert_reference(reference) });
+        }
+
+        let mut converted = Map::new();
+        for (key, value) in parameter {
+            if matches!(
+                key.as_str(),
+                "name" | "in" | "description" | "required" | "allowEmptyValue"
+            ) || key.starts_with("x-")
+            {
+                converted.insert(key.clone(), value.clone());
+            }
+        }
+
+        let location = parameter.get("in").and_then(Value::as_str);
+        if parameter.get("type") == Some(&json!("array")) && location == Some("query") {
+            let collection_format = parameter
+                .get("collectionFormat")
+                .and_then(Value::as_str)
+                .unwrap_or("csv");
+            let (style, explode) = match collection_format {
+                "multi" => ("form", true),
+                "ssv" => ("spaceDelimited", false),
+                "pipes" => ("pipeDelimited", false),
+                _ => ("form", false),
+            };
+            converted.insert("style".to_string(), json!(style));
+            converted.insert("explode".to_string(), json!(explode));
+        }
+
+        converted.insert(
+            "schema".to_string(),
+            Value::Object(self.parameter_schema(parameter)),
+        );
+        Value::Object(converted)
+    }
+
+    /// Collects the schema keywords of a parameter, header or array items object.
+    fn parameter_schema(&self, parameter: &Map<String, Value>) -> Map<String, Value> {
+        let mut schema = Map::new();
+        for (key, value) in parameter {
+            if !SCHEMA_KEYWORDS.contains(&key.as_str()) {
+                continue;
+            }
+
+            match (key.as_str(), value) {
+                ("type", Value::String(schema_type)) if schema_type == "file" => {
+                    schema.insert("type".to_string(), json!("string"));
+                    schema.insert("format".to_string(), json!("binary"));
+                }
+                ("items", Value::Object(items)) => {
+                    schema.insert(key.clone(), Value::Object(self.parameter_schema(items)));
+                }
+                _ => {
+                    schema.insert(key.clone(), value.clone());
+                }
+            }
+        }
+        schema
+    }
+
+    fn convert_response(&self, response: &Value, produces: &[String]) -> Value {
+        let Some(response) = response.as_object() else {
+            return response.clone();
+        };
+        if let Some(reference) = response.get("$ref").and_then(Value::as_str) {
+            return json!({ "$ref": self.convert_reference(reference) });
+        }
+
+        let mut content = Map::new();
+        if let Some(schema) = response.get("schema") {
+            let schema = self.convert_schema(schema);
+            for media_type in produces {
+                content.insert(media_type.clone(), json!({ "schema": schema }));
+            }
+        }
+        for (media_type, example) in response
+            .get("examples")
+            .and_then(Value::as_object)
+            .into_iter()
+            .flatten()
+        {
+            let media_type_object = content
+                .entry(media_type.clone())
+                .or_insert_with(|| json!({}));
+            media_type_object["example"] = example.clone();
+        }
+
+        let mut converted = Map::new();
+        for (key, value) in response {
+            match key.as_str() {
+                "schema" | "examples" => {}
+                "headers" => {
+                    let headers = self.convert_map(value, |header| match header.as_object() {
+                        Some(header) => self.convert_header(header),
+                        None => header.clone(),
+                    });
+                    converted.insert(key.clone(), headers);
+                }
+                _ => {
+                    converted.insert(key.clone(), value.clone());
+                }
+            }
+        }
+        if !content.is_empty() {
+            converted.insert("content".to_string(), Value::Object(content));
+        }
+
+        Value::Object(converted)
+    }
+
+    fn convert_header(&self, header: &Map<String, Value>) -> Value {
+        let mut converted = Map::new();
+        if let Some(description) = header.get("description") {
+            converted.insert("description".to_string(), description.clone());
+        }
+        converted.insert(
+            "schema".to_string(),
+            Value::Object(self.parameter_schema(header)),
+        );
+        copy_extensions(header, &mut converted);
+
+        Value::Object(converted)
+    }
+
+    /// Converts a schema and the schemas nested in it: references are rewritten,
+    /// `type: file` becomes a binary string, `x-nullable` becomes `nullable`, and a
+    /// discriminator property name becomes a discriminator object.
+    fn convert_schema(&self, schema: &Value) -> Value {
+        let Some(schema) = schema.as_object() else {
+            return schema.clone();
+        };
+
+        let mut converted = Map::new();
+        for (key, value) in schema {
+            match (key.as_str(), value) {
+                ("$ref", Value::String(reference)) => {
+                    converted.insert(key.clone(), json!(self.convert_reference(reference)));
+                }
+                ("type", Value::String(schema_type)) if schema_type == "file" => {
+                    converted.insert("type".to_string(), json!("string"));
+                    converted.insert("format".to_string(), json!("binary"));
+                }
+                ("x-nullable", Value::Bool(_)) => {
+                    converted.insert("nullable".to_string(), value.clone());
+                }
+                ("discriminator", Value::String(property_name)) => {
+                    converted.insert(key.clone(), json!({ "propertyName": property_name }));
+                }
+                ("properties", Value::Object(properties)) => {
+                    let properties = properties
+                        .iter()
+                        .map(|(name, property)| (name.clone(), self.convert_schema(property)))
+                        .collect();
+                    converted.insert(key.clone(), Value::Object(properties));
+                }
+                ("items" | "additionalProperties" | "not", _) => {
+                    converted.insert(key.clone(), self.convert_schema(value));
+                }
+                ("allOf" | "oneOf" | "anyOf", Value::Array(schemas)) => {
+                    let schemas = schemas
+                        .iter()
+                        .map(|schema| self.convert_schema(schema))
+                        .collect();
+                    converted.insert(key.clone(), Value::Array(schemas));
+                }
+                _ => {
+                    converted.insert(key.clone(), value.clone());
+                }
+            }
+        }
+
+        Value::Object(converted)
+    }
+
+    fn convert_security_scheme(&self, scheme: &Value) -> Value {
+        let Some(scheme) = scheme.as_object() else {
+            return scheme.clone();
+        };
+
+        let mut converted = Map::new();
+        match scheme.get("type").and_then(Value::as_str) {
+            Some("basic") => {
+                converted.insert("type".to_string(), json!("http"));
+                converted.insert("scheme".to_string(), json!("basic"));
+            }
+            Some("oauth2") => {
+                let flow = match scheme.get("flow").and_then(Value::as_str) {
+                    Some("application") => "clientCredentials",
+                    Some("accessCode") => "authorizationCode",
+                    Some("password") => "password",
+                    _ => "implicit",
+                };
+
+                let mut flow_object = Map::new();
+                for key in ["authorizationUrl", "tokenUrl"] {
+                    if let Some(url) = scheme.get(key) {
+                        flow_object.insert(key.to_string(), url.clone());
+                    }
+                }
+                flow_object.insert(
+                    "scopes".to_string(),
+                    scheme.get("scopes").cloned().unwrap_or_else(|| json!({})),
+                );
+
+                converted.insert("type".to_string(), json!("oauth2"));
+                converted.insert("flows".to_string(), json!({ flow: flow_object }));
+            }
+            _ => {
+                for key in ["type", "name", "in"] {
+                    if let Some(value) = scheme.get(key) {
+                        converted.insert(key.to_string(), value.clone());
+                    }
+                }
+            }
+        }
+        if let Some(description) = scheme.get("description") {
+            converted.insert("description".to_string(), description.clone());
+        }
+        copy_extensions(scheme, &mut converted);
+
+        Value::Object(converted)
+    }
+
+    /// Rewrites a reference into the document to where its target moves. References
+    /// into other documents are kept as they are.
+    fn convert_reference(&self, reference: &str) -> String {
+        let Some(pointer) = reference.strip_prefix('#') else {
+            return reference.to_string();
+        };
+
+        if let Some(rest) = pointer.strip_prefix("/definitions/") {
+            format!("#/components/schemas/{rest}")
+        } else if let Some(rest) = pointer.strip_prefix("/responses/") {
+            format!("#/components/responses/{rest}")
+        } else if let Some(rest) = pointer.strip_prefix("/parameters/") {
+            let is_body = self
+                .resolve_parameter(&json!({ "$ref": reference }))
+                .and_then(|parameter| parameter.get("in"))
+                == Some(&json!("body"));
+            let section = if is_body {
+                "requestBodies"
+            } else {
+                "parameters"
+            };
+            format!("#/components/{section}/{rest}")
+        } else {
+            reference.to_string()
+        }
+    }
+
+    /// Returns a parameter, or the global parameter it references.
+    fn resolve_parameter<'v>(&'v self, parameter: &'v Value) -> Option<&'v Map<String, Value>> {
+        match parameter.get("$ref").and_then(Value::as_str) {
+            Some(reference) => {
+                let name = reference.strip_prefix("#/parameters/")?;
+                self.document.get("parameters")?.get(name)?.as_object()
+            }
+            None => parameter.as_object(),
+        }
+    }
+}
+
+/// Reads a `consumes` or `produces` list, defaulting to JSON.
+fn media_types(value: Option<&Value>) -> Vec<String> {
+    let media_types: Vec<String> = value
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+        .map(str::to_string)
+        .collect();
+
+    if media_types.is_empty() {
+        vec!["application/json".to_string()]
+    } else {
+        media_types
+    }
+}
+
+fn copy_extensions(from: &Map<String, Value>, to: &mut Map<String, Value>) {
+    for (key, value) in from {
+        if key.starts_with("x-") {
+            to.insert(key.clone(), value.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn swagger(document: Value) -> Value {
+        let mut base = json!({
+            "swagger": "2.0",
+            "info": { "title": "Test", "version": "1.0.0" },
+            "paths": {}
+        });
+        for (key, value) in document.as_object().unwrap() {
+            base[key] = value.clone();
+        }
+        base
+    }
+
+    #[test]
+    fn test_is_swagger2() {
+        assert!(is_swagger2(&json!({ "swagger": "2.0" })));
+        assert!(is_swagger2(&json!({ "swagger": 2.0 })));
+        assert!(!is_swagger2(&json!({ "openapi": "3.0.3" })));
+        assert!(!is_swagger2(&json!({ "swagger": "1.2" })));
+    }
+
+    #[test]
+    fn test_servers() {
+        let converted = upconvert(&swagger(json!({
+            "host": "api.example.com",
+            "basePath": "/v1",
+            "schemes": ["http", "https"]
+        })));
+        assert_eq!(
+            converted["servers"],
+            json!([
+                { "url": "http://api.example.com/v1" },
+                { "url": "https://api.example.com/v1" }
+            ])
+        );
+        assert_eq!(converted["openapi"], CONVERTED_OPENAPI_VERSION);
+        assert!(converted.get("swagger").is_none());
+
+        let relative = upconvert(&swagger(json!({ "basePath": "/v1" })));
+        assert_eq!(relative["servers"], json!([{ "url": "/v1" }]));
+        assert!(upconvert(&swagger(json!({}))).get("servers").is_none());
+    }
+
+    #[test]
+    fn test_definitions_become_component_schemas() {
+        let converted = upconvert(&swagger(json!({
+            "definitions": {
+                "Pet": {
+                    "type": "object",
+                    "discriminator": "petType",
+                    "properties": {
+                        "petType": { "type": "string" },
+                        "owner": { "$ref": "#/definitions/Owner" },
+                        "photo": { "type": "file" },
+                        "tag": { "type": "string", "x-nullable": true },
+                        "example": { "$ref": "#/definitions/Owner" }
+                    }
+                },
+                "Owner": { "type": "object" }
+            }
+        })));
+
+        let pet = &converted["components"]["schemas"]["Pet"];
+        assert_eq!(pet["discriminator"], json!({ "propertyName": "petType" }));
+        let properties = &pet["properties"];
+        assert_eq!(properties["owner"]["$ref"], "#/components/schemas/Owner");
+        assert_eq!(properties["example"]["$ref"], "#/components/schemas/Owner");
+        assert_eq!(
+            properties["photo"],
+            json!({ "type": "string", "format": "binary" })
+        );
+        assert_eq!(
+            properties["tag"],
+            json!({ "type": "string", "nullable": true })
+        );
+    }
+
+    #[test]
+    fn test_parameters_move_their_type_into_a_schema() {
+        let converted = upconvert(&swagger(json!({
+            "paths": {
+                "/pets": {
+                    "get": {
+                        "parameters": [
+                            {
+                                "name": "tags",
+                                "in": "query",
+                                "type": "array",
+                                "items": { "type": "string" },
+                                "collectionFormat": "pipes"
+                            },
+                            { "name": "ids", "in": "query", "type": "array", "items": { "type": "integer" } },
+                            { "name": "X-Trace", "in": "header", "type": "string", "maxLength": 32 },
+                            { "$ref": "#/parameters/Limit" }
+                        ],
+                        "responses": { "200": { "description": "OK" } }
+                    }
+                }
+            },
+            "parameters": {
+                "Limit": { "name": "limit", "in": "query", "type": "integer", "minimum": 1 }
+            }
+        })));
+
+        let parameters = &converted["paths"]["/pets"]["get"]["parameters"];
+        assert_eq!(
+            parameters[0],
+            json!({
+                "name": "tags",
+                "in": "query",
+                "style": "pipeDelimited",
+                "explode": false,
+                "schema": { "type": "array", "items": { "type": "string" } }
+            })
+        );
+        // Arrays default to comma-separated values
+        assert_eq!(parameters[1]["style"], "form");
+        assert_eq!(parameters[1]["explode"], false);
+        assert_eq!(
+            parameters[2]["schema"],
+            json!({ "type": "string", "maxLength": 32 })
+        );
+        assert_eq!(parameters[3]["$ref"], "#/components/parameters/Limit");
+        assert_eq!(
+            converted["components"]["parameters"]["Limit"]["schema"],
+            json!({ "type": "integer", "minimum": 1 })
+        );
+    }
+
+    #[test]
+    fn test_body_parameters_become_request_bodies() {
+        let converted = upconvert(&swagger(json!({
+            "consumes": ["application/json", "application/xml"],
+            "paths": {
+                "/pets": {
+                    "post": {
+                        "parameters": [{
+                            "name": "pet",
+                            "in": "body",
+                            "required": true,
+                            "schema": { "$ref": "#/definitions/Pet" }
+                        }],
+                        "responses": { "201": { "description": "Created" } }
+                    },
+                    "put": {
+                        "consumes": ["application/json"],
+                        "parameters": [{ "$ref": "#/parameters/Pets" }],
+                        "responses": { "204": { "description": "Updated" } }
+                    }
+                }
+            },
+            "parameters": {
+                "Pets": { "name": "pets", "in": "body", "schema": { "type": "array" } }
+            }
+        })));
+
+        let post = &converted["paths"]["/pets"]["post"];
+        assert!(post.get("parameters").is_none());
+        assert_eq!(post["requestBody"]["required"], true);
+        let content = post["requestBody"]["content"].as_object().unwrap();
+        assert_eq!(
+            content.keys().collect::<Vec<_>>(),
+            ["application/json", "application/xml"]
+        );
+        assert_eq!(
+            content["application/xml"]["schema"]["$ref"],
+            "#/components/schemas/Pet"
+        );
+
+        let put = &converted["paths"]["/pets"]["put"];
+        assert_eq!(
+            put["requestBody"],
+            json!({ "$ref": "#/components/requestBodies/Pets" })
+        );
+        assert!(
+            converted["components"]["requestBodies"]["Pets"]["content"]["application/json"]
+                .is_object()
+        );
+        assert!(converted["components"].get("parameters").is_none());
+    }
+
+    #[test]
+    fn test_form_parameters_become_a_form_request_body() {
+        let converted = upconvert(&swagger(json!({
+            "paths": {
+                "/pets/{id}": {
+                    "parameters": [
+                        { "name": "id", "in": "path", "required": true, "type": "string" },
+                        { "name": "note", "in": "formData", "type": "string" }
+                    ],
+                    "post": {
+                        "parameters": [
+                            { "name": "image", "in": "formData", "type": "file", "required": true }
+                        ],
+                        "responses": { "200": { "description": "OK" } }
+                    }
+                }
+            }
+        })));
+
+        let path_item = &converted["paths"]["/pets/{id}"];
+        assert_eq!(path_item["parameters"].as_array().unwrap().len(), 1);
+        assert_eq!(
+            path_item["post"]["requestBody"],
+            json!({
+                "content": {
+                    "multipart/form-data": {
+                        "schema": {
+                            "type": "object",
+                            "properties": {
+                                "image": { "type": "string", "format": "binary" },
+                                "note": { "type": "string" }
+                            },
+                            "required": ["image"]
+                        }
+                    }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_responses_use_produces_as_content() {
+        let converted = upconvert(&swagger(json!({
+            "produces": ["application/json"],
+            "paths": {
+                "/pets": {
+                    "get": {
+                        "responses": {
+                            "200": {
+                                "description": "OK",
+                                "schema": { "type": "array", "items": { "$ref": "#/definitions/Pet" } },
+                                "headers": { "X-Total": { "type": "integer" } },
+                                "examples": { "application/json": [] }
+                            },
+                            "default": { "$ref": "#/responses/Error" }
+                        }
+                    }
+                }
+            },
+            "responses": { "Error": { "description": "Unexpected error" } }
+        })));
+
+        let responses = &converted["paths"]["/pets"]["get"]["responses"];
+        let json_content = &responses["200"]["content"]["application/json"];
+        assert_eq!(
+            json_content["schema"]["items"]["$ref"],
+            "#/components/schemas/Pet"
+        );
+        assert_eq!(json_content["example"], json!([]));
+        assert_eq!(
+            responses["200"]["headers"]["X-Total"],
+            json!({ "schema": { "type": "integer" } })
+        );
+        assert_eq!(responses["default"]["$ref"], "#/components/responses/Error");
+        assert_eq!(
+            converted["components"]["responses"]["Error"],
+            json!({ "description": "Unexpected error" })
+        );
+    }
+
+    #[test]
+    fn test_security_definitions_become_security_schemes() {
+        let converted = upconvert(&swagger(json!({
+            "securityDefinitions": {
+                "basic": { "type": "basic" },
+                "key": { "type": "apiKey", "name": "X-Key", "in": "header" },
+                "oauth": {
+                    "type": "oauth2",
+                    "flow": "accessCode",
+                    "authorizationUrl": "https://example.com/authorize",
+                    "tokenUrl": "https://example.com/token",
+                    "scopes": { "read": "Read access" }
+                }
+            },
+            "security": [{ "oauth": ["read"] }]
+        })));
+
+        let schemes = &converted["components"]["securitySchemes"];
+        assert_eq!(
+            schemes["basic"],
+            json!({ "type": "http", "scheme": "basic" })
+        );
+        assert_eq!(
+            schemes["key"],
+            json!({ "type": "apiKey", "name": "X-Key", "in": "header" })
+        );
+        assert_eq!(
+            schemes["oauth"]["flows"]["authorizationCode"],
+            json!({
+                "authorizationUrl": "https://example.com/authorize",
+                "tokenUrl": "https://example.com/token",
+                "scopes": { "read": "Read access" }
+            })
+        );
+        assert_eq!(converted["security"], json!([{ "oauth": ["read"] }]));
+    }
+}
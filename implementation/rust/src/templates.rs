@@ -1,289 +1,411 @@
+//! Kotlin sources are rendered from Handlebars templates embedded in the binary. A
+//! directory of `.hbs` files can replace any of them, or any partial, by name.
+//!
+//! Templates are rendered with the serialized [`KotlinClass`] or [`KotlinController`]
+//! as context, with `include_validation` and `include_swagger` added at the root.
+
+use handlebars::{
+    handlebars_helper, no_escape, Context, Handlebars, Helper, HelperDef, HelperResult, Output,
+    RenderContext, RenderError, Renderable, StringOutput, Template,
+};
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::path::Path;
+use tokio::fs;
+
+use crate::errors::{self, ErrorContext, Result};
+use crate::escaping::{escape_kdoc, escape_string, string_literal};
+use crate::naming::{camel_case, pascal_case};
 use crate::types::*;
 
+/// The embedded templates and partials, by name.
+const TEMPLATES: &[(&str, &str)] = &[
+    (
+        "file_header",
+        include_str!("../templates/kotlin/file_header.hbs"),
+    ),
+    (
+        "class_header",
+        include_str!("../templates/kotlin/class_header.hbs"),
+    ),
+    (
+        "supertypes",
+        include_str!("../templates/kotlin/supertypes.hbs"),
+    ),
+    ("property", include_str!("../templates/kotlin/property.hbs")),
+    (
+        "data_class",
+        include_str!("../templates/kotlin/data_class.hbs"),
+    ),
+    (
+        "enum_class",
+        include_str!("../templates/kotlin/enum_class.hbs"),
+    ),
+    (
+        "sealed_class",
+        include_str!("../templates/kotlin/sealed_class.hbs"),
+    ),
+    (
+        "sealed_sub_type",
+        include_str!("../templates/kotlin/sealed_sub_type.hbs"),
+    ),
+    (
+        "controller",
+        include_str!("../templates/kotlin/controller.hbs"),
+    ),
+    ("method", include_str!("../templates/kotlin/method.hbs")),
+    (
+        "parameter",
+        include_str!("../templates/kotlin/parameter.hbs"),
+    ),
+    (
+        "parameter_converters",
+        include_str!("../templates/kotlin/parameter_converters.hbs"),
+    ),
+    (
+        "build_gradle",
+        include_str!("../templates/kotlin/build_gradle.hbs"),
+    ),
+];
+
+/// Templates read from a directory, replacing the embedded templates and partials
+/// named like their files, e.g. `property.hbs` replaces `property`. Files with other
+/// names are available to the templates as partials.
+#[derive(Debug, Clone, Default)]
+pub struct TemplateOverrides {
+    templates: BTreeMap<String, Template>,
+}
+
+impl TemplateOverrides {
+    /// Reads and compiles every `.hbs` file in a directory.
+    pub async fn load<P: AsRef<Path>>(dir: P) -> Result<Self> {
+        let dir = dir.as_ref();
+        let mut entries = fs::read_dir(dir)
+            .await
+            .map_err(|_| errors::file_not_found(dir.display().to_string()))?;
+
+        let mut templates = BTreeMap::new();
+        while let Some(entry) = entries.next_entry().await? {
+            let path = entry.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some("hbs") {
+                continue;
+            }
+            let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) else {
+                continue;
+            };
+
+            let source = fs::read_to_string(&path).await?;
+            let template = Template::compile(&source).map_err(|e| {
+                let mut context = ErrorContext::new().with_file(path.display().to_string());
+                if let (Some(line), Some(column)) = (e.line_no, e.column_no) {
+                    context = context.with_location(line as u32, column as u32);
+                }
+                errors::invalid_template(name, e.reason().to_string()).with_context(context)
+            })?;
+            templates.insert(name.to_string(), template);
+        }
+
+        Ok(Self { templates })
+    }
+
+    /// Names of the templates read, in order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.templates.keys().map(String::as_str)
+    }
+}
+
 pub struct TemplateEngine {
     include_validation: bool,
     include_swagger: bool,
+    handlebars: Handlebars<'static>,
 }
 
 impl TemplateEngine {
+    #[allow(dead_code)]
     pub fn new(include_validation: bool, include_swagger: bool) -> Self {
-        Self {
+        Self::with_overrides(
             include_validation,
             include_swagger,
-        }
+            &TemplateOverrides::default(),
+        )
     }
 
-    pub fn generate_kotlin_class(&self, kotlin_class: &KotlinClass) -> String {
-        let mut content = String::new();
-
-        // Package declaration
-        content.push_str(&format!("package {}\n\n", kotlin_class.package_name));
-
-        // Imports
-        if !kotlin_class.imports.is_empty() {
-            for import in &kotlin_class.imports {
-                content.push_str(&format!("import {import}\n"));
-            }
-            content.push('\n');
-        }
-
-        // Class documentation
-        if let Some(description) = &kotlin_class.description {
-            content.push_str(&format!("/**\n * {description}\n */\n"));
+    /// Creates an engine whose templates are replaced by the overrides of the same name.
+    pub fn with_overrides(
+        include_validation: bool,
+        include_swagger: bool,
+        overrides: &TemplateOverrides,
+    ) -> Self {
+        let mut handlebars = Handlebars::new();
+        handlebars.register_escape_fn(no_escape);
+        handlebars.set_prevent_indent(true);
+        handlebars.register_helper("camelCase", Box::new(camel_case_helper));
+        handlebars.register_helper("pascalCase", Box::new(pascal_case_helper));
+        handlebars.register_helper("escape", Box::new(escape_helper));
+        handlebars.register_helper("kdoc", Box::new(kdoc_helper));
+        handlebars.register_helper("indent", Box::new(IndentHelper));
+        handlebars.register_helper("httpMapping", Box::new(http_mapping_helper));
+        handlebars.register_helper("parameterAnnotation", Box::new(parameter_annotation_helper));
+
+        for (name, source) in TEMPLATES {
+            handlebars
+                .register_template_string(name, source)
+                .expect("embedded templates compile");
         }
-
-        // Schema annotation
-        if self.include_swagger {
-            let desc = kotlin_class
-                .description
-                .as_deref()
-                .unwrap_or(&kotlin_class.name);
-            content.push_str(&format!("@Schema(description = \"{desc}\")\n"));
+        for (name, template) in &overrides.templates {
+            handlebars.register_template(name, template.clone());
         }
 
-        // Class declaration
-        content.push_str(&format!("data class {}(\n", kotlin_class.name));
-
-        // Properties
-        for (i, prop) in kotlin_class.properties.iter().enumerate() {
-            let is_last = i == kotlin_class.properties.len() - 1;
-            content.push_str(&self.generate_property_content(prop, is_last));
+        Self {
+            include_validation,
+            include_swagger,
+            handlebars,
         }
-
-        content.push_str(")\n");
-
-        content
     }
 
-    fn generate_property_content(&self, prop: &KotlinProperty, is_last: bool) -> String {
-        let mut content = String::new();
-
-        // Property documentation
-        if let Some(description) = &prop.description {
-            content.push_str(&format!("    /**\n     * {description}\n     */\n"));
-        }
-
-        // Schema annotation
-        if self.include_swagger {
-            let desc = prop.description.as_deref().unwrap_or(&prop.name);
-            content.push_str(&format!("    @Schema(description = \"{desc}\""));
-            if let Some(default_val) = &prop.default_value {
-                if default_val != "null" {
-                    content.push_str(&format!(", example = \"{default_val}\""));
-                }
-            }
-            content.push_str(")\n");
-        }
-
-        // JsonProperty annotation
-        if let Some(json_property) = &prop.json_property {
-            content.push_str(&format!("    @JsonProperty(\"{json_property}\")\n"));
-        }
-
-        // Validation annotations
-        if self.include_validation {
-            for validation in &prop.validation {
-                content.push_str(&format!("    {validation}\n"));
-            }
-        }
-
-        // Property declaration
-        let nullable_suffix = if prop.nullable { "?" } else { "" };
-        let default_suffix = if let Some(default_val) = &prop.default_value {
-            format!(" = {default_val}")
-        } else {
-            String::new()
-        };
-
-        content.push_str(&format!(
-            "    val {}: {}{}{}",
-            prop.name, prop.kotlin_type, nullable_suffix, default_suffix
-        ));
-
-        if !is_last {
-            content.push(',');
-        }
-
-        content.push('\n');
-
-        content
+    /// Renders a template with the serialized model as context, adding the options
+    /// templates check at the root.
+    fn render<T: Serialize>(&self, name: &str, model: &T, component: &str) -> Result<String> {
+        let mut context = serde_json::to_value(model)?;
+        context["include_validation"] = json!(self.include_validation);
+        context["include_swagger"] = json!(self.include_swagger);
+        self.render_context(name, &context, component)
     }
 
-    pub fn generate_kotlin_controller(&self, kotlin_controller: &KotlinController) -> String {
-        let mut content = String::new();
-
-        // Package declaration
-        content.push_str(&format!("package {}\n\n", kotlin_controller.package_name));
-
-        // Imports
-        if !kotlin_controller.imports.is_empty() {
-            for import in &kotlin_controller.imports {
-                content.push_str(&format!("import {import}\n"));
-            }
-            content.push('\n');
-        }
-
-        // Interface documentation
-        if let Some(description) = &kotlin_controller.description {
-            content.push_str(&format!("/**\n * {description}\n */\n"));
-        }
-
-        // Interface declaration
-        content.push_str(&format!("interface {} {{\n\n", kotlin_controller.name));
-
-        // Methods
-        for method in &kotlin_controller.methods {
-            content.push_str(&self.generate_method_content(method));
-            content.push('\n');
-        }
-
-        content.push_str("}\n");
-
-        content
+    fn render_context(&self, name: &str, context: &Value, component: &str) -> Result<String> {
+        self.handlebars
+            .render(name, context)
+            .map_err(|e| errors::template_generation_failed(component, e.to_string()))
     }
 
-    fn generate_method_content(&self, method: &KotlinMethod) -> String {
-        let mut content = String::new();
-
-        // Swagger annotations
-        if self.include_swagger && (method.summary.is_some() || method.description.is_some()) {
-            let summary = method.summary.as_deref().unwrap_or(&method.name);
-            content.push_str(&format!("    @Operation(summary = \"{summary}\""));
-            if let Some(description) = &method.description {
-                content.push_str(&format!(", description = \"{description}\""));
-            }
-            content.push_str(")\n");
-
-            content.push_str("    @ApiResponses(value = [\n");
-            let response_desc = method.response_description.as_deref().unwrap_or("Success");
-            content.push_str(&format!(
-                "        ApiResponse(responseCode = \"200\", description = \"{response_desc}\"),\n"
-            ));
-            content.push_str(
-                "        ApiResponse(responseCode = \"400\", description = \"Bad Request\")\n",
+    pub fn generate_kotlin_class(&self, kotlin_class: &KotlinClass) -> Result<String> {
+        let component = format!("class {}", kotlin_class.name);
+        if kotlin_class.is_sealed == Some(true) {
+            return self.render(
+                "sealed_class",
+                &sealed_class_view(kotlin_class)?,
+                &component,
             );
-            content.push_str("    ])\n");
         }
-
-        // HTTP mapping annotation
-        let http_annotation = self.get_http_annotation(&method.http_method);
-        content.push_str(&format!("    @{}(\"{}\")\n", http_annotation, method.path));
-
-        // Method signature
-        content.push_str(&format!("    fun {}(\n", method.name));
-
-        // Parameters
-        let mut all_params = method.parameters.clone();
-        if let Some(request_body) = &method.request_body {
-            all_params.push(request_body.clone());
+        if kotlin_class.enum_constants.is_some() {
+            return self.render("enum_class", kotlin_class, &component);
         }
+        self.render("data_class", kotlin_class, &component)
+    }
 
-        for (i, param) in all_params.iter().enumerate() {
-            let is_last = i == all_params.len() - 1;
-            content.push_str(&self.generate_parameter_content(param, is_last));
+    pub fn generate_kotlin_controller(
+        &self,
+        kotlin_controller: &KotlinController,
+    ) -> Result<String> {
+        let mut view = serde_json::to_value(kotlin_controller)?;
+        for (method, view) in kotlin_controller
+            .methods
+            .iter()
+            .zip(view["methods"].as_array_mut().into_iter().flatten())
+        {
+            view["arguments"] = serde_json::to_value(arguments(method))?;
         }
 
-        content.push_str(&format!("    ): {}\n", method.return_type));
-
-        content
+        self.render(
+            "controller",
+            &view,
+            &format!("controller {}", kotlin_controller.name),
+        )
     }
 
-    fn generate_parameter_content(&self, param: &KotlinParameter, is_last: bool) -> String {
-        let mut content = String::new();
-
-        // Validation annotations
-        if self.include_validation {
-            for validation in &param.validation {
-                content.push_str(&format!("        {validation} "));
-            }
-        }
+    /// Generates the types and Spring converters for parameters that can't be bound natively.
+    /// Classes JSON parameters are read into are imported from the model package.
+    pub fn generate_parameter_converters(
+        &self,
+        package_name: &str,
+        model_package: &str,
+        conversions: &[ParameterConversion],
+    ) -> Result<String> {
+        let json_classes: Vec<&str> = conversions
+            .iter()
+            .filter_map(|conversion| match conversion {
+                ParameterConversion::Json(class_name) => Some(class_name.as_str()),
+                _ => None,
+            })
+            .collect();
+        let delimited_lists: Vec<DelimitedList> = conversions
+            .iter()
+            .filter_map(ParameterConversion::delimited_list)
+            .collect();
+        let mut imports: Vec<String> = delimited_lists
+            .iter()
+            .filter_map(|list| list.import.map(str::to_string))
+            .collect();
+        imports.extend(
+            json_classes
+                .iter()
+                .filter(|_| model_package != package_name)
+                .map(|class_name| format!("{model_package}.{class_name}")),
+        );
+        imports.sort();
+        imports.dedup();
+        let context = json!({
+            "package_name": package_name,
+            "imports": imports,
+            "delimited_lists": delimited_lists,
+            "json_classes": json_classes,
+        });
+
+        self.render_context("parameter_converters", &context, "parameter converters")
+    }
 
-        // Parameter annotation
-        let annotation = self.get_parameter_annotation(param);
-        content.push_str(&format!(
-            "{} {}: {}",
-            annotation, param.name, param.kotlin_type
-        ));
+    pub fn generate_build_file(
+        &self,
+        base_package: &str,
+        spring_boot: SpringBootProfile,
+    ) -> Result<String> {
+        let context = json!({
+            "base_package": base_package,
+            "versions": spring_boot.build_versions(),
+        });
+        self.render_context("build_gradle", &context, "build.gradle.kts")
+    }
+}
 
-        // Nullable suffix for optional parameters
-        if !param.required && !matches!(param.param_type, ParameterType::Body) {
-            content.push('?');
-        }
+/// Returns a sealed class as context, marking the properties of its subtypes that
+/// override one of its abstract properties with `override`.
+fn sealed_class_view(kotlin_class: &KotlinClass) -> Result<Value> {
+    let mut view = serde_json::to_value(kotlin_class)?;
+    let sub_types = view["sealed_sub_types"]
+        .as_array_mut()
+        .into_iter()
+        .flatten();
+    for property in
+        sub_types.flat_map(|sub_type| sub_type["properties"].as_array_mut().into_iter().flatten())
+    {
+        let is_override = kotlin_class
+            .properties
+            .iter()
+            .any(|parent| property["name"] == parent.name.as_str());
+        property["override"] = json!(is_override);
+    }
+    Ok(view)
+}
 
-        if !is_last {
-            content.push(',');
-        }
+/// Returns the parameters of a method followed by its request body.
+fn arguments(method: &KotlinMethod) -> Vec<&KotlinParameter> {
+    method
+        .parameters
+        .iter()
+        .chain(method.request_body.as_ref())
+        .collect()
+}
 
-        content.push('\n');
+/// Indents every non-empty line of text by a number of spaces.
+fn indent(text: &str, width: usize) -> String {
+    let padding = " ".repeat(width);
+    text.split_inclusive('\n')
+        .map(|line| {
+            if line.trim().is_empty() {
+                line.to_string()
+            } else {
+                format!("{padding}{line}")
+            }
+        })
+        .collect()
+}
 
-        content
+fn http_mapping(method: &str) -> &'static str {
+    match method {
+        "get" => "GetMapping",
+        "post" => "PostMapping",
+        "put" => "PutMapping",
+        "delete" => "DeleteMapping",
+        "patch" => "PatchMapping",
+        "head" => "HeadMapping",
+        "options" => "OptionsMapping",
+        _ => "RequestMapping",
     }
+}
 
-    fn get_http_annotation(&self, method: &str) -> &'static str {
-        match method {
-            "get" => "GetMapping",
-            "post" => "PostMapping",
-            "put" => "PutMapping",
-            "delete" => "DeleteMapping",
-            "patch" => "PatchMapping",
-            "head" => "HeadMapping",
-            "options" => "OptionsMapping",
-            _ => "RequestMapping",
-        }
+/// Returns the annotation binding a parameter, by its serialized [`ParameterType`],
+/// naming the parameter when it is sent by another name than its own.
+fn parameter_annotation(param_type: &str, required: bool, wire_name: Option<&str>) -> String {
+    let name = wire_name.map(string_literal);
+    let arguments = |annotation: &str| match &name {
+        Some(name) => format!("{annotation}(name = {name}, required = {required})"),
+        None => format!("{annotation}(required = {required})"),
+    };
+    match param_type {
+        "path" => match &name {
+            Some(name) => format!("@PathVariable({name})"),
+            None => "@PathVariable".to_string(),
+        },
+        "query" => arguments("@RequestParam"),
+        "header" => arguments("@RequestHeader"),
+        "cookie" => arguments("@CookieValue"),
+        _ => "@RequestBody".to_string(),
     }
+}
 
-    fn get_parameter_annotation(&self, param: &KotlinParameter) -> String {
-        match param.param_type {
-            ParameterType::Path => "@PathVariable".to_string(),
-            ParameterType::Query => format!("@RequestParam(required = {})", param.required),
-            ParameterType::Header => format!("@RequestHeader(required = {})", param.required),
-            ParameterType::Body => "@RequestBody".to_string(),
-        }
-    }
+handlebars_helper!(camel_case_helper: |text: str| camel_case(text));
+handlebars_helper!(pascal_case_helper: |text: str| pascal_case(text));
+handlebars_helper!(escape_helper: |text: str| escape_string(text));
+handlebars_helper!(kdoc_helper: |text: str| escape_kdoc(text));
+handlebars_helper!(http_mapping_helper: |method: str| http_mapping(method));
+handlebars_helper!(parameter_annotation_helper: |param_type: str, required: bool, wire_name: Json| {
+    parameter_annotation(param_type, required, wire_name.as_str())
+});
+
+/// `{{indent text 4}}` indents text by a number of spaces, and `{{#indent 4}}...{{/indent}}`
+/// the rendered block.
+struct IndentHelper;
+
+impl HelperDef for IndentHelper {
+    fn call<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'reg, 'rc>,
+        r: &'reg Handlebars<'reg>,
+        ctx: &'rc Context,
+        rc: &mut RenderContext<'reg, 'rc>,
+        out: &mut dyn Output,
+    ) -> HelperResult {
+        let (text, width) = match h.template() {
+            Some(template) => {
+                let mut block = StringOutput::new();
+                template.render(r, ctx, rc, &mut block)?;
+                (block.into_string()?, h.param(0))
+            }
+            None => {
+                let text = h.param(0).and_then(|param| param.value().as_str());
+                (text.unwrap_or_default().to_string(), h.param(1))
+            }
+        };
+        let width = width
+            .and_then(|param| param.value().as_u64())
+            .ok_or_else(|| RenderError::new("`indent` helper: expected a number of spaces"))?;
 
-    pub fn generate_build_file(&self, base_package: &str) -> String {
-        format!(
-            r#"plugins {{
-    kotlin("jvm") version "1.9.20"
-    kotlin("plugin.spring") version "1.9.20"
-    id("org.springframework.boot") version "3.1.0"
-    id("io.spring.dependency-management") version "1.1.0"
-}}
-
-group = "{base_package}"
-version = "0.0.1-SNAPSHOT"
-
-repositories {{
-    mavenCentral()
-}}
-
-dependencies {{
-    implementation("org.springframework.boot:spring-boot-starter-web")
-    implementation("org.springframework.boot:spring-boot-starter-validation")
-    implementation("com.fasterxml.jackson.module:jackson-module-kotlin")
-    implementation("org.jetbrains.kotlin:kotlin-reflect")
-    implementation("org.springdoc:springdoc-openapi-starter-webmvc-ui:2.1.0")
-    testImplementation("org.springframework.boot:spring-boot-starter-test")
-}}
-
-tasks.withType<org.jetbrains.kotlin.gradle.tasks.KotlinCompile> {{
-    kotlinOptions {{
-        freeCompilerArgs = listOf("-Xjsr305=strict")
-        jvmTarget = "17"
-    }}
-}}
-
-tasks.withType<Test> {{
-    useJUnitPlatform()
-}}
-"#
-        )
+        out.write(&indent(&text, width as usize))?;
+        Ok(())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use tempfile::TempDir;
+
+    /// Renders a partial with a model as context, plus the fields of `extra`.
+    fn render_partial<T: Serialize>(
+        engine: &TemplateEngine,
+        name: &str,
+        model: &T,
+        extra: Value,
+    ) -> String {
+        let mut context = serde_json::to_value(model).unwrap();
+        for (key, value) in extra.as_object().unwrap() {
+            context[key] = value.clone();
+        }
+        context["include_validation"] = json!(engine.include_validation);
+        context["include_swagger"] = json!(engine.include_swagger);
+        engine.render_context(name, &context, name).unwrap()
+    }
 
     #[test]
     fn test_template_engine_creation() {
@@ -307,18 +429,25 @@ mod tests {
                 kotlin_type: "Int".to_string(),
                 nullable: false,
                 default_value: None,
+                example: None,
                 description: None,
                 json_property: None,
                 validation: vec![],
+                annotations: Vec::new(),
             }],
             imports: vec!["import com.example.utils.Util".to_string()],
             description: Some("Test class description".to_string()),
             is_sealed: None,
             sealed_sub_types: None,
             parent_class: None,
+            discriminator_property: None,
+            discriminator_value: None,
+            enum_constants: None,
+            annotations: Vec::new(),
+            interfaces: Vec::new(),
         };
 
-        let result = engine.generate_kotlin_class(&kotlin_class);
+        let result = engine.generate_kotlin_class(&kotlin_class).unwrap();
 
         assert!(result.contains("package com.example.test"));
         assert!(result.contains("import com.example.utils.Util"));
@@ -338,25 +467,112 @@ mod tests {
                 kotlin_type: "String".to_string(),
                 nullable: false,
                 default_value: Some("\"defaultUser\"".to_string()),
+                example: Some("defaultUser".to_string()),
                 description: Some("The username".to_string()),
                 json_property: Some("user_name".to_string()),
                 validation: vec![],
+                annotations: Vec::new(),
             }],
             imports: vec![],
             description: Some("User model class".to_string()),
             is_sealed: None,
             sealed_sub_types: None,
             parent_class: None,
+            discriminator_property: None,
+            discriminator_value: None,
+            enum_constants: None,
+            annotations: Vec::new(),
+            interfaces: Vec::new(),
         };
 
-        let result = engine.generate_kotlin_class(&kotlin_class);
+        let result = engine.generate_kotlin_class(&kotlin_class).unwrap();
 
         assert!(result.contains("@Schema(description = \"User model class\")"));
-        assert!(result.contains("@Schema(description = \"The username\""));
+        assert!(
+            result.contains("@Schema(description = \"The username\", example = \"defaultUser\")")
+        );
         assert!(result.contains("@JsonProperty(\"user_name\")"));
         assert!(result.contains("val username: String = \"defaultUser\""));
     }
 
+    #[test]
+    fn test_generate_kotlin_class_escapes_descriptions() {
+        let engine = TemplateEngine::new(false, true);
+        let kotlin_class = KotlinClass {
+            name: "Glob".to_string(),
+            package_name: "com.example.model".to_string(),
+            properties: vec![KotlinProperty {
+                name: "`object`".to_string(),
+                kotlin_type: "String".to_string(),
+                nullable: false,
+                default_value: None,
+                example: None,
+                description: Some("Costs \"$5\"".to_string()),
+                json_property: None,
+                validation: vec![],
+                annotations: Vec::new(),
+            }],
+            imports: vec![],
+            description: Some("Matches /*.json */\nand more".to_string()),
+            is_sealed: None,
+            sealed_sub_types: None,
+            parent_class: None,
+            discriminator_property: None,
+            discriminator_value: None,
+            enum_constants: None,
+            annotations: Vec::new(),
+            interfaces: Vec::new(),
+        };
+
+        let result = engine.generate_kotlin_class(&kotlin_class).unwrap();
+
+        assert!(result.contains("/**\n * Matches &#47;*.json *&#47;\n * and more\n */"));
+        assert!(result.contains("@Schema(description = \"Matches /*.json */\\nand more\")"));
+        assert!(result.contains("/**\n     * Costs \"$5\"\n     */"));
+        assert!(result.contains("@Schema(description = \"Costs \\\"\\$5\\\"\")"));
+        assert!(result.contains("val `object`: String"));
+    }
+
+    #[test]
+    fn test_generate_kotlin_class_omits_missing_property_descriptions() {
+        let engine = TemplateEngine::new(false, true);
+        let property = |name: &str, default_value: &str, example: Option<&str>| KotlinProperty {
+            name: name.to_string(),
+            kotlin_type: "String".to_string(),
+            nullable: true,
+            default_value: Some(default_value.to_string()),
+            example: example.map(str::to_string),
+            description: None,
+            json_property: None,
+            validation: vec![],
+            annotations: Vec::new(),
+        };
+        let kotlin_class = KotlinClass {
+            name: "Shape".to_string(),
+            package_name: "com.example.model".to_string(),
+            properties: vec![
+                property("`object`", "null", None),
+                property("kind", "\"box\"", Some("box")),
+            ],
+            imports: vec![],
+            description: None,
+            is_sealed: None,
+            sealed_sub_types: None,
+            parent_class: None,
+            discriminator_property: None,
+            discriminator_value: None,
+            enum_constants: None,
+            annotations: Vec::new(),
+            interfaces: Vec::new(),
+        };
+
+        let result = engine.generate_kotlin_class(&kotlin_class).unwrap();
+
+        assert!(!result.contains("`object`\")"));
+        assert!(result.contains("@Schema(example = \"box\")\n"));
+        assert!(result.contains("val `object`: String? = null"));
+    }
+
     #[test]
     fn test_generate_kotlin_class_with_validation() {
         let engine = TemplateEngine::new(true, false);
@@ -368,24 +584,252 @@ mod tests {
                 kotlin_type: "String".to_string(),
                 nullable: true,
                 default_value: None,
+                example: None,
                 description: None,
                 json_property: None,
                 validation: vec!["@Email".to_string(), "@NotBlank".to_string()],
+                annotations: Vec::new(),
             }],
             imports: vec![],
             description: None,
             is_sealed: None,
             sealed_sub_types: None,
             parent_class: None,
+            discriminator_property: None,
+            discriminator_value: None,
+            enum_constants: None,
+            annotations: Vec::new(),
+            interfaces: Vec::new(),
         };
 
-        let result = engine.generate_kotlin_class(&kotlin_class);
+        let result = engine.generate_kotlin_class(&kotlin_class).unwrap();
 
         assert!(result.contains("@Email"));
         assert!(result.contains("@NotBlank"));
         assert!(result.contains("val email: String?"));
     }
 
+    fn sealed_pet_class(discriminator_property: Option<&str>) -> KotlinClass {
+        let sub_type =
+            |name: &str, value: Option<&str>, prop: &str, kotlin_type: &str| KotlinClass {
+                name: name.to_string(),
+                package_name: "com.example.model".to_string(),
+                description: None,
+                properties: vec![KotlinProperty {
+                    name: prop.to_string(),
+                    kotlin_type: kotlin_type.to_string(),
+                    nullable: false,
+                    default_value: None,
+                    example: None,
+                    description: None,
+                    json_property: None,
+                    validation: vec![],
+                    annotations: Vec::new(),
+                }],
+                imports: vec![],
+                is_sealed: None,
+                sealed_sub_types: None,
+                parent_class: Some("Pet".to_string()),
+                discriminator_property: None,
+                discriminator_value: value.map(str::to_string),
+                enum_constants: None,
+                annotations: Vec::new(),
+                interfaces: Vec::new(),
+            };
+
+        KotlinClass {
+            name: "Pet".to_string(),
+            package_name: "com.example.model".to_string(),
+            description: Some("A pet".to_string()),
+            properties: vec![],
+            imports: vec![
+                "com.fasterxml.jackson.annotation.JsonSubTypes".to_string(),
+                "com.fasterxml.jackson.annotation.JsonTypeInfo".to_string(),
+            ],
+            is_sealed: Some(true),
+            sealed_sub_types: Some(vec![
+                sub_type(
+                    "Dog",
+                    discriminator_property.map(|_| "dog"),
+                    "breed",
+                    "String",
+                ),
+                sub_type(
+                    "Cat",
+                    discriminator_property.map(|_| "cat"),
+                    "indoor",
+                    "Boolean",
+                ),
+            ]),
+            parent_class: None,
+            discriminator_property: discriminator_property.map(str::to_string),
+            discriminator_value: None,
+            enum_constants: None,
+            annotations: Vec::new(),
+            interfaces: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_generate_sealed_class_with_discriminator() {
+        let engine = TemplateEngine::new(false, false);
+        let result = engine
+            .generate_kotlin_class(&sealed_pet_class(Some("petType")))
+            .unwrap();
+
+        assert!(result.contains("import com.fasterxml.jackson.annotation.JsonSubTypes"));
+        assert!(result.contains(
+            "@JsonTypeInfo(use = JsonTypeInfo.Id.NAME, include = JsonTypeInfo.As.PROPERTY, property = \"petType\")"
+        ));
+        assert!(result.contains("    JsonSubTypes.Type(value = Pet.Dog::class, name = \"dog\"),\n"));
+        assert!(result.contains("    JsonSubTypes.Type(value = Pet.Cat::class, name = \"cat\")\n"));
+        assert!(result.contains("sealed class Pet {"));
+        assert!(result.contains("    data class Dog(\n        val breed: String\n    ) : Pet()"));
+        assert!(result.contains("    data class Cat(\n        val indoor: Boolean\n    ) : Pet()"));
+        assert!(!result.contains("data class Pet("));
+    }
+
+    #[test]
+    fn test_generate_sealed_class_without_discriminator_uses_deduction() {
+        let engine = TemplateEngine::new(false, false);
+        let result = engine
+            .generate_kotlin_class(&sealed_pet_class(None))
+            .unwrap();
+
+        assert!(result.contains("@JsonTypeInfo(use = JsonTypeInfo.Id.DEDUCTION)"));
+        assert!(result.contains("JsonSubTypes.Type(value = Pet.Dog::class),"));
+        assert!(!result.contains("name = \"dog\""));
+    }
+
+    #[test]
+    fn test_generate_sealed_class_shared_and_empty_sub_types() {
+        let engine = TemplateEngine::new(false, false);
+        let shared = KotlinProperty {
+            name: "name".to_string(),
+            kotlin_type: "String".to_string(),
+            nullable: false,
+            default_value: None,
+            example: None,
+            description: None,
+            json_property: None,
+            validation: vec![],
+            annotations: Vec::new(),
+        };
+        let mut pet = sealed_pet_class(Some("petType"));
+        pet.properties.push(shared.clone());
+        let sub_types = pet.sealed_sub_types.as_mut().unwrap();
+        sub_types[0].properties.insert(0, shared);
+        sub_types[1].properties.clear();
+
+        let result = engine.generate_kotlin_class(&pet).unwrap();
+
+        assert!(result.contains("    abstract val name: String\n"));
+        assert!(result.contains("        override val name: String,\n        val breed: String\n"));
+        assert!(result.contains("    class Cat : Pet()\n"));
+    }
+
+    #[test]
+    fn test_generate_annotations_and_interfaces() {
+        let engine = TemplateEngine::new(false, false);
+        let mut pet = sealed_pet_class(Some("petType"));
+        pet.annotations = vec!["@Immutable".to_string()];
+        pet.interfaces = vec!["java.io.Serializable".to_string()];
+        let sub_types = pet.sealed_sub_types.as_mut().unwrap();
+        sub_types[0].interfaces = vec!["Barks".to_string()];
+        sub_types[0].properties[0].annotations = vec!["@JsonIgnore".to_string()];
+        sub_types[1].properties.clear();
+        sub_types[1].interfaces = vec!["Purrs".to_string()];
+
+        let result = engine.generate_kotlin_class(&pet).unwrap();
+
+        assert!(result.contains("/**\n * A pet\n */\n@Immutable\n@JsonTypeInfo("));
+        assert!(result.contains("sealed class Pet : java.io.Serializable {\n"));
+        assert!(result
+            .contains("        @JsonIgnore\n        val breed: String\n    ) : Pet(), Barks\n"));
+        assert!(result.contains("    class Cat : Pet(), Purrs\n"));
+
+        let mut status = enum_class("String", Vec::new());
+        status.interfaces = vec!["Labeled".to_string()];
+        assert!(engine
+            .generate_kotlin_class(&status)
+            .unwrap()
+            .contains("enum class Status(val value: String) : Labeled {\n"));
+    }
+
+    fn enum_class(value_type: &str, constants: Vec<KotlinEnumConstant>) -> KotlinClass {
+        KotlinClass {
+            name: "Status".to_string(),
+            package_name: "com.example.model".to_string(),
+            description: None,
+            properties: vec![KotlinProperty {
+                name: "value".to_string(),
+                kotlin_type: value_type.to_string(),
+                nullable: false,
+                default_value: None,
+                example: None,
+                description: None,
+                json_property: None,
+                validation: vec![],
+                annotations: Vec::new(),
+            }],
+            imports: vec![],
+            is_sealed: None,
+            sealed_sub_types: None,
+            parent_class: None,
+            discriminator_property: None,
+            discriminator_value: None,
+            enum_constants: Some(constants),
+            annotations: Vec::new(),
+            interfaces: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_generate_enum_class_with_json_property() {
+        let engine = TemplateEngine::new(true, false);
+        let status = enum_class(
+            "String",
+            vec![
+                KotlinEnumConstant {
+                    name: "IN_PROGRESS".to_string(),
+                    value: "\"in-progress\"".to_string(),
+                    json_property: Some("in-progress".to_string()),
+                },
+                KotlinEnumConstant {
+                    name: "DONE".to_string(),
+                    value: "\"DONE\"".to_string(),
+                    json_property: None,
+                },
+            ],
+        );
+
+        let result = engine.generate_kotlin_class(&status).unwrap();
+
+        assert!(result.contains("enum class Status(val value: String) {\n"));
+        assert!(result.contains(
+            "    @JsonProperty(\"in-progress\")\n    IN_PROGRESS(\"in-progress\"),\n    DONE(\"DONE\")\n}\n"
+        ));
+        assert!(!result.contains("data class"));
+    }
+
+    #[test]
+    fn test_generate_integer_enum_class_uses_json_value() {
+        let engine = TemplateEngine::new(false, false);
+        let status = enum_class(
+            "Int",
+            vec![KotlinEnumConstant {
+                name: "VALUE_1".to_string(),
+                value: "1".to_string(),
+                json_property: None,
+            }],
+        );
+
+        let result = engine.generate_kotlin_class(&status).unwrap();
+
+        assert!(result
+            .contains("enum class Status(@get:JsonValue val value: Int) {\n    VALUE_1(1)\n}\n"));
+    }
+
     #[test]
     fn test_generate_kotlin_controller_basic() {
         let engine = TemplateEngine::new(false, false);
@@ -398,8 +842,10 @@ mod tests {
                 path: "/user/{id}".to_string(),
                 parameters: vec![KotlinParameter {
                     name: "id".to_string(),
+                    wire_name: None,
                     kotlin_type: "Long".to_string(),
                     param_type: ParameterType::Path,
+                    conversion: None,
                     required: true,
                     description: None,
                     validation: vec![],
@@ -409,12 +855,15 @@ mod tests {
                 summary: None,
                 description: None,
                 response_description: None,
+                annotations: Vec::new(),
             }],
             imports: vec!["org.springframework.web.bind.annotation.*".to_string()],
             description: Some("User management controller".to_string()),
         };
 
-        let result = engine.generate_kotlin_controller(&kotlin_controller);
+        let result = engine
+            .generate_kotlin_controller(&kotlin_controller)
+            .unwrap();
 
         assert!(result.contains("package com.example.controller"));
         assert!(result.contains("import org.springframework.web.bind.annotation.*"));
@@ -439,8 +888,10 @@ mod tests {
                 parameters: vec![],
                 request_body: Some(KotlinParameter {
                     name: "user".to_string(),
+                    wire_name: None,
                     kotlin_type: "User".to_string(),
                     param_type: ParameterType::Body,
+                    conversion: None,
                     required: true,
                     description: None,
                     validation: vec![],
@@ -449,12 +900,15 @@ mod tests {
                 summary: Some("Create a new user".to_string()),
                 description: Some("Creates a new user in the system".to_string()),
                 response_description: Some("Created user".to_string()),
+                annotations: Vec::new(),
             }],
             imports: vec![],
             description: None,
         };
 
-        let result = engine.generate_kotlin_controller(&kotlin_controller);
+        let result = engine
+            .generate_kotlin_controller(&kotlin_controller)
+            .unwrap();
 
         assert!(result.contains("@Operation(summary = \"Create a new user\", description = \"Creates a new user in the system\")"));
         assert!(result.contains("@ApiResponses(value = ["));
@@ -480,8 +934,10 @@ mod tests {
                 path: "/users/search".to_string(),
                 parameters: vec![KotlinParameter {
                     name: "query".to_string(),
+                    wire_name: None,
                     kotlin_type: "String".to_string(),
                     param_type: ParameterType::Query,
+                    conversion: None,
                     required: false,
                     description: None,
                     validation: vec!["@Size(min = 3, max = 50)".to_string()],
@@ -491,97 +947,133 @@ mod tests {
                 summary: None,
                 description: None,
                 response_description: None,
+                annotations: Vec::new(),
             }],
             imports: vec![],
             description: None,
         };
 
-        let result = engine.generate_kotlin_controller(&kotlin_controller);
+        let result = engine
+            .generate_kotlin_controller(&kotlin_controller)
+            .unwrap();
 
         assert!(result.contains("@Size(min = 3, max = 50)"));
         assert!(result.contains("@RequestParam(required = false) query: String?"));
     }
 
     #[test]
-    fn test_get_http_annotation() {
-        let engine = TemplateEngine::new(false, false);
-
-        assert_eq!(engine.get_http_annotation("get"), "GetMapping");
-        assert_eq!(engine.get_http_annotation("post"), "PostMapping");
-        assert_eq!(engine.get_http_annotation("put"), "PutMapping");
-        assert_eq!(engine.get_http_annotation("delete"), "DeleteMapping");
-        assert_eq!(engine.get_http_annotation("patch"), "PatchMapping");
-        assert_eq!(engine.get_http_annotation("head"), "HeadMapping");
-        assert_eq!(engine.get_http_annotation("options"), "OptionsMapping");
-        assert_eq!(engine.get_http_annotation("unknown"), "RequestMapping");
+    fn test_http_mapping() {
+        assert_eq!(http_mapping("get"), "GetMapping");
+        assert_eq!(http_mapping("post"), "PostMapping");
+        assert_eq!(http_mapping("put"), "PutMapping");
+        assert_eq!(http_mapping("delete"), "DeleteMapping");
+        assert_eq!(http_mapping("patch"), "PatchMapping");
+        assert_eq!(http_mapping("head"), "HeadMapping");
+        assert_eq!(http_mapping("options"), "OptionsMapping");
+        assert_eq!(http_mapping("unknown"), "RequestMapping");
     }
 
     #[test]
-    fn test_get_parameter_annotation() {
-        let engine = TemplateEngine::new(false, false);
-
-        let path_param = KotlinParameter {
-            name: "id".to_string(),
-            kotlin_type: "Long".to_string(),
-            param_type: ParameterType::Path,
-            required: true,
-            description: None,
-            validation: vec![],
-        };
-        assert_eq!(
-            engine.get_parameter_annotation(&path_param),
-            "@PathVariable"
-        );
-
-        let query_param = KotlinParameter {
-            name: "filter".to_string(),
-            kotlin_type: "String".to_string(),
-            param_type: ParameterType::Query,
-            required: false,
-            description: None,
-            validation: vec![],
-        };
+    fn test_parameter_annotation() {
+        assert_eq!(parameter_annotation("path", true, None), "@PathVariable");
         assert_eq!(
-            engine.get_parameter_annotation(&query_param),
+            parameter_annotation("query", false, None),
             "@RequestParam(required = false)"
         );
-
-        let header_param = KotlinParameter {
-            name: "authorization".to_string(),
-            kotlin_type: "String".to_string(),
-            param_type: ParameterType::Header,
-            required: true,
-            description: None,
-            validation: vec![],
-        };
         assert_eq!(
-            engine.get_parameter_annotation(&header_param),
+            parameter_annotation("header", true, None),
             "@RequestHeader(required = true)"
         );
+        assert_eq!(parameter_annotation("body", true, None), "@RequestBody");
+        assert_eq!(
+            parameter_annotation("cookie", false, None),
+            "@CookieValue(required = false)"
+        );
+        assert_eq!(
+            parameter_annotation("path", true, Some("pet-id")),
+            "@PathVariable(\"pet-id\")"
+        );
+        assert_eq!(
+            parameter_annotation("header", false, Some("X-Request-ID")),
+            "@RequestHeader(name = \"X-Request-ID\", required = false)"
+        );
+    }
 
-        let body_param = KotlinParameter {
-            name: "user".to_string(),
-            kotlin_type: "User".to_string(),
-            param_type: ParameterType::Body,
-            required: true,
-            description: None,
-            validation: vec![],
-        };
-        assert_eq!(engine.get_parameter_annotation(&body_param), "@RequestBody");
+    #[test]
+    fn test_generate_parameter_converters() {
+        let engine = TemplateEngine::new(false, false);
+
+        let result = engine
+            .generate_parameter_converters(
+                "com.example.api",
+                "com.example.model",
+                &[ParameterConversion::PipeDelimited("String".to_string())],
+            )
+            .unwrap();
+        assert!(result.starts_with("package com.example.api\n"));
+        assert!(result.contains("data class PipeDelimitedList(val values: List<String>)"));
+        assert!(!result.contains("SpaceDelimitedList"));
+        assert!(!result.contains("ObjectMapper"));
+        assert!(result.contains("class ParameterConverterConfiguration : WebMvcConfigurer {"));
+        assert!(result.contains("source.split(\"|\")"));
+
+        let result = engine
+            .generate_parameter_converters(
+                "com.example.api",
+                "com.example.model",
+                &[
+                    ParameterConversion::SpaceDelimited("String".to_string()),
+                    ParameterConversion::PipeDelimited("Int".to_string()),
+                    ParameterConversion::PipeDelimited("UUID".to_string()),
+                    ParameterConversion::Json("Filter".to_string()),
+                ],
+            )
+            .unwrap();
+        assert!(result.contains("import com.fasterxml.jackson.databind.ObjectMapper"));
+        assert!(result.contains("import com.example.model.Filter\n"));
+        assert!(result.contains("data class SpaceDelimitedList(val values: List<String>)"));
+        assert!(result.contains("data class PipeDelimitedIntList(val values: List<Int>)"));
+        assert!(result.contains(
+            "PipeDelimitedIntList(source.split(\"|\").filter { it.isNotEmpty() }.map { it.toInt() })"
+        ));
+        assert!(result.contains("import java.util.UUID\n"));
+        assert!(result.contains("data class PipeDelimitedUUIDList(val values: List<UUID>)"));
+        assert!(result.contains("(private val objectMapper: ObjectMapper) : WebMvcConfigurer"));
+        assert!(result.contains("registry.addConverter(String::class.java, Filter::class.java)"));
+        assert!(result.contains("objectMapper.readValue(source, Filter::class.java)"));
     }
 
     #[test]
     fn test_generate_build_file() {
         let engine = TemplateEngine::new(false, false);
-        let result = engine.generate_build_file("com.example.test");
+        let result = engine
+            .generate_build_file("com.example.test", SpringBootProfile::Boot3)
+            .unwrap();
 
         assert!(result.contains("group = \"com.example.test\""));
         assert!(result.contains("kotlin(\"jvm\") version \"1.9.20\""));
+        assert!(result.contains("id(\"org.springframework.boot\") version \"3.1.0\""));
         assert!(result.contains("org.springframework.boot:spring-boot-starter-web"));
         assert!(result.contains("org.springdoc:springdoc-openapi-starter-webmvc-ui:2.1.0"));
         assert!(result.contains("jvmTarget = \"17\""));
     }
 
+    #[test]
+    fn test_generate_build_file_spring_boot_2() {
+        let engine = TemplateEngine::new(false, false);
+        let result = engine
+            .generate_build_file("com.example.test", SpringBootProfile::Boot2)
+            .unwrap();
+
+        assert!(result.contains("id(\"org.springframework.boot\") version \"2.7.18\""));
+        assert!(
+            result.contains("id(\"io.spring.dependency-management\") version \"1.0.15.RELEASE\"")
+        );
+        assert!(result.contains("kotlin(\"plugin.spring\") version \"1.8.22\""));
+        assert!(result.contains("org.springdoc:springdoc-openapi-ui:1.7.0"));
+        assert!(result.contains("jvmTarget = \"11\""));
+    }
+
     #[test]
     fn test_generate_property_content_nullable_with_default() {
         let engine = TemplateEngine::new(false, false);
@@ -590,14 +1082,16 @@ mod tests {
             kotlin_type: "String".to_string(),
             nullable: true,
             default_value: Some("null".to_string()),
+            example: None,
             description: Some("The status of the entity".to_string()),
             json_property: None,
             validation: vec![],
+            annotations: Vec::new(),
         };
 
-        let result = engine.generate_property_content(&prop, true);
+        let result = render_partial(&engine, "property", &prop, json!({ "last": true }));
 
-        assert!(result.contains("/**\n     * The status of the entity\n     */"));
+        assert!(result.contains("/**\n * The status of the entity\n */"));
         assert!(result.contains("val status: String? = null"));
         assert!(!result.contains(",")); // is_last = true
     }
@@ -610,12 +1104,14 @@ mod tests {
             kotlin_type: "String".to_string(),
             nullable: false,
             default_value: None,
+            example: None,
             description: None,
             json_property: None,
             validation: vec![],
+            annotations: Vec::new(),
         };
 
-        let result = engine.generate_property_content(&prop, false);
+        let result = render_partial(&engine, "property", &prop, json!({ "last": false }));
 
         assert!(result.contains("val name: String,")); // is_last = false
     }
@@ -630,16 +1126,20 @@ mod tests {
             parameters: vec![
                 KotlinParameter {
                     name: "id".to_string(),
+                    wire_name: None,
                     kotlin_type: "Long".to_string(),
                     param_type: ParameterType::Path,
+                    conversion: None,
                     required: true,
                     description: None,
                     validation: vec![],
                 },
                 KotlinParameter {
                     name: "version".to_string(),
+                    wire_name: None,
                     kotlin_type: "String".to_string(),
                     param_type: ParameterType::Header,
+                    conversion: None,
                     required: false,
                     description: None,
                     validation: vec!["@Pattern(regexp = \"v[0-9]+\")".to_string()],
@@ -647,8 +1147,10 @@ mod tests {
             ],
             request_body: Some(KotlinParameter {
                 name: "user".to_string(),
+                wire_name: None,
                 kotlin_type: "User".to_string(),
                 param_type: ParameterType::Body,
+                conversion: None,
                 required: true,
                 description: None,
                 validation: vec!["@Valid".to_string()],
@@ -657,9 +1159,11 @@ mod tests {
             summary: Some("Update user".to_string()),
             description: None,
             response_description: None,
+            annotations: Vec::new(),
         };
 
-        let result = engine.generate_method_content(&method);
+        let arguments = json!({ "arguments": arguments(&method) });
+        let result = render_partial(&engine, "method", &method, arguments);
 
         assert!(result.contains("@Operation(summary = \"Update user\")"));
         assert!(result.contains("@PutMapping(\"/users/{id}\")"));
@@ -676,15 +1180,64 @@ mod tests {
         let engine = TemplateEngine::new(false, false);
         let param = KotlinParameter {
             name: "limit".to_string(),
+            wire_name: None,
             kotlin_type: "Int".to_string(),
             param_type: ParameterType::Query,
+            conversion: None,
             required: false,
             description: None,
             validation: vec![],
         };
 
-        let result = engine.generate_parameter_content(&param, false);
+        let result = render_partial(&engine, "parameter", &param, json!({ "last": false }));
 
         assert!(result.contains("@RequestParam(required = false) limit: Int?,"));
     }
+
+    #[tokio::test]
+    async fn test_template_overrides_replace_templates() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(
+            temp_dir.path().join("property.hbs"),
+            "{{> annotation}}val {{camelCase name}}: {{kotlin_type}}{{#unless last}},{{/unless}}\n",
+        )
+        .unwrap();
+        std::fs::write(temp_dir.path().join("annotation.hbs"), "@field:Custom ").unwrap();
+        std::fs::write(temp_dir.path().join("notes.txt"), "{{").unwrap();
+
+        let overrides = TemplateOverrides::load(temp_dir.path()).await.unwrap();
+        assert_eq!(
+            overrides.names().collect::<Vec<_>>(),
+            vec!["annotation", "property"]
+        );
+
+        let engine = TemplateEngine::with_overrides(false, false, &overrides);
+        let mut kotlin_class = enum_class("String", Vec::new());
+        kotlin_class.enum_constants = None;
+        kotlin_class.properties[0].name = "pet_name".to_string();
+        let result = engine.generate_kotlin_class(&kotlin_class).unwrap();
+
+        assert!(result.contains("(\n    @field:Custom val petName: String\n)"));
+    }
+
+    #[tokio::test]
+    async fn test_template_overrides_report_invalid_templates() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(temp_dir.path().join("method.hbs"), "fun {{#if name}}\n").unwrap();
+
+        let error = TemplateOverrides::load(temp_dir.path()).await.unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            errors::OpenAPIError::InvalidTemplate { name, .. } if name == "method"
+        ));
+
+        let missing = temp_dir.path().join("missing");
+        assert!(TemplateOverrides::load(&missing).await.is_err());
+    }
+
+    #[test]
+    fn test_indent() {
+        assert_eq!(indent("a\n\nb\n", 4), "    a\n\n    b\n");
+        assert_eq!(indent("a", 2), "  a");
+    }
 }
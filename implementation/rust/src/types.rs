@@ -1,130 +1,262 @@
 use indexmap::IndexMap;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 use std::collections::HashMap;
 use std::path::PathBuf;
 
+use crate::config::OperationFilters;
+use crate::mappings::TypeMappings;
+use crate::templates::TemplateOverrides;
+
+/// Vendor extensions of an object by name, such as `x-kotlin-type`, in document order.
+/// The specification leaves `x-` keys to tools; every object that allows them keeps
+/// them in a flattened `extensions` field read by `deserialize_extensions`.
+pub type Extensions = IndexMap<String, serde_json::Value>;
+
+/// Keeps the `x-` keys among those an object's struct doesn't declare, and drops the
+/// rest, so unknown fields are ignored as before.
+fn deserialize_extensions<'de, D>(deserializer: D) -> Result<Extensions, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let fields = Extensions::deserialize(deserializer)?;
+    Ok(fields
+        .into_iter()
+        .filter(|(key, _)| key.starts_with("x-"))
+        .collect())
+}
+
 // OpenAPI specification types
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct OpenAPISpec {
     pub openapi: String,
     pub info: OpenAPIInfo,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub servers: Vec<OpenAPIServer>,
-    pub paths: IndexMap<String, OpenAPIPathItem>,
+    /// Required before OpenAPI 3.1, which allows documents with only webhooks or components
     #[serde(default)]
+    pub paths: IndexMap<String, OpenAPIPathItem>,
+    /// Requests the API sends rather than serves (OpenAPI 3.1)
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub webhooks: IndexMap<String, OpenAPIPathItem>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "jsonSchemaDialect"
+    )]
+    pub json_schema_dialect: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub components: Option<OpenAPIComponents>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub security: Vec<OpenAPISecurityRequirement>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub tags: Vec<OpenAPITag>,
-    #[serde(default, rename = "externalDocs")]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "externalDocs"
+    )]
     pub external_docs: Option<OpenAPIExternalDocumentation>,
+    #[serde(
+        flatten,
+        default,
+        deserialize_with = "deserialize_extensions",
+        skip_serializing_if = "IndexMap::is_empty"
+    )]
+    pub extensions: Extensions,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct OpenAPIInfo {
     pub title: String,
     pub version: String,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
-    #[serde(default, rename = "termsOfService")]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "termsOfService"
+    )]
     pub terms_of_service: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub contact: Option<OpenAPIContact>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub license: Option<OpenAPILicense>,
+    #[serde(
+        flatten,
+        default,
+        deserialize_with = "deserialize_extensions",
+        skip_serializing_if = "IndexMap::is_empty"
+    )]
+    pub extensions: Extensions,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OpenAPIContact {
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub email: Option<String>,
+    #[serde(
+        flatten,
+        default,
+        deserialize_with = "deserialize_extensions",
+        skip_serializing_if = "IndexMap::is_empty"
+    )]
+    pub extensions: Extensions,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OpenAPILicense {
     pub name: String,
-    #[serde(default)]
+    /// SPDX license expression, exclusive with `url` (OpenAPI 3.1)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub identifier: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
+    #[serde(
+        flatten,
+        default,
+        deserialize_with = "deserialize_extensions",
+        skip_serializing_if = "IndexMap::is_empty"
+    )]
+    pub extensions: Extensions,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OpenAPIServer {
     pub url: String,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
     pub variables: HashMap<String, OpenAPIServerVariable>,
+    #[serde(
+        flatten,
+        default,
+        deserialize_with = "deserialize_extensions",
+        skip_serializing_if = "IndexMap::is_empty"
+    )]
+    pub extensions: Extensions,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OpenAPIServerVariable {
-    #[serde(default, rename = "enum")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty", rename = "enum")]
     pub enum_values: Vec<String>,
     pub default: String,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    #[serde(
+        flatten,
+        default,
+        deserialize_with = "deserialize_extensions",
+        skip_serializing_if = "IndexMap::is_empty"
+    )]
+    pub extensions: Extensions,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct OpenAPIPathItem {
-    #[serde(default, rename = "$ref")]
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "$ref")]
     pub reference: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub summary: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub get: Option<OpenAPIOperation>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub put: Option<OpenAPIOperation>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub post: Option<OpenAPIOperation>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub delete: Option<OpenAPIOperation>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub options: Option<OpenAPIOperation>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub head: Option<OpenAPIOperation>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub patch: Option<OpenAPIOperation>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub trace: Option<OpenAPIOperation>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub servers: Vec<OpenAPIServer>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub parameters: Vec<OpenAPIParameterOrRef>,
+    #[serde(
+        flatten,
+        default,
+        deserialize_with = "deserialize_extensions",
+        skip_serializing_if = "IndexMap::is_empty"
+    )]
+    pub extensions: Extensions,
+}
+
+impl OpenAPIPathItem {
+    /// Returns the operations the path item defines, each with its lowercase method.
+    pub fn operations(&self) -> impl Iterator<Item = (&'static str, &OpenAPIOperation)> {
+        [
+            ("get", &self.get),
+            ("post", &self.post),
+            ("put", &self.put),
+            ("delete", &self.delete),
+            ("patch", &self.patch),
+            ("head", &self.head),
+            ("options", &self.options),
+            ("trace", &self.trace),
+        ]
+        .into_iter()
+        .filter_map(|(method, operation)| Some((method, operation.as_ref()?)))
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct OpenAPIOperation {
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub tags: Vec<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub summary: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
-    #[serde(default, rename = "externalDocs")]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "externalDocs"
+    )]
     pub external_docs: Option<OpenAPIExternalDocumentation>,
-    #[serde(default, rename = "operationId")]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "operationId"
+    )]
     pub operation_id: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub parameters: Vec<OpenAPIParameterOrRef>,
-    #[serde(default, rename = "requestBody")]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "requestBody"
+    )]
     pub request_body: Option<OpenAPIRequestBodyOrRef>,
     pub responses: IndexMap<String, OpenAPIResponseOrRef>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
     pub callbacks: IndexMap<String, OpenAPICallbackOrRef>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
     pub deprecated: bool,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub security: Vec<OpenAPISecurityRequirement>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub servers: Vec<OpenAPIServer>,
+    #[serde(
+        flatten,
+        default,
+        deserialize_with = "deserialize_extensions",
+        skip_serializing_if = "IndexMap::is_empty"
+    )]
+    pub extensions: Extensions,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -139,28 +271,43 @@ pub struct OpenAPIParameter {
     pub name: String,
     #[serde(rename = "in")]
     pub location: String, // 'query', 'header', 'path', 'cookie'
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
     pub required: bool,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
     pub deprecated: bool,
-    #[serde(default, rename = "allowEmptyValue")]
+    #[serde(
+        default,
+        skip_serializing_if = "std::ops::Not::not",
+        rename = "allowEmptyValue"
+    )]
     pub allow_empty_value: bool,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub style: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub explode: Option<bool>,
-    #[serde(default, rename = "allowReserved")]
+    #[serde(
+        default,
+        skip_serializing_if = "std::ops::Not::not",
+        rename = "allowReserved"
+    )]
     pub allow_reserved: bool,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub schema: Option<OpenAPISchemaOrRef>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub example: Option<serde_json::Value>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
     pub examples: IndexMap<String, OpenAPIExampleOrRef>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
     pub content: IndexMap<String, OpenAPIMediaType>,
+    #[serde(
+        flatten,
+        default,
+        deserialize_with = "deserialize_extensions",
+        skip_serializing_if = "IndexMap::is_empty"
+    )]
+    pub extensions: Extensions,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -172,11 +319,18 @@ pub enum OpenAPIRequestBodyOrRef {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OpenAPIRequestBody {
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     pub content: IndexMap<String, OpenAPIMediaType>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
     pub required: bool,
+    #[serde(
+        flatten,
+        default,
+        deserialize_with = "deserialize_extensions",
+        skip_serializing_if = "IndexMap::is_empty"
+    )]
+    pub extensions: Extensions,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -189,38 +343,67 @@ pub enum OpenAPIResponseOrRef {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OpenAPIResponse {
     pub description: String,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
     pub headers: IndexMap<String, OpenAPIHeaderOrRef>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
     pub content: IndexMap<String, OpenAPIMediaType>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
     pub links: IndexMap<String, OpenAPILinkOrRef>,
+    #[serde(
+        flatten,
+        default,
+        deserialize_with = "deserialize_extensions",
+        skip_serializing_if = "IndexMap::is_empty"
+    )]
+    pub extensions: Extensions,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OpenAPIMediaType {
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub schema: Option<OpenAPISchemaOrRef>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub example: Option<serde_json::Value>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
     pub examples: IndexMap<String, OpenAPIExampleOrRef>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
     pub encoding: IndexMap<String, OpenAPIEncoding>,
+    #[serde(
+        flatten,
+        default,
+        deserialize_with = "deserialize_extensions",
+        skip_serializing_if = "IndexMap::is_empty"
+    )]
+    pub extensions: Extensions,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OpenAPIEncoding {
-    #[serde(default, rename = "contentType")]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "contentType"
+    )]
     pub content_type: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
     pub headers: IndexMap<String, OpenAPIHeaderOrRef>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub style: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub explode: Option<bool>,
-    #[serde(default, rename = "allowReserved")]
+    #[serde(
+        default,
+        skip_serializing_if = "std::ops::Not::not",
+        rename = "allowReserved"
+    )]
     pub allow_reserved: bool,
+    #[serde(
+        flatten,
+        default,
+        deserialize_with = "deserialize_extensions",
+        skip_serializing_if = "IndexMap::is_empty"
+    )]
+    pub extensions: Extensions,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -233,144 +416,390 @@ pub enum OpenAPISchemaOrRef {
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct OpenAPISchema {
     // Core schema properties
-    #[serde(default, rename = "type")]
-    pub schema_type: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub schema_type: Option<SchemaType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub format: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub default: Option<serde_json::Value>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub example: Option<serde_json::Value>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub examples: Vec<serde_json::Value>,
-    #[serde(default, rename = "enum")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty", rename = "enum")]
     pub enum_values: Vec<serde_json::Value>,
-    #[serde(default, rename = "const")]
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "const")]
     pub const_value: Option<serde_json::Value>,
+    /// Constant names to use for the `enum` values, in the same order
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        rename = "x-enum-varnames",
+        alias = "x-enumNames"
+    )]
+    pub enum_var_names: Vec<String>,
 
     // Numeric properties
-    #[serde(default, rename = "multipleOf")]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "multipleOf"
+    )]
     pub multiple_of: Option<f64>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub maximum: Option<f64>,
-    #[serde(default, rename = "exclusiveMaximum")]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "exclusiveMaximum"
+    )]
     pub exclusive_maximum: Option<serde_json::Value>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub minimum: Option<f64>,
-    #[serde(default, rename = "exclusiveMinimum")]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "exclusiveMinimum"
+    )]
     pub exclusive_minimum: Option<serde_json::Value>,
 
     // String properties
-    #[serde(default, rename = "maxLength")]
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "maxLength")]
     pub max_length: Option<u32>,
-    #[serde(default, rename = "minLength")]
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "minLength")]
     pub min_length: Option<u32>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub pattern: Option<String>,
 
     // Array properties
-    #[serde(default, rename = "maxItems")]
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "maxItems")]
     pub max_items: Option<u32>,
-    #[serde(default, rename = "minItems")]
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "minItems")]
     pub min_items: Option<u32>,
-    #[serde(default, rename = "uniqueItems")]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "uniqueItems"
+    )]
     pub unique_items: Option<bool>,
-    #[serde(default)]
+    /// `true` is read as an empty schema and `false`, which forbids items beyond
+    /// `prefixItems`, as no schema
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "deserialize_items"
+    )]
     pub items: Option<Box<OpenAPISchemaOrRef>>,
+    /// Schemas of the leading items of a tuple-like array (JSON Schema 2020-12)
+    #[serde(default, skip_serializing_if = "Vec::is_empty", rename = "prefixItems")]
+    pub prefix_items: Vec<OpenAPISchemaOrRef>,
 
     // Object properties
-    #[serde(default, rename = "maxProperties")]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "maxProperties"
+    )]
     pub max_properties: Option<u32>,
-    #[serde(default, rename = "minProperties")]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "minProperties"
+    )]
     pub min_properties: Option<u32>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub required: Vec<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
     pub properties: IndexMap<String, OpenAPISchemaOrRef>,
-    #[serde(default, rename = "additionalProperties")]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "additionalProperties"
+    )]
     pub additional_properties: Option<serde_json::Value>,
+    /// Properties that become required when the named property is present
+    #[serde(
+        default,
+        skip_serializing_if = "IndexMap::is_empty",
+        rename = "dependentRequired"
+    )]
+    pub dependent_required: IndexMap<String, Vec<String>>,
+    /// Schemas that apply when the named property is present
+    #[serde(
+        default,
+        skip_serializing_if = "IndexMap::is_empty",
+        rename = "dependentSchemas"
+    )]
+    pub dependent_schemas: IndexMap<String, OpenAPISchemaOrRef>,
 
     // Composition
-    #[serde(default, rename = "allOf")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty", rename = "allOf")]
     pub all_of: Vec<OpenAPISchemaOrRef>,
-    #[serde(default, rename = "oneOf")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty", rename = "oneOf")]
     pub one_of: Vec<OpenAPISchemaOrRef>,
-    #[serde(default, rename = "anyOf")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty", rename = "anyOf")]
     pub any_of: Vec<OpenAPISchemaOrRef>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub not: Option<Box<OpenAPISchemaOrRef>>,
 
+    // Conditionals
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "if")]
+    pub if_schema: Option<Box<OpenAPISchemaOrRef>>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "then")]
+    pub then_schema: Option<Box<OpenAPISchemaOrRef>>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "else")]
+    pub else_schema: Option<Box<OpenAPISchemaOrRef>>,
+
+    // Schema resources (JSON Schema 2020-12)
+    /// Base URI that references inside this schema resolve against
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "$id")]
+    pub id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "$schema")]
+    pub schema_dialect: Option<String>,
+    /// Schemas local to this one, referenced as `<this schema>/$defs/<name>`
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty", rename = "$defs")]
+    pub defs: IndexMap<String, OpenAPISchemaOrRef>,
+
     // Code generation helpers for composite schemas
     #[serde(skip)]
     pub one_of_variants: Option<Vec<(String, OpenAPISchema)>>,
     #[serde(skip)]
     pub any_of_variants: Option<Vec<(String, OpenAPISchema)>>,
+    /// Name of the component this schema was resolved from through a `$ref`,
+    /// or the name given to an inline schema that is generated as its own class
+    #[serde(skip)]
+    pub reference_name: Option<String>,
 
     // Other
-    #[serde(default)]
+    /// OpenAPI 3.0 only; 3.1 lists `"null"` among the schema's types instead
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub nullable: Option<bool>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub discriminator: Option<OpenAPIDiscriminator>,
-    #[serde(default, rename = "readOnly")]
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "readOnly")]
     pub read_only: Option<bool>,
-    #[serde(default, rename = "writeOnly")]
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "writeOnly")]
     pub write_only: Option<bool>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub deprecated: Option<bool>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub xml: Option<OpenAPIXML>,
-    #[serde(default, rename = "externalDocs")]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "externalDocs"
+    )]
     pub external_docs: Option<OpenAPIExternalDocumentation>,
+    #[serde(
+        flatten,
+        default,
+        deserialize_with = "deserialize_extensions",
+        skip_serializing_if = "IndexMap::is_empty"
+    )]
+    pub extensions: Extensions,
+}
+
+/// The `type` of a schema: a single type name, or a list of them in OpenAPI 3.1,
+/// where `["string", "null"]` replaces 3.0's `nullable: true`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SchemaType {
+    Single(String),
+    Multiple(Vec<String>),
+}
+
+impl SchemaType {
+    /// Returns the only type other than `null`, or `None` when there are several.
+    pub fn primary(&self) -> Option<&str> {
+        match self {
+            Self::Single(name) => Some(name)
+                .filter(|name| *name != "null")
+                .map(String::as_str),
+            Self::Multiple(names) => {
+                let mut types = names.iter().filter(|name| *name != "null");
+                match (types.next(), types.next()) {
+                    (Some(name), None) => Some(name),
+                    _ => None,
+                }
+            }
+        }
+    }
+
+    /// Whether `null` is one of the types.
+    pub fn allows_null(&self) -> bool {
+        match self {
+            Self::Single(name) => name == "null",
+            Self::Multiple(names) => names.iter().any(|name| name == "null"),
+        }
+    }
+}
+
+impl From<&str> for SchemaType {
+    fn from(name: &str) -> Self {
+        Self::Single(name.to_string())
+    }
+}
+
+impl OpenAPISchema {
+    /// Returns the type of the schema's non-null values, inferred from `const` when
+    /// no type is declared. `None` when the schema declares no type or several.
+    pub fn primary_type(&self) -> Option<&str> {
+        match &self.schema_type {
+            Some(schema_type) => schema_type.primary(),
+            None => match self.const_value.as_ref()? {
+                serde_json::Value::String(_) => Some("string"),
+                serde_json::Value::Number(number) if number.is_f64() => Some("number"),
+                serde_json::Value::Number(_) => Some("integer"),
+                serde_json::Value::Bool(_) => Some("boolean"),
+                serde_json::Value::Array(_) => Some("array"),
+                serde_json::Value::Object(_) => Some("object"),
+                serde_json::Value::Null => None,
+            },
+        }
+    }
+
+    /// Whether the schema allows `null`, through 3.0's `nullable` or a 3.1 type list.
+    pub fn is_nullable(&self) -> bool {
+        self.nullable == Some(true)
+            || self
+                .schema_type
+                .as_ref()
+                .is_some_and(SchemaType::allows_null)
+    }
+
+    /// Returns the lower bound and whether it is exclusive. OpenAPI 3.0 marks
+    /// `minimum` exclusive with a boolean `exclusiveMinimum`, while 3.1 gives the
+    /// exclusive bound itself as `exclusiveMinimum`.
+    pub fn lower_bound(&self) -> Option<(f64, bool)> {
+        Self::bound(self.minimum, self.exclusive_minimum.as_ref(), f64::max)
+    }
+
+    /// Returns the upper bound and whether it is exclusive, as [`Self::lower_bound`].
+    pub fn upper_bound(&self) -> Option<(f64, bool)> {
+        Self::bound(self.maximum, self.exclusive_maximum.as_ref(), f64::min)
+    }
+
+    fn bound(
+        inclusive: Option<f64>,
+        exclusive: Option<&serde_json::Value>,
+        tighter: fn(f64, f64) -> f64,
+    ) -> Option<(f64, bool)> {
+        match (inclusive, exclusive) {
+            (Some(value), Some(serde_json::Value::Bool(exclusive))) => Some((value, *exclusive)),
+            (inclusive, Some(serde_json::Value::Number(exclusive))) => {
+                let exclusive = exclusive.as_f64()?;
+                match inclusive {
+                    Some(value) if tighter(value, exclusive) != exclusive => Some((value, false)),
+                    _ => Some((exclusive, true)),
+                }
+            }
+            (inclusive, _) => inclusive.map(|value| (value, false)),
+        }
+    }
+}
+
+/// Reads `items`, which JSON Schema 2020-12 also allows to be a boolean schema.
+fn deserialize_items<'de, D>(
+    deserializer: D,
+) -> std::result::Result<Option<Box<OpenAPISchemaOrRef>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Items {
+        Boolean(bool),
+        Schema(Box<OpenAPISchemaOrRef>),
+    }
+
+    Ok(match Option::<Items>::deserialize(deserializer)? {
+        Some(Items::Boolean(true)) => Some(Box::new(OpenAPISchemaOrRef::Schema(Box::default()))),
+        Some(Items::Boolean(false)) | None => None,
+        Some(Items::Schema(schema)) => Some(schema),
+    })
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct OpenAPIDiscriminator {
     #[serde(rename = "propertyName")]
     pub property_name: String,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
     pub mapping: IndexMap<String, String>,
+    #[serde(
+        flatten,
+        default,
+        deserialize_with = "deserialize_extensions",
+        skip_serializing_if = "IndexMap::is_empty"
+    )]
+    pub extensions: Extensions,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OpenAPIXML {
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub namespace: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub prefix: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub attribute: Option<bool>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub wrapped: Option<bool>,
+    #[serde(
+        flatten,
+        default,
+        deserialize_with = "deserialize_extensions",
+        skip_serializing_if = "IndexMap::is_empty"
+    )]
+    pub extensions: Extensions,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct OpenAPIComponents {
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
     pub schemas: IndexMap<String, OpenAPISchemaOrRef>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
     pub responses: IndexMap<String, OpenAPIResponseOrRef>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
     pub parameters: IndexMap<String, OpenAPIParameterOrRef>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
     pub examples: IndexMap<String, OpenAPIExampleOrRef>,
-    #[serde(default, rename = "requestBodies")]
+    #[serde(
+        default,
+        skip_serializing_if = "IndexMap::is_empty",
+        rename = "requestBodies"
+    )]
     pub request_bodies: IndexMap<String, OpenAPIRequestBodyOrRef>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
     pub headers: IndexMap<String, OpenAPIHeaderOrRef>,
-    #[serde(default, rename = "securitySchemes")]
+    #[serde(
+        default,
+        skip_serializing_if = "IndexMap::is_empty",
+        rename = "securitySchemes"
+    )]
     pub security_schemes: IndexMap<String, OpenAPISecuritySchemeOrRef>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
     pub links: IndexMap<String, OpenAPILinkOrRef>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
     pub callbacks: IndexMap<String, OpenAPICallbackOrRef>,
-    #[serde(default, rename = "pathItems")]
+    #[serde(
+        default,
+        skip_serializing_if = "IndexMap::is_empty",
+        rename = "pathItems"
+    )]
     pub path_items: IndexMap<String, OpenAPIPathItem>,
+    #[serde(
+        flatten,
+        default,
+        deserialize_with = "deserialize_extensions",
+        skip_serializing_if = "IndexMap::is_empty"
+    )]
+    pub extensions: Extensions,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -382,69 +811,113 @@ pub struct OpenAPIReference {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum OpenAPIExampleOrRef {
-    Example(OpenAPIExample),
     Reference(OpenAPIReference),
+    Example(OpenAPIExample),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OpenAPIExample {
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub summary: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub value: Option<serde_json::Value>,
-    #[serde(default, rename = "externalValue")]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "externalValue"
+    )]
     pub external_value: Option<String>,
+    #[serde(
+        flatten,
+        default,
+        deserialize_with = "deserialize_extensions",
+        skip_serializing_if = "IndexMap::is_empty"
+    )]
+    pub extensions: Extensions,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum OpenAPIHeaderOrRef {
-    Header(Box<OpenAPIHeader>),
     Reference(OpenAPIReference),
+    Header(Box<OpenAPIHeader>),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OpenAPIHeader {
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
     pub required: bool,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
     pub deprecated: bool,
-    #[serde(default, rename = "allowEmptyValue")]
+    #[serde(
+        default,
+        skip_serializing_if = "std::ops::Not::not",
+        rename = "allowEmptyValue"
+    )]
     pub allow_empty_value: bool,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub style: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub explode: Option<bool>,
-    #[serde(default, rename = "allowReserved")]
+    #[serde(
+        default,
+        skip_serializing_if = "std::ops::Not::not",
+        rename = "allowReserved"
+    )]
     pub allow_reserved: bool,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub schema: Option<OpenAPISchemaOrRef>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub example: Option<serde_json::Value>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
     pub examples: IndexMap<String, OpenAPIExampleOrRef>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
     pub content: IndexMap<String, OpenAPIMediaType>,
+    #[serde(
+        flatten,
+        default,
+        deserialize_with = "deserialize_extensions",
+        skip_serializing_if = "IndexMap::is_empty"
+    )]
+    pub extensions: Extensions,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct OpenAPITag {
     pub name: String,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
-    #[serde(default, rename = "externalDocs")]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "externalDocs"
+    )]
     pub external_docs: Option<OpenAPIExternalDocumentation>,
+    #[serde(
+        flatten,
+        default,
+        deserialize_with = "deserialize_extensions",
+        skip_serializing_if = "IndexMap::is_empty"
+    )]
+    pub extensions: Extensions,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OpenAPIExternalDocumentation {
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     pub url: String,
+    #[serde(
+        flatten,
+        default,
+        deserialize_with = "deserialize_extensions",
+        skip_serializing_if = "IndexMap::is_empty"
+    )]
+    pub extensions: Extensions,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -458,43 +931,88 @@ pub enum OpenAPISecuritySchemeOrRef {
 pub struct OpenAPISecurityScheme {
     #[serde(rename = "type")]
     pub scheme_type: String, // 'apiKey', 'http', 'oauth2', 'openIdConnect'
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
-    #[serde(default, rename = "in")]
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "in")]
     pub location: Option<String>, // 'query', 'header', 'cookie'
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub scheme: Option<String>,
-    #[serde(default, rename = "bearerFormat")]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "bearerFormat"
+    )]
     pub bearer_format: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub flows: Option<OpenAPIOAuthFlows>,
-    #[serde(default, rename = "openIdConnectUrl")]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "openIdConnectUrl"
+    )]
     pub open_id_connect_url: Option<String>,
+    #[serde(
+        flatten,
+        default,
+        deserialize_with = "deserialize_extensions",
+        skip_serializing_if = "IndexMap::is_empty"
+    )]
+    pub extensions: Extensions,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OpenAPIOAuthFlows {
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub implicit: Option<OpenAPIOAuthFlow>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub password: Option<OpenAPIOAuthFlow>,
-    #[serde(default, rename = "clientCredentials")]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "clientCredentials"
+    )]
     pub client_credentials: Option<OpenAPIOAuthFlow>,
-    #[serde(default, rename = "authorizationCode")]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "authorizationCode"
+    )]
     pub authorization_code: Option<OpenAPIOAuthFlow>,
+    #[serde(
+        flatten,
+        default,
+        deserialize_with = "deserialize_extensions",
+        skip_serializing_if = "IndexMap::is_empty"
+    )]
+    pub extensions: Extensions,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OpenAPIOAuthFlow {
-    #[serde(default, rename = "authorizationUrl")]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "authorizationUrl"
+    )]
     pub authorization_url: Option<String>,
-    #[serde(default, rename = "tokenUrl")]
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "tokenUrl")]
     pub token_url: Option<String>,
-    #[serde(default, rename = "refreshUrl")]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "refreshUrl"
+    )]
     pub refresh_url: Option<String>,
     pub scopes: IndexMap<String, String>,
+    #[serde(
+        flatten,
+        default,
+        deserialize_with = "deserialize_extensions",
+        skip_serializing_if = "IndexMap::is_empty"
+    )]
+    pub extensions: Extensions,
 }
 
 pub type OpenAPISecurityRequirement = IndexMap<String, Vec<String>>;
@@ -502,24 +1020,43 @@ pub type OpenAPISecurityRequirement = IndexMap<String, Vec<String>>;
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum OpenAPILinkOrRef {
-    Link(Box<OpenAPILink>),
     Reference(OpenAPIReference),
+    Link(Box<OpenAPILink>),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OpenAPILink {
-    #[serde(default, rename = "operationRef")]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "operationRef"
+    )]
     pub operation_ref: Option<String>,
-    #[serde(default, rename = "operationId")]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "operationId"
+    )]
     pub operation_id: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
     pub parameters: IndexMap<String, serde_json::Value>,
-    #[serde(default, rename = "requestBody")]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "requestBody"
+    )]
     pub request_body: Option<serde_json::Value>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub server: Option<OpenAPIServer>,
+    #[serde(
+        flatten,
+        default,
+        deserialize_with = "deserialize_extensions",
+        skip_serializing_if = "IndexMap::is_empty"
+    )]
+    pub extensions: Extensions,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -531,16 +1068,239 @@ pub enum OpenAPICallbackOrRef {
 
 pub type OpenAPICallback = IndexMap<String, OpenAPIPathItem>;
 
+/// An object given either inline or as a `$ref` to an entry of `components`.
+pub trait ComponentOrRef: Sized + 'static {
+    type Component;
+
+    /// Name of the `components` map holding objects of this kind
+    const SECTION: &'static str;
+
+    fn reference(&self) -> Option<&str>;
+    fn component(&self) -> Option<&Self::Component>;
+    fn section(components: &OpenAPIComponents) -> &IndexMap<String, Self>;
+}
+
+macro_rules! impl_component_or_ref {
+    ($or_ref:ty, $variant:ident, $component:ty, $section:literal, $field:ident) => {
+        impl ComponentOrRef for $or_ref {
+            type Component = $component;
+
+            const SECTION: &'static str = $section;
+
+            fn reference(&self) -> Option<&str> {
+                match self {
+                    Self::Reference(reference) => Some(&reference.reference),
+                    Self::$variant(_) => None,
+                }
+            }
+
+            fn component(&self) -> Option<&Self::Component> {
+                match self {
+                    Self::$variant(component) => Some(component),
+                    Self::Reference(_) => None,
+                }
+            }
+
+            fn section(components: &OpenAPIComponents) -> &IndexMap<String, Self> {
+                &components.$field
+            }
+        }
+    };
+}
+
+impl_component_or_ref!(
+    OpenAPISchemaOrRef,
+    Schema,
+    OpenAPISchema,
+    "schemas",
+    schemas
+);
+impl_component_or_ref!(
+    OpenAPIParameterOrRef,
+    Parameter,
+    OpenAPIParameter,
+    "parameters",
+    parameters
+);
+impl_component_or_ref!(
+    OpenAPIRequestBodyOrRef,
+    RequestBody,
+    OpenAPIRequestBody,
+    "requestBodies",
+    request_bodies
+);
+impl_component_or_ref!(
+    OpenAPIResponseOrRef,
+    Response,
+    OpenAPIResponse,
+    "responses",
+    responses
+);
+impl_component_or_ref!(
+    OpenAPIHeaderOrRef,
+    Header,
+    OpenAPIHeader,
+    "headers",
+    headers
+);
+impl_component_or_ref!(
+    OpenAPIExampleOrRef,
+    Example,
+    OpenAPIExample,
+    "examples",
+    examples
+);
+impl_component_or_ref!(OpenAPILinkOrRef, Link, OpenAPILink, "links", links);
+impl_component_or_ref!(
+    OpenAPICallbackOrRef,
+    Callback,
+    OpenAPICallback,
+    "callbacks",
+    callbacks
+);
+impl_component_or_ref!(
+    OpenAPISecuritySchemeOrRef,
+    SecurityScheme,
+    OpenAPISecurityScheme,
+    "securitySchemes",
+    security_schemes
+);
+
+/// Path items carry their `$ref` as a field rather than being an either/or.
+impl ComponentOrRef for OpenAPIPathItem {
+    type Component = OpenAPIPathItem;
+
+    const SECTION: &'static str = "pathItems";
+
+    fn reference(&self) -> Option<&str> {
+        self.reference.as_deref()
+    }
+
+    fn component(&self) -> Option<&Self::Component> {
+        match self.reference {
+            Some(_) => None,
+            None => Some(self),
+        }
+    }
+
+    fn section(components: &OpenAPIComponents) -> &IndexMap<String, Self> {
+        &components.path_items
+    }
+}
+
 // Code generation configuration and result types
 #[derive(Debug, Clone)]
 pub struct GeneratorConfig {
     pub output_dir: PathBuf,
     pub base_package: String,
+    /// Package of generated models, `<base_package>.model` when unset
+    pub model_package: Option<String>,
+    /// Package of generated controllers, `<base_package>.api` when unset
+    pub api_package: Option<String>,
     pub generate_controllers: bool,
     pub generate_models: bool,
     pub include_validation: bool,
     pub include_swagger: bool,
     pub verbose: bool,
+    pub inline_naming: InlineNamingStrategy,
+    pub target: TargetLanguage,
+    pub spring_boot: SpringBootProfile,
+    pub type_mappings: TypeMappings,
+    pub templates: TemplateOverrides,
+    pub filters: OperationFilters,
+}
+
+impl GeneratorConfig {
+    pub fn model_package(&self) -> String {
+        self.model_package
+            .clone()
+            .unwrap_or_else(|| format!("{}.model", self.base_package))
+    }
+
+    pub fn api_package(&self) -> String {
+        self.api_package
+            .clone()
+            .unwrap_or_else(|| format!("{}.api", self.base_package))
+    }
+}
+
+/// How classes synthesized for inline object and enum schemas are named.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum InlineNamingStrategy {
+    /// Owning class name followed by the property name, e.g. `OrderShippingAddress`
+    #[default]
+    ParentAndProperty,
+    /// Property name only, e.g. `ShippingAddress`
+    Property,
+    /// The inline schema's `title`, falling back to the owning class and property name
+    Title,
+}
+
+/// The language and framework code is generated for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum TargetLanguage {
+    /// Kotlin data classes and Spring Boot controller interfaces
+    #[default]
+    Kotlin,
+    /// Rust serde models and axum handlers dispatching to a trait per tag
+    Rust,
+}
+
+/// The Spring Boot generation a Kotlin project is generated for, which decides the
+/// validation namespace of models and controllers and the versions in the build file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum, Serialize, Deserialize)]
+pub enum SpringBootProfile {
+    /// Spring Boot 2.7 with `javax.validation` and springdoc 1.x, on Java 11
+    #[value(name = "2", alias = "2.7")]
+    #[serde(rename = "2", alias = "2.7")]
+    Boot2,
+    /// Spring Boot 3 with `jakarta.validation` and springdoc 2.x, on Java 17
+    #[default]
+    #[value(name = "3")]
+    #[serde(rename = "3")]
+    Boot3,
+}
+
+impl SpringBootProfile {
+    /// Package of the Bean Validation API
+    pub fn validation_package(self) -> &'static str {
+        match self {
+            SpringBootProfile::Boot2 => "javax.validation",
+            SpringBootProfile::Boot3 => "jakarta.validation",
+        }
+    }
+
+    pub fn build_versions(self) -> BuildVersions {
+        match self {
+            SpringBootProfile::Boot2 => BuildVersions {
+                spring_boot: "2.7.18",
+                dependency_management: "1.0.15.RELEASE",
+                kotlin: "1.8.22",
+                jvm_target: "11",
+                springdoc: "org.springdoc:springdoc-openapi-ui:1.7.0",
+            },
+            SpringBootProfile::Boot3 => BuildVersions {
+                spring_boot: "3.1.0",
+                dependency_management: "1.1.0",
+                kotlin: "1.9.20",
+                jvm_target: "17",
+                springdoc: "org.springdoc:springdoc-openapi-starter-webmvc-ui:2.1.0",
+            },
+        }
+    }
+}
+
+/// Plugin, library and JVM versions written into a generated build file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct BuildVersions {
+    pub spring_boot: &'static str,
+    pub dependency_management: &'static str,
+    pub kotlin: &'static str,
+    pub jvm_target: &'static str,
+    /// Coordinates of the springdoc UI starter
+    pub springdoc: &'static str,
 }
 
 #[derive(Debug, Clone)]
@@ -552,18 +1312,23 @@ pub struct GenerationResult {
 }
 
 // Internal code generation types
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct KotlinProperty {
     pub name: String,
     pub kotlin_type: String,
     pub nullable: bool,
+    /// Kotlin expression the property is initialized with
     pub default_value: Option<String>,
+    /// Wire value of the schema's default, shown as the Swagger example
+    pub example: Option<String>,
     pub description: Option<String>,
     pub validation: Vec<String>,
     pub json_property: Option<String>,
+    /// Annotations added from `x-kotlin-annotations`, written as they appear in source
+    pub annotations: Vec<String>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 #[allow(dead_code)]
 pub struct KotlinClass {
     pub name: String,
@@ -574,9 +1339,27 @@ pub struct KotlinClass {
     pub is_sealed: Option<bool>,
     pub sealed_sub_types: Option<Vec<KotlinClass>>,
     pub parent_class: Option<String>,
+    /// Property Jackson uses to select the subtype of a sealed class.
+    pub discriminator_property: Option<String>,
+    /// Wire value identifying this class as a subtype of its sealed parent.
+    pub discriminator_value: Option<String>,
+    /// Constants of an enum class; its single property holds the wire value.
+    pub enum_constants: Option<Vec<KotlinEnumConstant>>,
+    /// Annotations added from `x-kotlin-annotations`, written as they appear in source.
+    pub annotations: Vec<String>,
+    /// Interfaces the class implements, from `x-kotlin-implements`.
+    pub interfaces: Vec<String>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
+pub struct KotlinEnumConstant {
+    pub name: String,
+    /// Kotlin literal of the wire value passed to the enum constructor
+    pub value: String,
+    pub json_property: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
 pub struct KotlinMethod {
     pub name: String,
     pub http_method: String,
@@ -587,28 +1370,110 @@ pub struct KotlinMethod {
     pub request_body: Option<KotlinParameter>,
     pub return_type: String,
     pub response_description: Option<String>,
+    /// Annotations added from `x-kotlin-annotations`, written as they appear in source
+    pub annotations: Vec<String>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 #[allow(dead_code)]
 pub struct KotlinParameter {
     pub name: String,
+    /// Name the parameter is sent by, when it differs from `name`
+    pub wire_name: Option<String>,
     pub kotlin_type: String,
     pub param_type: ParameterType,
+    pub conversion: Option<ParameterConversion>,
     pub required: bool,
     pub description: Option<String>,
     pub validation: Vec<String>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum ParameterType {
     Path,
     Query,
     Body,
     Header,
+    Cookie,
 }
 
-#[derive(Debug, Clone)]
+/// A conversion for a parameter whose serialization Spring cannot bind natively.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
+pub enum ParameterConversion {
+    /// A `spaceDelimited` array of the given Kotlin item type.
+    SpaceDelimited(String),
+    /// A `pipeDelimited` array of the given Kotlin item type.
+    PipeDelimited(String),
+    /// A parameter defined with JSON `content`, deserialized into the named class.
+    Json(String),
+}
+
+/// Kotlin types the items of a delimited array can be parsed into, with the
+/// expression parsing an item `it` and the import that expression needs. Items of
+/// other types are bound as strings.
+pub const DELIMITED_ITEM_TYPES: &[(&str, &str, Option<&str>)] = &[
+    ("String", "it", None),
+    ("Int", "it.toInt()", None),
+    ("Long", "it.toLong()", None),
+    ("Float", "it.toFloat()", None),
+    ("Double", "it.toDouble()", None),
+    ("Boolean", "it.toBooleanStrict()", None),
+    ("UUID", "UUID.fromString(it)", Some("java.util.UUID")),
+    (
+        "LocalDate",
+        "LocalDate.parse(it)",
+        Some("java.time.LocalDate"),
+    ),
+    (
+        "OffsetDateTime",
+        "OffsetDateTime.parse(it)",
+        Some("java.time.OffsetDateTime"),
+    ),
+];
+
+/// The class a delimited array parameter is bound to, holding its parsed items.
+#[derive(Debug, Clone, Serialize)]
+pub struct DelimitedList {
+    /// `SpaceDelimitedList` or `PipeDelimitedList` for strings, with the item type
+    /// in between for other items, e.g. `PipeDelimitedIntList`
+    pub class_name: String,
+    pub style: &'static str,
+    pub delimiter: &'static str,
+    pub item_type: String,
+    /// Expression parsing an item `it`, unless items are strings
+    pub parse: Option<&'static str>,
+    pub import: Option<&'static str>,
+}
+
+impl ParameterConversion {
+    /// Returns the class a delimited array is bound to, `None` for other conversions.
+    pub fn delimited_list(&self) -> Option<DelimitedList> {
+        let (style, class_prefix, delimiter, item_type) = match self {
+            Self::SpaceDelimited(item_type) => ("spaceDelimited", "SpaceDelimited", " ", item_type),
+            Self::PipeDelimited(item_type) => ("pipeDelimited", "PipeDelimited", "|", item_type),
+            Self::Json(_) => return None,
+        };
+        let (_, parse, import) = DELIMITED_ITEM_TYPES
+            .iter()
+            .find(|(name, _, _)| name == item_type)?;
+        let is_string = item_type == "String";
+
+        Some(DelimitedList {
+            class_name: format!(
+                "{class_prefix}{}List",
+                if is_string { "" } else { item_type }
+            ),
+            style,
+            delimiter,
+            item_type: item_type.clone(),
+            parse: (!is_string).then_some(*parse),
+            import: *import,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
 pub struct KotlinController {
     pub name: String,
     pub package_name: String,
@@ -618,6 +1483,7 @@ pub struct KotlinController {
 }
 
 #[cfg(test)]
+#[allow(clippy::field_reassign_with_default)]
 mod tests {
     use super::*;
 
@@ -628,17 +1494,22 @@ mod tests {
             info: OpenAPIInfo {
                 title: "Test API".to_string(),
                 version: "1.0.0".to_string(),
+                summary: None,
                 description: Some("A test API".to_string()),
                 terms_of_service: None,
                 contact: None,
                 license: None,
+                extensions: Extensions::new(),
             },
             servers: vec![],
             paths: IndexMap::new(),
+            webhooks: IndexMap::new(),
+            json_schema_dialect: None,
             components: None,
             security: vec![],
             tags: vec![],
             external_docs: None,
+            extensions: Extensions::new(),
         };
 
         let json_str = serde_json::to_string(&spec).unwrap();
@@ -668,17 +1539,22 @@ mod tests {
         let info = OpenAPIInfo {
             title: "Complete API".to_string(),
             version: "2.0.0".to_string(),
+            summary: Some("Everything about the API".to_string()),
             description: Some("A complete API description".to_string()),
             terms_of_service: Some("https://example.com/terms".to_string()),
             contact: Some(OpenAPIContact {
                 name: Some("Support Team".to_string()),
                 url: Some("https://example.com/support".to_string()),
                 email: Some("support@example.com".to_string()),
+                extensions: Extensions::new(),
             }),
             license: Some(OpenAPILicense {
                 name: "MIT".to_string(),
+                identifier: None,
                 url: Some("https://opensource.org/licenses/MIT".to_string()),
+                extensions: Extensions::new(),
             }),
+            extensions: Extensions::new(),
         };
 
         assert_eq!(info.title, "Complete API");
@@ -704,6 +1580,7 @@ mod tests {
                         enum_values: vec!["dev".to_string(), "staging".to_string()],
                         default: "dev".to_string(),
                         description: Some("Environment name".to_string()),
+                        extensions: Extensions::new(),
                     },
                 );
                 vars.insert(
@@ -712,10 +1589,12 @@ mod tests {
                         enum_values: vec![],
                         default: "8080".to_string(),
                         description: Some("Server port".to_string()),
+                        extensions: Extensions::new(),
                     },
                 );
                 vars
             },
+            extensions: Extensions::new(),
         };
 
         assert!(server.url.contains("{environment}"));
@@ -776,6 +1655,184 @@ mod tests {
         assert_eq!(object_schema, "object");
     }
 
+    #[test]
+    fn test_openapi_3_1_schema_keywords() {
+        let schema: OpenAPISchema = serde_json::from_value(serde_json::json!({
+            "$id": "https://example.com/schemas/point",
+            "type": ["array", "null"],
+            "prefixItems": [{ "type": "number" }, { "type": "number" }],
+            "items": false,
+            "dependentRequired": { "label": ["color"] },
+            "if": { "minItems": 3 },
+            "then": { "maxItems": 3 },
+            "$defs": { "Coordinate": { "type": "number" } }
+        }))
+        .unwrap();
+
+        assert_eq!(
+            schema.schema_type,
+            Some(SchemaType::Multiple(vec![
+                "array".to_string(),
+                "null".to_string()
+            ]))
+        );
+        assert_eq!(schema.primary_type(), Some("array"));
+        assert!(schema.is_nullable());
+        assert_eq!(schema.prefix_items.len(), 2);
+        assert!(schema.items.is_none());
+        assert_eq!(schema.dependent_required["label"], ["color"]);
+        assert!(schema.if_schema.is_some() && schema.then_schema.is_some());
+        assert!(schema.else_schema.is_none());
+        assert!(schema.defs.contains_key("Coordinate"));
+        assert_eq!(
+            schema.id.as_deref(),
+            Some("https://example.com/schemas/point")
+        );
+
+        // `items: true` allows any item
+        let any_items: OpenAPISchema =
+            serde_json::from_value(serde_json::json!({ "type": "array", "items": true })).unwrap();
+        assert!(matches!(
+            any_items.items.as_deref(),
+            Some(OpenAPISchemaOrRef::Schema(schema)) if schema.schema_type.is_none()
+        ));
+    }
+
+    #[test]
+    fn test_schema_type_primary_and_nullability() {
+        assert_eq!(SchemaType::from("string").primary(), Some("string"));
+        assert_eq!(SchemaType::from("null").primary(), None);
+        assert!(SchemaType::from("null").allows_null());
+        let several = SchemaType::Multiple(vec!["string".to_string(), "integer".to_string()]);
+        assert_eq!(several.primary(), None);
+        assert!(!several.allows_null());
+
+        let nullable = OpenAPISchema {
+            schema_type: Some("string".into()),
+            nullable: Some(true),
+            ..Default::default()
+        };
+        assert!(nullable.is_nullable());
+
+        // Without a type, the type of `const` is used
+        let constant = OpenAPISchema {
+            const_value: Some(serde_json::json!(42)),
+            ..Default::default()
+        };
+        assert_eq!(constant.primary_type(), Some("integer"));
+        assert!(!constant.is_nullable());
+    }
+
+    #[test]
+    fn test_schema_bounds() {
+        let bounded = |minimum, exclusive_minimum| OpenAPISchema {
+            minimum,
+            exclusive_minimum,
+            ..Default::default()
+        };
+
+        assert_eq!(bounded(Some(1.0), None).lower_bound(), Some((1.0, false)));
+        assert_eq!(
+            bounded(Some(1.0), Some(serde_json::json!(true))).lower_bound(),
+            Some((1.0, true))
+        );
+        // A boolean without a bound has nothing to apply to
+        assert_eq!(
+            bounded(None, Some(serde_json::json!(true))).lower_bound(),
+            None
+        );
+        assert_eq!(
+            bounded(None, Some(serde_json::json!(2.5))).lower_bound(),
+            Some((2.5, true))
+        );
+        assert_eq!(
+            bounded(Some(2.5), Some(serde_json::json!(2.5))).lower_bound(),
+            Some((2.5, true))
+        );
+
+        let upper = OpenAPISchema {
+            maximum: Some(5.0),
+            exclusive_maximum: Some(serde_json::json!(10)),
+            ..Default::default()
+        };
+        assert_eq!(upper.upper_bound(), Some((5.0, false)));
+    }
+
+    #[test]
+    fn test_openapi_3_1_document_without_paths() {
+        let spec: OpenAPISpec = serde_json::from_value(serde_json::json!({
+            "openapi": "3.1.0",
+            "info": { "title": "Events", "version": "1.0.0" },
+            "jsonSchemaDialect": "https://json-schema.org/draft/2020-12/schema",
+            "webhooks": {
+                "orderPlaced": {
+                    "post": { "responses": { "200": { "description": "Received" } } }
+                }
+            }
+        }))
+        .unwrap();
+
+        assert!(spec.paths.is_empty());
+        assert!(spec.webhooks["orderPlaced"].post.is_some());
+        assert!(spec.json_schema_dialect.is_some());
+    }
+
+    #[test]
+    fn test_extensions_round_trip() {
+        let document = serde_json::json!({
+            "openapi": "3.0.3",
+            "info": { "title": "Pets", "version": "1.0.0", "x-audience": "internal" },
+            "paths": {
+                "/pets": {
+                    "x-owner": "pets-team",
+                    "get": {
+                        "x-kotlin-name": "listAllPets",
+                        "responses": { "200": { "description": "OK", "x-cache": 60 } }
+                    }
+                }
+            },
+            "components": {
+                "schemas": {
+                    "Pet": {
+                        "type": "object",
+                        "unknownKeyword": true,
+                        "x-kotlin-type": { "type": "Pet", "import": "com.example.Pet" },
+                        "x-enum-varnames": []
+                    }
+                }
+            }
+        });
+        let spec: OpenAPISpec = serde_json::from_value(document.clone()).unwrap();
+
+        assert_eq!(spec.info.extensions["x-audience"], "internal");
+        let path_item = &spec.paths["/pets"];
+        assert_eq!(path_item.extensions["x-owner"], "pets-team");
+        let operation = path_item.get.as_ref().unwrap();
+        assert_eq!(operation.extensions["x-kotlin-name"], "listAllPets");
+        let Some(OpenAPISchemaOrRef::Schema(pet)) = spec
+            .components
+            .as_ref()
+            .and_then(|components| components.schemas.get("Pet"))
+        else {
+            panic!("Expected an inline Pet schema");
+        };
+        // Declared fields and keys other than extensions don't end up among them
+        assert_eq!(
+            pet.extensions.keys().collect::<Vec<_>>(),
+            vec!["x-kotlin-type"]
+        );
+
+        let serialized = serde_json::to_value(&spec).unwrap();
+        assert_eq!(serialized["paths"]["/pets"], document["paths"]["/pets"]);
+        assert_eq!(
+            serialized["components"]["schemas"]["Pet"],
+            serde_json::json!({
+                "type": "object",
+                "x-kotlin-type": { "type": "Pet", "import": "com.example.Pet" }
+            })
+        );
+    }
+
     #[test]
     fn test_openapi_schema_format_variants() {
         let formats = vec![
@@ -834,6 +1891,7 @@ mod tests {
         let discriminator = OpenAPIDiscriminator {
             property_name: "petType".to_string(),
             mapping,
+            extensions: Extensions::new(),
         };
 
         assert_eq!(discriminator.property_name, "petType");
@@ -860,6 +1918,7 @@ mod tests {
                 example: None,
                 examples: IndexMap::new(),
                 encoding: IndexMap::new(),
+                extensions: Extensions::new(),
             },
         );
 
@@ -868,6 +1927,7 @@ mod tests {
             headers: IndexMap::new(),
             content,
             links: IndexMap::new(),
+            extensions: Extensions::new(),
         };
 
         assert_eq!(response.description, "Successful response");
@@ -888,7 +1948,7 @@ mod tests {
             explode: Some(false),
             allow_reserved: false,
             schema: Some(OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
-                schema_type: Some("integer".to_string()),
+                schema_type: Some("integer".into()),
                 format: Some("int32".to_string()),
                 minimum: Some(1.0),
                 maximum: Some(100.0),
@@ -897,6 +1957,7 @@ mod tests {
             example: None,
             examples: IndexMap::new(),
             content: IndexMap::new(),
+            extensions: Extensions::new(),
         };
 
         assert_eq!(query_param.name, "limit");
@@ -916,6 +1977,7 @@ mod tests {
             bearer_format: None,
             flows: None,
             open_id_connect_url: None,
+            extensions: Extensions::new(),
         };
 
         let oauth_scheme = OpenAPISecurityScheme {
@@ -938,10 +2000,13 @@ mod tests {
                         scopes.insert("write".to_string(), "Write access".to_string());
                         scopes
                     },
+                    extensions: Extensions::new(),
                 }),
                 authorization_code: None,
+                extensions: Extensions::new(),
             }),
             open_id_connect_url: None,
+            extensions: Extensions::new(),
         };
 
         assert_eq!(api_key_scheme.scheme_type, "apiKey");
@@ -972,7 +2037,9 @@ mod tests {
             external_docs: Some(OpenAPIExternalDocumentation {
                 description: Some("Find more info here".to_string()),
                 url: "https://example.com/docs/users".to_string(),
+                extensions: Extensions::new(),
             }),
+            extensions: Extensions::new(),
         };
 
         assert_eq!(tag.name, "users");
@@ -1003,7 +2070,7 @@ mod tests {
 
     #[test]
     fn test_schema_or_ref_enum_variants() {
-        let schema_variant = OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema::default()));
+        let schema_variant = OpenAPISchemaOrRef::Schema(Box::default());
         let reference_variant = OpenAPISchemaOrRef::Reference(OpenAPIReference {
             reference: "#/components/schemas/User".to_string(),
         });
@@ -1037,6 +2104,7 @@ mod tests {
             example: None,
             examples: IndexMap::new(),
             content: IndexMap::new(),
+            extensions: Extensions::new(),
         }));
 
         let reference_variant = OpenAPIParameterOrRef::Reference(OpenAPIReference {
@@ -1065,11 +2133,19 @@ mod tests {
         let config = GeneratorConfig {
             output_dir: PathBuf::from("/tmp/output"),
             base_package: "com.example.api".to_string(),
+            model_package: None,
+            api_package: None,
             generate_controllers: true,
             generate_models: true,
             include_validation: true,
             include_swagger: false,
             verbose: false,
+            inline_naming: InlineNamingStrategy::ParentAndProperty,
+            target: TargetLanguage::Kotlin,
+            spring_boot: SpringBootProfile::default(),
+            type_mappings: TypeMappings::default(),
+            templates: TemplateOverrides::default(),
+            filters: OperationFilters::default(),
         };
 
         assert_eq!(config.output_dir, PathBuf::from("/tmp/output"));
@@ -1108,9 +2184,11 @@ mod tests {
             kotlin_type: "String".to_string(),
             nullable: false,
             default_value: None,
+            example: None,
             description: Some("User email address".to_string()),
             validation: vec!["@Email".to_string(), "@NotBlank".to_string()],
             json_property: Some("email_address".to_string()),
+            annotations: Vec::new(),
         };
 
         assert_eq!(property.name, "email");
@@ -1135,24 +2213,33 @@ mod tests {
                     kotlin_type: "Long".to_string(),
                     nullable: false,
                     default_value: None,
+                    example: None,
                     description: Some("User ID".to_string()),
                     validation: vec!["@NotNull".to_string()],
                     json_property: None,
+                    annotations: Vec::new(),
                 },
                 KotlinProperty {
                     name: "name".to_string(),
                     kotlin_type: "String".to_string(),
                     nullable: false,
                     default_value: None,
+                    example: None,
                     description: Some("User name".to_string()),
                     validation: vec!["@NotBlank".to_string()],
                     json_property: None,
+                    annotations: Vec::new(),
                 },
             ],
             imports: vec!["javax.validation.constraints.NotNull".to_string()],
             is_sealed: Some(false),
             sealed_sub_types: None,
             parent_class: None,
+            discriminator_property: None,
+            discriminator_value: None,
+            enum_constants: None,
+            annotations: Vec::new(),
+            interfaces: Vec::new(),
         };
 
         assert_eq!(kotlin_class.name, "User");
@@ -1176,8 +2263,10 @@ mod tests {
             description: Some("Retrieves a user by their unique identifier".to_string()),
             parameters: vec![KotlinParameter {
                 name: "id".to_string(),
+                wire_name: None,
                 kotlin_type: "Long".to_string(),
                 param_type: ParameterType::Path,
+                conversion: None,
                 required: true,
                 description: Some("User ID".to_string()),
                 validation: vec!["@Min(1)".to_string()],
@@ -1185,6 +2274,7 @@ mod tests {
             request_body: None,
             return_type: "User".to_string(),
             response_description: Some("User object".to_string()),
+            annotations: Vec::new(),
         };
 
         assert_eq!(method.name, "getUser");
@@ -1230,6 +2320,7 @@ mod tests {
                     request_body: None,
                     return_type: "List<User>".to_string(),
                     response_description: Some("List of users".to_string()),
+                    annotations: Vec::new(),
                 },
                 KotlinMethod {
                     name: "createUser".to_string(),
@@ -1240,14 +2331,17 @@ mod tests {
                     parameters: vec![],
                     request_body: Some(KotlinParameter {
                         name: "user".to_string(),
+                        wire_name: None,
                         kotlin_type: "User".to_string(),
                         param_type: ParameterType::Body,
+                        conversion: None,
                         required: true,
                         description: Some("User to create".to_string()),
                         validation: vec!["@Valid".to_string()],
                     }),
                     return_type: "User".to_string(),
                     response_description: Some("Created user".to_string()),
+                    annotations: Vec::new(),
                 },
             ],
             imports: vec![
@@ -1268,7 +2362,7 @@ mod tests {
     #[test]
     fn test_complex_schema_json_serialization() {
         let schema = OpenAPISchema {
-            schema_type: Some("object".to_string()),
+            schema_type: Some("object".into()),
             format: None,
             title: Some("User".to_string()),
             description: Some("User model".to_string()),
@@ -1277,7 +2371,7 @@ mod tests {
                 props.insert(
                     "id".to_string(),
                     OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
-                        schema_type: Some("integer".to_string()),
+                        schema_type: Some("integer".into()),
                         format: Some("int64".to_string()),
                         ..Default::default()
                     })),
@@ -1285,7 +2379,7 @@ mod tests {
                 props.insert(
                     "name".to_string(),
                     OpenAPISchemaOrRef::Schema(Box::new(OpenAPISchema {
-                        schema_type: Some("string".to_string()),
+                        schema_type: Some("string".into()),
                         ..Default::default()
                     })),
                 );
@@ -0,0 +1,723 @@
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+
+use crate::errors::{self, OpenAPIError, Result};
+use crate::parser::OpenAPIParser;
+use crate::source_map::escape;
+use crate::types::*;
+use indexmap::IndexMap;
+
+/// Values a parameter's `in` may take.
+const PARAMETER_LOCATIONS: [&str; 4] = ["query", "header", "path", "cookie"];
+
+/// How serious a problem found by validation or linting is. Errors make a
+/// specification unusable for generation, warnings point at likely mistakes, and
+/// infos at style issues.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Error,
+    Warning,
+    Info,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Error => write!(f, "error"),
+            Severity::Warning => write!(f, "warning"),
+            Severity::Info => write!(f, "info"),
+        }
+    }
+}
+
+/// A problem found in a specification, located in its source where possible.
+#[derive(Debug)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub error: OpenAPIError,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.severity, self.error)
+    }
+}
+
+/// Parses and validates a specification file, reporting every problem found rather
+/// than stopping at the first:
+/// - every `$ref` resolves, including discriminator mappings
+/// - operation IDs are unique
+/// - path templates and path parameters match each other
+/// - parameters have a known `in`
+/// - required properties are defined
+/// - `pattern`s are valid regular expressions
+///
+/// # Errors
+///
+/// Returns an error only if the file can't be read into a specification at all, e.g.
+/// because it isn't valid JSON or YAML.
+///
+/// # Examples
+///
+/// ```rust
+/// use openapi_codegen_rust::validator::{self, Severity};
+///
+/// // Use in async context:
+/// // let diagnostics = validator::validate_file("api.yaml").await.unwrap();
+/// // let valid = diagnostics.iter().all(|d| d.severity != Severity::Error);
+/// ```
+pub async fn validate_file<P: AsRef<Path>>(file_path: P) -> Result<Vec<Diagnostic>> {
+    let mut parser = OpenAPIParser::new();
+    let problems = parser.load_file(file_path).await?;
+
+    let mut validator = SpecValidator::new(&parser);
+    for problem in problems {
+        validator.report(Severity::Error, problem);
+    }
+    validator.validate();
+    Ok(validator.diagnostics)
+}
+
+struct SpecValidator<'a> {
+    parser: &'a OpenAPIParser,
+    diagnostics: Vec<Diagnostic>,
+    /// First operation using each operation ID, as `METHOD /path`.
+    operation_ids: HashMap<String, String>,
+}
+
+impl<'a> SpecValidator<'a> {
+    fn new(parser: &'a OpenAPIParser) -> Self {
+        Self {
+            parser,
+            diagnostics: Vec::new(),
+            operation_ids: HashMap::new(),
+        }
+    }
+
+    /// Adds a diagnostic, unless the same one was already reported.
+    fn report(&mut self, severity: Severity, error: OpenAPIError) {
+        let message = error.to_string();
+        if self
+            .diagnostics
+            .iter()
+            .all(|diagnostic| diagnostic.error.to_string() != message)
+        {
+            self.diagnostics.push(Diagnostic { severity, error });
+        }
+    }
+
+    fn report_at(&mut self, severity: Severity, pointer: &str, error: OpenAPIError) {
+        let error = self.parser.locate_at(pointer, error);
+        self.report(severity, error);
+    }
+
+    /// Reports a failure to resolve the `$ref` at `pointer`. Failures further along a
+    /// chain of references point at the reference that failed, and a `$ref` that
+    /// already has a problem, such as a missing file, isn't reported again.
+    fn report_reference(&mut self, reference: &str, pointer: &str, error: OpenAPIError) {
+        let failed_reference = match error.kind() {
+            OpenAPIError::ReferenceNotFound { reference, .. }
+            | OpenAPIError::CircularReference { reference }
+            | OpenAPIError::ExternalReferenceNotSupported { reference, .. } => Some(reference),
+            _ => None,
+        };
+        let error = if failed_reference.is_some_and(|failed| failed == reference) {
+            self.parser.locate_at(&format!("{pointer}/$ref"), error)
+        } else {
+            self.parser.locate(error)
+        };
+
+        let reported = location(&error).is_some_and(|location| {
+            self.diagnostics
+                .iter()
+                .any(|diagnostic| self::location(&diagnostic.error).as_ref() == Some(&location))
+        });
+        if !reported {
+            self.report(Severity::Error, error);
+        }
+    }
+
+    /// Resolves an object given inline or by reference, reporting a reference that
+    /// doesn't resolve.
+    fn resolve<T: ComponentOrRef>(
+        &mut self,
+        item: &'a T,
+        pointer: &str,
+    ) -> Option<&'a T::Component> {
+        match self.parser.resolve_component(item) {
+            Ok(component) => Some(component),
+            Err(error) => {
+                self.report_reference(item.reference().unwrap_or_default(), pointer, error);
+                None
+            }
+        }
+    }
+
+    /// Returns an object given inline. A reference is only checked to resolve, as the
+    /// object it points at is checked where it is defined.
+    fn inline<T: ComponentOrRef>(
+        &mut self,
+        item: &'a T,
+        pointer: &str,
+    ) -> Option<&'a T::Component> {
+        let component = self.resolve(item, pointer)?;
+        item.component().map(|_| component)
+    }
+
+    fn validate(&mut self) {
+        let spec = self.parser.get_spec();
+
+        for (path, path_item) in &spec.paths {
+            let pointer = format!("/paths/{}", escape(path));
+            self.check_path_item(path, path_item, &pointer, true);
+        }
+
+        for (name, path_item) in &spec.webhooks {
+            let pointer = format!("/webhooks/{}", escape(name));
+            self.check_path_item(name, path_item, &pointer, false);
+        }
+
+        if let Some(components) = &spec.components {
+            self.check_components(components);
+        }
+    }
+
+    fn check_components(&mut self, components: &'a OpenAPIComponents) {
+        for (name, schema) in &components.schemas {
+            self.check_schema(schema, &format!("/components/schemas/{}", escape(name)));
+        }
+
+        for (name, parameter) in &components.parameters {
+            let pointer = format!("/components/parameters/{}", escape(name));
+            if let Some(parameter) = self.inline(parameter, &pointer) {
+                self.check_parameter(parameter, &pointer);
+            }
+        }
+
+        for (name, request_body) in &components.request_bodies {
+            let pointer = format!("/components/requestBodies/{}", escape(name));
+            if let Some(request_body) = self.inline(request_body, &pointer) {
+                self.check_content(&request_body.content, &format!("{pointer}/content"));
+            }
+        }
+
+        for (name, response) in &components.responses {
+            let pointer = format!("/components/responses/{}", escape(name));
+            if let Some(response) = self.inline(response, &pointer) {
+                self.check_response(response, &pointer);
+            }
+        }
+
+        for (name, header) in &components.headers {
+            let pointer = format!("/components/headers/{}", escape(name));
+            if let Some(header) = self.inline(header, &pointer) {
+                self.check_header(header, &pointer);
+            }
+        }
+
+        for (name, path_item) in &components.path_items {
+            let pointer = format!("/components/pathItems/{}", escape(name));
+            self.check_path_item(name, path_item, &pointer, false);
+        }
+
+        for (name, callback) in &components.callbacks {
+            let pointer = format!("/components/callbacks/{}", escape(name));
+            self.check_callback(callback, &pointer);
+        }
+    }
+
+    /// Checks a path item and its operations. Path templates are only checked for
+    /// entries of `paths`; webhooks and callbacks are keyed by names and expressions.
+    fn check_path_item(
+        &mut self,
+        path: &str,
+        path_item: &'a OpenAPIPathItem,
+        pointer: &str,
+        templated: bool,
+    ) {
+        let Some(path_item) = self.inline(path_item, pointer) else {
+            return;
+        };
+
+        let path_parameters =
+            self.check_parameters(&path_item.parameters, &format!("{pointer}/parameters"));
+        for (method, operation) in path_item.operations() {
+            let operation_pointer = format!("{pointer}/{method}");
+            let name = format!("{} {path}", method.to_uppercase());
+
+            if let Some(operation_id) = &operation.operation_id {
+                match self.operation_ids.get(operation_id) {
+                    Some(first) => {
+                        let error = errors::duplicate_operation_id(operation_id, &name, first);
+                        self.report_at(
+                            Severity::Error,
+                            &format!("{operation_pointer}/operationId"),
+                            error,
+                        );
+                    }
+                    None => {
+                        self.operation_ids
+                            .insert(operation_id.clone(), name.clone());
+                    }
+                }
+            }
+
+            let mut parameters = path_parameters.clone();
+            for (parameter, parameter_pointer) in self.check_parameters(
+                &operation.parameters,
+                &format!("{operation_pointer}/parameters"),
+            ) {
+                // Operation parameters override path item parameters of the same name and location
+                parameters.retain(|(existing, _)| {
+                    existing.name != parameter.name || existing.location != parameter.location
+                });
+                parameters.push((parameter, parameter_pointer));
+            }
+            if templated {
+                self.check_path_template(path, &parameters, &operation_pointer);
+            }
+
+            if let Some(request_body) = &operation.request_body {
+                let request_body_pointer = format!("{operation_pointer}/requestBody");
+                if let Some(request_body) = self.inline(request_body, &request_body_pointer) {
+                    self.check_content(
+                        &request_body.content,
+                        &format!("{request_body_pointer}/content"),
+                    );
+                }
+            }
+
+            for (status, response) in &operation.responses {
+                let response_pointer = format!("{operation_pointer}/responses/{}", escape(status));
+                if let Some(response) = self.inline(response, &response_pointer) {
+                    self.check_response(response, &response_pointer);
+                }
+            }
+
+            for (name, callback) in &operation.callbacks {
+                let callback_pointer = format!("{operation_pointer}/callbacks/{}", escape(name));
+                self.check_callback(callback, &callback_pointer);
+            }
+        }
+    }
+
+    /// Checks that every templated segment of `path` has a path parameter, and every
+    /// path parameter a templated segment.
+    fn check_path_template(
+        &mut self,
+        path: &str,
+        parameters: &[(&OpenAPIParameter, String)],
+        operation_pointer: &str,
+    ) {
+        let template_names = OpenAPIParser::path_template_names(path);
+
+        for name in &template_names {
+            if !parameters
+                .iter()
+                .any(|(parameter, _)| parameter.location == "path" && parameter.name == *name)
+            {
+                let error = errors::missing_path_parameter(name, path);
+                self.report_at(Severity::Error, operation_pointer, error);
+            }
+        }
+
+        for (parameter, pointer) in parameters {
+            if parameter.location == "path" && !template_names.contains(&parameter.name.as_str()) {
+                let error = errors::unused_path_parameter(&parameter.name, path);
+                self.report_at(Severity::Error, pointer, error);
+            }
+        }
+    }
+
+    /// Checks a list of parameters and returns those that resolve, with their pointers.
+    fn check_parameters(
+        &mut self,
+        parameters: &'a [OpenAPIParameterOrRef],
+        pointer: &str,
+    ) -> Vec<(&'a OpenAPIParameter, String)> {
+        let mut resolved = Vec::new();
+        for (index, parameter) in parameters.iter().enumerate() {
+            let parameter_pointer = format!("{pointer}/{index}");
+            let Some(resolved_parameter) = self.resolve(parameter, &parameter_pointer) else {
+                continue;
+            };
+            // Referenced parameters are checked where they are defined
+            if parameter.component().is_some() {
+                self.check_parameter(resolved_parameter, &parameter_pointer);
+            }
+            resolved.push((resolved_parameter, parameter_pointer));
+        }
+        resolved
+    }
+
+    fn check_parameter(&mut self, parameter: &OpenAPIParameter, pointer: &str) {
+        if !PARAMETER_LOCATIONS.contains(&parameter.location.as_str()) {
+            let error = errors::invalid_parameter_location(
+                &parameter.name,
+                &parameter.location,
+                &pointer[1..],
+            );
+            self.report_at(Severity::Error, &format!("{pointer}/in"), error);
+        }
+
+        if let Some(schema) = &parameter.schema {
+            self.check_schema(schema, &format!("{pointer}/schema"));
+        }
+        self.check_content(&parameter.content, &format!("{pointer}/content"));
+    }
+
+    fn check_response(&mut self, response: &'a OpenAPIResponse, pointer: &str) {
+        for (name, header) in &response.headers {
+            let header_pointer = format!("{pointer}/headers/{}", escape(name));
+            if let Some(header) = self.inline(header, &header_pointer) {
+                self.check_header(header, &header_pointer);
+            }
+        }
+        self.check_content(&response.content, &format!("{pointer}/content"));
+
+        for (name, link) in &response.links {
+            self.resolve(link, &format!("{pointer}/links/{}", escape(name)));
+        }
+    }
+
+    fn check_header(&mut self, header: &OpenAPIHeader, pointer: &str) {
+        if let Some(schema) = &header.schema {
+            self.check_schema(schema, &format!("{pointer}/schema"));
+        }
+        self.check_content(&header.content, &format!("{pointer}/content"));
+    }
+
+    fn check_callback(&mut self, callback: &'a OpenAPICallbackOrRef, pointer: &str) {
+        if let Some(callback) = self.inline(callback, pointer) {
+            for (expression, path_item) in callback {
+                let path_item_pointer = format!("{pointer}/{}", escape(expression));
+                self.check_path_item(expression, path_item, &path_item_pointer, false);
+            }
+        }
+    }
+
+    fn check_content(&mut self, content: &IndexMap<String, OpenAPIMediaType>, pointer: &str) {
+        for (media_type, media) in content {
+            if let Some(schema) = &media.schema {
+                self.check_schema(schema, &format!("{pointer}/{}/schema", escape(media_type)));
+            }
+        }
+    }
+
+    fn check_schema(&mut self, schema: &OpenAPISchemaOrRef, pointer: &str) {
+        match schema {
+            OpenAPISchemaOrRef::Reference(reference) => {
+                if let Err(error) = self.parser.resolve_reference(&reference.reference) {
+                    self.report_reference(&reference.reference, pointer, error);
+                }
+            }
+            OpenAPISchemaOrRef::Schema(schema) => self.check_schema_object(schema, pointer),
+        }
+    }
+
+    fn check_schema_object(&mut self, schema: &OpenAPISchema, pointer: &str) {
+        let path = &pointer[1..];
+
+        // Composed schemas may define required properties in their parts
+        let composed =
+            !schema.all_of.is_empty() || !schema.one_of.is_empty() || !schema.any_of.is_empty();
+        if !composed {
+            for (index, property) in schema.required.iter().enumerate() {
+                if !schema.properties.contains_key(property) {
+                    let error = errors::undefined_required_property(property, path);
+                    self.report_at(
+                        Severity::Warning,
+                        &format!("{pointer}/required/{index}"),
+                        error,
+                    );
+                }
+            }
+        }
+
+        if let Some(pattern) = &schema.pattern {
+            if let Err(error) = Regex::new(pattern) {
+                let message = error.to_string();
+                let reason = message
+                    .lines()
+                    .last()
+                    .map(|line| line.trim_start_matches("error: "))
+                    .unwrap_or_default();
+                // ECMA-262 features the regex crate lacks, such as look-around, can't be checked
+                if !reason.contains("not supported") {
+                    let error = errors::invalid_pattern(pattern, path, reason);
+                    self.report_at(Severity::Error, &format!("{pointer}/pattern"), error);
+                }
+            }
+        }
+
+        if let Some(discriminator) = &schema.discriminator {
+            for (value, target) in &discriminator.mapping {
+                // Mapping values are either references or component names
+                let reference = if target.contains('/') || target.contains('#') {
+                    target.clone()
+                } else {
+                    format!("#/components/schemas/{target}")
+                };
+                if let Err(error) = self.parser.resolve_reference(&reference) {
+                    let mapping_pointer =
+                        format!("{pointer}/discriminator/mapping/{}", escape(value));
+                    self.report_at(Severity::Error, &mapping_pointer, error);
+                }
+            }
+        }
+
+        for (name, property) in &schema.properties {
+            self.check_schema(property, &format!("{pointer}/properties/{}", escape(name)));
+        }
+        if let Some(items) = &schema.items {
+            self.check_schema(items, &format!("{pointer}/items"));
+        }
+        if let Some(additional_properties @ serde_json::Value::Object(_)) =
+            &schema.additional_properties
+        {
+            if let Ok(additional_properties) =
+                serde_json::from_value::<OpenAPISchemaOrRef>(additional_properties.clone())
+            {
+                self.check_schema(
+                    &additional_properties,
+                    &format!("{pointer}/additionalProperties"),
+                );
+            }
+        }
+
+        let subschemas = [
+            ("prefixItems", &schema.prefix_items),
+            ("allOf", &schema.all_of),
+            ("oneOf", &schema.one_of),
+            ("anyOf", &schema.any_of),
+        ];
+        for (keyword, subschemas) in subschemas {
+            for (index, subschema) in subschemas.iter().enumerate() {
+                self.check_schema(subschema, &format!("{pointer}/{keyword}/{index}"));
+            }
+        }
+
+        let optional = [
+            ("not", &schema.not),
+            ("if", &schema.if_schema),
+            ("then", &schema.then_schema),
+            ("else", &schema.else_schema),
+        ];
+        for (keyword, subschema) in optional {
+            if let Some(subschema) = subschema {
+                self.check_schema(subschema, &format!("{pointer}/{keyword}"));
+            }
+        }
+
+        for (name, subschema) in &schema.dependent_schemas {
+            self.check_schema(
+                subschema,
+                &format!("{pointer}/dependentSchemas/{}", escape(name)),
+            );
+        }
+        for (name, subschema) in &schema.defs {
+            self.check_schema(subschema, &format!("{pointer}/$defs/{}", escape(name)));
+        }
+    }
+}
+
+/// File, line and column an error points at.
+fn location(error: &OpenAPIError) -> Option<(Option<String>, Option<u32>, Option<u32>)> {
+    error
+        .context()
+        .map(|context| (context.file.clone(), context.line, context.column))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    const SPEC: &str = r#"openapi: 3.0.3
+info:
+  title: ''
+  version: '1'
+paths:
+  /pets/{petId}:
+    get:
+      operationId: getPet
+      parameters:
+        - name: id
+          in: path
+          required: true
+          schema:
+            type: string
+        - name: q
+          in: body
+      responses:
+        '200':
+          description: ok
+          content:
+            application/json:
+              schema:
+                $ref: '#/components/schemas/Pett'
+  /pets:
+    get:
+      operationId: getPet
+      parameters:
+        - $ref: '#/components/parameters/Missing'
+      responses:
+        '200':
+          $ref: './nope.yaml#/Response'
+components:
+  schemas:
+    Pet:
+      type: object
+      required: [name, age]
+      properties:
+        name:
+          type: string
+          pattern: '[a-'
+        code:
+          type: string
+          pattern: '^(?=a).*$'
+      discriminator:
+        propertyName: kind
+        mapping:
+          dog: Dog
+          pet: '#/components/schemas/Pet'
+"#;
+
+    fn summary(diagnostics: &[Diagnostic]) -> Vec<(Severity, String, Option<u32>)> {
+        diagnostics
+            .iter()
+            .map(|diagnostic| {
+                let message = diagnostic.error.kind().to_string();
+                let line = diagnostic.error.context().and_then(|context| context.line);
+                (diagnostic.severity, message, line)
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_validate_file_reports_every_problem() {
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = temp_dir.path().join("api.yaml");
+        fs::write(&file_path, SPEC).unwrap();
+
+        let diagnostics = validate_file(&file_path).await.unwrap();
+        let missing_file = temp_dir.path().join("nope.yaml");
+
+        assert_eq!(
+            summary(&diagnostics),
+            vec![
+                (
+                    Severity::Error,
+                    "Missing required field 'title' at info.title".to_string(),
+                    Some(3)
+                ),
+                (
+                    Severity::Error,
+                    format!("File not found: {}", missing_file.display()),
+                    Some(31)
+                ),
+                (
+                    Severity::Error,
+                    "Parameter 'q' at paths/~1pets~1{petId}/get/parameters/1 has unknown location 'body'"
+                        .to_string(),
+                    Some(16)
+                ),
+                (
+                    Severity::Error,
+                    "Path parameter 'petId' is not declared for /pets/{petId}".to_string(),
+                    Some(7)
+                ),
+                (
+                    Severity::Error,
+                    "Path parameter 'id' does not appear in the template of /pets/{petId}"
+                        .to_string(),
+                    Some(10)
+                ),
+                (
+                    Severity::Error,
+                    "Reference '#/components/schemas/Pett' not found at components/schemas/Pett"
+                        .to_string(),
+                    Some(23)
+                ),
+                (
+                    Severity::Error,
+                    "Operation ID 'getPet' of GET /pets is already used by GET /pets/{petId}"
+                        .to_string(),
+                    Some(26)
+                ),
+                (
+                    Severity::Error,
+                    "Reference '#/components/parameters/Missing' not found at components/parameters/Missing"
+                        .to_string(),
+                    Some(28)
+                ),
+                (
+                    Severity::Warning,
+                    "Required property 'age' is not defined in the properties at components/schemas/Pet"
+                        .to_string(),
+                    Some(36)
+                ),
+                (
+                    Severity::Error,
+                    "Reference '#/components/schemas/Dog' not found at components/schemas/Dog"
+                        .to_string(),
+                    Some(47)
+                ),
+                (
+                    Severity::Error,
+                    "Invalid pattern '[a-' at components/schemas/Pet/properties/name: unclosed character class"
+                        .to_string(),
+                    Some(40)
+                ),
+            ]
+        );
+        assert!(diagnostics[0].to_string().starts_with("error: Missing"));
+    }
+
+    #[tokio::test]
+    async fn test_validate_valid_spec() {
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = temp_dir.path().join("api.json");
+        fs::write(
+            &file_path,
+            r##"{
+  "openapi": "3.1.0",
+  "info": {"title": "Pets", "version": "1"},
+  "paths": {
+    "/pets/{petId}": {
+      "parameters": [{"$ref": "#/components/parameters/PetId"}],
+      "get": {"operationId": "getPet", "responses": {"200": {"description": "ok"}}},
+      "delete": {"operationId": "deletePet", "responses": {"204": {"description": "gone"}}}
+    }
+  },
+  "components": {
+    "parameters": {
+      "PetId": {"name": "petId", "in": "path", "required": true, "schema": {"type": "string"}}
+    },
+    "schemas": {
+      "Pet": {"allOf": [{"$ref": "#/components/schemas/Base"}], "required": ["id"]},
+      "Base": {"type": "object", "properties": {"id": {"type": "string", "pattern": "^[a-z]+$"}}}
+    }
+  }
+}"##,
+        )
+        .unwrap();
+
+        assert!(validate_file(&file_path).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_validate_file_fails_on_unreadable_documents() {
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = temp_dir.path().join("api.yaml");
+        fs::write(&file_path, "openapi: [").unwrap();
+
+        let error = validate_file(&file_path).await.unwrap_err();
+        assert!(matches!(error.kind(), OpenAPIError::InvalidYaml { .. }));
+    }
+}
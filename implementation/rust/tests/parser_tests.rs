@@ -1,15 +1,15 @@
+#![allow(clippy::assertions_on_constants)]
+
 use openapi_codegen_rust::parser::OpenAPIParser;
 use openapi_codegen_rust::types::*;
 use serde_json::json;
 use std::fs;
 use tempfile::TempDir;
-use tokio;
 
 /// Comprehensive OpenAPI Parser Unit Tests
 ///
 /// This module provides extensive testing coverage for the OpenAPIParser
 /// to ensure robust parsing, validation, and schema resolution capabilities.
-
 #[cfg(test)]
 mod parser_unit_tests {
     use super::*;
@@ -257,7 +257,7 @@ mod parser_unit_tests {
 
         assert!(result.is_ok(), "Should resolve valid reference");
         let schema = result.unwrap();
-        assert_eq!(schema.schema_type, Some("object".to_string()));
+        assert_eq!(schema.schema_type, Some("object".into()));
     }
 
     #[tokio::test]
@@ -1597,6 +1597,189 @@ mod error_handling_tests {
     }
 }
 
+/// External `$ref` Resolution Tests
+#[cfg(test)]
+mod external_reference_tests {
+    use super::*;
+
+    fn write_file(dir: &std::path::Path, name: &str, content: &str) -> std::path::PathBuf {
+        let path = dir.join(name);
+        fs::create_dir_all(path.parent().unwrap()).expect("Failed to create directory");
+        fs::write(&path, content).expect("Failed to write file");
+        path
+    }
+
+    const ROOT_SPEC: &str = r#"
+openapi: 3.0.3
+info:
+  title: Orders
+  version: 1.0.0
+paths: {}
+components:
+  schemas:
+    Order:
+      type: object
+      properties:
+        error:
+          $ref: './common/errors.yaml#/components/schemas/Error'
+        total:
+          $ref: './common/money.json#/Money'
+"#;
+
+    const ERRORS_DOC: &str = r#"
+components:
+  schemas:
+    Error:
+      type: object
+      properties:
+        code:
+          $ref: '#/components/schemas/ErrorCode'
+        amount:
+          $ref: 'money.json#/Money'
+    ErrorCode:
+      type: string
+    Unused:
+      type: object
+"#;
+
+    const MONEY_DOC: &str =
+        r#"{ "Money": { "type": "object", "properties": { "currency": { "type": "string" } } } }"#;
+
+    #[tokio::test]
+    async fn test_resolve_references_into_relative_files() {
+        let temp_dir = TempDir::new().expect("Failed to create temp directory");
+        let root = write_file(temp_dir.path(), "api/orders.yaml", ROOT_SPEC);
+        write_file(temp_dir.path(), "api/common/errors.yaml", ERRORS_DOC);
+        write_file(temp_dir.path(), "api/common/money.json", MONEY_DOC);
+
+        let mut parser = OpenAPIParser::new();
+        parser
+            .parse_file(&root)
+            .await
+            .expect("Should load referenced files");
+
+        let error_ref = OpenAPISchemaOrRef::Reference(OpenAPIReference {
+            reference: "./common/errors.yaml#/components/schemas/Error".to_string(),
+        });
+        let error = parser.resolve_schema(&error_ref).unwrap();
+        assert_eq!(error.reference_name, Some("Error".to_string()));
+
+        // Pointers inside the external document resolve against that document
+        let code = parser.resolve_schema(&error.properties["code"]).unwrap();
+        assert_eq!(code.schema_type, Some("string".into()));
+        assert_eq!(code.reference_name, Some("ErrorCode".to_string()));
+
+        let amount = parser.resolve_schema(&error.properties["amount"]).unwrap();
+        assert!(amount.properties.contains_key("currency"));
+    }
+
+    #[tokio::test]
+    async fn test_get_all_schemas_includes_referenced_external_schemas() {
+        let temp_dir = TempDir::new().expect("Failed to create temp directory");
+        let root = write_file(temp_dir.path(), "orders.yaml", ROOT_SPEC);
+        write_file(temp_dir.path(), "common/errors.yaml", ERRORS_DOC);
+        write_file(temp_dir.path(), "common/money.json", MONEY_DOC);
+
+        let mut parser = OpenAPIParser::new();
+        parser.parse_file(&root).await.unwrap();
+
+        let mut names: Vec<String> = parser
+            .get_all_schemas()
+            .unwrap()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+        names.sort();
+
+        // Only schemas reachable from the parsed document are included
+        assert_eq!(names, ["Error", "ErrorCode", "Money", "Order"]);
+    }
+
+    #[tokio::test]
+    async fn test_cross_file_reference_cycle_is_detected() {
+        let temp_dir = TempDir::new().expect("Failed to create temp directory");
+        let root = write_file(
+            temp_dir.path(),
+            "api.yaml",
+            r#"
+openapi: 3.0.3
+info:
+  title: Cycle
+  version: 1.0.0
+paths: {}
+components:
+  schemas:
+    Start:
+      $ref: './a.yaml#/A'
+"#,
+        );
+        write_file(temp_dir.path(), "a.yaml", "A:\n  $ref: './b.yaml#/B'\n");
+        write_file(temp_dir.path(), "b.yaml", "B:\n  $ref: './a.yaml#/A'\n");
+
+        let mut parser = OpenAPIParser::new();
+        parser
+            .parse_file(&root)
+            .await
+            .expect("Loading cyclic files should terminate");
+
+        let result = parser.resolve_reference("#/components/schemas/Start");
+        assert!(matches!(
+            result,
+            Err(openapi_codegen_rust::errors::OpenAPIError::CircularReference { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_missing_external_file_and_pointer() {
+        let temp_dir = TempDir::new().expect("Failed to create temp directory");
+        let spec = |reference: &str| {
+            format!(
+                "openapi: 3.0.3\ninfo:\n  title: T\n  version: '1'\npaths: {{}}\ncomponents:\n  schemas:\n    A:\n      $ref: '{reference}'\n"
+            )
+        };
+        write_file(temp_dir.path(), "common.yaml", "Present:\n  type: string\n");
+
+        let missing_file = write_file(
+            temp_dir.path(),
+            "missing-file.yaml",
+            &spec("./nope.yaml#/X"),
+        );
+        let error = OpenAPIParser::new()
+            .parse_file(&missing_file)
+            .await
+            .map(|_| ())
+            .unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            openapi_codegen_rust::errors::OpenAPIError::FileNotFound { .. }
+        ));
+        // Both errors point at the `$ref` that needs fixing
+        let context = error.context().expect("Error should have a location");
+        assert_eq!((context.line, context.column), (Some(9), Some(7)));
+
+        let missing_pointer = write_file(
+            temp_dir.path(),
+            "missing-pointer.yaml",
+            &spec("./common.yaml#/Absent"),
+        );
+        let error = OpenAPIParser::new()
+            .parse_file(&missing_pointer)
+            .await
+            .map(|_| ())
+            .unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            openapi_codegen_rust::errors::OpenAPIError::ReferenceNotFound { .. }
+        ));
+        let context = error.context().expect("Error should have a location");
+        assert_eq!(
+            context.file.as_deref(),
+            Some(missing_pointer.display().to_string().as_str())
+        );
+        assert_eq!((context.line, context.column), (Some(9), Some(7)));
+    }
+}
+
 /// Integration Tests for Full Parsing Workflow
 #[cfg(test)]
 mod integration_tests {
@@ -1956,7 +2139,7 @@ mod integration_tests {
         assert!(pet_schema_result.is_ok(), "Should resolve Pet schema");
 
         let pet_schema = pet_schema_result.unwrap();
-        assert_eq!(pet_schema.schema_type, Some("object".to_string()));
+        assert_eq!(pet_schema.schema_type, Some("object".into()));
         assert!(
             !pet_schema.properties.is_empty(),
             "Pet schema should have properties"
@@ -2071,3 +2254,600 @@ mod integration_tests {
         assert!(!schemas.is_empty(), "Should still have schemas available");
     }
 }
+
+/// Tests for resolving `$ref`s to parameters, request bodies, responses and
+/// the other referenceable `components` sections.
+#[cfg(test)]
+mod component_reference_tests {
+    use super::*;
+
+    const COMPONENTS_SPEC: &str = r#"
+openapi: 3.0.3
+info:
+  title: Shared Components
+  version: 1.0.0
+paths:
+  /orders:
+    $ref: '#/components/pathItems/Orders'
+components:
+  pathItems:
+    Orders:
+      get:
+        tags: [orders]
+        parameters:
+          - $ref: '#/components/parameters/Limit'
+        responses:
+          '200':
+            $ref: '#/components/responses/OrderList'
+          '404':
+            $ref: '#/components/responses/NotFound'
+      post:
+        tags: [orders]
+        requestBody:
+          $ref: '#/components/requestBodies/NewOrder'
+        responses:
+          '201':
+            description: Created
+        callbacks:
+          onShipped:
+            $ref: '#/components/callbacks/Shipped'
+  parameters:
+    Limit:
+      $ref: '#/components/parameters/PageSize'
+    PageSize:
+      name: limit
+      in: query
+      schema:
+        type: integer
+    Loop:
+      $ref: '#/components/parameters/Loop'
+  requestBodies:
+    NewOrder:
+      required: true
+      content:
+        application/json:
+          schema:
+            type: object
+  responses:
+    OrderList:
+      description: A page of orders
+      headers:
+        X-Total-Count:
+          $ref: '#/components/headers/TotalCount'
+      links:
+        next:
+          $ref: '#/components/links/NextPage'
+      content:
+        application/json:
+          examples:
+            empty:
+              $ref: '#/components/examples/Empty'
+    NotFound:
+      description: Not found
+  headers:
+    TotalCount:
+      description: Total number of orders
+      schema:
+        type: integer
+  examples:
+    Empty:
+      summary: No orders
+      value: []
+  links:
+    NextPage:
+      operationId: listOrders
+  callbacks:
+    Shipped:
+      '{$request.body#/callbackUrl}':
+        post:
+          responses:
+            '200':
+              description: OK
+"#;
+
+    async fn parse_components_spec() -> (TempDir, OpenAPIParser) {
+        let temp_dir = TempDir::new().expect("Failed to create temp directory");
+        let path = temp_dir.path().join("api.yaml");
+        fs::write(&path, COMPONENTS_SPEC).expect("Failed to write file");
+
+        let mut parser = OpenAPIParser::new();
+        parser
+            .parse_file(&path)
+            .await
+            .expect("Should parse spec with shared components");
+        (temp_dir, parser)
+    }
+
+    fn orders_path_item(parser: &OpenAPIParser) -> &OpenAPIPathItem {
+        let path_item = &parser.get_spec().paths["/orders"];
+        parser
+            .resolve_component(path_item)
+            .expect("Should resolve path item")
+    }
+
+    #[tokio::test]
+    async fn test_resolve_parameter_reference_chain() {
+        let (_temp_dir, parser) = parse_components_spec().await;
+        let get = orders_path_item(&parser).get.as_ref().unwrap();
+
+        let parameter = parser.resolve_component(&get.parameters[0]).unwrap();
+        assert_eq!(parameter.name, "limit");
+        assert_eq!(parameter.location, "query");
+    }
+
+    #[tokio::test]
+    async fn test_resolve_responses_headers_links_and_examples() {
+        let (_temp_dir, parser) = parse_components_spec().await;
+        let get = orders_path_item(&parser).get.as_ref().unwrap();
+
+        let response = parser.resolve_component(&get.responses["200"]).unwrap();
+        assert_eq!(response.description, "A page of orders");
+
+        let header = parser
+            .resolve_component(&response.headers["X-Total-Count"])
+            .unwrap();
+        assert_eq!(
+            header.description,
+            Some("Total number of orders".to_string())
+        );
+
+        let link = parser.resolve_component(&response.links["next"]).unwrap();
+        assert_eq!(link.operation_id, Some("listOrders".to_string()));
+
+        let media_type = &response.content["application/json"];
+        let example = parser
+            .resolve_component(&media_type.examples["empty"])
+            .unwrap();
+        assert_eq!(example.summary, Some("No orders".to_string()));
+
+        let not_found = parser.resolve_component(&get.responses["404"]).unwrap();
+        assert_eq!(not_found.description, "Not found");
+    }
+
+    #[tokio::test]
+    async fn test_resolve_request_body_and_callback_references() {
+        let (_temp_dir, parser) = parse_components_spec().await;
+        let post = orders_path_item(&parser).post.as_ref().unwrap();
+
+        let request_body = parser
+            .resolve_component(post.request_body.as_ref().unwrap())
+            .unwrap();
+        assert!(request_body.required);
+        assert!(request_body.content.contains_key("application/json"));
+
+        let callback = parser
+            .resolve_component(&post.callbacks["onShipped"])
+            .unwrap();
+        assert!(callback.contains_key("{$request.body#/callbackUrl}"));
+    }
+
+    #[tokio::test]
+    async fn test_operations_of_referenced_path_items_are_grouped() {
+        let (_temp_dir, parser) = parse_components_spec().await;
+
+        let operations = parser.get_operations_by_tag().unwrap();
+        let methods: Vec<&str> = operations["orders"]
+            .iter()
+            .map(|(_, method, _)| method.as_str())
+            .collect();
+        assert_eq!(methods, vec!["get", "post"]);
+        assert_eq!(parser.get_all_tags(), vec!["orders".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_component_reference_errors() {
+        let (_temp_dir, parser) = parse_components_spec().await;
+
+        let circular = OpenAPIParameterOrRef::Reference(OpenAPIReference {
+            reference: "#/components/parameters/Loop".to_string(),
+        });
+        assert!(matches!(
+            parser.resolve_component(&circular),
+            Err(openapi_codegen_rust::errors::OpenAPIError::CircularReference { .. })
+        ));
+
+        let missing = OpenAPIResponseOrRef::Reference(OpenAPIReference {
+            reference: "#/components/responses/Missing".to_string(),
+        });
+        assert!(matches!(
+            parser.resolve_component(&missing),
+            Err(openapi_codegen_rust::errors::OpenAPIError::ReferenceNotFound { .. })
+        ));
+
+        // A reference must point into the section matching the object's kind
+        let wrong_section = OpenAPIParameterOrRef::Reference(OpenAPIReference {
+            reference: "#/components/responses/NotFound".to_string(),
+        });
+        assert!(matches!(
+            parser.resolve_component(&wrong_section),
+            Err(openapi_codegen_rust::errors::OpenAPIError::ReferenceNotFound { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_reference_below_a_component() {
+        let temp_dir = TempDir::new().expect("Failed to create temp directory");
+        let path = temp_dir.path().join("api.yaml");
+        fs::write(
+            &path,
+            r#"
+openapi: 3.0.3
+info:
+  title: Nested Pointers
+  version: 1.0.0
+paths: {}
+components:
+  schemas:
+    Contact:
+      type: object
+      properties:
+        address:
+          type: object
+          properties:
+            city:
+              type: string
+    Driver:
+      type: object
+      properties:
+        address:
+          $ref: '#/components/schemas/Contact/properties/address'
+"#,
+        )
+        .expect("Failed to write file");
+
+        let mut parser = OpenAPIParser::new();
+        parser
+            .parse_file(&path)
+            .await
+            .expect("Should parse spec with nested pointers");
+
+        let address = parser
+            .resolve_reference("#/components/schemas/Contact/properties/address")
+            .unwrap();
+        assert!(address.properties.contains_key("city"));
+    }
+
+    #[tokio::test]
+    async fn test_parse_openapi_3_1_document() {
+        let temp_dir = TempDir::new().expect("Failed to create temp directory");
+        let path = temp_dir.path().join("api.yaml");
+        fs::write(
+            &path,
+            r#"
+openapi: 3.1.0
+info:
+  title: Shelter
+  version: 1.0.0
+webhooks:
+  adopted:
+    post:
+      requestBody:
+        content:
+          application/json:
+            schema:
+              $ref: '#/components/schemas/Pet'
+      responses:
+        '200':
+          description: Received
+components:
+  schemas:
+    Pet:
+      $id: https://example.com/schemas/pet
+      type: object
+      properties:
+        nickname:
+          type: [string, 'null']
+        owner:
+          anyOf:
+            - $ref: owner
+            - type: 'null'
+        street:
+          $ref: '#/$defs/Street'
+      $defs:
+        Street:
+          type: string
+    Owner:
+      $id: https://example.com/schemas/owner
+      type: object
+      properties:
+        name:
+          type: string
+"#,
+        )
+        .expect("Failed to write file");
+
+        let mut parser = OpenAPIParser::new();
+        parser
+            .parse_file(&path)
+            .await
+            .expect("Should parse an OpenAPI 3.1 document without paths");
+        assert!(parser.get_spec().webhooks.contains_key("adopted"));
+
+        let pet = parser
+            .resolve_reference("#/components/schemas/Pet")
+            .unwrap();
+        let nickname = parser.resolve_schema(&pet.properties["nickname"]).unwrap();
+        assert_eq!(nickname.primary_type(), Some("string"));
+        assert!(nickname.is_nullable());
+
+        // `anyOf` with `null` makes the `$id`-relative reference nullable
+        let owner = parser.resolve_schema(&pet.properties["owner"]).unwrap();
+        assert_eq!(owner.reference_name, Some("Owner".to_string()));
+        assert!(owner.is_nullable());
+
+        // `#/$defs/...` resolves against the schema with the `$id`
+        let street = parser.resolve_schema(&pet.properties["street"]).unwrap();
+        assert_eq!(street.schema_type, Some("string".into()));
+        assert_eq!(street.reference_name, Some("Street".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_parse_swagger_2_document() {
+        let temp_dir = TempDir::new().expect("Failed to create temp directory");
+        let path = temp_dir.path().join("swagger.yaml");
+        fs::write(
+            &path,
+            r#"
+swagger: '2.0'
+info:
+  title: Legacy Inventory
+  version: 1.0.0
+host: inventory.example.com
+basePath: /api
+paths:
+  /items:
+    post:
+      operationId: createItem
+      parameters:
+        - in: body
+          name: item
+          required: true
+          schema:
+            $ref: '#/definitions/Item'
+      responses:
+        '201':
+          description: Created
+          schema:
+            $ref: '#/definitions/Item'
+definitions:
+  Item:
+    type: object
+    properties:
+      sku:
+        type: string
+"#,
+        )
+        .expect("Failed to write file");
+
+        let mut parser = OpenAPIParser::new();
+        let spec = parser
+            .parse_file(&path)
+            .await
+            .expect("Should upconvert a Swagger 2.0 document");
+        assert!(spec.openapi.starts_with("3.0"));
+        assert_eq!(spec.servers[0].url, "https://inventory.example.com/api");
+
+        let post = spec.paths["/items"].post.clone().unwrap();
+        let request_body = parser
+            .resolve_component(post.request_body.as_ref().unwrap())
+            .unwrap();
+        assert!(request_body.required);
+        let item = parser
+            .resolve_schema(
+                request_body.content["application/json"]
+                    .schema
+                    .as_ref()
+                    .unwrap(),
+            )
+            .unwrap();
+        assert_eq!(item.reference_name, Some("Item".to_string()));
+        assert!(item.properties.contains_key("sku"));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_components_from_external_files() {
+        let temp_dir = TempDir::new().expect("Failed to create temp directory");
+        let root = temp_dir.path().join("api.yaml");
+        fs::write(
+            &root,
+            r#"
+openapi: 3.0.3
+info:
+  title: External Components
+  version: 1.0.0
+paths:
+  /orders:
+    get:
+      parameters:
+        - $ref: 'common.yaml#/components/parameters/Cursor'
+      responses:
+        default:
+          $ref: 'common.yaml#/components/responses/Error'
+"#,
+        )
+        .expect("Failed to write file");
+        fs::write(
+            temp_dir.path().join("common.yaml"),
+            r#"
+components:
+  parameters:
+    Cursor:
+      name: cursor
+      in: query
+      schema:
+        $ref: '#/components/schemas/Cursor'
+  responses:
+    Error:
+      description: Unexpected error
+      content:
+        application/json:
+          schema:
+            $ref: '#/components/schemas/Problem'
+  schemas:
+    Cursor:
+      type: string
+    Problem:
+      type: object
+      properties:
+        title:
+          type: string
+"#,
+        )
+        .expect("Failed to write file");
+
+        let mut parser = OpenAPIParser::new();
+        parser
+            .parse_file(&root)
+            .await
+            .expect("Should load referenced components");
+
+        let get = parser.get_spec().paths["/orders"].get.as_ref().unwrap();
+        let parameter = parser.resolve_component(&get.parameters[0]).unwrap();
+        assert_eq!(parameter.name, "cursor");
+        let cursor = parser
+            .resolve_schema(parameter.schema.as_ref().unwrap())
+            .unwrap();
+        assert_eq!(cursor.schema_type, Some("string".into()));
+
+        let response = parser.resolve_component(&get.responses["default"]).unwrap();
+        assert_eq!(response.description, "Unexpected error");
+        let problem = parser
+            .resolve_schema(
+                response.content["application/json"]
+                    .schema
+                    .as_ref()
+                    .unwrap(),
+            )
+            .unwrap();
+        assert_eq!(problem.reference_name, Some("Problem".to_string()));
+    }
+}
+
+/// Tests for merging path-item level parameters into operations.
+#[cfg(test)]
+mod path_parameter_tests {
+    use super::*;
+
+    const PATH_PARAMETERS_SPEC: &str = r#"
+openapi: 3.0.3
+info:
+  title: Path Parameters
+  version: 1.0.0
+paths:
+  /users/{userId}:
+    parameters:
+      - name: userId
+        in: path
+        required: true
+        schema:
+          type: string
+      - $ref: '#/components/parameters/Verbose'
+    get:
+      parameters:
+        - name: verbose
+          in: query
+          description: Overridden by the operation
+          schema:
+            type: boolean
+        - name: verbose
+          in: header
+          schema:
+            type: boolean
+      responses:
+        '200':
+          description: OK
+    delete:
+      responses:
+        '204':
+          description: Deleted
+  /orders/{orderId}:
+    get:
+      responses:
+        '200':
+          description: OK
+components:
+  parameters:
+    Verbose:
+      name: verbose
+      in: query
+      schema:
+        type: string
+"#;
+
+    async fn parse_path_parameters_spec() -> (TempDir, OpenAPIParser) {
+        let temp_dir = TempDir::new().expect("Failed to create temp directory");
+        let path = temp_dir.path().join("api.yaml");
+        fs::write(&path, PATH_PARAMETERS_SPEC).expect("Failed to write file");
+
+        let mut parser = OpenAPIParser::new();
+        parser
+            .parse_file(&path)
+            .await
+            .expect("Should parse spec with path parameters");
+        (temp_dir, parser)
+    }
+
+    #[tokio::test]
+    async fn test_path_level_parameters_are_inherited() {
+        let (_temp_dir, parser) = parse_path_parameters_spec().await;
+        let delete = parser.get_spec().paths["/users/{userId}"]
+            .delete
+            .as_ref()
+            .unwrap();
+
+        let parameters = parser
+            .get_operation_parameters("/users/{userId}", delete)
+            .unwrap();
+        let names: Vec<(&str, &str)> = parameters
+            .iter()
+            .map(|param| (param.name.as_str(), param.location.as_str()))
+            .collect();
+        assert_eq!(names, vec![("userId", "path"), ("verbose", "query")]);
+    }
+
+    #[tokio::test]
+    async fn test_operation_parameters_override_by_name_and_location() {
+        let (_temp_dir, parser) = parse_path_parameters_spec().await;
+        let get = parser.get_spec().paths["/users/{userId}"]
+            .get
+            .as_ref()
+            .unwrap();
+
+        let parameters = parser
+            .get_operation_parameters("/users/{userId}", get)
+            .unwrap();
+        assert_eq!(parameters.len(), 3);
+
+        // The query parameter keeps its position but takes the operation's definition
+        assert_eq!(parameters[1].name, "verbose");
+        assert_eq!(parameters[1].location, "query");
+        assert_eq!(
+            parameters[1].description,
+            Some("Overridden by the operation".to_string())
+        );
+
+        // Same name in another location is a different parameter
+        assert_eq!(parameters[2].name, "verbose");
+        assert_eq!(parameters[2].location, "header");
+    }
+
+    #[tokio::test]
+    async fn test_missing_path_parameter_is_reported() {
+        let (_temp_dir, parser) = parse_path_parameters_spec().await;
+        let get = parser.get_spec().paths["/orders/{orderId}"]
+            .get
+            .as_ref()
+            .unwrap();
+
+        match parser.get_operation_parameters("/orders/{orderId}", get) {
+            Err(openapi_codegen_rust::errors::OpenAPIError::MissingPathParameter {
+                parameter,
+                path,
+            }) => {
+                assert_eq!(parameter, "orderId");
+                assert_eq!(path, "/orders/{orderId}");
+            }
+            other => panic!("Expected MissingPathParameter error, got {other:?}"),
+        }
+    }
+}